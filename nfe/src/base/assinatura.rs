@@ -0,0 +1,459 @@
+//! Assinatura digital(XMLDSig) dos documentos
+
+use super::c14n::{self, Canonicalizacao, Seletor};
+use super::certificado::{Certificado, CertificadoA1};
+use super::documento::{Cnpj, Documento};
+use super::Error;
+use chrono::prelude::*;
+use openssl::base64;
+use openssl::hash::MessageDigest;
+use openssl::sha::sha1;
+use openssl::sign::Verifier;
+use openssl::x509::store::X509StoreRef;
+use serde::Deserialize;
+use std::fmt;
+use std::str::FromStr;
+
+/// Namespace das assinaturas XMLDSig
+pub const NAMESPACE_XMLDSIG: &str = "http://www.w3.org/2000/09/xmldsig#";
+/// Canonicalização C14N, sem comentários
+pub const ALGORITMO_C14N: &str = "http://www.w3.org/TR/2001/REC-xml-c14n-20010315";
+/// Transformação que remove a própria assinatura do elemento assinado
+pub const ALGORITMO_ENVELOPED: &str = "http://www.w3.org/2000/09/xmldsig#enveloped-signature";
+/// Assinatura RSA com SHA1
+pub const ALGORITMO_RSA_SHA1: &str = "http://www.w3.org/2000/09/xmldsig#rsa-sha1";
+/// Digest SHA1
+pub const ALGORITMO_SHA1: &str = "http://www.w3.org/2000/09/xmldsig#sha1";
+
+/// Assinatura digital do documento, a tag <Signature>
+///
+/// Mantém o XML original da tag, que é reescrito sem alterações
+/// ao serializar o documento.
+#[derive(Debug, Clone)]
+pub struct Assinatura {
+    xml: String,
+    metodo_canonicalizacao: String,
+    metodo_assinatura: String,
+    referencia: String,
+    transformacoes: Vec<String>,
+    metodo_digest: String,
+    digest: String,
+    valor: String,
+    certificado: String,
+    assinado: Option<ElementoAssinado>,
+}
+
+/// Elemento assinado como lido do XML original ou gerado na assinatura
+///
+/// O modelo não cobre todos os grupos do documento, então o elemento
+/// reescrito a partir dele não confere com o DigestValue. Enquanto o
+/// documento não for alterado, o XML assinado é reescrito sem alterações.
+#[derive(Debug, Clone)]
+struct ElementoAssinado {
+    /// Trecho do documento até o fechamento do elemento assinado
+    original: String,
+    /// XML gerado pelo modelo, sem a assinatura, no momento da leitura
+    gerado: String,
+}
+
+impl Assinatura {
+    /// XML original da tag <Signature>
+    pub fn xml(&self) -> &str {
+        &self.xml
+    }
+
+    /// Algoritmo de canonicalização do SignedInfo
+    pub fn metodo_canonicalizacao(&self) -> &str {
+        &self.metodo_canonicalizacao
+    }
+
+    /// Algoritmo da assinatura. Ex.: RSA-SHA1
+    pub fn metodo_assinatura(&self) -> &str {
+        &self.metodo_assinatura
+    }
+
+    /// URI do elemento assinado. Ex.: #NFe4318...
+    pub fn referencia(&self) -> &str {
+        &self.referencia
+    }
+
+    /// Algoritmos das transformações aplicadas ao elemento assinado
+    pub fn transformacoes(&self) -> &[String] {
+        &self.transformacoes
+    }
+
+    /// Algoritmo do digest. Ex.: SHA1
+    pub fn metodo_digest(&self) -> &str {
+        &self.metodo_digest
+    }
+
+    /// DigestValue, em base64
+    pub fn digest(&self) -> &str {
+        &self.digest
+    }
+
+    /// SignatureValue, em base64 e sem quebras de linha
+    pub fn valor(&self) -> &str {
+        &self.valor
+    }
+
+    /// Certificado do assinante em base64, sem quebras de linha
+    pub fn certificado_base64(&self) -> &str {
+        &self.certificado
+    }
+
+    /// Certificado do assinante
+    pub fn certificado(&self) -> Result<Certificado, Error> {
+        Certificado::from_base64(&self.certificado)
+    }
+
+    /// Guarda o trecho assinado original e o XML gerado pelo modelo a partir dele
+    pub(crate) fn com_original(mut self, original: &str, gerado: String) -> Self {
+        self.assinado = Some(ElementoAssinado {
+            original: original.to_string(),
+            gerado,
+        });
+
+        self
+    }
+
+    /// Trecho assinado original, se o documento gerado ainda for o mesmo da leitura
+    pub(crate) fn original(&self, gerado: &str) -> Option<&str> {
+        self.assinado
+            .as_ref()
+            .filter(|a| a.gerado == gerado)
+            .map(|a| a.original.as_str())
+    }
+
+    /// Verifica a assinatura do elemento no XML original do documento
+    ///
+    /// O `documento` é o do emitente/autor do documento e o `horario`, o da
+    /// sua emissão, quando o certificado precisava estar válido.
+    pub(crate) fn verificar(
+        &self,
+        xml: &str,
+        id: &str,
+        documento: &Documento,
+        horario: DateTime<Utc>,
+        confiaveis: Option<&X509StoreRef>,
+    ) -> Result<VerificacaoAssinatura, Error> {
+        let nao_suportado = || {
+            Error::AssinaturaInvalida(format!(
+                "Algoritmos não suportados: {}, {} e {}",
+                self.metodo_canonicalizacao, self.metodo_assinatura, self.metodo_digest
+            ))
+        };
+
+        if self.metodo_assinatura != ALGORITMO_RSA_SHA1 || self.metodo_digest != ALGORITMO_SHA1 {
+            return Err(nao_suportado());
+        }
+
+        let metodo_signed_info = Canonicalizacao::from_algoritmo(&self.metodo_canonicalizacao)
+            .ok_or_else(nao_suportado)?;
+        // A canonicalização do elemento é a última transformação informada
+        let metodo_elemento = self
+            .transformacoes
+            .iter()
+            .rev()
+            .find_map(|t| Canonicalizacao::from_algoritmo(t))
+            .unwrap_or(Canonicalizacao::Inclusiva);
+
+        let certificado = self.certificado()?;
+
+        let canonico = c14n::canonicalizar_com(xml, Seletor::Id(id), metodo_elemento)?;
+        let digest_calculado = base64::encode_block(&sha1(canonico.as_bytes()));
+
+        let signed_info =
+            c14n::canonicalizar_com(&self.xml, Seletor::Tag("SignedInfo"), metodo_signed_info)?;
+        let assinatura_valida = base64::decode_block(&self.valor)
+            .ok()
+            .and_then(|valor| {
+                let chave = certificado.x509().public_key().ok()?;
+                let mut verifier = Verifier::new(MessageDigest::sha1(), &chave).ok()?;
+                verifier.verify_oneshot(&valor, signed_info.as_bytes()).ok()
+            })
+            .unwrap_or(false);
+
+        let cadeia_verificada = match confiaveis {
+            Some(confiaveis) => certificado.cadeia_valida(confiaveis)?,
+            None => false,
+        };
+        let cnpj_certificado = certificado.cnpj();
+        let validade_certificado = (certificado.validade_inicio()?, certificado.validade_fim()?);
+
+        Ok(VerificacaoAssinatura {
+            referencia_valida: self.referencia == format!("#{}", id),
+            digest_valido: digest_calculado == self.digest,
+            digest_calculado,
+            assinatura_valida,
+            cnpj_valido: match (documento, &cnpj_certificado) {
+                (Documento::Cnpj(cnpj), Some(c)) => c.raiz() == cnpj.raiz(),
+                (Documento::Cnpj(_), None) => false,
+                // Pessoa física assina com e-CPF, que não traz CNPJ
+                (Documento::Cpf(_), certificado) => certificado.is_none(),
+                // Quem assina é o emitente, sempre identificado no Brasil
+                (Documento::Estrangeiro(_), _) => false,
+            },
+            cnpj_certificado,
+            certificado_vigente: horario >= validade_certificado.0
+                && horario <= validade_certificado.1,
+            validade_certificado,
+            cadeia_verificada,
+        })
+    }
+}
+
+/// Resultado da verificação da assinatura de um documento recebido
+#[derive(Debug, PartialEq, Clone)]
+pub struct VerificacaoAssinatura {
+    /// A assinatura referencia o Id do elemento assinado
+    pub referencia_valida: bool,
+    /// Digest do elemento canonicalizado, em base64
+    pub digest_calculado: String,
+    /// O DigestValue confere com o digest calculado
+    pub digest_valido: bool,
+    /// O SignatureValue confere com a chave pública do certificado
+    pub assinatura_valida: bool,
+    /// CNPJ do titular do certificado, informado pelo próprio certificado
+    pub cnpj_certificado: Option<Cnpj>,
+    /// A raiz do CNPJ do certificado é a mesma do emitente. Para emitente
+    /// pessoa física, indica que o certificado não é de uma empresa
+    pub cnpj_valido: bool,
+    /// Início e fim da validade do certificado
+    pub validade_certificado: (DateTime<Utc>, DateTime<Utc>),
+    /// O certificado estava válido na emissão do documento
+    pub certificado_vigente: bool,
+    /// A cadeia do certificado foi conferida até uma autoridade confiável.
+    /// Falso quando não verificada: sem ela, o titular e o CNPJ do
+    /// certificado não são garantidos
+    pub cadeia_verificada: bool,
+}
+
+impl VerificacaoAssinatura {
+    /// Indica se todas as verificações da assinatura foram atendidas
+    ///
+    /// Não considera a cadeia do certificado, logo não é uma decisão de
+    /// confiança no assinante. Para isso, ver [`VerificacaoAssinatura::confiavel`].
+    pub fn valida(&self) -> bool {
+        self.problemas().is_empty()
+    }
+
+    /// Indica se a assinatura é válida e o certificado foi emitido por uma
+    /// autoridade confiável
+    pub fn confiavel(&self) -> bool {
+        self.valida() && self.cadeia_verificada
+    }
+
+    /// Descrição das verificações não atendidas
+    pub fn problemas(&self) -> Vec<String> {
+        let mut problemas = vec![];
+
+        if !self.referencia_valida {
+            problemas.push("Referência da assinatura difere do Id do documento".to_string());
+        }
+
+        if !self.digest_valido {
+            problemas.push(format!(
+                "DigestValue não confere com o documento. Calculado: {}",
+                self.digest_calculado
+            ));
+        }
+
+        if !self.assinatura_valida {
+            problemas.push("SignatureValue não confere com o certificado".to_string());
+        }
+
+        if !self.cnpj_valido {
+            problemas.push(match &self.cnpj_certificado {
+                Some(cnpj) => format!("CNPJ do certificado({}) de outra empresa", cnpj),
+                None => "Certificado sem CNPJ".to_string(),
+            });
+        }
+
+        if !self.certificado_vigente {
+            problemas.push(format!(
+                "Certificado fora da validade na emissão: {} a {}",
+                self.validade_certificado.0, self.validade_certificado.1
+            ));
+        }
+
+        problemas
+    }
+}
+
+/// Extrai a assinatura que segue o elemento assinado
+///
+/// Ex.: para a NF-e, a tag <Signature> após o </infNFe>
+pub(crate) fn extrair(xml: &str, fim_assinado: &str) -> Result<Option<Assinatura>, Error> {
+    let apos = match xml.find(fim_assinado) {
+        Some(pos) => pos + fim_assinado.len(),
+        None => return Ok(None),
+    };
+
+    let inicio = match xml[apos..].find("<Signature") {
+        Some(pos) => apos + pos,
+        None => return Ok(None),
+    };
+
+    // A assinatura deve ser a próxima tag após o elemento assinado
+    if !xml[apos..inicio].trim().is_empty() {
+        return Ok(None);
+    }
+
+    let fim = xml[inicio..]
+        .find("</Signature>")
+        .map(|pos| inicio + pos + "</Signature>".len())
+        .ok_or_else(|| Error::AssinaturaInvalida("Tag </Signature> não encontrada".to_string()))?;
+
+    xml[inicio..fim].parse().map(Some)
+}
+
+/// Assina o elemento, gerando a tag <Signature> no padrão da SEFAZ
+///
+/// Assinatura enveloped, com canonicalização C14N, RSA-SHA1 e referência
+/// ao Id do elemento assinado.
+pub(crate) fn assinar(
+    xml: &str,
+    id: &str,
+    certificado: &CertificadoA1,
+) -> Result<Assinatura, Error> {
+    let canonico = c14n::canonicalizar_com(xml, Seletor::Id(id), Canonicalizacao::Inclusiva)?;
+    let digest = base64::encode_block(&sha1(canonico.as_bytes()));
+
+    let conteudo = format!(
+        "<CanonicalizationMethod Algorithm=\"{c14n}\"></CanonicalizationMethod>\
+         <SignatureMethod Algorithm=\"{rsa}\"></SignatureMethod>\
+         <Reference URI=\"#{id}\">\
+         <Transforms>\
+         <Transform Algorithm=\"{enveloped}\"></Transform>\
+         <Transform Algorithm=\"{c14n}\"></Transform>\
+         </Transforms>\
+         <DigestMethod Algorithm=\"{sha1}\"></DigestMethod>\
+         <DigestValue>{digest}</DigestValue>\
+         </Reference>",
+        c14n = ALGORITMO_C14N,
+        rsa = ALGORITMO_RSA_SHA1,
+        id = id,
+        enveloped = ALGORITMO_ENVELOPED,
+        sha1 = ALGORITMO_SHA1,
+        digest = digest
+    );
+
+    // O SignedInfo é assinado na forma canônica, com o namespace herdado da <Signature>
+    let signed_info = format!(
+        "<SignedInfo xmlns=\"{}\">{}</SignedInfo>",
+        NAMESPACE_XMLDSIG, conteudo
+    );
+    let valor = base64::encode_block(&certificado.assinar(signed_info.as_bytes())?);
+
+    format!(
+        "<Signature xmlns=\"{}\"><SignedInfo>{}</SignedInfo>\
+         <SignatureValue>{}</SignatureValue>\
+         <KeyInfo><X509Data><X509Certificate>{}</X509Certificate></X509Data></KeyInfo>\
+         </Signature>",
+        NAMESPACE_XMLDSIG,
+        conteudo,
+        valor,
+        certificado.certificado().base64()?
+    )
+    .parse()
+}
+
+impl FromStr for Assinatura {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sig: SignatureContainer = quick_xml::de::from_str(s)?;
+        let referencia = sig.signed_info.referencia;
+
+        let sem_espacos = |s: String| s.split_whitespace().collect::<String>();
+
+        Ok(Self {
+            xml: s.to_string(),
+            metodo_canonicalizacao: sig.signed_info.canonicalizacao.algoritmo,
+            metodo_assinatura: sig.signed_info.assinatura.algoritmo,
+            referencia: referencia.uri,
+            transformacoes: referencia
+                .transformacoes
+                .transformacoes
+                .into_iter()
+                .map(|t| t.algoritmo)
+                .collect(),
+            metodo_digest: referencia.digest_metodo.algoritmo,
+            digest: sem_espacos(referencia.digest),
+            valor: sem_espacos(sig.valor),
+            certificado: sem_espacos(sig.key_info.dados.certificado),
+            assinado: None,
+        })
+    }
+}
+
+impl PartialEq for Assinatura {
+    /// Assinaturas iguais possuem o mesmo XML da tag <Signature>
+    fn eq(&self, other: &Self) -> bool {
+        self.xml == other.xml
+    }
+}
+
+impl fmt::Display for Assinatura {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.xml)
+    }
+}
+
+#[derive(Deserialize)]
+struct SignatureContainer {
+    #[serde(rename = "SignedInfo")]
+    pub signed_info: SignedInfoContainer,
+    #[serde(rename = "$unflatten=SignatureValue")]
+    pub valor: String,
+    #[serde(rename = "KeyInfo")]
+    pub key_info: KeyInfoContainer,
+}
+
+#[derive(Deserialize)]
+struct SignedInfoContainer {
+    #[serde(rename = "CanonicalizationMethod")]
+    pub canonicalizacao: AlgoritmoContainer,
+    #[serde(rename = "SignatureMethod")]
+    pub assinatura: AlgoritmoContainer,
+    #[serde(rename = "Reference")]
+    pub referencia: ReferenceContainer,
+}
+
+#[derive(Deserialize)]
+struct ReferenceContainer {
+    #[serde(rename = "URI")]
+    pub uri: String,
+    #[serde(rename = "Transforms")]
+    pub transformacoes: TransformsContainer,
+    #[serde(rename = "DigestMethod")]
+    pub digest_metodo: AlgoritmoContainer,
+    #[serde(rename = "$unflatten=DigestValue")]
+    pub digest: String,
+}
+
+#[derive(Deserialize)]
+struct TransformsContainer {
+    #[serde(rename = "Transform")]
+    #[serde(default)]
+    pub transformacoes: Vec<AlgoritmoContainer>,
+}
+
+#[derive(Deserialize)]
+struct AlgoritmoContainer {
+    #[serde(rename = "Algorithm")]
+    pub algoritmo: String,
+}
+
+#[derive(Deserialize)]
+struct KeyInfoContainer {
+    #[serde(rename = "X509Data")]
+    pub dados: X509DataContainer,
+}
+
+#[derive(Deserialize)]
+struct X509DataContainer {
+    #[serde(rename = "$unflatten=X509Certificate")]
+    pub certificado: String,
+}
@@ -0,0 +1,317 @@
+//! Lote de autorização da NF-e(enviNFe) e consulta do seu processamento
+//!
+//! Mensagens dos serviços NFeAutorizacao4 e NFeRetAutorizacao4, também
+//! usadas na leitura de retornos salvos.
+
+use super::c14n;
+use super::ide::TipoAmbiente;
+use super::protocolo::ProtocoloNfe;
+use super::{Error, Nfe, VersaoLayout, NAMESPACE_NFE};
+use chrono::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Quantidade máxima de notas por lote
+pub const MAXIMO_NOTAS_LOTE: usize = 50;
+
+/// Lote de notas enviado para autorização, a tag <enviNFe>
+#[derive(Debug, PartialEq)]
+pub struct EnvioLote {
+    /// Identificador do lote(idLote)
+    pub lote: u64,
+    /// Processamento síncrono(indSinc 1). Permitido apenas para uma nota
+    pub sincrono: bool,
+    pub notas: Vec<Nfe>,
+}
+
+/// Recibo do lote recebido para processamento assíncrono
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+pub struct Recibo {
+    /// Número do recibo, usado na consulta do processamento
+    #[serde(rename = "$unflatten=nRec")]
+    pub numero: String,
+    /// Tempo médio de resposta do serviço, em segundos
+    #[serde(rename = "$unflatten=tMed")]
+    pub tempo_medio: u32,
+}
+
+/// Resultado do envio do lote, a tag <retEnviNFe>
+#[derive(Debug, PartialEq, Clone)]
+pub struct RetornoEnvioLote {
+    pub ambiente: TipoAmbiente,
+    /// Versão do aplicativo que recebeu o lote
+    pub versao_aplicativo: String,
+    /// Código do status(cStat)
+    pub status: u16,
+    /// Descrição do status
+    pub motivo: String,
+    pub codigo_uf: u8,
+    /// Data e hora do recebimento
+    pub recebimento: DateTime<FixedOffset>,
+    /// Recibo do processamento assíncrono
+    pub recibo: Option<Recibo>,
+    /// Protocolo da nota no processamento síncrono
+    pub protocolo: Option<ProtocoloNfe>,
+}
+
+/// Consulta do processamento de um lote assíncrono, a tag <consReciNFe>
+#[derive(Debug, PartialEq, Clone)]
+pub struct ConsultaRecibo {
+    pub ambiente: TipoAmbiente,
+    /// Número do recibo do lote
+    pub recibo: String,
+}
+
+/// Resultado da consulta do processamento do lote, a tag <retConsReciNFe>
+#[derive(Debug, PartialEq, Clone)]
+pub struct RetornoConsultaRecibo {
+    pub ambiente: TipoAmbiente,
+    pub versao_aplicativo: String,
+    /// Número do recibo consultado
+    pub recibo: String,
+    /// Código do status(cStat)
+    pub status: u16,
+    pub motivo: String,
+    pub codigo_uf: u8,
+    pub recebimento: DateTime<FixedOffset>,
+    /// Protocolo de cada nota do lote processado
+    pub protocolos: Vec<ProtocoloNfe>,
+}
+
+impl EnvioLote {
+    /// Monta o lote, com 1 a 50 notas assinadas
+    ///
+    /// O processamento síncrono aceita apenas uma nota. O DigestValue de
+    /// cada assinatura é conferido com o infNFe que será enviado.
+    pub fn new(lote: u64, sincrono: bool, notas: Vec<Nfe>) -> Result<Self, Error> {
+        if notas.is_empty() || notas.len() > MAXIMO_NOTAS_LOTE {
+            return Err(Error::LoteInvalido(format!(
+                "O lote deve possuir de 1 a {} notas",
+                MAXIMO_NOTAS_LOTE
+            )));
+        }
+
+        if sincrono && notas.len() > 1 {
+            return Err(Error::LoteInvalido(
+                "O processamento síncrono aceita apenas uma nota".to_string(),
+            ));
+        }
+
+        for nfe in &notas {
+            let assinatura = nfe.assinatura.as_ref().ok_or_else(|| {
+                Error::LoteInvalido(format!("Nota sem assinatura: {}", nfe.chave_acesso))
+            })?;
+
+            if nfe.pagamento.is_none() {
+                return Err(Error::LoteInvalido(format!(
+                    "Nota sem o grupo de pagamento(pag): {}",
+                    nfe.chave_acesso
+                )));
+            }
+
+            let digest = c14n::digest(&nfe.to_string(), "infNFe")?;
+            if digest != assinatura.digest() {
+                return Err(Error::LoteInvalido(format!(
+                    "Assinatura não confere com a nota {}. DigestValue {} e calculado {}",
+                    nfe.chave_acesso,
+                    assinatura.digest(),
+                    digest
+                )));
+            }
+        }
+
+        Ok(Self {
+            lote,
+            sincrono,
+            notas,
+        })
+    }
+}
+
+impl RetornoEnvioLote {
+    /// Indica se o lote foi recebido para processamento assíncrono
+    pub fn recebido(&self) -> bool {
+        self.status == 103
+    }
+
+    /// Indica se o lote foi processado. O resultado da nota está no protocolo
+    pub fn processado(&self) -> bool {
+        self.status == 104
+    }
+}
+
+impl RetornoConsultaRecibo {
+    /// Indica se o lote foi processado. Cada nota possui o seu protocolo
+    pub fn processado(&self) -> bool {
+        self.status == 104
+    }
+
+    /// Indica se o lote ainda está em processamento e deve ser consultado novamente
+    pub fn em_processamento(&self) -> bool {
+        self.status == 105
+    }
+
+    /// Protocolo da nota com a chave de acesso informada
+    pub fn protocolo(&self, chave_acesso: &str) -> Option<&ProtocoloNfe> {
+        self.protocolos
+            .iter()
+            .find(|p| p.chave_acesso == chave_acesso)
+    }
+}
+
+impl fmt::Display for EnvioLote {
+    /// As notas são escritas pelo seu próprio Display, preservando a assinatura
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "<enviNFe xmlns=\"{}\" versao=\"{}\"><idLote>{}</idLote><indSinc>{}</indSinc>",
+            NAMESPACE_NFE,
+            VersaoLayout::V4_00.as_str(),
+            self.lote,
+            self.sincrono as u8
+        )?;
+
+        for nfe in &self.notas {
+            write!(f, "{}", nfe)?;
+        }
+
+        f.write_str("</enviNFe>")
+    }
+}
+
+impl fmt::Display for ConsultaRecibo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar a consulta");
+
+        f.write_str(&xml)
+    }
+}
+
+impl FromStr for RetornoEnvioLote {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl FromStr for RetornoConsultaRecibo {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl Serialize for ConsultaRecibo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let consulta = ConsReciNfeContainer {
+            namespace: NAMESPACE_NFE.to_string(),
+            versao: VersaoLayout::V4_00,
+            ambiente: self.ambiente,
+            recibo: self.recibo.clone(),
+        };
+
+        consulta.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for RetornoEnvioLote {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ret = RetEnviNfeContainer::deserialize(deserializer)?;
+
+        Ok(Self {
+            ambiente: ret.ambiente,
+            versao_aplicativo: ret.versao_aplicativo,
+            status: ret.status,
+            motivo: ret.motivo,
+            codigo_uf: ret.codigo_uf,
+            recebimento: ret.recebimento,
+            recibo: ret.recibo,
+            protocolo: ret.protocolo,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for RetornoConsultaRecibo {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ret = RetConsReciNfeContainer::deserialize(deserializer)?;
+
+        Ok(Self {
+            ambiente: ret.ambiente,
+            versao_aplicativo: ret.versao_aplicativo,
+            recibo: ret.recibo,
+            status: ret.status,
+            motivo: ret.motivo,
+            codigo_uf: ret.codigo_uf,
+            recebimento: ret.recebimento,
+            protocolos: ret.protocolos,
+        })
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename = "consReciNFe")]
+struct ConsReciNfeContainer {
+    #[serde(rename = "xmlns")]
+    pub namespace: String,
+    #[serde(rename = "versao")]
+    pub versao: VersaoLayout,
+    #[serde(rename = "$unflatten=tpAmb")]
+    pub ambiente: TipoAmbiente,
+    #[serde(rename = "$unflatten=nRec")]
+    pub recibo: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "retEnviNFe")]
+struct RetEnviNfeContainer {
+    #[serde(rename = "$unflatten=tpAmb")]
+    pub ambiente: TipoAmbiente,
+    #[serde(rename = "$unflatten=verAplic")]
+    pub versao_aplicativo: String,
+    #[serde(rename = "$unflatten=cStat")]
+    pub status: u16,
+    #[serde(rename = "$unflatten=xMotivo")]
+    pub motivo: String,
+    #[serde(rename = "$unflatten=cUF")]
+    pub codigo_uf: u8,
+    #[serde(rename = "$unflatten=dhRecbto")]
+    pub recebimento: DateTime<FixedOffset>,
+    #[serde(rename = "infRec")]
+    pub recibo: Option<Recibo>,
+    #[serde(rename = "protNFe")]
+    pub protocolo: Option<ProtocoloNfe>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "retConsReciNFe")]
+struct RetConsReciNfeContainer {
+    #[serde(rename = "$unflatten=tpAmb")]
+    pub ambiente: TipoAmbiente,
+    #[serde(rename = "$unflatten=verAplic")]
+    pub versao_aplicativo: String,
+    #[serde(rename = "$unflatten=nRec")]
+    pub recibo: String,
+    #[serde(rename = "$unflatten=cStat")]
+    pub status: u16,
+    #[serde(rename = "$unflatten=xMotivo")]
+    pub motivo: String,
+    #[serde(rename = "$unflatten=cUF")]
+    pub codigo_uf: u8,
+    #[serde(rename = "$unflatten=dhRecbto")]
+    pub recebimento: DateTime<FixedOffset>,
+    #[serde(rename = "protNFe")]
+    #[serde(default)]
+    pub protocolos: Vec<ProtocoloNfe>,
+}
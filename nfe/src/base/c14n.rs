@@ -0,0 +1,379 @@
+//! Canonicalização(C14N) do XML para assinatura digital
+//!
+//! Implementa a Canonical XML 1.0(REC-xml-c14n-20010315) e a Exclusive
+//! XML Canonicalization 1.0(xml-exc-c14n), ambas sem comentários, sobre
+//! um elemento do documento e seus descendentes. É a forma usada no
+//! digest e na assinatura do infNFe, infEvento e infInut.
+
+use super::assinatura::NAMESPACE_XMLDSIG;
+use super::Error;
+use openssl::base64;
+use openssl::sha::sha1;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::collections::BTreeMap;
+
+/// Namespaces declarados, por prefixo. O namespace padrão usa o prefixo vazio
+type Namespaces = BTreeMap<String, String>;
+
+/// Método de canonicalização
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Canonicalizacao {
+    /// Canonical XML 1.0, exigida pela SEFAZ
+    ///
+    /// Todos os namespaces em vigor nos ancestrais são escritos no elemento.
+    Inclusiva,
+    /// Exclusive XML Canonicalization 1.0
+    ///
+    /// Escreve somente os namespaces usados pelo elemento e seus atributos.
+    Exclusiva,
+}
+
+/// Elemento a ser canonicalizado
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Seletor<'a> {
+    /// Primeiro elemento com o nome local informado. Ex.: infNFe
+    Tag(&'a str),
+    /// Elemento com o atributo Id informado, como referenciado na assinatura
+    Id(&'a str),
+}
+
+impl Canonicalizacao {
+    /// URI do algoritmo, como informado na assinatura
+    pub fn algoritmo(&self) -> &'static str {
+        match self {
+            Canonicalizacao::Inclusiva => "http://www.w3.org/TR/2001/REC-xml-c14n-20010315",
+            Canonicalizacao::Exclusiva => "http://www.w3.org/2001/10/xml-exc-c14n#",
+        }
+    }
+
+    /// Método a partir da URI do algoritmo
+    pub fn from_algoritmo(algoritmo: &str) -> Option<Self> {
+        [Canonicalizacao::Inclusiva, Canonicalizacao::Exclusiva]
+            .iter()
+            .find(|c| c.algoritmo() == algoritmo)
+            .copied()
+    }
+}
+
+/// Forma canônica inclusiva do primeiro elemento com o nome informado
+///
+/// Ex.: `canonicalizar(xml, "infNFe")`
+pub fn canonicalizar(xml: &str, elemento: &str) -> Result<String, Error> {
+    canonicalizar_com(xml, Seletor::Tag(elemento), Canonicalizacao::Inclusiva)
+}
+
+/// Digest SHA1 da forma canônica inclusiva do elemento, em base64
+///
+/// É o valor do DigestValue da assinatura e do digVal do protocolo.
+pub fn digest(xml: &str, elemento: &str) -> Result<String, Error> {
+    Ok(base64::encode_block(&sha1(
+        canonicalizar(xml, elemento)?.as_bytes(),
+    )))
+}
+
+/// Nome local do elemento raiz do documento. Ex.: nfeProc
+pub(crate) fn elemento_raiz(xml: &str) -> Result<String, Error> {
+    let mut reader = Reader::from_str(xml);
+    let mut buf = Vec::new();
+
+    loop {
+        match reader
+            .read_event(&mut buf)
+            .map_err(|e| Error::XmlInvalido(e.to_string()))?
+        {
+            Event::Start(tag) | Event::Empty(tag) => {
+                return Ok(texto(tag.local_name())?.to_string())
+            }
+            Event::Eof => {
+                return Err(Error::XmlInvalido(
+                    "Documento sem elemento raiz".to_string(),
+                ))
+            }
+            _ => buf.clear(),
+        }
+    }
+}
+
+/// Forma canônica do elemento selecionado
+///
+/// Os namespaces declarados nos ancestrais são propagados ao elemento e as
+/// tags <Signature> descendentes são removidas(transformação enveloped-signature).
+pub fn canonicalizar_com(
+    xml: &str,
+    seletor: Seletor,
+    metodo: Canonicalizacao,
+) -> Result<String, Error> {
+    let mut reader = Reader::from_str(xml);
+    reader.expand_empty_elements(true).trim_text(false);
+
+    let invalido = |e: quick_xml::Error| Error::XmlInvalido(e.to_string());
+
+    let mut buf = Vec::new();
+    let mut saida = String::new();
+    // Namespaces declarados em cada elemento aberto
+    let mut escopo: Vec<Namespaces> = vec![];
+    // Namespaces já escritos na saída, por elemento aberto a partir do elemento buscado
+    let mut renderizados: Vec<Namespaces> = vec![];
+    // Profundidade da tag <Signature> sendo ignorada
+    let mut ignorando = 0;
+    let mut encontrado = false;
+
+    loop {
+        match reader.read_event(&mut buf).map_err(invalido)? {
+            Event::Start(tag) => {
+                escopo.push(declaracoes(&tag)?);
+                let nome = texto(tag.name())?;
+                let local = texto(tag.local_name())?;
+
+                if !encontrado && selecionado(&tag, seletor)? {
+                    encontrado = true;
+                    renderizados.push(Namespaces::new());
+                }
+
+                if !renderizados.is_empty() {
+                    if ignorando > 0
+                        || (local == "Signature"
+                            && namespace(&escopo, prefixo(nome)) == NAMESPACE_XMLDSIG)
+                    {
+                        ignorando += 1;
+                    } else {
+                        let anteriores = renderizados.last().cloned().unwrap_or_default();
+                        let atuais = escrever_tag(&mut saida, &tag, &escopo, &anteriores, metodo)?;
+                        renderizados.push(atuais);
+                    }
+                }
+            }
+            Event::End(tag) => {
+                escopo.pop();
+
+                if ignorando > 0 {
+                    ignorando -= 1;
+                } else if !renderizados.is_empty() {
+                    saida.push_str("</");
+                    saida.push_str(texto(tag.name())?);
+                    saida.push('>');
+
+                    renderizados.pop();
+                    // O primeiro item é a base vazia do elemento buscado
+                    if renderizados.len() == 1 {
+                        return Ok(saida);
+                    }
+                }
+            }
+            Event::Text(conteudo) if renderizados.len() > 1 && ignorando == 0 => {
+                let bruto = normalizar_quebras(texto(conteudo.escaped())?);
+                let valor = quick_xml::escape::unescape(bruto.as_bytes())
+                    .map_err(|e| Error::XmlInvalido(e.to_string()))?;
+                escapar_texto(&mut saida, texto(&valor)?);
+            }
+            Event::CData(conteudo) if renderizados.len() > 1 && ignorando == 0 => {
+                escapar_texto(&mut saida, &normalizar_quebras(texto(&conteudo)?));
+            }
+            Event::PI(conteudo) if renderizados.len() > 1 && ignorando == 0 => {
+                saida.push_str("<?");
+                saida.push_str(texto(&conteudo)?);
+                saida.push_str("?>");
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+
+        buf.clear();
+    }
+
+    Err(Error::XmlInvalido(match seletor {
+        Seletor::Tag(tag) => format!("Elemento <{}> não encontrado", tag),
+        Seletor::Id(id) => format!("Elemento com Id {} não encontrado", id),
+    }))
+}
+
+fn selecionado(tag: &BytesStart, seletor: Seletor) -> Result<bool, Error> {
+    match seletor {
+        Seletor::Tag(nome) => Ok(texto(tag.local_name())? == nome),
+        Seletor::Id(id) => {
+            for atributo in tag.attributes() {
+                let atributo = atributo.map_err(|e| Error::XmlInvalido(e.to_string()))?;
+
+                if atributo.key == b"Id" {
+                    return Ok(&*atributo.value == id.as_bytes());
+                }
+            }
+
+            Ok(false)
+        }
+    }
+}
+
+/// Escreve a tag de abertura na forma canônica
+///
+/// Retorna os namespaces em vigor na saída após o elemento
+fn escrever_tag(
+    saida: &mut String,
+    tag: &BytesStart,
+    escopo: &[Namespaces],
+    anteriores: &Namespaces,
+    metodo: Canonicalizacao,
+) -> Result<Namespaces, Error> {
+    let mut atuais = anteriores.clone();
+    let nome_tag = texto(tag.name())?;
+
+    // Atributos sem namespace primeiro, depois por namespace e nome local
+    let mut atributos = vec![];
+    for atributo in tag.attributes() {
+        let atributo = atributo.map_err(|e| Error::XmlInvalido(e.to_string()))?;
+        let nome = texto(atributo.key)?;
+
+        if nome == "xmlns" || nome.starts_with("xmlns:") {
+            continue;
+        }
+
+        let (uri, local) = match nome.split_once(':') {
+            Some((prefixo, local)) => (namespace(escopo, prefixo), local),
+            None => (String::new(), nome),
+        };
+
+        let bruto = texto(&atributo.value)?.replace(&['\t', '\n', '\r'][..], " ");
+        let valor = quick_xml::escape::unescape(bruto.as_bytes())
+            .map_err(|e| Error::XmlInvalido(e.to_string()))?;
+
+        atributos.push((
+            uri,
+            local.to_string(),
+            nome.to_string(),
+            texto(&valor)?.to_string(),
+        ));
+    }
+    atributos.sort();
+
+    // Na exclusiva, apenas os prefixos usados pela tag e pelos atributos
+    let em_uso: Vec<&str> = std::iter::once(prefixo(nome_tag))
+        .chain(
+            atributos
+                .iter()
+                .map(|(_, _, nome, _)| prefixo(nome))
+                .filter(|p| !p.is_empty()),
+        )
+        .collect();
+
+    saida.push('<');
+    saida.push_str(nome_tag);
+
+    // Namespaces: o padrão primeiro e os demais em ordem de prefixo
+    for (prefixo, uri) in em_vigor(escopo) {
+        let anterior = anteriores.get(&prefixo).map(String::as_str).unwrap_or("");
+
+        if prefixo == "xml" || anterior == uri {
+            continue;
+        }
+
+        if metodo == Canonicalizacao::Exclusiva && !em_uso.contains(&prefixo.as_str()) {
+            continue;
+        }
+
+        if prefixo.is_empty() {
+            saida.push_str(" xmlns=\"");
+        } else {
+            saida.push_str(" xmlns:");
+            saida.push_str(&prefixo);
+            saida.push_str("=\"");
+        }
+        escapar_atributo(saida, &uri);
+        saida.push('"');
+
+        atuais.insert(prefixo, uri);
+    }
+
+    for (_, _, nome, valor) in atributos {
+        saida.push(' ');
+        saida.push_str(&nome);
+        saida.push_str("=\"");
+        escapar_atributo(saida, &valor);
+        saida.push('"');
+    }
+
+    saida.push('>');
+
+    Ok(atuais)
+}
+
+/// Namespaces declarados na própria tag
+fn declaracoes(tag: &BytesStart) -> Result<Namespaces, Error> {
+    let mut namespaces = Namespaces::new();
+
+    for atributo in tag.attributes() {
+        let atributo = atributo.map_err(|e| Error::XmlInvalido(e.to_string()))?;
+        let nome = texto(atributo.key)?;
+
+        let prefixo = match nome {
+            "xmlns" => "",
+            _ => match nome.strip_prefix("xmlns:") {
+                Some(prefixo) => prefixo,
+                None => continue,
+            },
+        };
+
+        let valor = atributo
+            .unescaped_value()
+            .map_err(|e| Error::XmlInvalido(e.to_string()))?;
+        namespaces.insert(prefixo.to_string(), texto(&valor)?.to_string());
+    }
+
+    Ok(namespaces)
+}
+
+/// Namespaces em vigor no elemento mais interno do escopo
+fn em_vigor(escopo: &[Namespaces]) -> Namespaces {
+    escopo.iter().fold(Namespaces::new(), |mut todos, atuais| {
+        todos.extend(atuais.clone());
+        todos
+    })
+}
+
+/// URI do namespace do prefixo no elemento mais interno do escopo
+fn namespace(escopo: &[Namespaces], prefixo: &str) -> String {
+    escopo
+        .iter()
+        .rev()
+        .find_map(|n| n.get(prefixo))
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn prefixo(nome: &str) -> &str {
+    nome.split_once(':').map(|(p, _)| p).unwrap_or("")
+}
+
+fn texto(bytes: &[u8]) -> Result<&str, Error> {
+    std::str::from_utf8(bytes).map_err(|e| Error::XmlInvalido(e.to_string()))
+}
+
+fn normalizar_quebras(texto: &str) -> String {
+    texto.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+fn escapar_texto(saida: &mut String, texto: &str) {
+    for c in texto.chars() {
+        match c {
+            '&' => saida.push_str("&amp;"),
+            '<' => saida.push_str("&lt;"),
+            '>' => saida.push_str("&gt;"),
+            '\r' => saida.push_str("&#xD;"),
+            _ => saida.push(c),
+        }
+    }
+}
+
+fn escapar_atributo(saida: &mut String, valor: &str) {
+    for c in valor.chars() {
+        match c {
+            '&' => saida.push_str("&amp;"),
+            '<' => saida.push_str("&lt;"),
+            '"' => saida.push_str("&quot;"),
+            '\t' => saida.push_str("&#x9;"),
+            '\n' => saida.push_str("&#xA;"),
+            '\r' => saida.push_str("&#xD;"),
+            _ => saida.push(c),
+        }
+    }
+}
@@ -0,0 +1,305 @@
+//! Consulta do cadastro de contribuintes do ICMS(ConsCad)
+//!
+//! Disponível apenas nas UFs que oferecem o serviço CadConsultaCadastro4.
+
+use super::documento::{remover_mascara, Cnpj, Cpf, Documento};
+use super::ie::{validar_ie, ISENTO};
+use super::uf::Uf;
+use super::{Error, NAMESPACE_NFE};
+use chrono::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+/// Versão do leiaute da consulta cadastro
+pub const VERSAO_CADASTRO: &str = "2.00";
+
+/// Documento do contribuinte consultado
+#[derive(Debug, PartialEq, Clone)]
+pub enum DocumentoCadastro {
+    Cnpj(Cnpj),
+    Cpf(Cpf),
+    /// Inscrição Estadual, sem máscara
+    Ie(String),
+}
+
+/// Consulta do cadastro, a tag <ConsCad>
+#[derive(Debug, PartialEq, Clone)]
+pub struct ConsultaCadastro {
+    /// UF do cadastro consultado
+    pub uf: Uf,
+    pub documento: DocumentoCadastro,
+}
+
+/// Resultado da consulta do cadastro, a tag <retConsCad>
+#[derive(Debug, PartialEq, Clone)]
+pub struct RetornoCadastro {
+    pub versao_aplicativo: String,
+    /// Código do status(cStat)
+    pub status: u16,
+    /// Descrição do status
+    pub motivo: String,
+    pub uf: Uf,
+    /// Data e hora do processamento da consulta
+    pub consulta: DateTime<FixedOffset>,
+    pub codigo_uf: u8,
+    /// Estabelecimentos encontrados
+    pub contribuintes: Vec<Contribuinte>,
+}
+
+/// Estabelecimento do cadastro, a tag <infCad>
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+#[serde(try_from = "ContribuinteContainer")]
+pub struct Contribuinte {
+    pub ie: String,
+    /// CNPJ ou CPF do contribuinte
+    pub documento: Documento,
+    pub uf: Uf,
+    /// Situação(cSit): 0 não habilitado e 1 habilitado
+    pub situacao: u8,
+    /// Credenciamento para emissão de NF-e(indCredNFe)
+    pub credenciamento_nfe: u8,
+    /// Credenciamento para emissão de CT-e(indCredCTe)
+    pub credenciamento_cte: u8,
+    pub nome: String,
+    pub nome_fantasia: Option<String>,
+    /// Regime de apuração do ICMS
+    pub regime_apuracao: Option<String>,
+    pub cnae: Option<String>,
+    /// Data de início da atividade
+    pub inicio_atividade: Option<NaiveDate>,
+    /// Data da última alteração da situação
+    pub ultima_situacao: Option<NaiveDate>,
+    pub baixa: Option<NaiveDate>,
+    pub endereco: Option<EnderecoCadastro>,
+}
+
+/// Endereço do estabelecimento no cadastro
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+pub struct EnderecoCadastro {
+    #[serde(rename = "$unflatten=xLgr")]
+    pub logradouro: Option<String>,
+    #[serde(rename = "$unflatten=nro")]
+    pub numero: Option<String>,
+    #[serde(rename = "$unflatten=xCpl")]
+    pub complemento: Option<String>,
+    #[serde(rename = "$unflatten=xBairro")]
+    pub bairro: Option<String>,
+    #[serde(rename = "$unflatten=cMun")]
+    pub codigo_municipio: Option<u32>,
+    #[serde(rename = "$unflatten=xMun")]
+    pub nome_municipio: Option<String>,
+    #[serde(rename = "$unflatten=CEP")]
+    pub cep: Option<String>,
+}
+
+impl ConsultaCadastro {
+    /// Valida a IE consultada de acordo com as regras da UF
+    pub fn validar(&self) -> Result<(), Error> {
+        match &self.documento {
+            DocumentoCadastro::Ie(ie) if ie.trim().eq_ignore_ascii_case(ISENTO) => Err(
+                Error::IeInvalida("Não é possível consultar o cadastro de um isento".to_string()),
+            ),
+            DocumentoCadastro::Ie(ie) => validar_ie(self.uf.sigla(), ie),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl RetornoCadastro {
+    /// Indica se a consulta encontrou um(111) ou mais(112) estabelecimentos
+    pub fn encontrado(&self) -> bool {
+        matches!(self.status, 111 | 112)
+    }
+
+    /// Estabelecimento com a IE informada, ignorando a máscara
+    pub fn contribuinte(&self, ie: &str) -> Option<&Contribuinte> {
+        let ie = remover_mascara(ie);
+
+        self.contribuintes
+            .iter()
+            .find(|c| remover_mascara(&c.ie) == ie)
+    }
+}
+
+impl Contribuinte {
+    /// Indica se o estabelecimento está habilitado
+    pub fn habilitado(&self) -> bool {
+        self.situacao == 1
+    }
+
+    /// Indica se o estabelecimento está credenciado a emitir NF-e
+    pub fn credenciado_nfe(&self) -> bool {
+        matches!(self.credenciamento_nfe, 1..=3)
+    }
+}
+
+impl fmt::Display for ConsultaCadastro {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut inf = InfConsContainer {
+            servico: "CONS-CAD".to_string(),
+            uf: self.uf.sigla().to_string(),
+            cnpj: None,
+            cpf: None,
+            ie: None,
+        };
+
+        match &self.documento {
+            DocumentoCadastro::Cnpj(cnpj) => inf.cnpj = Some(cnpj.to_string()),
+            DocumentoCadastro::Cpf(cpf) => inf.cpf = Some(cpf.to_string()),
+            DocumentoCadastro::Ie(ie) => inf.ie = Some(remover_mascara(ie.trim())),
+        }
+
+        let consulta = ConsCadContainer {
+            namespace: NAMESPACE_NFE.to_string(),
+            versao: VERSAO_CADASTRO.to_string(),
+            inf,
+        };
+
+        let xml = quick_xml::se::to_string(&consulta).expect("Falha ao serializar a consulta");
+
+        f.write_str(&xml)
+    }
+}
+
+impl FromStr for RetornoCadastro {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ret: RetConsCadContainer = quick_xml::de::from_str(s)?;
+        let inf = ret.inf;
+
+        Ok(Self {
+            versao_aplicativo: inf.versao_aplicativo,
+            status: inf.status,
+            motivo: inf.motivo,
+            uf: inf.uf,
+            consulta: inf.consulta,
+            codigo_uf: inf.codigo_uf,
+            contribuintes: inf.contribuintes,
+        })
+    }
+}
+
+impl TryFrom<ContribuinteContainer> for Contribuinte {
+    type Error = Error;
+
+    fn try_from(cad: ContribuinteContainer) -> Result<Self, Self::Error> {
+        Ok(Self {
+            ie: cad.ie,
+            documento: Documento::from_tags(cad.cnpj, cad.cpf, None)?,
+            uf: cad.uf,
+            situacao: cad.situacao,
+            credenciamento_nfe: cad.credenciamento_nfe,
+            credenciamento_cte: cad.credenciamento_cte,
+            nome: cad.nome,
+            nome_fantasia: cad.nome_fantasia,
+            regime_apuracao: cad.regime_apuracao,
+            cnae: cad.cnae,
+            inicio_atividade: cad.inicio_atividade,
+            ultima_situacao: cad.ultima_situacao,
+            baixa: cad.baixa,
+            endereco: cad.endereco,
+        })
+    }
+}
+
+fn deserialize_sigla_uf<'de, D>(deserializer: D) -> Result<Uf, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let sigla = String::deserialize(deserializer)?;
+
+    sigla.parse().map_err(serde::de::Error::custom)
+}
+
+#[derive(Serialize)]
+#[serde(rename = "ConsCad")]
+struct ConsCadContainer {
+    #[serde(rename = "xmlns")]
+    pub namespace: String,
+    #[serde(rename = "versao")]
+    pub versao: String,
+    #[serde(rename = "infCons")]
+    pub inf: InfConsContainer,
+}
+
+#[derive(Serialize)]
+struct InfConsContainer {
+    #[serde(rename = "$unflatten=xServ")]
+    pub servico: String,
+    #[serde(rename = "$unflatten=UF")]
+    pub uf: String,
+    #[serde(rename = "$unflatten=CNPJ")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cnpj: Option<String>,
+    #[serde(rename = "$unflatten=CPF")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpf: Option<String>,
+    #[serde(rename = "$unflatten=IE")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ie: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "retConsCad")]
+struct RetConsCadContainer {
+    #[serde(rename = "infCons")]
+    pub inf: InfRetConsContainer,
+}
+
+#[derive(Deserialize)]
+struct InfRetConsContainer {
+    #[serde(rename = "$unflatten=verAplic")]
+    pub versao_aplicativo: String,
+    #[serde(rename = "$unflatten=cStat")]
+    pub status: u16,
+    #[serde(rename = "$unflatten=xMotivo")]
+    pub motivo: String,
+    #[serde(rename = "$unflatten=UF")]
+    #[serde(deserialize_with = "deserialize_sigla_uf")]
+    pub uf: Uf,
+    #[serde(rename = "$unflatten=dhCons")]
+    pub consulta: DateTime<FixedOffset>,
+    #[serde(rename = "$unflatten=cUF")]
+    pub codigo_uf: u8,
+    #[serde(rename = "infCad")]
+    #[serde(default)]
+    pub contribuintes: Vec<Contribuinte>,
+}
+
+#[derive(Deserialize)]
+struct ContribuinteContainer {
+    #[serde(rename = "$unflatten=IE")]
+    pub ie: String,
+    #[serde(rename = "$unflatten=CNPJ")]
+    pub cnpj: Option<Cnpj>,
+    #[serde(rename = "$unflatten=CPF")]
+    pub cpf: Option<Cpf>,
+    #[serde(rename = "$unflatten=UF")]
+    #[serde(deserialize_with = "deserialize_sigla_uf")]
+    pub uf: Uf,
+    #[serde(rename = "$unflatten=cSit")]
+    pub situacao: u8,
+    #[serde(rename = "$unflatten=indCredNFe")]
+    pub credenciamento_nfe: u8,
+    #[serde(rename = "$unflatten=indCredCTe")]
+    pub credenciamento_cte: u8,
+    #[serde(rename = "$unflatten=xNome")]
+    pub nome: String,
+    #[serde(rename = "$unflatten=xFant")]
+    pub nome_fantasia: Option<String>,
+    #[serde(rename = "$unflatten=xRegApur")]
+    pub regime_apuracao: Option<String>,
+    #[serde(rename = "$unflatten=CNAE")]
+    pub cnae: Option<String>,
+    #[serde(rename = "$unflatten=dIniAtiv")]
+    pub inicio_atividade: Option<NaiveDate>,
+    #[serde(rename = "$unflatten=dUltSit")]
+    pub ultima_situacao: Option<NaiveDate>,
+    #[serde(rename = "$unflatten=dBaixa")]
+    pub baixa: Option<NaiveDate>,
+    #[serde(rename = "ender")]
+    pub endereco: Option<EnderecoCadastro>,
+}
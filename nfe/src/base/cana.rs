@@ -0,0 +1,83 @@
+//! Informações do registro de aquisição de cana
+
+use super::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Registro de aquisição de cana, usado pelas usinas
+#[derive(Debug, Deserialize, PartialEq, Serialize, Clone)]
+#[serde(rename = "cana")]
+pub struct Cana {
+    /// Identificação da safra. Ex.: 2021 ou 2021/2022
+    #[serde(rename = "$unflatten=safra")]
+    pub safra: String,
+    /// Mês e ano de referência, no formato MM/AAAA
+    #[serde(rename = "$unflatten=ref")]
+    pub referencia: String,
+    /// Fornecimentos diários de cana
+    #[serde(rename = "forDia")]
+    pub fornecimentos: Vec<FornecimentoDiario>,
+    /// Quantidade total do mês
+    #[serde(rename = "$unflatten=qTotMes")]
+    pub quantidade_total_mes: f32,
+    /// Quantidade total anterior
+    #[serde(rename = "$unflatten=qTotAnt")]
+    pub quantidade_total_anterior: f32,
+    /// Quantidade total geral
+    #[serde(rename = "$unflatten=qTotGer")]
+    pub quantidade_total_geral: f32,
+    /// Deduções (taxas e contribuições)
+    #[serde(rename = "deduc")]
+    #[serde(default)]
+    pub deducoes: Vec<Deducao>,
+    /// Valor dos fornecimentos
+    #[serde(rename = "$unflatten=vFor")]
+    pub valor_fornecimentos: f32,
+    /// Valor total das deduções
+    #[serde(rename = "$unflatten=vTotDed")]
+    pub valor_deducoes: f32,
+    /// Valor líquido dos fornecimentos
+    #[serde(rename = "$unflatten=vLiqFor")]
+    pub valor_liquido: f32,
+}
+
+/// Fornecimento de cana em um dia do mês
+#[derive(Debug, Deserialize, PartialEq, Serialize, Clone)]
+#[serde(rename = "forDia")]
+pub struct FornecimentoDiario {
+    /// Dia do mês
+    #[serde(rename = "dia")]
+    pub dia: u8,
+    /// Quantidade fornecida no dia
+    #[serde(rename = "$unflatten=qtde")]
+    pub quantidade: f32,
+}
+
+/// Dedução sobre o fornecimento de cana
+#[derive(Debug, Deserialize, PartialEq, Serialize, Clone)]
+#[serde(rename = "deduc")]
+pub struct Deducao {
+    /// Descrição da dedução
+    #[serde(rename = "$unflatten=xDed")]
+    pub descricao: String,
+    /// Valor da dedução
+    #[serde(rename = "$unflatten=vDed")]
+    pub valor: f32,
+}
+
+impl FromStr for Cana {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl fmt::Display for Cana {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar a cana");
+
+        f.write_str(&xml)
+    }
+}
@@ -0,0 +1,262 @@
+//! Certificado digital X.509 usado na assinatura dos documentos
+
+use super::documento::Cnpj;
+use super::Error;
+use chrono::prelude::*;
+use openssl::asn1::{Asn1Time, Asn1TimeRef};
+use openssl::hash::MessageDigest;
+use openssl::nid::Nid;
+use openssl::pkcs12::Pkcs12;
+use openssl::pkey::{PKey, Private};
+use openssl::sign::Signer;
+use openssl::stack::Stack;
+use openssl::x509::store::X509StoreRef;
+use openssl::x509::{X509NameRef, X509StoreContext, X509};
+use std::fs;
+use std::path::Path;
+
+/// OID 2.16.76.1.3.3, usado pela ICP-Brasil para o CNPJ do titular
+const OID_CNPJ: [u8; 7] = [0x06, 0x05, 0x60, 0x4c, 0x01, 0x03, 0x03];
+
+/// Certificado digital X.509
+#[derive(Clone)]
+pub struct Certificado {
+    x509: X509,
+}
+
+impl Certificado {
+    /// Carrega o certificado no formato DER
+    pub fn from_der(der: &[u8]) -> Result<Self, Error> {
+        let x509 = X509::from_der(der).map_err(|e| Error::CertificadoInvalido(e.to_string()))?;
+
+        Ok(Self { x509 })
+    }
+
+    /// Carrega o certificado em base64, como informado na tag <X509Certificate>
+    pub fn from_base64(base64: &str) -> Result<Self, Error> {
+        let base64: String = base64.split_whitespace().collect();
+        let der = openssl::base64::decode_block(&base64)
+            .map_err(|e| Error::CertificadoInvalido(format!("Base64 inválido: {}", e)))?;
+
+        Self::from_der(&der)
+    }
+
+    /// Certificado no formato DER
+    pub fn der(&self) -> Result<Vec<u8>, Error> {
+        self.x509
+            .to_der()
+            .map_err(|e| Error::CertificadoInvalido(e.to_string()))
+    }
+
+    /// Certificado em base64, sem quebras de linha
+    pub fn base64(&self) -> Result<String, Error> {
+        Ok(openssl::base64::encode_block(&self.der()?))
+    }
+
+    /// Nome(CN) do titular
+    pub fn titular(&self) -> Option<String> {
+        nome_comum(self.x509.subject_name())
+    }
+
+    /// Nome(CN) da autoridade certificadora emissora
+    pub fn emissor(&self) -> Option<String> {
+        nome_comum(self.x509.issuer_name())
+    }
+
+    /// Número de série em hexadecimal
+    pub fn numero_serie(&self) -> Option<String> {
+        self.x509
+            .serial_number()
+            .to_bn()
+            .and_then(|n| n.to_hex_str().map(|h| h.to_string()))
+            .ok()
+    }
+
+    /// Início da validade
+    pub fn validade_inicio(&self) -> Result<DateTime<Utc>, Error> {
+        converter_horario(self.x509.not_before())
+    }
+
+    /// Fim da validade
+    pub fn validade_fim(&self) -> Result<DateTime<Utc>, Error> {
+        converter_horario(self.x509.not_after())
+    }
+
+    /// Indica se o certificado estava válido no horário
+    pub fn valido_em(&self, horario: DateTime<Utc>) -> Result<bool, Error> {
+        Ok(horario >= self.validade_inicio()? && horario <= self.validade_fim()?)
+    }
+
+    /// CNPJ do titular
+    ///
+    /// Buscado no campo otherName(OID 2.16.76.1.3.3) do e-CNPJ. Na ausência,
+    /// usa o final do nome do titular, no formato "RAZAO SOCIAL:CNPJ". Qualquer
+    /// um pode gerar um certificado com esse nome: o CNPJ só é garantido após
+    /// conferir a cadeia, ver [`Certificado::cadeia_valida`].
+    pub fn cnpj(&self) -> Option<Cnpj> {
+        self.der()
+            .ok()
+            .and_then(|der| cnpj_icp_brasil(&der))
+            .or_else(|| {
+                self.titular()
+                    .and_then(|cn| cn.rsplit(':').next().map(str::to_string))
+                    .and_then(|cnpj| cnpj.parse().ok())
+            })
+    }
+
+    /// Confere a cadeia do certificado até uma das autoridades confiáveis
+    ///
+    /// As raízes, como as da ICP-Brasil, e as autoridades intermediárias devem
+    /// estar em `confiaveis`. A validade é conferida no horário atual e a
+    /// revogação(LCR) não é consultada.
+    pub fn cadeia_valida(&self, confiaveis: &X509StoreRef) -> Result<bool, Error> {
+        let invalido = |e: openssl::error::ErrorStack| Error::CertificadoInvalido(e.to_string());
+
+        let intermediarios = Stack::new().map_err(invalido)?;
+        let mut contexto = X509StoreContext::new().map_err(invalido)?;
+
+        contexto
+            .init(confiaveis, &self.x509, &intermediarios, |c| c.verify_cert())
+            .map_err(invalido)
+    }
+
+    /// Certificado da OpenSSL, para operações não cobertas por esta struct
+    pub fn x509(&self) -> &X509 {
+        &self.x509
+    }
+}
+
+/// Certificado A1 da ICP-Brasil, com a chave privada, usado para assinar os documentos
+#[derive(Clone)]
+pub struct CertificadoA1 {
+    certificado: Certificado,
+    chave: PKey<Private>,
+}
+
+impl CertificadoA1 {
+    /// Carrega o certificado do conteúdo de um arquivo PKCS#12(.pfx/.p12)
+    ///
+    /// Arquivos cifrados com algoritmos antigos(RC2, 3DES) dependem do
+    /// suporte da OpenSSL instalada.
+    pub fn from_pkcs12(der: &[u8], senha: &str) -> Result<Self, Error> {
+        let invalido = |e: openssl::error::ErrorStack| Error::CertificadoInvalido(e.to_string());
+
+        let pkcs12 = Pkcs12::from_der(der)
+            .and_then(|p| p.parse2(senha))
+            .map_err(|e| Error::CertificadoInvalido(format!("Falha ao abrir o PKCS#12: {}", e)))?;
+
+        let x509 = pkcs12.cert.ok_or_else(|| {
+            Error::CertificadoInvalido("PKCS#12 sem o certificado do titular".to_string())
+        })?;
+        let chave = pkcs12
+            .pkey
+            .ok_or_else(|| Error::CertificadoInvalido("PKCS#12 sem a chave privada".to_string()))?;
+
+        if !x509.public_key().map_err(invalido)?.public_eq(&chave) {
+            return Err(Error::CertificadoInvalido(
+                "A chave privada não corresponde ao certificado".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            certificado: x509.into(),
+            chave,
+        })
+    }
+
+    /// Carrega o certificado de um arquivo PKCS#12(.pfx/.p12)
+    pub fn from_arquivo<P: AsRef<Path>>(caminho: P, senha: &str) -> Result<Self, Error> {
+        let der = fs::read(caminho).map_err(Error::Io)?;
+
+        Self::from_pkcs12(&der, senha)
+    }
+
+    /// Certificado público do titular
+    pub fn certificado(&self) -> &Certificado {
+        &self.certificado
+    }
+
+    /// Chave privada, usada na autenticação TLS com a SEFAZ
+    #[cfg(feature = "client")]
+    pub(crate) fn chave(&self) -> &PKey<Private> {
+        &self.chave
+    }
+
+    /// Assina os dados com RSA-SHA1, como exigido pela SEFAZ
+    pub fn assinar(&self, dados: &[u8]) -> Result<Vec<u8>, Error> {
+        let invalido = |e: openssl::error::ErrorStack| Error::AssinaturaInvalida(e.to_string());
+
+        let mut signer = Signer::new(MessageDigest::sha1(), &self.chave).map_err(invalido)?;
+        signer.update(dados).map_err(invalido)?;
+
+        signer.sign_to_vec().map_err(invalido)
+    }
+}
+
+impl std::fmt::Debug for CertificadoA1 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // A chave privada nunca é exibida
+        f.debug_struct("CertificadoA1")
+            .field("certificado", &self.certificado)
+            .finish()
+    }
+}
+
+impl From<X509> for Certificado {
+    fn from(x509: X509) -> Self {
+        Self { x509 }
+    }
+}
+
+impl std::fmt::Debug for Certificado {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Certificado")
+            .field("titular", &self.titular())
+            .field("emissor", &self.emissor())
+            .field("numero_serie", &self.numero_serie())
+            .finish()
+    }
+}
+
+fn nome_comum(nome: &X509NameRef) -> Option<String> {
+    nome.entries_by_nid(Nid::COMMONNAME)
+        .next()
+        .and_then(|e| e.data().to_string().ok())
+}
+
+fn converter_horario(horario: &Asn1TimeRef) -> Result<DateTime<Utc>, Error> {
+    let invalido = |e: openssl::error::ErrorStack| Error::CertificadoInvalido(e.to_string());
+
+    let diferenca = Asn1Time::from_unix(0)
+        .map_err(invalido)?
+        .diff(horario)
+        .map_err(invalido)?;
+
+    Utc.timestamp_opt(diferenca.days as i64 * 86_400 + diferenca.secs as i64, 0)
+        .single()
+        .ok_or_else(|| Error::CertificadoInvalido("Validade fora do limite".to_string()))
+}
+
+/// Busca o CNPJ no otherName do certificado e-CNPJ
+///
+/// O valor segue o OID como uma string de 14 caracteres, dentro
+/// de uma tag de contexto [0].
+fn cnpj_icp_brasil(der: &[u8]) -> Option<Cnpj> {
+    let inicio = der.windows(OID_CNPJ.len()).position(|w| w == OID_CNPJ)? + OID_CNPJ.len();
+    let resto = der.get(inicio..)?;
+
+    // [0] { OCTET STRING | PrintableString | UTF8String }
+    if resto.first()? != &0xa0 {
+        return None;
+    }
+
+    let valor = resto.get(2..)?;
+    if !matches!(valor.first()?, 0x04 | 0x13 | 0x0c) {
+        return None;
+    }
+
+    let tamanho = *valor.get(1)? as usize;
+    let cnpj = std::str::from_utf8(valor.get(2..2 + tamanho)?).ok()?;
+
+    cnpj.parse().ok()
+}
@@ -0,0 +1,358 @@
+//! Chave de acesso da NF-e
+
+use super::documento::digito_modulo11;
+use super::emit::Emitente;
+use super::ide::*;
+use super::Error;
+use chrono::prelude::*;
+use rand::Rng;
+use std::fmt;
+use std::str::FromStr;
+
+/// Chave de acesso da NF-e
+///
+/// Composta por 44 posições: cUF, AAMM da emissão, CNPJ/CPF
+/// do emitente, modelo, série, número, tpEmis, cNF e cDV.
+///
+/// Com o CNPJ alfanumérico, as posições do CNPJ podem conter letras.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ChaveAcesso {
+    /// Código IBGE da UF do emitente
+    pub codigo_uf: u8,
+    /// Ano da emissão, com dois dígitos
+    pub ano: u8,
+    /// Mês da emissão
+    pub mes: u8,
+    /// CNPJ ou CPF do emitente. O CPF é completado com zeros à esquerda
+    pub documento: String,
+    /// Modelo do documento fiscal
+    pub modelo: ModeloDocumentoFiscal,
+    /// Série do documento fiscal
+    pub serie: u16,
+    /// Número do documento fiscal
+    pub numero: u32,
+    /// Forma de emissão da NF-e
+    pub tipo_emissao: TipoEmissao,
+    /// Código numérico que compõe a chave(cNF)
+    pub codigo: String,
+    /// Dígito verificador(cDV)
+    pub digito_verificador: u8,
+}
+
+impl ChaveAcesso {
+    /// Gera a chave de acesso para um novo documento
+    ///
+    /// O cNF é sorteado respeitando as regras do MOC e o cDV é calculado
+    pub fn gerar(
+        codigo_uf: u8,
+        emissao: DateTime<Utc>,
+        documento: &str,
+        modelo: ModeloDocumentoFiscal,
+        serie: u16,
+        numero: u32,
+        tipo_emissao: TipoEmissao,
+    ) -> Result<Self, Error> {
+        let emissao = emissao.with_timezone(&horario_brasilia());
+
+        let mut chave = Self {
+            codigo_uf,
+            ano: (emissao.year() % 100) as u8,
+            mes: emissao.month() as u8,
+            documento: format!("{:0>14}", documento),
+            modelo,
+            serie,
+            numero,
+            tipo_emissao,
+            codigo: gerar_codigo_numerico(numero),
+            digito_verificador: 0,
+        };
+
+        chave.validar_componentes()?;
+        chave.digito_verificador = calcular_digito_verificador(&chave.sem_digito());
+
+        Ok(chave)
+    }
+
+    /// Monta a chave de acesso a partir da identificação e do emitente da nota
+    ///
+    /// O dígito verificador é sempre recalculado, ignorando o cDV
+    /// informado na identificação.
+    pub fn from_nfe(ide: &Identificacao, emit: &Emitente) -> Result<Self, Error> {
+        let emissao = ide.emissao.horario.with_timezone(&horario_brasilia());
+
+        let mut chave = Self {
+            codigo_uf: ide.codigo_uf,
+            ano: (emissao.year() % 100) as u8,
+            mes: emissao.month() as u8,
+            documento: format!("{:0>14}", emit.documento),
+            modelo: ide.modelo,
+            serie: ide.serie,
+            numero: ide.numero,
+            tipo_emissao: ide.emissao.tipo,
+            codigo: ide.chave.codigo.clone(),
+            digito_verificador: 0,
+        };
+
+        chave.validar_componentes()?;
+        chave.digito_verificador = calcular_digito_verificador(&chave.sem_digito());
+
+        Ok(chave)
+    }
+
+    /// Valor do atributo Id da tag <infNFe>
+    pub fn id(&self) -> String {
+        format!("NFe{}", self)
+    }
+
+    /// Compara a chave com os dados da identificação e do emitente
+    ///
+    /// Retorna o primeiro campo divergente encontrado
+    pub fn conferir(&self, ide: &Identificacao, emit: &Emitente) -> Result<(), Error> {
+        let esperada = Self::from_nfe(ide, emit)?;
+
+        let divergencia = |campo: &str, chave: String, nota: String| {
+            Err(Error::ChaveAcessoDivergente(format!(
+                "{} da chave({}) difere do informado na nota({})",
+                campo, chave, nota
+            )))
+        };
+
+        if self.codigo_uf != esperada.codigo_uf {
+            return divergencia(
+                "cUF",
+                self.codigo_uf.to_string(),
+                esperada.codigo_uf.to_string(),
+            );
+        }
+
+        if self.ano != esperada.ano || self.mes != esperada.mes {
+            return divergencia(
+                "AAMM",
+                format!("{:02}{:02}", self.ano, self.mes),
+                format!("{:02}{:02}", esperada.ano, esperada.mes),
+            );
+        }
+
+        if self.documento != esperada.documento {
+            return divergencia("CNPJ/CPF", self.documento.clone(), esperada.documento);
+        }
+
+        if self.modelo != esperada.modelo {
+            return divergencia(
+                "mod",
+                (self.modelo as u8).to_string(),
+                (esperada.modelo as u8).to_string(),
+            );
+        }
+
+        if self.serie != esperada.serie {
+            return divergencia("serie", self.serie.to_string(), esperada.serie.to_string());
+        }
+
+        if self.numero != esperada.numero {
+            return divergencia("nNF", self.numero.to_string(), esperada.numero.to_string());
+        }
+
+        if self.tipo_emissao != esperada.tipo_emissao {
+            return divergencia(
+                "tpEmis",
+                (self.tipo_emissao as u8).to_string(),
+                (esperada.tipo_emissao as u8).to_string(),
+            );
+        }
+
+        if self.codigo != esperada.codigo {
+            return divergencia("cNF", self.codigo.clone(), esperada.codigo);
+        }
+
+        if self.digito_verificador != ide.chave.digito_verificador {
+            return divergencia(
+                "cDV",
+                self.digito_verificador.to_string(),
+                ide.chave.digito_verificador.to_string(),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Confere se os campos cabem nas posições da chave
+    fn validar_componentes(&self) -> Result<(), Error> {
+        if self.documento.len() > 14
+            || !self
+                .documento
+                .chars()
+                .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase())
+        {
+            return Err(Error::ChaveAcessoInvalida(format!(
+                "CNPJ/CPF inválido: {}",
+                self.documento
+            )));
+        }
+
+        if self.codigo.len() != 8 || !self.codigo.chars().all(|c| c.is_ascii_digit()) {
+            return Err(Error::ChaveAcessoInvalida(format!(
+                "cNF deve possuir 8 dígitos: {}",
+                self.codigo
+            )));
+        }
+
+        if self.serie > 999 || self.numero > 999_999_999 {
+            return Err(Error::ChaveAcessoInvalida(format!(
+                "Série({}) ou número({}) fora do limite",
+                self.serie, self.numero
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Primeiros 43 dígitos da chave, usados no cálculo do dígito verificador
+    fn sem_digito(&self) -> String {
+        format!(
+            "{:02}{:02}{:02}{:0>14}{:02}{:03}{:09}{}{}",
+            self.codigo_uf,
+            self.ano,
+            self.mes,
+            self.documento,
+            self.modelo as u8,
+            self.serie,
+            self.numero,
+            self.tipo_emissao as u8,
+            self.codigo
+        )
+    }
+}
+
+/// O AAMM da chave é referente ao horário de Brasília
+fn horario_brasilia() -> FixedOffset {
+    FixedOffset::west_opt(3 * 3600).expect("Offset válido")
+}
+
+/// Sorteia um cNF válido para o número do documento
+pub fn gerar_codigo_numerico(numero: u32) -> String {
+    let mut rng = rand::thread_rng();
+
+    loop {
+        let codigo = format!("{:08}", rng.gen_range(0..100_000_000));
+
+        if codigo_numerico_valido(&codigo, numero) {
+            return codigo;
+        }
+    }
+}
+
+/// Verifica se o cNF respeita as regras do MOC
+///
+/// O código não pode ser igual ao nNF e nem formar uma sequência
+/// trivial, como 00000000, 11111111, 12345678 ou 87654321.
+pub fn codigo_numerico_valido(codigo: &str, numero: u32) -> bool {
+    if codigo.len() != 8 || !codigo.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+
+    if codigo == format!("{:08}", numero) {
+        return false;
+    }
+
+    let digitos: Vec<u8> = codigo.bytes().map(|b| b - b'0').collect();
+    let sequencia = |passo: u8| digitos.windows(2).all(|d| (d[0] + passo) % 10 == d[1]);
+
+    !(sequencia(0) || sequencia(1) || sequencia(9))
+}
+
+/// Calcula o dígito verificador(módulo 11) das 43 primeiras posições da chave
+///
+/// O valor de cada caractere é o seu código ASCII menos 48, regra que
+/// mantém o cálculo original e atende às chaves com CNPJ alfanumérico.
+pub fn calcular_digito_verificador(chave: &str) -> u8 {
+    digito_modulo11(chave, 9) as u8
+}
+
+impl FromStr for ChaveAcesso {
+    type Err = Error;
+
+    /// Aceita a chave com ou sem o prefixo "NFe"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chave = s.trim();
+        let chave = chave.strip_prefix("NFe").unwrap_or(chave);
+
+        let valida = chave.len() == 44
+            && chave.char_indices().all(|(i, c)| match i {
+                // Raiz e ordem do CNPJ alfanumérico
+                6..=17 => c.is_ascii_digit() || c.is_ascii_uppercase(),
+                _ => c.is_ascii_digit(),
+            });
+
+        if !valida {
+            return Err(Error::ChaveAcessoInvalida(format!(
+                "A chave deve possuir 44 posições: {}",
+                chave
+            )));
+        }
+
+        let numero =
+            |ini: usize, fim: usize| -> u32 { chave[ini..fim].parse().expect("Apenas dígitos") };
+
+        let modelo = match numero(20, 22) {
+            55 => ModeloDocumentoFiscal::Nfe,
+            65 => ModeloDocumentoFiscal::Nfce,
+            m => {
+                return Err(Error::ChaveAcessoInvalida(format!(
+                    "Modelo não suportado: {}",
+                    m
+                )))
+            }
+        };
+
+        let tipo_emissao = match numero(34, 35) {
+            1 => TipoEmissao::Normal,
+            2 => TipoEmissao::ContigenciaFsIa,
+            3 => TipoEmissao::ContingenciaScan,
+            4 => TipoEmissao::ContigenciaEpec,
+            5 => TipoEmissao::ContigenciaFsDa,
+            6 => TipoEmissao::ContigenciaSvcAn,
+            7 => TipoEmissao::ContigenciaSvcRs,
+            9 => TipoEmissao::ContigenciaOfflineNfce,
+            t => {
+                return Err(Error::ChaveAcessoInvalida(format!(
+                    "Tipo de emissão inválido: {}",
+                    t
+                )))
+            }
+        };
+
+        let mes = numero(4, 6) as u8;
+        if !(1..=12).contains(&mes) {
+            return Err(Error::ChaveAcessoInvalida(format!("Mês inválido: {}", mes)));
+        }
+
+        let digito_verificador = numero(43, 44) as u8;
+        let calculado = calcular_digito_verificador(&chave[..43]);
+        if digito_verificador != calculado {
+            return Err(Error::ChaveAcessoInvalida(format!(
+                "Dígito verificador {} difere do calculado {}",
+                digito_verificador, calculado
+            )));
+        }
+
+        Ok(Self {
+            codigo_uf: numero(0, 2) as u8,
+            ano: numero(2, 4) as u8,
+            mes,
+            documento: chave[6..20].to_string(),
+            modelo,
+            serie: numero(22, 25) as u16,
+            numero: numero(25, 34),
+            tipo_emissao,
+            codigo: chave[35..43].to_string(),
+            digito_verificador,
+        })
+    }
+}
+
+impl fmt::Display for ChaveAcesso {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.sem_digito(), self.digito_verificador)
+    }
+}
@@ -0,0 +1,40 @@
+//! Informações de compras
+
+use super::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Informações de compras públicas e privadas
+#[derive(Debug, Deserialize, PartialEq, Serialize, Clone)]
+#[serde(rename = "compra")]
+pub struct Compra {
+    /// Identificação da nota de empenho, quando se tratar de compras públicas
+    #[serde(rename = "$unflatten=xNEmp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nota_empenho: Option<String>,
+    /// Informação do pedido
+    #[serde(rename = "$unflatten=xPed")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pedido: Option<String>,
+    /// Informação do contrato
+    #[serde(rename = "$unflatten=xCont")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contrato: Option<String>,
+}
+
+impl FromStr for Compra {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl fmt::Display for Compra {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar a compra");
+
+        f.write_str(&xml)
+    }
+}
@@ -1,17 +1,22 @@
 //! Destinatário da NF-e
 
+use super::documento::{Cnpj, Cpf, Documento};
 use super::endereco::*;
+use super::ie::{validar_ie_de, ISENTO};
 use super::Error;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::convert::TryFrom;
+use std::fmt;
 use std::str::FromStr;
 
 /// Destinatário base da NF-e
 #[derive(Debug, Deserialize, PartialEq, Serialize, Clone)]
-#[serde(rename = "dest")]
+#[serde(rename = "dest", try_from = "DestinatarioContainer")]
 pub struct Destinatario {
-    #[serde(rename = "$unflatten=CNPJ")]
-    pub cnpj: String,
+    /// CNPJ, CPF ou idEstrangeiro do destinatário
+    #[serde(rename = "$value")]
+    pub documento: Documento,
     #[serde(rename = "$unflatten=xNome")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub razao_social: Option<String>,
@@ -36,6 +41,48 @@ pub enum IndicadorContribuicaoIe {
     NaoContribuinte = 9,
 }
 
+impl Destinatario {
+    /// Valida a IE de acordo com o indicador e a UF do endereço
+    ///
+    /// O contribuinte deve informar uma IE válida. Para os demais, a IE
+    /// é validada apenas quando informada.
+    pub fn validar_ie(&self) -> Result<(), Error> {
+        let ie = self
+            .ie
+            .as_deref()
+            .map(str::trim)
+            .filter(|ie| !ie.is_empty());
+
+        let ie = match (self.indicador_ie, ie) {
+            (IndicadorContribuicaoIe::Contribuinte, None) => {
+                return Err(Error::IeInvalida(
+                    "Destinatário contribuinte sem IE informada".to_string(),
+                ))
+            }
+            (IndicadorContribuicaoIe::Contribuinte, Some(ie))
+                if ie.eq_ignore_ascii_case(ISENTO) =>
+            {
+                return Err(Error::IeInvalida(
+                    "Destinatário contribuinte com IE ISENTO".to_string(),
+                ))
+            }
+            (_, None) => return Ok(()),
+            (_, Some(ie)) => ie,
+        };
+
+        let uf = match &self.endereco {
+            Some(endereco) => &endereco.sigla_uf,
+            None => {
+                return Err(Error::IeInvalida(
+                    "Destinatário sem endereço para validação da IE".to_string(),
+                ))
+            }
+        };
+
+        validar_ie_de("Destinatário", uf, ie)
+    }
+}
+
 impl FromStr for Destinatario {
     type Err = Error;
 
@@ -44,8 +91,43 @@ impl FromStr for Destinatario {
     }
 }
 
-impl ToString for Destinatario {
-    fn to_string(&self) -> String {
-        quick_xml::se::to_string(self).expect("Falha ao serializar o destinatário")
+impl fmt::Display for Destinatario {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar o destinatário");
+
+        f.write_str(&xml)
     }
 }
+
+impl TryFrom<DestinatarioContainer> for Destinatario {
+    type Error = Error;
+
+    fn try_from(dest: DestinatarioContainer) -> Result<Self, Self::Error> {
+        Ok(Self {
+            documento: Documento::from_tags(dest.cnpj, dest.cpf, dest.estrangeiro)?,
+            razao_social: dest.razao_social,
+            endereco: dest.endereco,
+            ie: dest.ie,
+            indicador_ie: dest.indicador_ie,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "dest")]
+struct DestinatarioContainer {
+    #[serde(rename = "$unflatten=CNPJ")]
+    cnpj: Option<Cnpj>,
+    #[serde(rename = "$unflatten=CPF")]
+    cpf: Option<Cpf>,
+    #[serde(rename = "$unflatten=idEstrangeiro")]
+    estrangeiro: Option<String>,
+    #[serde(rename = "$unflatten=xNome")]
+    razao_social: Option<String>,
+    #[serde(rename = "enderDest")]
+    endereco: Option<Endereco>,
+    #[serde(rename = "$unflatten=IE")]
+    ie: Option<String>,
+    #[serde(rename = "$unflatten=indIEDest")]
+    indicador_ie: IndicadorContribuicaoIe,
+}
@@ -0,0 +1,566 @@
+//! Distribuição de documentos fiscais de interesse(distDFeInt)
+//!
+//! O Ambiente Nacional entrega os documentos em que o interessado é emitente,
+//! destinatário ou autorizado, identificados pelo NSU(número sequencial
+//! único). Cada documento vem compactado(gzip) e em base64 na tag <docZip>.
+
+use super::chave::ChaveAcesso;
+use super::documento::{Cnpj, Cpf, Documento};
+use super::evento::ProcEvento;
+use super::ide::{TipoAmbiente, TipoOperacao};
+use super::protocolo::NfeProc;
+use super::{Error, NAMESPACE_NFE};
+use chrono::prelude::*;
+use chrono::Duration;
+use flate2::read::GzDecoder;
+use openssl::base64;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::Read;
+use std::str::FromStr;
+
+/// Versão do leiaute da distribuição
+pub const VERSAO_DISTRIBUICAO: &str = "1.01";
+
+/// Espera exigida pela SEFAZ após alcançar o último NSU disponível
+pub const INTERVALO_CONSULTA_DISTRIBUICAO: i64 = 60 * 60;
+
+/// Interessado nos documentos, identificado pelo CNPJ ou CPF
+#[derive(Debug, PartialEq, Clone)]
+pub enum Interessado {
+    Cnpj(Cnpj),
+    Cpf(Cpf),
+}
+
+/// Forma da consulta da distribuição
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum ConsultaDistribuicao {
+    /// Documentos posteriores ao último NSU recebido(distNSU)
+    UltimoNsu(u64),
+    /// Documento de um NSU específico(consNSU)
+    Nsu(u64),
+}
+
+/// Pedido de distribuição, a tag <distDFeInt>
+#[derive(Debug, PartialEq, Clone)]
+pub struct DistribuicaoDFe {
+    pub ambiente: TipoAmbiente,
+    /// Código da UF do interessado(cUFAutor)
+    pub codigo_uf_autor: u8,
+    pub interessado: Interessado,
+    /// Consulta por NSU, quando não informada a chave de acesso
+    pub consulta: Option<ConsultaDistribuicao>,
+    /// Consulta dos documentos de uma nota(consChNFe)
+    pub chave_acesso: Option<String>,
+}
+
+/// Resultado da distribuição, a tag <retDistDFeInt>
+#[derive(Debug, PartialEq, Clone)]
+pub struct RetornoDistribuicao {
+    pub ambiente: TipoAmbiente,
+    pub versao_aplicativo: String,
+    /// Código do status(cStat)
+    pub status: u16,
+    /// Descrição do status
+    pub motivo: String,
+    /// Data e hora da resposta
+    pub resposta: DateTime<FixedOffset>,
+    /// Último NSU entregue, a ser usado na próxima consulta
+    pub ultimo_nsu: u64,
+    /// Maior NSU disponível para o interessado
+    pub maximo_nsu: u64,
+    pub documentos: Vec<DocumentoZip>,
+}
+
+/// Documento compactado, a tag <docZip>
+#[derive(Debug, PartialEq, Clone)]
+pub struct DocumentoZip {
+    pub nsu: u64,
+    /// Schema do documento, como `resNFe_v1.01.xsd`
+    pub schema: String,
+    /// Conteúdo em base64 do XML compactado com gzip
+    pub conteudo: String,
+}
+
+/// Documento distribuído, já descompactado e lido
+#[derive(Debug, PartialEq)]
+pub enum DocumentoDistribuido {
+    ResumoNfe(ResumoNfe),
+    ResumoEvento(ResumoEvento),
+    /// Nota completa com o protocolo(procNFe)
+    Nfe(Box<NfeProc>),
+    /// Evento com o registro(procEventoNFe)
+    Evento(Box<ProcEvento>),
+    /// Documento de schema ainda não tratado, mantido como XML
+    Outro {
+        schema: String,
+        xml: String,
+    },
+}
+
+/// Resumo da nota, entregue ao destinatário antes da manifestação, a tag <resNFe>
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+#[serde(try_from = "ResumoNfeContainer")]
+pub struct ResumoNfe {
+    pub chave_acesso: String,
+    /// CNPJ ou CPF do emitente
+    pub documento: Documento,
+    /// Razão social do emitente
+    pub nome: String,
+    pub ie: Option<String>,
+    pub emissao: DateTime<FixedOffset>,
+    pub tipo_operacao: TipoOperacao,
+    pub valor_total: f32,
+    pub digest: Option<String>,
+    pub recebimento: DateTime<FixedOffset>,
+    /// Número do protocolo de autorização
+    pub protocolo: String,
+    /// Situação(cSitNFe): 1 autorizada, 2 denegada e 3 cancelada
+    pub situacao: u8,
+}
+
+/// Resumo de evento de uma nota de interesse, a tag <resEvento>
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+#[serde(try_from = "ResumoEventoContainer")]
+pub struct ResumoEvento {
+    /// Código do órgão que registrou o evento
+    pub orgao: u8,
+    /// CNPJ ou CPF do autor do evento
+    pub autor: Documento,
+    pub chave_acesso: String,
+    pub horario: DateTime<FixedOffset>,
+    /// Código do tipo do evento(tpEvento)
+    pub tipo: u32,
+    pub sequencia: u8,
+    pub descricao: String,
+    pub recebimento: DateTime<FixedOffset>,
+    /// Número do protocolo do evento
+    pub protocolo: String,
+}
+
+/// Controle do NSU entre as consultas da distribuição
+///
+/// Guarda o último NSU recebido e o horário a partir do qual uma nova
+/// consulta é permitida, evitando a rejeição por consumo indevido(656).
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ControleNsu {
+    pub ultimo_nsu: u64,
+    pub maximo_nsu: u64,
+    /// Horário da próxima consulta permitida, quando houver espera
+    pub proxima_consulta: Option<DateTime<Utc>>,
+}
+
+impl DistribuicaoDFe {
+    /// Valida a forma da consulta: por NSU ou por chave de acesso
+    pub fn validar(&self) -> Result<(), Error> {
+        match (&self.consulta, &self.chave_acesso) {
+            (Some(_), None) => Ok(()),
+            (None, Some(chave)) => chave.parse::<ChaveAcesso>().map(|_| ()),
+            _ => Err(Error::DistribuicaoInvalida(
+                "Informe a consulta por NSU ou a chave de acesso".to_string(),
+            )),
+        }
+    }
+}
+
+impl RetornoDistribuicao {
+    /// Indica se foram entregues documentos(138)
+    pub fn documentos_localizados(&self) -> bool {
+        self.status == 138
+    }
+
+    /// Indica se não havia documentos a partir do NSU consultado(137)
+    pub fn nenhum_documento(&self) -> bool {
+        self.status == 137
+    }
+
+    /// Indica a rejeição por consultas em excesso(656)
+    pub fn consumo_indevido(&self) -> bool {
+        self.status == 656
+    }
+
+    /// Indica se ainda há documentos além do último NSU entregue
+    pub fn possui_mais(&self) -> bool {
+        self.ultimo_nsu < self.maximo_nsu
+    }
+
+    /// NSUs entre o último anterior e o último entregue que não vieram no lote
+    pub fn lacunas(&self, ultimo_nsu_anterior: u64) -> Vec<u64> {
+        (ultimo_nsu_anterior + 1..=self.ultimo_nsu)
+            .filter(|nsu| self.documentos.iter().all(|d| d.nsu != *nsu))
+            .collect()
+    }
+
+    /// Descompacta e lê todos os documentos, junto com o seu NSU
+    ///
+    /// Cada documento possui o seu resultado, para que um documento inválido
+    /// não impeça a leitura dos demais do lote.
+    pub fn decodificar(&self) -> Vec<(u64, Result<DocumentoDistribuido, Error>)> {
+        self.documentos
+            .iter()
+            .map(|doc| (doc.nsu, doc.decodificar()))
+            .collect()
+    }
+}
+
+impl DocumentoZip {
+    /// Nome do documento no schema, como `resNFe` ou `procEventoNFe`
+    pub fn tipo(&self) -> &str {
+        self.schema.split('_').next().unwrap_or(&self.schema)
+    }
+
+    /// XML descompactado
+    pub fn xml(&self) -> Result<String, Error> {
+        let compactado = base64::decode_block(self.conteudo.trim()).map_err(|e| {
+            Error::DistribuicaoInvalida(format!("NSU {} com base64 inválido: {}", self.nsu, e))
+        })?;
+
+        let mut xml = String::new();
+        GzDecoder::new(&compactado[..])
+            .read_to_string(&mut xml)
+            .map_err(|e| {
+                Error::DistribuicaoInvalida(format!("NSU {} com gzip inválido: {}", self.nsu, e))
+            })?;
+
+        Ok(xml)
+    }
+
+    /// Descompacta e lê o documento conforme o seu schema
+    pub fn decodificar(&self) -> Result<DocumentoDistribuido, Error> {
+        let xml = self.xml()?;
+
+        Ok(match self.tipo() {
+            "resNFe" => DocumentoDistribuido::ResumoNfe(xml.parse()?),
+            "resEvento" => DocumentoDistribuido::ResumoEvento(xml.parse()?),
+            "procNFe" => DocumentoDistribuido::Nfe(Box::new(xml.parse()?)),
+            "procEventoNFe" => DocumentoDistribuido::Evento(Box::new(xml.parse()?)),
+            _ => DocumentoDistribuido::Outro {
+                schema: self.schema.clone(),
+                xml,
+            },
+        })
+    }
+}
+
+impl ResumoNfe {
+    pub fn autorizada(&self) -> bool {
+        self.situacao == 1
+    }
+
+    pub fn denegada(&self) -> bool {
+        self.situacao == 2
+    }
+
+    pub fn cancelada(&self) -> bool {
+        self.situacao == 3
+    }
+}
+
+impl ControleNsu {
+    /// Controle a partir do último NSU já recebido. Zero para o início
+    pub fn new(ultimo_nsu: u64) -> Self {
+        Self {
+            ultimo_nsu,
+            maximo_nsu: ultimo_nsu,
+            proxima_consulta: None,
+        }
+    }
+
+    /// Consulta dos documentos posteriores ao último NSU
+    pub fn consulta(&self) -> ConsultaDistribuicao {
+        ConsultaDistribuicao::UltimoNsu(self.ultimo_nsu)
+    }
+
+    /// Indica se uma nova consulta é permitida no horário informado
+    pub fn pode_consultar(&self, agora: DateTime<Utc>) -> bool {
+        self.proxima_consulta
+            .map_or(true, |proxima| agora >= proxima)
+    }
+
+    /// Registra o retorno de uma consulta por último NSU
+    ///
+    /// Ao alcançar o maior NSU, ou sem documentos, a próxima consulta só é
+    /// permitida após o intervalo exigido pela SEFAZ. O NSU nunca retrocede.
+    pub fn registrar(&mut self, retorno: &RetornoDistribuicao, agora: DateTime<Utc>) {
+        let espera = agora + Duration::seconds(INTERVALO_CONSULTA_DISTRIBUICAO);
+
+        if retorno.consumo_indevido() {
+            self.proxima_consulta = Some(espera);
+            return;
+        }
+
+        if !retorno.documentos_localizados() && !retorno.nenhum_documento() {
+            return;
+        }
+
+        self.ultimo_nsu = self.ultimo_nsu.max(retorno.ultimo_nsu);
+        self.maximo_nsu = retorno.maximo_nsu.max(self.ultimo_nsu);
+        self.proxima_consulta = if retorno.nenhum_documento() || !retorno.possui_mais() {
+            Some(espera)
+        } else {
+            None
+        };
+    }
+}
+
+impl fmt::Display for DistribuicaoDFe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (cnpj, cpf) = match &self.interessado {
+            Interessado::Cnpj(cnpj) => (Some(cnpj.to_string()), None),
+            Interessado::Cpf(cpf) => (None, Some(cpf.to_string())),
+        };
+
+        let dist = DistDFeIntContainer {
+            namespace: NAMESPACE_NFE.to_string(),
+            versao: VERSAO_DISTRIBUICAO.to_string(),
+            ambiente: self.ambiente,
+            codigo_uf_autor: self.codigo_uf_autor,
+            cnpj,
+            cpf,
+            ultimo_nsu: match self.consulta {
+                Some(ConsultaDistribuicao::UltimoNsu(nsu)) => Some(UltimoNsuContainer {
+                    nsu: formatar_nsu(nsu),
+                }),
+                _ => None,
+            },
+            nsu: match self.consulta {
+                Some(ConsultaDistribuicao::Nsu(nsu)) => Some(NsuContainer {
+                    nsu: formatar_nsu(nsu),
+                }),
+                _ => None,
+            },
+            chave_acesso: self.chave_acesso.as_ref().map(|chave| ChaveContainer {
+                chave_acesso: chave.clone(),
+            }),
+        };
+
+        let xml = quick_xml::se::to_string(&dist).expect("Falha ao serializar a distribuição");
+
+        f.write_str(&xml)
+    }
+}
+
+impl FromStr for RetornoDistribuicao {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ret: RetDistDFeIntContainer = quick_xml::de::from_str(s)?;
+
+        Ok(Self {
+            ambiente: ret.ambiente,
+            versao_aplicativo: ret.versao_aplicativo,
+            status: ret.status,
+            motivo: ret.motivo,
+            resposta: ret.resposta,
+            ultimo_nsu: ret.ultimo_nsu,
+            maximo_nsu: ret.maximo_nsu,
+            documentos: ret
+                .lote
+                .map(|lote| lote.documentos)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|doc| DocumentoZip {
+                    nsu: doc.nsu,
+                    schema: doc.schema,
+                    conteudo: doc.conteudo,
+                })
+                .collect(),
+        })
+    }
+}
+
+impl FromStr for ResumoNfe {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl FromStr for ResumoEvento {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl TryFrom<ResumoNfeContainer> for ResumoNfe {
+    type Error = Error;
+
+    fn try_from(resumo: ResumoNfeContainer) -> Result<Self, Self::Error> {
+        Ok(Self {
+            chave_acesso: resumo.chave_acesso,
+            documento: Documento::from_tags(resumo.cnpj, resumo.cpf, None)?,
+            nome: resumo.nome,
+            ie: resumo.ie,
+            emissao: resumo.emissao,
+            tipo_operacao: resumo.tipo_operacao,
+            valor_total: resumo.valor_total,
+            digest: resumo.digest,
+            recebimento: resumo.recebimento,
+            protocolo: resumo.protocolo,
+            situacao: resumo.situacao,
+        })
+    }
+}
+
+impl TryFrom<ResumoEventoContainer> for ResumoEvento {
+    type Error = Error;
+
+    fn try_from(resumo: ResumoEventoContainer) -> Result<Self, Self::Error> {
+        Ok(Self {
+            orgao: resumo.orgao,
+            autor: Documento::from_tags(resumo.cnpj, resumo.cpf, None)?,
+            chave_acesso: resumo.chave_acesso,
+            horario: resumo.horario,
+            tipo: resumo.tipo,
+            sequencia: resumo.sequencia,
+            descricao: resumo.descricao,
+            recebimento: resumo.recebimento,
+            protocolo: resumo.protocolo,
+        })
+    }
+}
+
+/// NSU com 15 dígitos, completado com zeros
+fn formatar_nsu(nsu: u64) -> String {
+    format!("{:015}", nsu)
+}
+
+#[derive(Serialize)]
+#[serde(rename = "distDFeInt")]
+struct DistDFeIntContainer {
+    #[serde(rename = "xmlns")]
+    pub namespace: String,
+    #[serde(rename = "versao")]
+    pub versao: String,
+    #[serde(rename = "$unflatten=tpAmb")]
+    pub ambiente: TipoAmbiente,
+    #[serde(rename = "$unflatten=cUFAutor")]
+    pub codigo_uf_autor: u8,
+    #[serde(rename = "$unflatten=CNPJ")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cnpj: Option<String>,
+    #[serde(rename = "$unflatten=CPF")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpf: Option<String>,
+    #[serde(rename = "distNSU")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ultimo_nsu: Option<UltimoNsuContainer>,
+    #[serde(rename = "consNSU")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nsu: Option<NsuContainer>,
+    #[serde(rename = "consChNFe")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chave_acesso: Option<ChaveContainer>,
+}
+
+#[derive(Serialize)]
+struct UltimoNsuContainer {
+    #[serde(rename = "$unflatten=ultNSU")]
+    pub nsu: String,
+}
+
+#[derive(Serialize)]
+struct NsuContainer {
+    #[serde(rename = "$unflatten=NSU")]
+    pub nsu: String,
+}
+
+#[derive(Serialize)]
+struct ChaveContainer {
+    #[serde(rename = "$unflatten=chNFe")]
+    pub chave_acesso: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "retDistDFeInt")]
+struct RetDistDFeIntContainer {
+    #[serde(rename = "$unflatten=tpAmb")]
+    pub ambiente: TipoAmbiente,
+    #[serde(rename = "$unflatten=verAplic")]
+    pub versao_aplicativo: String,
+    #[serde(rename = "$unflatten=cStat")]
+    pub status: u16,
+    #[serde(rename = "$unflatten=xMotivo")]
+    pub motivo: String,
+    #[serde(rename = "$unflatten=dhResp")]
+    pub resposta: DateTime<FixedOffset>,
+    #[serde(rename = "$unflatten=ultNSU")]
+    pub ultimo_nsu: u64,
+    #[serde(rename = "$unflatten=maxNSU")]
+    pub maximo_nsu: u64,
+    #[serde(rename = "loteDistDFeInt")]
+    pub lote: Option<LoteContainer>,
+}
+
+#[derive(Deserialize)]
+struct LoteContainer {
+    #[serde(rename = "docZip")]
+    #[serde(default)]
+    pub documentos: Vec<DocZipContainer>,
+}
+
+#[derive(Deserialize)]
+struct DocZipContainer {
+    #[serde(rename = "NSU")]
+    pub nsu: u64,
+    #[serde(rename = "schema")]
+    pub schema: String,
+    #[serde(rename = "$value")]
+    pub conteudo: String,
+}
+
+#[derive(Deserialize)]
+struct ResumoNfeContainer {
+    #[serde(rename = "$unflatten=chNFe")]
+    pub chave_acesso: String,
+    #[serde(rename = "$unflatten=CNPJ")]
+    pub cnpj: Option<Cnpj>,
+    #[serde(rename = "$unflatten=CPF")]
+    pub cpf: Option<Cpf>,
+    #[serde(rename = "$unflatten=xNome")]
+    pub nome: String,
+    #[serde(rename = "$unflatten=IE")]
+    pub ie: Option<String>,
+    #[serde(rename = "$unflatten=dhEmi")]
+    pub emissao: DateTime<FixedOffset>,
+    #[serde(rename = "$unflatten=tpNF")]
+    pub tipo_operacao: TipoOperacao,
+    #[serde(rename = "$unflatten=vNF")]
+    pub valor_total: f32,
+    #[serde(rename = "$unflatten=digVal")]
+    pub digest: Option<String>,
+    #[serde(rename = "$unflatten=dhRecbto")]
+    pub recebimento: DateTime<FixedOffset>,
+    #[serde(rename = "$unflatten=nProt")]
+    pub protocolo: String,
+    #[serde(rename = "$unflatten=cSitNFe")]
+    pub situacao: u8,
+}
+
+#[derive(Deserialize)]
+struct ResumoEventoContainer {
+    #[serde(rename = "$unflatten=cOrgao")]
+    pub orgao: u8,
+    #[serde(rename = "$unflatten=CNPJ")]
+    pub cnpj: Option<Cnpj>,
+    #[serde(rename = "$unflatten=CPF")]
+    pub cpf: Option<Cpf>,
+    #[serde(rename = "$unflatten=chNFe")]
+    pub chave_acesso: String,
+    #[serde(rename = "$unflatten=dhEvento")]
+    pub horario: DateTime<FixedOffset>,
+    #[serde(rename = "$unflatten=tpEvento")]
+    pub tipo: u32,
+    #[serde(rename = "$unflatten=nSeqEvento")]
+    pub sequencia: u8,
+    #[serde(rename = "$unflatten=xEvento")]
+    pub descricao: String,
+    #[serde(rename = "$unflatten=dhRecbto")]
+    pub recebimento: DateTime<FixedOffset>,
+    #[serde(rename = "$unflatten=nProt")]
+    pub protocolo: String,
+}
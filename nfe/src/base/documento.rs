@@ -0,0 +1,392 @@
+//! Documentos de identificação: CNPJ, CPF e do estrangeiro
+
+use super::Error;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// CNPJ - Cadastro Nacional da Pessoa Jurídica
+///
+/// Suporta o formato numérico e o alfanumérico, válido a partir
+/// de julho de 2026. No alfanumérico, as 12 primeiras posições
+/// aceitam letras e números e os dígitos verificadores continuam
+/// numéricos.
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Cnpj(String);
+
+/// CPF - Cadastro de Pessoas Físicas
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Cpf(String);
+
+/// Tamanho da identificação do estrangeiro, quando informada
+const TAMANHO_ESTRANGEIRO: RangeInclusive<usize> = 5..=20;
+
+/// Documento de uma pessoa jurídica(CNPJ), física(CPF) ou do exterior
+///
+/// Serializado como a tag <CNPJ>, <CPF> ou <idEstrangeiro>, conforme a variante.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Serialize)]
+pub enum Documento {
+    #[serde(rename = "CNPJ")]
+    Cnpj(Cnpj),
+    #[serde(rename = "CPF")]
+    Cpf(Cpf),
+    /// Identificação do destinatário estrangeiro(idEstrangeiro), como o
+    /// passaporte. Vazia quando o destinatário não possui
+    #[serde(rename = "idEstrangeiro")]
+    Estrangeiro(String),
+}
+
+impl Cnpj {
+    /// CNPJ sem a máscara
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// CNPJ com a máscara. Ex.: 12.ABC.345/01DE-35
+    pub fn formatado(&self) -> String {
+        format!(
+            "{}.{}.{}/{}-{}",
+            &self.0[0..2],
+            &self.0[2..5],
+            &self.0[5..8],
+            &self.0[8..12],
+            &self.0[12..14]
+        )
+    }
+
+    /// Raiz do CNPJ, que identifica a empresa independente do estabelecimento
+    pub fn raiz(&self) -> &str {
+        &self.0[0..8]
+    }
+
+    /// Indica se o CNPJ está no formato alfanumérico
+    pub fn alfanumerico(&self) -> bool {
+        self.0.chars().any(|c| c.is_ascii_alphabetic())
+    }
+}
+
+impl Cpf {
+    /// CPF sem a máscara
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// CPF com a máscara. Ex.: 123.456.789-09
+    pub fn formatado(&self) -> String {
+        format!(
+            "{}.{}.{}-{}",
+            &self.0[0..3],
+            &self.0[3..6],
+            &self.0[6..9],
+            &self.0[9..11]
+        )
+    }
+}
+
+impl Documento {
+    /// Documento sem a máscara
+    pub fn as_str(&self) -> &str {
+        match self {
+            Documento::Cnpj(cnpj) => cnpj.as_str(),
+            Documento::Cpf(cpf) => cpf.as_str(),
+            Documento::Estrangeiro(id) => id,
+        }
+    }
+
+    /// Documento com a máscara. A identificação do estrangeiro não possui
+    pub fn formatado(&self) -> String {
+        match self {
+            Documento::Cnpj(cnpj) => cnpj.formatado(),
+            Documento::Cpf(cpf) => cpf.formatado(),
+            Documento::Estrangeiro(id) => id.clone(),
+        }
+    }
+
+    /// CNPJ, quando pessoa jurídica
+    pub fn cnpj(&self) -> Option<&Cnpj> {
+        match self {
+            Documento::Cnpj(cnpj) => Some(cnpj),
+            _ => None,
+        }
+    }
+
+    /// CPF, quando pessoa física
+    pub fn cpf(&self) -> Option<&Cpf> {
+        match self {
+            Documento::Cpf(cpf) => Some(cpf),
+            _ => None,
+        }
+    }
+
+    /// Identificação do estrangeiro, quando do exterior
+    pub fn estrangeiro(&self) -> Option<&str> {
+        match self {
+            Documento::Estrangeiro(id) => Some(id),
+            _ => None,
+        }
+    }
+
+    /// Monta o documento a partir das tags <CNPJ>, <CPF> e <idEstrangeiro> do XML
+    ///
+    /// Exatamente uma delas deve estar presente. O idEstrangeiro, quando
+    /// não vazio, deve possuir de 5 a 20 caracteres.
+    pub(crate) fn from_tags(
+        cnpj: Option<Cnpj>,
+        cpf: Option<Cpf>,
+        estrangeiro: Option<String>,
+    ) -> Result<Self, Error> {
+        match (cnpj, cpf, estrangeiro) {
+            (Some(cnpj), None, None) => Ok(Documento::Cnpj(cnpj)),
+            (None, Some(cpf), None) => Ok(Documento::Cpf(cpf)),
+            (None, None, Some(id)) => {
+                let id = id.trim();
+
+                if !id.is_empty() && !TAMANHO_ESTRANGEIRO.contains(&id.chars().count()) {
+                    return Err(Error::DocumentoInvalido(format!(
+                        "O idEstrangeiro deve possuir de {} a {} caracteres: {}",
+                        TAMANHO_ESTRANGEIRO.start(),
+                        TAMANHO_ESTRANGEIRO.end(),
+                        id
+                    )));
+                }
+
+                Ok(Documento::Estrangeiro(id.to_string()))
+            }
+            (None, None, None) => Err(Error::DocumentoInvalido(
+                "CNPJ, CPF ou idEstrangeiro não informado".to_string(),
+            )),
+            _ => Err(Error::DocumentoInvalido(
+                "Informe apenas um documento: CNPJ, CPF ou idEstrangeiro".to_string(),
+            )),
+        }
+    }
+}
+
+/// Remove a máscara(pontos, barra, hífen e espaços) do documento
+pub fn remover_mascara(documento: &str) -> String {
+    documento
+        .chars()
+        .filter(|c| !matches!(c, '.' | '/' | '-' | ' '))
+        .collect()
+}
+
+/// Calcula o dígito verificador pelo módulo 11, com pesos de 2 a `peso_maximo`
+///
+/// O valor de cada caractere é o seu código ASCII menos 48, o que mantém
+/// o cálculo tradicional para dígitos e atende ao CNPJ alfanumérico.
+pub(crate) fn digito_modulo11(base: &str, peso_maximo: u32) -> u32 {
+    let soma: u32 = base
+        .bytes()
+        .rev()
+        .zip((2..=peso_maximo).cycle())
+        .map(|(c, p)| (c as u32).saturating_sub(48) * p)
+        .sum();
+
+    match soma % 11 {
+        0 | 1 => 0,
+        resto => 11 - resto,
+    }
+}
+
+impl FromStr for Cnpj {
+    type Err = Error;
+
+    /// Aceita o CNPJ com ou sem máscara
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cnpj = remover_mascara(s.trim()).to_uppercase();
+
+        let invalido = |motivo: &str| Err(Error::CnpjInvalido(format!("{}: {}", motivo, s)));
+
+        if cnpj.len() != 14 {
+            return invalido("O CNPJ deve possuir 14 caracteres");
+        }
+
+        if !cnpj[..12]
+            .chars()
+            .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase())
+            || !cnpj[12..].chars().all(|c| c.is_ascii_digit())
+        {
+            return invalido("Caracteres inválidos");
+        }
+
+        if cnpj.chars().all(|c| c == '0') {
+            return invalido("CNPJ zerado");
+        }
+
+        let dv1 = digito_modulo11(&cnpj[..12], 9);
+        let dv2 = digito_modulo11(&format!("{}{}", &cnpj[..12], dv1), 9);
+
+        if cnpj[12..] != format!("{}{}", dv1, dv2) {
+            return invalido("Dígitos verificadores incorretos");
+        }
+
+        Ok(Self(cnpj))
+    }
+}
+
+impl FromStr for Cpf {
+    type Err = Error;
+
+    /// Aceita o CPF com ou sem máscara
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cpf = remover_mascara(s.trim());
+
+        let invalido = |motivo: &str| Err(Error::CpfInvalido(format!("{}: {}", motivo, s)));
+
+        if cpf.len() != 11 || !cpf.chars().all(|c| c.is_ascii_digit()) {
+            return invalido("O CPF deve possuir 11 dígitos");
+        }
+
+        if cpf.chars().all(|c| c == cpf.chars().next().unwrap_or('0')) {
+            return invalido("Dígitos repetidos");
+        }
+
+        let dv1 = digito_modulo11(&cpf[..9], 11);
+        let dv2 = digito_modulo11(&format!("{}{}", &cpf[..9], dv1), 11);
+
+        if cpf[9..] != format!("{}{}", dv1, dv2) {
+            return invalido("Dígitos verificadores incorretos");
+        }
+
+        Ok(Self(cpf))
+    }
+}
+
+impl FromStr for Documento {
+    type Err = Error;
+
+    /// Identifica o documento pelo tamanho: 11 dígitos para o CPF e 14
+    /// caracteres para o CNPJ, com ou sem máscara
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if remover_mascara(s.trim()).len() == 11 {
+            s.parse().map(Documento::Cpf)
+        } else {
+            s.parse().map(Documento::Cnpj)
+        }
+    }
+}
+
+impl From<Cnpj> for Documento {
+    fn from(cnpj: Cnpj) -> Self {
+        Documento::Cnpj(cnpj)
+    }
+}
+
+impl From<Cpf> for Documento {
+    fn from(cpf: Cpf) -> Self {
+        Documento::Cpf(cpf)
+    }
+}
+
+impl fmt::Display for Cnpj {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl fmt::Display for Cpf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl fmt::Display for Documento {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Cnpj {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let cnpj = String::deserialize(deserializer)?;
+
+        cnpj.parse().map_err(de::Error::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for Cpf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let cpf = String::deserialize(deserializer)?;
+
+        cpf.parse().map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Cnpj {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl Serialize for Cpf {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl PartialEq<str> for Cnpj {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Cnpj {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<Cnpj> for &str {
+    fn eq(&self, other: &Cnpj) -> bool {
+        *self == other.0
+    }
+}
+
+impl PartialEq<str> for Cpf {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Cpf {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<Cpf> for &str {
+    fn eq(&self, other: &Cpf) -> bool {
+        *self == other.0
+    }
+}
+
+impl PartialEq<str> for Documento {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Documento {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<Documento> for &str {
+    fn eq(&self, other: &Documento) -> bool {
+        *self == other.as_str()
+    }
+}
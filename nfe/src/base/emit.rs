@@ -1,16 +1,20 @@
 //! Emitente da NF-e
 
+use super::documento::{Cnpj, Cpf, Documento};
 use super::endereco::*;
 use super::Error;
 use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::fmt;
 use std::str::FromStr;
 
 /// Emitente da NF-e
 #[derive(Debug, Deserialize, PartialEq, Serialize, Clone)]
-#[serde(rename = "emit")]
+#[serde(rename = "emit", try_from = "EmitenteContainer")]
 pub struct Emitente {
-    #[serde(rename = "$unflatten=CNPJ")]
-    pub cnpj: String,
+    /// CNPJ ou CPF do emitente
+    #[serde(rename = "$value")]
+    pub documento: Documento,
     #[serde(rename = "$unflatten=xNome")]
     pub razao_social: String,
     #[serde(rename = "$unflatten=xFant")]
@@ -33,8 +37,44 @@ impl FromStr for Emitente {
     }
 }
 
-impl ToString for Emitente {
-    fn to_string(&self) -> String {
-        quick_xml::se::to_string(self).expect("Falha ao serializar o emitente")
+impl fmt::Display for Emitente {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar o emitente");
+
+        f.write_str(&xml)
+    }
+}
+
+impl TryFrom<EmitenteContainer> for Emitente {
+    type Error = Error;
+
+    fn try_from(emit: EmitenteContainer) -> Result<Self, Self::Error> {
+        Ok(Self {
+            documento: Documento::from_tags(emit.cnpj, emit.cpf, None)?,
+            razao_social: emit.razao_social,
+            nome_fantasia: emit.nome_fantasia,
+            ie: emit.ie,
+            iest: emit.iest,
+            endereco: emit.endereco,
+        })
     }
 }
+
+#[derive(Deserialize)]
+#[serde(rename = "emit")]
+struct EmitenteContainer {
+    #[serde(rename = "$unflatten=CNPJ")]
+    cnpj: Option<Cnpj>,
+    #[serde(rename = "$unflatten=CPF")]
+    cpf: Option<Cpf>,
+    #[serde(rename = "$unflatten=xNome")]
+    razao_social: String,
+    #[serde(rename = "$unflatten=xFant")]
+    nome_fantasia: Option<String>,
+    #[serde(rename = "$unflatten=IE")]
+    ie: String,
+    #[serde(rename = "$unflatten=IEST")]
+    iest: Option<u32>,
+    #[serde(rename = "enderEmit")]
+    endereco: Endereco,
+}
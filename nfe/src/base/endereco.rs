@@ -1,7 +1,11 @@
 //! Endereço do emitente/destinatário da NF-e
 
+use super::municipio::{validar_municipio, CODIGO_EXTERIOR};
+use super::pais::{validar_pais, Pais, CODIGO_BRASIL, NOME_BRASIL};
+use super::uf::Uf;
 use super::Error;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 
 /// Representação de um endereço usado na NFe
@@ -33,6 +37,73 @@ pub struct Endereco {
     pub telefone: Option<String>,
 }
 
+/// Sigla usada nos endereços do exterior
+pub const SIGLA_EXTERIOR: &str = "EX";
+
+/// Nome do município usado nos endereços do exterior
+pub const MUNICIPIO_EXTERIOR: &str = "EXTERIOR";
+
+impl Endereco {
+    /// UF do endereço, a partir da sigla
+    pub fn uf(&self) -> Result<Uf, Error> {
+        self.sigla_uf.parse()
+    }
+
+    /// Indica se o endereço é do exterior(UF "EX")
+    pub fn exterior(&self) -> bool {
+        self.sigla_uf.trim().eq_ignore_ascii_case(SIGLA_EXTERIOR)
+    }
+
+    /// Valida o código do município contra a UF do endereço
+    ///
+    /// Endereços do exterior devem usar o código 9999999.
+    pub fn validar_municipio(&self) -> Result<(), Error> {
+        if self.exterior() {
+            if self.codigo_municipio != CODIGO_EXTERIOR {
+                return Err(Error::MunicipioInvalido(format!(
+                    "Endereço do exterior deve usar o código {}: {}",
+                    CODIGO_EXTERIOR, self.codigo_municipio
+                )));
+            }
+
+            return Ok(());
+        }
+
+        validar_municipio(self.codigo_municipio, self.uf()?)
+    }
+
+    /// Valida o país do endereço
+    ///
+    /// Endereços nacionais devem usar 1058(BRASIL) e os do exterior,
+    /// qualquer outro país.
+    pub fn validar_pais(&self) -> Result<(), Error> {
+        validar_pais(self.codigo_pais, &self.nome_pais)?;
+
+        match (self.exterior(), self.codigo_pais == CODIGO_BRASIL) {
+            (true, true) => Err(Error::PaisInvalido(format!(
+                "Endereço do exterior não pode usar o país {}({})",
+                CODIGO_BRASIL, NOME_BRASIL
+            ))),
+            (false, false) => Err(Error::PaisInvalido(format!(
+                "Endereço nacional deve usar o país {}({}): {}",
+                CODIGO_BRASIL, NOME_BRASIL, self.codigo_pais
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Converte o endereço para o exterior
+    ///
+    /// Preenche a UF "EX", o município 9999999(EXTERIOR) e o país informado
+    pub fn definir_exterior(&mut self, pais: Pais) {
+        self.sigla_uf = SIGLA_EXTERIOR.to_string();
+        self.codigo_municipio = CODIGO_EXTERIOR;
+        self.nome_municipio = MUNICIPIO_EXTERIOR.to_string();
+        self.codigo_pais = pais.codigo;
+        self.nome_pais = pais.nome.to_string();
+    }
+}
+
 impl FromStr for Endereco {
     type Err = Error;
 
@@ -41,8 +112,10 @@ impl FromStr for Endereco {
     }
 }
 
-impl ToString for Endereco {
-    fn to_string(&self) -> String {
-        quick_xml::se::to_string(self).expect("Falha ao serializar o endereço")
+impl fmt::Display for Endereco {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar o endereço");
+
+        f.write_str(&xml)
     }
 }
@@ -8,4 +8,79 @@ pub enum Error {
     Io(std::io::Error),
     #[display(fmt = "Falha no parse: {}", _0)]
     Serde(quick_xml::de::DeError),
+    #[display(fmt = "Chave de acesso inválida: {}", _0)]
+    #[from(ignore)]
+    ChaveAcessoInvalida(#[error(not(source))] String),
+    #[display(fmt = "Chave de acesso divergente: {}", _0)]
+    #[from(ignore)]
+    ChaveAcessoDivergente(#[error(not(source))] String),
+    #[display(fmt = "CNPJ inválido. {}", _0)]
+    #[from(ignore)]
+    CnpjInvalido(#[error(not(source))] String),
+    #[display(fmt = "CPF inválido. {}", _0)]
+    #[from(ignore)]
+    CpfInvalido(#[error(not(source))] String),
+    #[display(fmt = "Documento inválido. {}", _0)]
+    #[from(ignore)]
+    DocumentoInvalido(#[error(not(source))] String),
+    #[display(fmt = "Inscrição estadual inválida. {}", _0)]
+    #[from(ignore)]
+    IeInvalida(#[error(not(source))] String),
+    #[display(fmt = "UF inválida. {}", _0)]
+    #[from(ignore)]
+    UfInvalida(#[error(not(source))] String),
+    #[display(fmt = "Município inválido. {}", _0)]
+    #[from(ignore)]
+    MunicipioInvalido(#[error(not(source))] String),
+    #[display(fmt = "País inválido. {}", _0)]
+    #[from(ignore)]
+    PaisInvalido(#[error(not(source))] String),
+    #[display(fmt = "GTIN inválido. {}", _0)]
+    #[from(ignore)]
+    GtinInvalido(#[error(not(source))] String),
+    #[display(fmt = "CFOP inválido. {}", _0)]
+    #[from(ignore)]
+    CfopInvalido(#[error(not(source))] String),
+    #[display(fmt = "NCM inválido. {}", _0)]
+    #[from(ignore)]
+    NcmInvalido(#[error(not(source))] String),
+    #[display(fmt = "CEST inválido. {}", _0)]
+    #[from(ignore)]
+    CestInvalido(#[error(not(source))] String),
+    #[display(fmt = "CST inválido. {}", _0)]
+    #[from(ignore)]
+    CstInvalido(#[error(not(source))] String),
+    #[display(fmt = "Pagamento inválido. {}", _0)]
+    #[from(ignore)]
+    PagamentoInvalido(#[error(not(source))] String),
+    #[display(fmt = "Protocolo inválido. {}", _0)]
+    #[from(ignore)]
+    ProtocoloInvalido(#[error(not(source))] String),
+    #[display(fmt = "Assinatura inválida. {}", _0)]
+    #[from(ignore)]
+    AssinaturaInvalida(#[error(not(source))] String),
+    #[display(fmt = "Certificado inválido. {}", _0)]
+    #[from(ignore)]
+    CertificadoInvalido(#[error(not(source))] String),
+    #[display(fmt = "XML inválido. {}", _0)]
+    #[from(ignore)]
+    XmlInvalido(#[error(not(source))] String),
+    #[display(fmt = "Evento inválido. {}", _0)]
+    #[from(ignore)]
+    EventoInvalido(#[error(not(source))] String),
+    #[display(fmt = "Inutilização inválida. {}", _0)]
+    #[from(ignore)]
+    InutilizacaoInvalida(#[error(not(source))] String),
+    #[display(fmt = "Lote inválido. {}", _0)]
+    #[from(ignore)]
+    LoteInvalido(#[error(not(source))] String),
+    #[display(fmt = "Falha na comunicação com a SEFAZ. {}", _0)]
+    #[from(ignore)]
+    Comunicacao(#[error(not(source))] String),
+    #[display(fmt = "Web service não encontrado. {}", _0)]
+    #[from(ignore)]
+    WebServiceNaoEncontrado(#[error(not(source))] String),
+    #[display(fmt = "Distribuição inválida. {}", _0)]
+    #[from(ignore)]
+    DistribuicaoInvalida(#[error(not(source))] String),
 }
@@ -0,0 +1,102 @@
+//! Cancelamento da NF-e(evento 110111)
+
+use super::{DetEventoContainer, DetalheEvento, Evento};
+use crate::base::{Error, Nfe};
+use chrono::prelude::*;
+
+/// Tamanho mínimo das justificativas dos eventos
+pub const TAMANHO_MINIMO_JUSTIFICATIVA: usize = 15;
+
+/// Tamanho máximo das justificativas dos eventos
+pub const TAMANHO_MAXIMO_JUSTIFICATIVA: usize = 255;
+
+/// Detalhe do evento de cancelamento
+#[derive(Debug, PartialEq, Clone)]
+pub struct Cancelamento {
+    /// Número do protocolo de autorização da nota(nProt)
+    pub protocolo: String,
+    /// Motivo do cancelamento(xJust)
+    pub justificativa: String,
+}
+
+impl Cancelamento {
+    /// Valida o protocolo e a justificativa
+    pub fn validar(&self) -> Result<(), Error> {
+        validar_protocolo(&self.protocolo)?;
+        validar_justificativa(&self.justificativa)
+    }
+
+    pub(super) fn preencher(&self, detalhe: &mut DetEventoContainer) {
+        detalhe.protocolo = Some(self.protocolo.clone());
+        detalhe.justificativa = Some(self.justificativa.clone());
+    }
+
+    pub(super) fn ler(detalhe: DetEventoContainer) -> Result<Self, Error> {
+        match (detalhe.protocolo, detalhe.justificativa) {
+            (Some(protocolo), Some(justificativa)) => Ok(Self {
+                protocolo,
+                justificativa,
+            }),
+            _ => Err(Error::EventoInvalido(
+                "Cancelamento sem o nProt ou o xJust".to_string(),
+            )),
+        }
+    }
+}
+
+impl Evento {
+    /// Evento de cancelamento da nota autorizada
+    ///
+    /// Órgão, ambiente, autor e chave são obtidos da nota. O cancelamento
+    /// possui apenas um evento por nota, sempre com a sequência 1.
+    pub fn cancelamento(
+        nfe: &Nfe,
+        protocolo: &str,
+        justificativa: &str,
+        horario: DateTime<FixedOffset>,
+    ) -> Result<Self, Error> {
+        let evento = Self {
+            orgao: nfe.ide.codigo_uf,
+            ambiente: nfe.ide.ambiente,
+            autor: nfe.emit.documento.clone(),
+            chave_acesso: nfe.chave_acesso.clone(),
+            horario,
+            sequencia: 1,
+            detalhe: DetalheEvento::Cancelamento(Cancelamento {
+                protocolo: protocolo.trim().to_string(),
+                justificativa: justificativa.trim().to_string(),
+            }),
+            assinatura: None,
+        };
+
+        evento.validar()?;
+
+        Ok(evento)
+    }
+}
+
+/// Valida o número do protocolo de autorização, com 15 dígitos
+pub(super) fn validar_protocolo(protocolo: &str) -> Result<(), Error> {
+    if protocolo.len() != 15 || !protocolo.chars().all(|c| c.is_ascii_digit()) {
+        return Err(Error::EventoInvalido(format!(
+            "O protocolo deve possuir 15 dígitos: {}",
+            protocolo
+        )));
+    }
+
+    Ok(())
+}
+
+/// Valida o tamanho da justificativa, de 15 a 255 caracteres
+pub(crate) fn validar_justificativa(justificativa: &str) -> Result<(), Error> {
+    let tamanho = justificativa.trim().chars().count();
+
+    if !(TAMANHO_MINIMO_JUSTIFICATIVA..=TAMANHO_MAXIMO_JUSTIFICATIVA).contains(&tamanho) {
+        return Err(Error::EventoInvalido(format!(
+            "A justificativa deve possuir de {} a {} caracteres. Informado: {}",
+            TAMANHO_MINIMO_JUSTIFICATIVA, TAMANHO_MAXIMO_JUSTIFICATIVA, tamanho
+        )));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+//! Carta de correção eletrônica - CC-e(evento 110110)
+
+use super::{DetEventoContainer, DetalheEvento, Evento, ProcEvento, TipoEvento};
+use crate::base::{Error, Nfe};
+use chrono::prelude::*;
+
+/// Condições de uso da CC-e, texto obrigatório da tag <xCondUso>
+pub const CONDICAO_USO_CCE: &str = "A Carta de Correcao e disciplinada pelo paragrafo 1o-A do art. 7o do Convenio S/N, de 15 de dezembro de 1970 e pode ser utilizada para regularizacao de erro ocorrido na emissao de documento fiscal, desde que o erro nao esteja relacionado com: I - as variaveis que determinam o valor do imposto tais como: base de calculo, aliquota, diferenca de preco, quantidade, valor da operacao ou da prestacao; II - a correcao de dados cadastrais que implique mudanca do remetente ou do destinatario; III - a data de emissao ou de saida.";
+
+/// Tamanho mínimo do texto da correção
+pub const TAMANHO_MINIMO_CORRECAO: usize = 15;
+
+/// Tamanho máximo do texto da correção
+pub const TAMANHO_MAXIMO_CORRECAO: usize = 1000;
+
+/// Quantidade máxima de CC-e para a mesma nota
+pub const MAXIMO_SEQUENCIA_CCE: u8 = 20;
+
+/// Detalhe da carta de correção
+///
+/// Cada nova CC-e substitui a anterior, devendo conter todas as
+/// correções já realizadas na nota.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CartaCorrecao {
+    /// Texto da correção(xCorrecao)
+    pub correcao: String,
+}
+
+impl CartaCorrecao {
+    /// Valida o texto da correção e a sequência do evento
+    pub fn validar(&self, sequencia: u8) -> Result<(), Error> {
+        let tamanho = self.correcao.trim().chars().count();
+
+        if !(TAMANHO_MINIMO_CORRECAO..=TAMANHO_MAXIMO_CORRECAO).contains(&tamanho) {
+            return Err(Error::EventoInvalido(format!(
+                "A correção deve possuir de {} a {} caracteres. Informado: {}",
+                TAMANHO_MINIMO_CORRECAO, TAMANHO_MAXIMO_CORRECAO, tamanho
+            )));
+        }
+
+        if sequencia > MAXIMO_SEQUENCIA_CCE {
+            return Err(Error::EventoInvalido(format!(
+                "Limite de {} CC-e por nota atingido",
+                MAXIMO_SEQUENCIA_CCE
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn preencher(&self, detalhe: &mut DetEventoContainer) {
+        detalhe.correcao = Some(self.correcao.clone());
+        detalhe.condicao_uso = Some(CONDICAO_USO_CCE.to_string());
+    }
+
+    pub(super) fn ler(detalhe: DetEventoContainer) -> Result<Self, Error> {
+        match (detalhe.correcao, detalhe.condicao_uso) {
+            (Some(correcao), Some(_)) => Ok(Self { correcao }),
+            _ => Err(Error::EventoInvalido(
+                "CC-e sem o xCorrecao ou o xCondUso".to_string(),
+            )),
+        }
+    }
+}
+
+impl Evento {
+    /// Carta de correção da nota
+    ///
+    /// A sequência deve seguir as CC-e já registradas para a nota,
+    /// ver [`proxima_sequencia_cce`].
+    pub fn carta_correcao(
+        nfe: &Nfe,
+        correcao: &str,
+        sequencia: u8,
+        horario: DateTime<FixedOffset>,
+    ) -> Result<Self, Error> {
+        let evento = Self {
+            orgao: nfe.ide.codigo_uf,
+            ambiente: nfe.ide.ambiente,
+            autor: nfe.emit.documento.clone(),
+            chave_acesso: nfe.chave_acesso.clone(),
+            horario,
+            sequencia,
+            detalhe: DetalheEvento::CartaCorrecao(CartaCorrecao {
+                correcao: correcao.trim().to_string(),
+            }),
+            assinatura: None,
+        };
+
+        evento.validar()?;
+
+        Ok(evento)
+    }
+}
+
+/// CC-e registradas para a chave de acesso, em ordem de sequência
+///
+/// A última é a que está em vigor, pois consolida as anteriores.
+pub fn cartas_correcao<'a>(chave_acesso: &str, eventos: &'a [ProcEvento]) -> Vec<&'a ProcEvento> {
+    let mut cartas: Vec<&ProcEvento> = eventos
+        .iter()
+        .filter(|p| {
+            p.evento.chave_acesso == chave_acesso
+                && p.evento.tipo() == TipoEvento::CartaCorrecao
+                && p.retorno.registrado()
+        })
+        .collect();
+
+    cartas.sort_by_key(|p| p.evento.sequencia);
+
+    cartas
+}
+
+/// Sequência da próxima CC-e da chave de acesso, a partir das já registradas
+pub fn proxima_sequencia_cce(chave_acesso: &str, eventos: &[ProcEvento]) -> Result<u8, Error> {
+    let sequencia = cartas_correcao(chave_acesso, eventos)
+        .last()
+        .map(|p| p.evento.sequencia.saturating_add(1))
+        .unwrap_or(1);
+
+    if sequencia > MAXIMO_SEQUENCIA_CCE {
+        return Err(Error::EventoInvalido(format!(
+            "Limite de {} CC-e por nota atingido",
+            MAXIMO_SEQUENCIA_CCE
+        )));
+    }
+
+    Ok(sequencia)
+}
@@ -0,0 +1,256 @@
+//! Evento prévio de emissão em contingência - EPEC(evento 110140)
+
+use super::{
+    DetEventoContainer, DetalheEvento, Evento, ORGAO_AMBIENTE_NACIONAL, TIPO_AUTOR_EMITENTE,
+};
+use crate::base::chave::ChaveAcesso;
+use crate::base::dest::IndicadorContribuicaoIe;
+use crate::base::documento::{Cnpj, Cpf, Documento};
+use crate::base::ide::{ModeloDocumentoFiscal, TipoEmissao, TipoOperacao};
+use crate::base::{Error, Nfe};
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize, Serializer};
+use std::convert::TryFrom;
+
+/// Detalhe do EPEC, com o resumo da nota emitida em contingência
+///
+/// Deve ser gerado a partir da própria nota, ver [`Epec::from_nfe`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct Epec {
+    /// Código da UF do autor(cOrgaoAutor)
+    pub orgao_autor: u8,
+    /// Tipo do autor(tpAutor). Apenas a empresa emitente
+    pub tipo_autor: u8,
+    /// Versão do aplicativo do autor(verAplic)
+    pub versao_aplicativo: String,
+    /// Data e hora da emissão da nota(dhEmi)
+    pub emissao: DateTime<Utc>,
+    /// Tipo da operação da nota(tpNF)
+    pub tipo_operacao: TipoOperacao,
+    /// Inscrição estadual do emitente
+    pub ie: String,
+    /// Resumo do destinatário e dos valores da nota
+    pub destinatario: DestinatarioEpec,
+}
+
+/// Resumo do destinatário no EPEC, a tag <dest>
+///
+/// Além da identificação do destinatário, traz os totais da nota.
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(try_from = "DestinatarioEpecContainer")]
+pub struct DestinatarioEpec {
+    /// Sigla da UF do destinatário
+    #[serde(rename = "$unflatten=UF")]
+    pub uf: String,
+    /// CNPJ, CPF ou idEstrangeiro do destinatário
+    #[serde(rename = "$value")]
+    pub documento: Documento,
+    /// Inscrição estadual, apenas de destinatário contribuinte
+    #[serde(rename = "$unflatten=IE")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ie: Option<String>,
+    /// Valor total da nota(vNF)
+    #[serde(rename = "$unflatten=vNF")]
+    #[serde(serialize_with = "serialize_valor")]
+    pub valor_total: f32,
+    /// Valor total do ICMS(vICMS)
+    #[serde(rename = "$unflatten=vICMS")]
+    #[serde(serialize_with = "serialize_valor")]
+    pub valor_icms: f32,
+    /// Valor total do ICMS ST(vST)
+    #[serde(rename = "$unflatten=vST")]
+    #[serde(serialize_with = "serialize_valor")]
+    pub valor_icms_st: f32,
+}
+
+impl Epec {
+    /// Resumo da nota a ser transmitida após o EPEC
+    pub fn from_nfe(nfe: &Nfe, versao_aplicativo: &str) -> Result<Self, Error> {
+        let dest = nfe.dest.as_ref().ok_or_else(|| {
+            Error::EventoInvalido("O EPEC exige o destinatário da nota".to_string())
+        })?;
+        let endereco = dest.endereco.as_ref().ok_or_else(|| {
+            Error::EventoInvalido("Destinatário sem endereço para o EPEC".to_string())
+        })?;
+
+        let destinatario = DestinatarioEpec {
+            uf: endereco.sigla_uf.clone(),
+            documento: dest.documento.clone(),
+            ie: match dest.indicador_ie {
+                IndicadorContribuicaoIe::Contribuinte => dest.ie.clone(),
+                _ => None,
+            },
+            valor_total: nfe.totais.valor_total,
+            valor_icms: nfe.totais.valor_icms,
+            valor_icms_st: nfe.totais.valor_icms_st,
+        };
+
+        Ok(Self {
+            orgao_autor: nfe.ide.codigo_uf,
+            tipo_autor: TIPO_AUTOR_EMITENTE,
+            versao_aplicativo: versao_aplicativo.trim().to_string(),
+            emissao: nfe.ide.emissao.horario,
+            tipo_operacao: nfe.ide.operacao.tipo,
+            ie: nfe.emit.ie.clone(),
+            destinatario,
+        })
+    }
+
+    /// Valida o autor e a forma de emissão da chave de acesso
+    pub fn validar(&self, chave_acesso: &str) -> Result<(), Error> {
+        if self.tipo_autor != TIPO_AUTOR_EMITENTE {
+            return Err(Error::EventoInvalido(format!(
+                "Tipo de autor não permitido no EPEC: {}",
+                self.tipo_autor
+            )));
+        }
+
+        if self.versao_aplicativo.trim().is_empty() || self.versao_aplicativo.len() > 20 {
+            return Err(Error::EventoInvalido(format!(
+                "A versão do aplicativo deve possuir de 1 a 20 caracteres: {}",
+                self.versao_aplicativo
+            )));
+        }
+
+        let chave = chave_acesso
+            .parse::<ChaveAcesso>()
+            .map_err(|e| Error::EventoInvalido(e.to_string()))?;
+
+        if chave.tipo_emissao != TipoEmissao::ContigenciaEpec {
+            return Err(Error::EventoInvalido(format!(
+                "A nota do EPEC deve ser emitida com o tpEmis 4. Informado: {}",
+                chave.tipo_emissao as u8
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Confere se a nota transmitida corresponde ao resumo registrado
+    pub fn conferir(&self, nfe: &Nfe) -> Result<(), Error> {
+        if Self::from_nfe(nfe, &self.versao_aplicativo)? != *self {
+            return Err(Error::EventoInvalido(format!(
+                "A nota {} diverge do resumo do EPEC",
+                nfe.chave_acesso
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn preencher(&self, detalhe: &mut DetEventoContainer) {
+        detalhe.orgao_autor = Some(self.orgao_autor);
+        detalhe.tipo_autor = Some(self.tipo_autor);
+        detalhe.versao_aplicativo = Some(self.versao_aplicativo.clone());
+        detalhe.emissao = Some(self.emissao);
+        detalhe.tipo_operacao = Some(self.tipo_operacao);
+        detalhe.ie = Some(self.ie.clone());
+        detalhe.destinatario = Some(self.destinatario.clone());
+    }
+
+    pub(super) fn ler(detalhe: DetEventoContainer) -> Result<Self, Error> {
+        match (
+            detalhe.orgao_autor,
+            detalhe.tipo_autor,
+            detalhe.versao_aplicativo,
+            detalhe.emissao,
+            detalhe.tipo_operacao,
+            detalhe.ie,
+            detalhe.destinatario,
+        ) {
+            (
+                Some(orgao_autor),
+                Some(tipo_autor),
+                Some(versao_aplicativo),
+                Some(emissao),
+                Some(tipo_operacao),
+                Some(ie),
+                Some(destinatario),
+            ) => Ok(Self {
+                orgao_autor,
+                tipo_autor,
+                versao_aplicativo,
+                emissao,
+                tipo_operacao,
+                ie,
+                destinatario,
+            }),
+            _ => Err(Error::EventoInvalido("EPEC incompleto".to_string())),
+        }
+    }
+}
+
+impl Evento {
+    /// EPEC da nota emitida em contingência
+    ///
+    /// O resumo é obtido da nota, que deve possuir o tpEmis 4. O evento da
+    /// NF-e é registrado no Ambiente Nacional e o da NFC-e na UF do emitente.
+    pub fn epec(
+        nfe: &Nfe,
+        versao_aplicativo: &str,
+        horario: DateTime<FixedOffset>,
+    ) -> Result<Self, Error> {
+        let orgao = match nfe.ide.modelo {
+            ModeloDocumentoFiscal::Nfe => ORGAO_AMBIENTE_NACIONAL,
+            ModeloDocumentoFiscal::Nfce => nfe.ide.codigo_uf,
+        };
+
+        let evento = Self {
+            orgao,
+            ambiente: nfe.ide.ambiente,
+            autor: nfe.emit.documento.clone(),
+            chave_acesso: nfe.chave_acesso.clone(),
+            horario,
+            sequencia: 1,
+            detalhe: DetalheEvento::Epec(Epec::from_nfe(nfe, versao_aplicativo)?),
+            assinatura: None,
+        };
+
+        evento.validar()?;
+
+        Ok(evento)
+    }
+}
+
+impl TryFrom<DestinatarioEpecContainer> for DestinatarioEpec {
+    type Error = Error;
+
+    fn try_from(dest: DestinatarioEpecContainer) -> Result<Self, Self::Error> {
+        Ok(Self {
+            uf: dest.uf,
+            documento: Documento::from_tags(dest.cnpj, dest.cpf, dest.estrangeiro)?,
+            ie: dest.ie,
+            valor_total: dest.valor_total,
+            valor_icms: dest.valor_icms,
+            valor_icms_st: dest.valor_icms_st,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct DestinatarioEpecContainer {
+    #[serde(rename = "$unflatten=UF")]
+    uf: String,
+    #[serde(rename = "$unflatten=CNPJ")]
+    cnpj: Option<Cnpj>,
+    #[serde(rename = "$unflatten=CPF")]
+    cpf: Option<Cpf>,
+    #[serde(rename = "$unflatten=idEstrangeiro")]
+    estrangeiro: Option<String>,
+    #[serde(rename = "$unflatten=IE")]
+    ie: Option<String>,
+    #[serde(rename = "$unflatten=vNF")]
+    valor_total: f32,
+    #[serde(rename = "$unflatten=vICMS")]
+    valor_icms: f32,
+    #[serde(rename = "$unflatten=vST")]
+    valor_icms_st: f32,
+}
+
+/// Valores monetários com duas casas decimais
+fn serialize_valor<S>(valor: &f32, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&format!("{:.2}", valor))
+}
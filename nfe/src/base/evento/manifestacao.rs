@@ -0,0 +1,103 @@
+//! Manifestação do destinatário(eventos 210200, 210210, 210220 e 210240)
+
+use super::cancelamento::validar_justificativa;
+use super::{DetEventoContainer, DetalheEvento, Evento, TipoEvento, ORGAO_AMBIENTE_NACIONAL};
+use crate::base::protocolo::NfeProc;
+use crate::base::Error;
+use chrono::prelude::*;
+
+/// Manifestação do destinatário sobre a operação da nota
+#[derive(Debug, PartialEq, Clone)]
+pub enum Manifestacao {
+    /// Confirmação da operação, realizada conforme a nota
+    Confirmacao,
+    /// Ciência da operação, sem manifestação conclusiva
+    Ciencia,
+    /// Desconhecimento da operação
+    Desconhecimento,
+    /// Operação não realizada, com a justificativa(xJust)
+    NaoRealizada(String),
+}
+
+impl Manifestacao {
+    /// Tipo do evento da manifestação
+    pub fn tipo(&self) -> TipoEvento {
+        match self {
+            Manifestacao::Confirmacao => TipoEvento::ConfirmacaoOperacao,
+            Manifestacao::Ciencia => TipoEvento::CienciaOperacao,
+            Manifestacao::Desconhecimento => TipoEvento::DesconhecimentoOperacao,
+            Manifestacao::NaoRealizada(_) => TipoEvento::OperacaoNaoRealizada,
+        }
+    }
+
+    /// Valida a justificativa da operação não realizada
+    pub fn validar(&self) -> Result<(), Error> {
+        match self {
+            Manifestacao::NaoRealizada(justificativa) => validar_justificativa(justificativa),
+            _ => Ok(()),
+        }
+    }
+
+    pub(super) fn preencher(&self, detalhe: &mut DetEventoContainer) {
+        if let Manifestacao::NaoRealizada(justificativa) = self {
+            detalhe.justificativa = Some(justificativa.clone());
+        }
+    }
+
+    pub(super) fn ler(tipo: TipoEvento, detalhe: DetEventoContainer) -> Result<Self, Error> {
+        match (tipo, detalhe.justificativa) {
+            (TipoEvento::ConfirmacaoOperacao, _) => Ok(Manifestacao::Confirmacao),
+            (TipoEvento::CienciaOperacao, _) => Ok(Manifestacao::Ciencia),
+            (TipoEvento::DesconhecimentoOperacao, _) => Ok(Manifestacao::Desconhecimento),
+            (TipoEvento::OperacaoNaoRealizada, Some(justificativa)) => {
+                Ok(Manifestacao::NaoRealizada(justificativa))
+            }
+            (TipoEvento::OperacaoNaoRealizada, None) => Err(Error::EventoInvalido(
+                "Operação não realizada sem o xJust".to_string(),
+            )),
+            (tipo, _) => Err(Error::EventoInvalido(format!(
+                "O evento {} não é uma manifestação do destinatário",
+                tipo
+            ))),
+        }
+    }
+}
+
+impl Evento {
+    /// Manifestação do destinatário sobre a nota recebida
+    ///
+    /// O autor é o destinatário da nota e o evento é registrado no Ambiente
+    /// Nacional(cOrgao 91). Cada tipo de manifestação usa a sequência 1.
+    pub fn manifestacao(
+        proc: &NfeProc,
+        manifestacao: Manifestacao,
+        horario: DateTime<FixedOffset>,
+    ) -> Result<Self, Error> {
+        let nfe = &proc.nfe;
+        let destinatario = nfe.dest.as_ref().ok_or_else(|| {
+            Error::EventoInvalido("Nota sem destinatário para a manifestação".to_string())
+        })?;
+
+        let manifestacao = match manifestacao {
+            Manifestacao::NaoRealizada(justificativa) => {
+                Manifestacao::NaoRealizada(justificativa.trim().to_string())
+            }
+            outra => outra,
+        };
+
+        let evento = Self {
+            orgao: ORGAO_AMBIENTE_NACIONAL,
+            ambiente: nfe.ide.ambiente,
+            autor: destinatario.documento.clone(),
+            chave_acesso: nfe.chave_acesso.clone(),
+            horario,
+            sequencia: 1,
+            detalhe: DetalheEvento::Manifestacao(manifestacao),
+            assinatura: None,
+        };
+
+        evento.validar()?;
+
+        Ok(evento)
+    }
+}
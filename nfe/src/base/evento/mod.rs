@@ -0,0 +1,450 @@
+//! Eventos da NF-e
+//!
+//! Registro de fatos relacionados à nota após a sua autorização,
+//! como o cancelamento, a carta de correção e a manifestação do destinatário.
+
+use super::assinatura::{self, Assinatura};
+use super::certificado::CertificadoA1;
+use super::documento::{Cnpj, Cpf, Documento};
+use super::ide::{TipoAmbiente, TipoOperacao};
+use super::{Error, NAMESPACE_NFE};
+use chrono::prelude::*;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+mod cancelamento;
+mod carta_correcao;
+mod epec;
+mod manifestacao;
+mod retorno;
+mod substituicao;
+
+pub use cancelamento::*;
+pub use carta_correcao::*;
+pub use epec::*;
+pub use manifestacao::*;
+pub use retorno::*;
+pub use substituicao::*;
+
+/// Versão do leiaute dos eventos
+pub const VERSAO_EVENTO: &str = "1.00";
+
+/// Quantidade máxima de eventos por lote
+pub const MAXIMO_EVENTOS_LOTE: usize = 20;
+
+/// Código do Ambiente Nacional, órgão de recepção da manifestação e do EPEC
+pub const ORGAO_AMBIENTE_NACIONAL: u8 = 91;
+
+/// Tipo do autor(tpAutor): empresa emitente
+pub const TIPO_AUTOR_EMITENTE: u8 = 1;
+
+/// Evento da NF-e, a tag <evento>
+#[derive(Debug, PartialEq, Clone)]
+pub struct Evento {
+    /// Código do órgão de recepção do evento(cOrgao). UF ou 91 para o Ambiente Nacional
+    pub orgao: u8,
+    pub ambiente: TipoAmbiente,
+    /// CNPJ ou CPF do autor do evento
+    pub autor: Documento,
+    pub chave_acesso: String,
+    /// Data e hora do evento
+    pub horario: DateTime<FixedOffset>,
+    /// Número sequencial do evento para o mesmo tipo e chave(nSeqEvento)
+    pub sequencia: u8,
+    pub detalhe: DetalheEvento,
+    /// Assinatura digital do evento
+    pub assinatura: Option<Assinatura>,
+}
+
+/// Tipo do evento(tpEvento)
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum TipoEvento {
+    /// Carta de correção eletrônica(CC-e)
+    CartaCorrecao = 110110,
+    /// Cancelamento da NF-e pelo emitente
+    Cancelamento = 110111,
+    /// Cancelamento da NFC-e substituída por outra
+    CancelamentoSubstituicao = 110112,
+    /// Evento prévio de emissão em contingência(EPEC)
+    Epec = 110140,
+    /// Confirmação da operação pelo destinatário
+    ConfirmacaoOperacao = 210200,
+    /// Ciência da operação pelo destinatário
+    CienciaOperacao = 210210,
+    /// Desconhecimento da operação pelo destinatário
+    DesconhecimentoOperacao = 210220,
+    /// Operação não realizada, informada pelo destinatário
+    OperacaoNaoRealizada = 210240,
+}
+
+/// Informações específicas de cada tipo de evento, a tag <detEvento>
+#[derive(Debug, PartialEq, Clone)]
+pub enum DetalheEvento {
+    CartaCorrecao(CartaCorrecao),
+    Cancelamento(Cancelamento),
+    CancelamentoSubstituicao(CancelamentoSubstituicao),
+    Epec(Epec),
+    Manifestacao(Manifestacao),
+}
+
+/// Lote de eventos enviado à SEFAZ, a tag <envEvento>
+#[derive(Debug, PartialEq, Clone)]
+pub struct EnvioEvento {
+    /// Identificador do lote(idLote)
+    pub lote: u64,
+    pub eventos: Vec<Evento>,
+}
+
+impl Evento {
+    /// Tipo do evento, de acordo com o detalhe
+    pub fn tipo(&self) -> TipoEvento {
+        match &self.detalhe {
+            DetalheEvento::CartaCorrecao(_) => TipoEvento::CartaCorrecao,
+            DetalheEvento::Cancelamento(_) => TipoEvento::Cancelamento,
+            DetalheEvento::CancelamentoSubstituicao(_) => TipoEvento::CancelamentoSubstituicao,
+            DetalheEvento::Epec(_) => TipoEvento::Epec,
+            DetalheEvento::Manifestacao(manifestacao) => manifestacao.tipo(),
+        }
+    }
+
+    /// Valor do atributo Id da tag <infEvento>
+    ///
+    /// Formato: ID + tpEvento + chave de acesso + nSeqEvento com 2 dígitos
+    pub fn id(&self) -> String {
+        format!(
+            "ID{}{}{:02}",
+            self.tipo().codigo(),
+            self.chave_acesso,
+            self.sequencia
+        )
+    }
+
+    /// Valida os campos comuns e o detalhe do evento
+    pub fn validar(&self) -> Result<(), Error> {
+        self.chave_acesso
+            .parse::<super::chave::ChaveAcesso>()
+            .map_err(|e| Error::EventoInvalido(e.to_string()))?;
+
+        if self.sequencia == 0 {
+            return Err(Error::EventoInvalido(
+                "O nSeqEvento deve iniciar em 1".to_string(),
+            ));
+        }
+
+        match &self.detalhe {
+            DetalheEvento::CartaCorrecao(carta) => carta.validar(self.sequencia),
+            DetalheEvento::Cancelamento(cancelamento) => cancelamento.validar(),
+            DetalheEvento::CancelamentoSubstituicao(cancelamento) => {
+                cancelamento.validar(&self.chave_acesso)
+            }
+            DetalheEvento::Epec(epec) => epec.validar(&self.chave_acesso),
+            DetalheEvento::Manifestacao(manifestacao) => manifestacao.validar(),
+        }
+    }
+
+    /// Assina o grupo infEvento com o certificado do autor
+    pub fn assinar(&mut self, certificado: &CertificadoA1) -> Result<(), Error> {
+        self.assinatura = None;
+
+        let xml = self.to_string();
+        self.assinatura = Some(assinatura::assinar(&xml, &self.id(), certificado)?);
+
+        Ok(())
+    }
+}
+
+impl TipoEvento {
+    /// Código do evento
+    pub fn codigo(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Descrição do evento, como informada na tag <descEvento>
+    pub fn descricao(&self) -> &'static str {
+        match self {
+            TipoEvento::CartaCorrecao => "Carta de Correcao",
+            TipoEvento::Cancelamento => "Cancelamento",
+            TipoEvento::CancelamentoSubstituicao => "Cancelamento por substituicao",
+            TipoEvento::Epec => "EPEC",
+            TipoEvento::ConfirmacaoOperacao => "Confirmacao da Operacao",
+            TipoEvento::CienciaOperacao => "Ciencia da Operacao",
+            TipoEvento::DesconhecimentoOperacao => "Desconhecimento da Operacao",
+            TipoEvento::OperacaoNaoRealizada => "Operacao nao Realizada",
+        }
+    }
+}
+
+impl TryFrom<u32> for TipoEvento {
+    type Error = Error;
+
+    fn try_from(codigo: u32) -> Result<Self, Self::Error> {
+        match codigo {
+            110110 => Ok(TipoEvento::CartaCorrecao),
+            110111 => Ok(TipoEvento::Cancelamento),
+            110112 => Ok(TipoEvento::CancelamentoSubstituicao),
+            110140 => Ok(TipoEvento::Epec),
+            210200 => Ok(TipoEvento::ConfirmacaoOperacao),
+            210210 => Ok(TipoEvento::CienciaOperacao),
+            210220 => Ok(TipoEvento::DesconhecimentoOperacao),
+            210240 => Ok(TipoEvento::OperacaoNaoRealizada),
+            _ => Err(Error::EventoInvalido(format!(
+                "Tipo de evento não suportado: {}",
+                codigo
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for TipoEvento {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.codigo())
+    }
+}
+
+impl EnvioEvento {
+    /// Monta o lote, com 1 a 20 eventos
+    pub fn new(lote: u64, eventos: Vec<Evento>) -> Result<Self, Error> {
+        if eventos.is_empty() || eventos.len() > MAXIMO_EVENTOS_LOTE {
+            return Err(Error::EventoInvalido(format!(
+                "O lote deve possuir de 1 a {} eventos",
+                MAXIMO_EVENTOS_LOTE
+            )));
+        }
+
+        Ok(Self { lote, eventos })
+    }
+}
+
+impl FromStr for Evento {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut evento: Evento = quick_xml::de::from_str(s)?;
+        evento.assinatura = assinatura::extrair(s, "</infEvento>")?;
+
+        Ok(evento)
+    }
+}
+
+impl fmt::Display for Evento {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar o evento");
+
+        // A assinatura é reescrita como lida, antes do fechamento da tag <evento>
+        match (&self.assinatura, xml.rfind("</evento>")) {
+            (Some(assinatura), Some(fim)) => {
+                write!(f, "{}{}{}", &xml[..fim], assinatura, &xml[fim..])
+            }
+            _ => f.write_str(&xml),
+        }
+    }
+}
+
+impl fmt::Display for EnvioEvento {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "<envEvento xmlns=\"{}\" versao=\"{}\"><idLote>{}</idLote>",
+            NAMESPACE_NFE, VERSAO_EVENTO, self.lote
+        )?;
+
+        for evento in &self.eventos {
+            write!(f, "{}", evento)?;
+        }
+
+        f.write_str("</envEvento>")
+    }
+}
+
+impl<'de> Deserialize<'de> for Evento {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let evento = EventoContainer::deserialize(deserializer)?;
+        let inf = evento.inf;
+
+        let tipo = TipoEvento::try_from(inf.tipo).map_err(de::Error::custom)?;
+        let detalhe = match tipo {
+            TipoEvento::CartaCorrecao => DetalheEvento::CartaCorrecao(
+                CartaCorrecao::ler(inf.detalhe).map_err(de::Error::custom)?,
+            ),
+            TipoEvento::Cancelamento => DetalheEvento::Cancelamento(
+                Cancelamento::ler(inf.detalhe).map_err(de::Error::custom)?,
+            ),
+            TipoEvento::CancelamentoSubstituicao => DetalheEvento::CancelamentoSubstituicao(
+                CancelamentoSubstituicao::ler(inf.detalhe).map_err(de::Error::custom)?,
+            ),
+            TipoEvento::Epec => {
+                DetalheEvento::Epec(Epec::ler(inf.detalhe).map_err(de::Error::custom)?)
+            }
+            TipoEvento::ConfirmacaoOperacao
+            | TipoEvento::CienciaOperacao
+            | TipoEvento::DesconhecimentoOperacao
+            | TipoEvento::OperacaoNaoRealizada => DetalheEvento::Manifestacao(
+                Manifestacao::ler(tipo, inf.detalhe).map_err(de::Error::custom)?,
+            ),
+        };
+
+        Ok(Self {
+            orgao: inf.orgao,
+            ambiente: inf.ambiente,
+            autor: Documento::from_tags(inf.cnpj, inf.cpf, None).map_err(de::Error::custom)?,
+            chave_acesso: inf.chave_acesso,
+            horario: inf.horario,
+            sequencia: inf.sequencia,
+            detalhe,
+            // Apenas o FromStr tem acesso ao XML original da assinatura
+            assinatura: None,
+        })
+    }
+}
+
+impl Serialize for Evento {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut detalhe = DetEventoContainer {
+            versao: VERSAO_EVENTO.to_string(),
+            descricao: self.tipo().descricao().to_string(),
+            ..Default::default()
+        };
+
+        match &self.detalhe {
+            DetalheEvento::CartaCorrecao(carta) => carta.preencher(&mut detalhe),
+            DetalheEvento::Cancelamento(cancelamento) => cancelamento.preencher(&mut detalhe),
+            DetalheEvento::CancelamentoSubstituicao(cancelamento) => {
+                cancelamento.preencher(&mut detalhe)
+            }
+            DetalheEvento::Epec(epec) => epec.preencher(&mut detalhe),
+            DetalheEvento::Manifestacao(manifestacao) => manifestacao.preencher(&mut detalhe),
+        }
+
+        let evento = EventoContainer {
+            namespace: NAMESPACE_NFE.to_string(),
+            versao: VERSAO_EVENTO.to_string(),
+            inf: InfEventoContainer {
+                id: self.id(),
+                orgao: self.orgao,
+                ambiente: self.ambiente,
+                cnpj: self.autor.cnpj().cloned(),
+                cpf: self.autor.cpf().cloned(),
+                chave_acesso: self.chave_acesso.clone(),
+                horario: self.horario,
+                tipo: self.tipo().codigo(),
+                sequencia: self.sequencia,
+                versao_evento: VERSAO_EVENTO.to_string(),
+                detalhe,
+            },
+        };
+
+        evento.serialize(serializer)
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "evento")]
+struct EventoContainer {
+    #[serde(rename = "xmlns")]
+    #[serde(skip_deserializing)]
+    pub namespace: String,
+    #[serde(rename = "versao")]
+    pub versao: String,
+    #[serde(rename = "infEvento")]
+    pub inf: InfEventoContainer,
+}
+
+#[derive(Deserialize, Serialize)]
+struct InfEventoContainer {
+    #[serde(rename = "Id")]
+    pub id: String,
+    #[serde(rename = "$unflatten=cOrgao")]
+    pub orgao: u8,
+    #[serde(rename = "$unflatten=tpAmb")]
+    pub ambiente: TipoAmbiente,
+    #[serde(rename = "$unflatten=CNPJ")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cnpj: Option<Cnpj>,
+    #[serde(rename = "$unflatten=CPF")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpf: Option<Cpf>,
+    #[serde(rename = "$unflatten=chNFe")]
+    pub chave_acesso: String,
+    #[serde(rename = "$unflatten=dhEvento")]
+    #[serde(serialize_with = "serialize_horario")]
+    pub horario: DateTime<FixedOffset>,
+    #[serde(rename = "$unflatten=tpEvento")]
+    pub tipo: u32,
+    #[serde(rename = "$unflatten=nSeqEvento")]
+    pub sequencia: u8,
+    #[serde(rename = "$unflatten=verEvento")]
+    pub versao_evento: String,
+    #[serde(rename = "detEvento")]
+    pub detalhe: DetEventoContainer,
+}
+
+/// Campos de todos os tipos de evento, preenchidos de acordo com o tipo
+#[derive(Deserialize, Serialize, Default)]
+struct DetEventoContainer {
+    #[serde(rename = "versao")]
+    pub versao: String,
+    #[serde(rename = "$unflatten=descEvento")]
+    pub descricao: String,
+    #[serde(rename = "$unflatten=cOrgaoAutor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orgao_autor: Option<u8>,
+    #[serde(rename = "$unflatten=tpAutor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tipo_autor: Option<u8>,
+    #[serde(rename = "$unflatten=verAplic")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub versao_aplicativo: Option<String>,
+    #[serde(rename = "$unflatten=dhEmi")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "serialize_horario_utc_op")]
+    pub emissao: Option<DateTime<Utc>>,
+    #[serde(rename = "$unflatten=tpNF")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tipo_operacao: Option<TipoOperacao>,
+    #[serde(rename = "$unflatten=IE")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ie: Option<String>,
+    #[serde(rename = "dest")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destinatario: Option<DestinatarioEpec>,
+    #[serde(rename = "$unflatten=nProt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocolo: Option<String>,
+    #[serde(rename = "$unflatten=xJust")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub justificativa: Option<String>,
+    #[serde(rename = "$unflatten=chNFeRef")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chave_referenciada: Option<String>,
+    #[serde(rename = "$unflatten=xCorrecao")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correcao: Option<String>,
+    #[serde(rename = "$unflatten=xCondUso")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condicao_uso: Option<String>,
+}
+
+fn serialize_horario<S>(date: &DateTime<FixedOffset>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&date.to_rfc3339())
+}
+
+fn serialize_horario_utc_op<S>(
+    date: &Option<DateTime<Utc>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&date.map(|d| d.to_rfc3339()).unwrap_or_default())
+}
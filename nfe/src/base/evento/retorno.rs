@@ -0,0 +1,304 @@
+//! Retorno da SEFAZ aos eventos e evento para distribuição(procEventoNFe)
+
+use super::{Evento, VERSAO_EVENTO};
+use crate::base::assinatura;
+use crate::base::ide::TipoAmbiente;
+use crate::base::{Error, NAMESPACE_NFE};
+use chrono::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Resultado do processamento de um evento, a tag <retEvento>
+#[derive(Debug, PartialEq, Clone)]
+pub struct RetornoEvento {
+    /// Identificador da tag <infEvento> do retorno
+    pub id: Option<String>,
+    pub ambiente: TipoAmbiente,
+    /// Versão do aplicativo que registrou o evento
+    pub versao_aplicativo: String,
+    /// Código do órgão que registrou o evento
+    pub orgao: u8,
+    /// Código do status(cStat)
+    pub status: u16,
+    /// Descrição do status
+    pub motivo: String,
+    pub chave_acesso: Option<String>,
+    /// Código do tipo do evento(tpEvento)
+    pub tipo: Option<u32>,
+    /// Descrição do evento registrado(xEvento)
+    pub descricao: Option<String>,
+    pub sequencia: Option<u8>,
+    /// Data e hora do registro do evento
+    pub registro: Option<DateTime<FixedOffset>>,
+    /// Número do protocolo do evento. Não informado nas rejeições
+    pub protocolo: Option<String>,
+}
+
+/// Resultado do processamento de um lote de eventos, a tag <retEnvEvento>
+#[derive(Debug, PartialEq, Clone)]
+pub struct RetornoLoteEvento {
+    /// Identificador do lote(idLote)
+    pub lote: u64,
+    pub ambiente: TipoAmbiente,
+    pub versao_aplicativo: String,
+    pub orgao: u8,
+    /// Código do status do lote(cStat)
+    pub status: u16,
+    pub motivo: String,
+    /// Retorno de cada evento do lote
+    pub retornos: Vec<RetornoEvento>,
+}
+
+/// Evento com o seu registro na SEFAZ, formato usado na distribuição
+#[derive(Debug, PartialEq, Clone)]
+pub struct ProcEvento {
+    pub evento: Evento,
+    pub retorno: RetornoEvento,
+}
+
+impl RetornoEvento {
+    /// Indica se o evento foi registrado e vinculado à nota
+    ///
+    /// Inclui o registro fora de prazo(155) e sem vínculo ao documento(136).
+    pub fn registrado(&self) -> bool {
+        matches!(self.status, 135 | 136 | 155)
+    }
+}
+
+impl RetornoLoteEvento {
+    /// Indica se o lote foi processado. Cada evento possui o seu retorno
+    pub fn processado(&self) -> bool {
+        self.status == 128
+    }
+
+    /// Retorno do evento da chave, do tipo e da sequência informados
+    pub fn retorno(&self, evento: &Evento) -> Option<&RetornoEvento> {
+        self.retornos.iter().find(|r| {
+            r.chave_acesso.as_deref() == Some(evento.chave_acesso.as_str())
+                && r.tipo.map_or(true, |t| t == evento.tipo().codigo())
+                && r.sequencia.map_or(true, |s| s == evento.sequencia)
+        })
+    }
+}
+
+impl ProcEvento {
+    /// Vincula o retorno ao evento
+    ///
+    /// O retorno deve ser da mesma chave de acesso e de um evento registrado
+    pub fn new(evento: Evento, retorno: RetornoEvento) -> Result<Self, Error> {
+        if retorno.chave_acesso.as_deref() != Some(evento.chave_acesso.as_str()) {
+            return Err(Error::EventoInvalido(format!(
+                "Chave do retorno({}) difere da do evento({})",
+                retorno.chave_acesso.as_deref().unwrap_or(""),
+                evento.chave_acesso
+            )));
+        }
+
+        if !retorno.registrado() {
+            return Err(Error::EventoInvalido(format!(
+                "Evento não registrado: {} - {}",
+                retorno.status, retorno.motivo
+            )));
+        }
+
+        Ok(Self { evento, retorno })
+    }
+}
+
+impl FromStr for RetornoEvento {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl fmt::Display for RetornoEvento {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar o retorno");
+
+        f.write_str(&xml)
+    }
+}
+
+impl FromStr for RetornoLoteEvento {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lote: RetEnvEventoContainer = quick_xml::de::from_str(s)?;
+
+        Ok(Self {
+            lote: lote.lote,
+            ambiente: lote.ambiente,
+            versao_aplicativo: lote.versao_aplicativo,
+            orgao: lote.orgao,
+            status: lote.status,
+            motivo: lote.motivo,
+            retornos: lote.retornos,
+        })
+    }
+}
+
+impl FromStr for ProcEvento {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let proc: ProcEventoContainer = quick_xml::de::from_str(s)?;
+
+        let mut evento = proc.evento;
+        evento.assinatura = assinatura::extrair(s, "</infEvento>")?;
+
+        Ok(Self {
+            evento,
+            retorno: proc.retorno,
+        })
+    }
+}
+
+impl fmt::Display for ProcEvento {
+    /// O evento é escrito pelo seu próprio Display, preservando a assinatura
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "<procEventoNFe xmlns=\"{}\" versao=\"{}\">{}{}</procEventoNFe>",
+            NAMESPACE_NFE, VERSAO_EVENTO, self.evento, self.retorno
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for RetornoEvento {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ret = RetEventoContainer::deserialize(deserializer)?;
+        let inf = ret.inf;
+
+        Ok(Self {
+            id: inf.id,
+            ambiente: inf.ambiente,
+            versao_aplicativo: inf.versao_aplicativo,
+            orgao: inf.orgao,
+            status: inf.status,
+            motivo: inf.motivo,
+            chave_acesso: inf.chave_acesso,
+            tipo: inf.tipo,
+            descricao: inf.descricao,
+            sequencia: inf.sequencia,
+            registro: inf.registro,
+            protocolo: inf.protocolo,
+        })
+    }
+}
+
+impl Serialize for RetornoEvento {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let ret = RetEventoContainer {
+            versao: VERSAO_EVENTO.to_string(),
+            inf: InfRetEventoContainer {
+                id: self.id.clone(),
+                ambiente: self.ambiente,
+                versao_aplicativo: self.versao_aplicativo.clone(),
+                orgao: self.orgao,
+                status: self.status,
+                motivo: self.motivo.clone(),
+                chave_acesso: self.chave_acesso.clone(),
+                tipo: self.tipo,
+                descricao: self.descricao.clone(),
+                sequencia: self.sequencia,
+                registro: self.registro,
+                protocolo: self.protocolo.clone(),
+            },
+        };
+
+        ret.serialize(serializer)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "procEventoNFe")]
+struct ProcEventoContainer {
+    #[serde(rename = "evento")]
+    pub evento: Evento,
+    #[serde(rename = "retEvento")]
+    pub retorno: RetornoEvento,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "retEnvEvento")]
+struct RetEnvEventoContainer {
+    #[serde(rename = "$unflatten=idLote")]
+    pub lote: u64,
+    #[serde(rename = "$unflatten=tpAmb")]
+    pub ambiente: TipoAmbiente,
+    #[serde(rename = "$unflatten=verAplic")]
+    pub versao_aplicativo: String,
+    #[serde(rename = "$unflatten=cOrgao")]
+    pub orgao: u8,
+    #[serde(rename = "$unflatten=cStat")]
+    pub status: u16,
+    #[serde(rename = "$unflatten=xMotivo")]
+    pub motivo: String,
+    #[serde(rename = "retEvento")]
+    #[serde(default)]
+    pub retornos: Vec<RetornoEvento>,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "retEvento")]
+struct RetEventoContainer {
+    #[serde(rename = "versao")]
+    pub versao: String,
+    #[serde(rename = "infEvento")]
+    pub inf: InfRetEventoContainer,
+}
+
+#[derive(Deserialize, Serialize)]
+struct InfRetEventoContainer {
+    #[serde(rename = "Id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "$unflatten=tpAmb")]
+    pub ambiente: TipoAmbiente,
+    #[serde(rename = "$unflatten=verAplic")]
+    pub versao_aplicativo: String,
+    #[serde(rename = "$unflatten=cOrgao")]
+    pub orgao: u8,
+    #[serde(rename = "$unflatten=cStat")]
+    pub status: u16,
+    #[serde(rename = "$unflatten=xMotivo")]
+    pub motivo: String,
+    #[serde(rename = "$unflatten=chNFe")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chave_acesso: Option<String>,
+    #[serde(rename = "$unflatten=tpEvento")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tipo: Option<u32>,
+    #[serde(rename = "$unflatten=xEvento")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descricao: Option<String>,
+    #[serde(rename = "$unflatten=nSeqEvento")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequencia: Option<u8>,
+    #[serde(rename = "$unflatten=dhRegEvento")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "serialize_horario_op")]
+    pub registro: Option<DateTime<FixedOffset>>,
+    #[serde(rename = "$unflatten=nProt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocolo: Option<String>,
+}
+
+fn serialize_horario_op<S>(
+    date: &Option<DateTime<FixedOffset>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&date.map(|d| d.to_rfc3339()).unwrap_or_default())
+}
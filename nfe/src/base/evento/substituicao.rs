@@ -0,0 +1,162 @@
+//! Cancelamento por substituição da NFC-e(evento 110112)
+
+use super::cancelamento::{validar_justificativa, validar_protocolo};
+use super::{DetEventoContainer, DetalheEvento, Evento, TIPO_AUTOR_EMITENTE};
+use crate::base::chave::ChaveAcesso;
+use crate::base::ide::ModeloDocumentoFiscal;
+use crate::base::{Error, Nfe};
+use chrono::prelude::*;
+
+/// Detalhe do cancelamento por substituição
+///
+/// Cancela a NFC-e emitida em contingência off-line que foi substituída
+/// por outra NFC-e do mesmo emitente para a mesma operação.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CancelamentoSubstituicao {
+    /// Código da UF do autor(cOrgaoAutor)
+    pub orgao_autor: u8,
+    /// Tipo do autor(tpAutor). Apenas a empresa emitente
+    pub tipo_autor: u8,
+    /// Versão do aplicativo do autor(verAplic)
+    pub versao_aplicativo: String,
+    /// Número do protocolo de autorização da nota cancelada(nProt)
+    pub protocolo: String,
+    /// Motivo do cancelamento(xJust)
+    pub justificativa: String,
+    /// Chave de acesso da NFC-e substituta(chNFeRef)
+    pub chave_referenciada: String,
+}
+
+impl CancelamentoSubstituicao {
+    /// Valida o detalhe e as chaves da nota cancelada e da substituta
+    ///
+    /// As duas devem ser NFC-e do mesmo emitente
+    pub fn validar(&self, chave_acesso: &str) -> Result<(), Error> {
+        if self.tipo_autor != TIPO_AUTOR_EMITENTE {
+            return Err(Error::EventoInvalido(format!(
+                "Tipo de autor não permitido no cancelamento por substituição: {}",
+                self.tipo_autor
+            )));
+        }
+
+        if self.versao_aplicativo.trim().is_empty() || self.versao_aplicativo.len() > 20 {
+            return Err(Error::EventoInvalido(format!(
+                "A versão do aplicativo deve possuir de 1 a 20 caracteres: {}",
+                self.versao_aplicativo
+            )));
+        }
+
+        validar_protocolo(&self.protocolo)?;
+        validar_justificativa(&self.justificativa)?;
+
+        let chave = ler_chave(chave_acesso)?;
+        let substituta = ler_chave(&self.chave_referenciada)?;
+
+        if chave == substituta {
+            return Err(Error::EventoInvalido(
+                "A NFC-e substituta deve ser diferente da cancelada".to_string(),
+            ));
+        }
+
+        if chave.documento != substituta.documento {
+            return Err(Error::EventoInvalido(format!(
+                "A NFC-e substituta é de outro emitente: {}",
+                substituta.documento
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn preencher(&self, detalhe: &mut DetEventoContainer) {
+        detalhe.orgao_autor = Some(self.orgao_autor);
+        detalhe.tipo_autor = Some(self.tipo_autor);
+        detalhe.versao_aplicativo = Some(self.versao_aplicativo.clone());
+        detalhe.protocolo = Some(self.protocolo.clone());
+        detalhe.justificativa = Some(self.justificativa.clone());
+        detalhe.chave_referenciada = Some(self.chave_referenciada.clone());
+    }
+
+    pub(super) fn ler(detalhe: DetEventoContainer) -> Result<Self, Error> {
+        match (
+            detalhe.orgao_autor,
+            detalhe.tipo_autor,
+            detalhe.versao_aplicativo,
+            detalhe.protocolo,
+            detalhe.justificativa,
+            detalhe.chave_referenciada,
+        ) {
+            (
+                Some(orgao_autor),
+                Some(tipo_autor),
+                Some(versao_aplicativo),
+                Some(protocolo),
+                Some(justificativa),
+                Some(chave_referenciada),
+            ) => Ok(Self {
+                orgao_autor,
+                tipo_autor,
+                versao_aplicativo,
+                protocolo,
+                justificativa,
+                chave_referenciada,
+            }),
+            _ => Err(Error::EventoInvalido(
+                "Cancelamento por substituição incompleto".to_string(),
+            )),
+        }
+    }
+}
+
+impl Evento {
+    /// Cancelamento por substituição da NFC-e
+    ///
+    /// A nota informada é a cancelada e a chave referenciada é a da NFC-e
+    /// que a substituiu. Órgão, ambiente, autor e chave são obtidos da nota.
+    pub fn cancelamento_substituicao(
+        nfe: &Nfe,
+        protocolo: &str,
+        justificativa: &str,
+        chave_referenciada: &str,
+        versao_aplicativo: &str,
+        horario: DateTime<FixedOffset>,
+    ) -> Result<Self, Error> {
+        let evento = Self {
+            orgao: nfe.ide.codigo_uf,
+            ambiente: nfe.ide.ambiente,
+            autor: nfe.emit.documento.clone(),
+            chave_acesso: nfe.chave_acesso.clone(),
+            horario,
+            sequencia: 1,
+            detalhe: DetalheEvento::CancelamentoSubstituicao(CancelamentoSubstituicao {
+                orgao_autor: nfe.ide.codigo_uf,
+                tipo_autor: TIPO_AUTOR_EMITENTE,
+                versao_aplicativo: versao_aplicativo.trim().to_string(),
+                protocolo: protocolo.trim().to_string(),
+                justificativa: justificativa.trim().to_string(),
+                chave_referenciada: chave_referenciada.trim().to_string(),
+            }),
+            assinatura: None,
+        };
+
+        evento.validar()?;
+
+        Ok(evento)
+    }
+}
+
+/// Chave de acesso de uma NFC-e
+fn ler_chave(chave: &str) -> Result<ChaveAcesso, Error> {
+    let chave = chave
+        .parse::<ChaveAcesso>()
+        .map_err(|e| Error::EventoInvalido(e.to_string()))?;
+
+    if chave.modelo != ModeloDocumentoFiscal::Nfce {
+        return Err(Error::EventoInvalido(format!(
+            "O cancelamento por substituição é exclusivo da NFC-e: {}",
+            chave
+        )));
+    }
+
+    Ok(chave)
+}
@@ -0,0 +1,38 @@
+//! Informações de comércio exterior
+
+use super::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Informações de exportação
+#[derive(Debug, Deserialize, PartialEq, Serialize, Clone)]
+#[serde(rename = "exporta")]
+pub struct Exportacao {
+    /// Sigla da UF de embarque ou de transposição de fronteira
+    #[serde(rename = "$unflatten=UFSaidaPais")]
+    pub uf_embarque: String,
+    /// Descrição do local de embarque ou de transposição de fronteira
+    #[serde(rename = "$unflatten=xLocExporta")]
+    pub local_embarque: String,
+    /// Descrição do local de despacho
+    #[serde(rename = "$unflatten=xLocDespacho")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_despacho: Option<String>,
+}
+
+impl FromStr for Exportacao {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl fmt::Display for Exportacao {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar a exportação");
+
+        f.write_str(&xml)
+    }
+}
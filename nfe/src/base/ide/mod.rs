@@ -1,9 +1,12 @@
 //! Identificação da NF-e
 
+use super::uf::Uf;
 use super::Error;
 use chrono::prelude::*;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::convert::TryFrom;
+use std::fmt;
 use std::str::FromStr;
 
 mod emissao;
@@ -28,7 +31,7 @@ pub struct Identificacao {
 }
 
 /// Modelo do documento fiscal: NF-e ou NFC-e
-#[derive(Debug, Eq, PartialEq, Copy, Clone, Deserialize_repr, Serialize_repr)]
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone, Deserialize_repr, Serialize_repr)]
 #[repr(u8)]
 pub enum ModeloDocumentoFiscal {
     Nfe = 55,
@@ -48,7 +51,7 @@ pub enum FormatoImpressaoDanfe {
 }
 
 /// Tipo do ambiente da NF
-#[derive(Debug, Eq, PartialEq, Copy, Clone, Deserialize_repr, Serialize_repr)]
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone, Deserialize_repr, Serialize_repr)]
 #[repr(u8)]
 pub enum TipoAmbiente {
     Producao = 1,
@@ -62,6 +65,13 @@ pub struct ComposicaoChaveAcesso {
     pub digito_verificador: u8,
 }
 
+impl Identificacao {
+    /// UF do emitente, a partir do cUF
+    pub fn uf(&self) -> Result<Uf, Error> {
+        Uf::try_from(self.codigo_uf)
+    }
+}
+
 impl FromStr for Identificacao {
     type Err = Error;
 
@@ -70,9 +80,11 @@ impl FromStr for Identificacao {
     }
 }
 
-impl ToString for Identificacao {
-    fn to_string(&self) -> String {
-        quick_xml::se::to_string(self).expect("Falha ao serializar a identificação")
+impl fmt::Display for Identificacao {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar a identificação");
+
+        f.write_str(&xml)
     }
 }
 
@@ -0,0 +1,373 @@
+//! Validação da Inscrição Estadual
+//!
+//! Cada UF possui o seu próprio formato e cálculo dos dígitos
+//! verificadores, conforme os roteiros publicados pelo Sintegra.
+
+use super::documento::remover_mascara;
+use super::Error;
+
+/// Valor aceito no lugar da IE pelos contribuintes isentos de inscrição
+pub const ISENTO: &str = "ISENTO";
+
+/// Valida a Inscrição Estadual de acordo com as regras da UF
+///
+/// Aceita a IE com ou sem máscara e também o literal ISENTO
+pub fn validar_ie(sigla_uf: &str, ie: &str) -> Result<(), Error> {
+    conferir(sigla_uf, ie).map_err(Error::IeInvalida)
+}
+
+/// Valida a IE identificando a origem(emitente, destinatário...) no erro
+pub(crate) fn validar_ie_de(origem: &str, sigla_uf: &str, ie: &str) -> Result<(), Error> {
+    conferir(sigla_uf, ie).map_err(|e| Error::IeInvalida(format!("{}. {}", origem, e)))
+}
+
+fn conferir(sigla_uf: &str, ie: &str) -> Result<(), String> {
+    let ie = remover_mascara(ie.trim()).to_uppercase();
+
+    if ie == ISENTO {
+        return Ok(());
+    }
+
+    let valida = match sigla_uf.to_uppercase().as_str() {
+        "SP" if ie.starts_with('P') => ie_sp_produtor_rural(&ie[1..]),
+        _ if ie.is_empty() || !ie.chars().all(|c| c.is_ascii_digit()) => false,
+        "AC" => ie_ac_df(&ie, "01"),
+        "AL" => ie_al(&ie),
+        "AP" => ie_ap(&ie),
+        "AM" => ie_am(&ie),
+        "BA" => ie_ba(&ie),
+        "CE" | "PB" | "PI" | "SE" => ie_modulo11(&ie, maior_ou_igual_10_zero),
+        "DF" => ie_ac_df(&ie, "07"),
+        "ES" | "SC" => ie_modulo11(&ie, resto_menor_2_zero),
+        "GO" => ie_go(&ie),
+        "MA" => ie.starts_with("12") && ie_modulo11(&ie, resto_menor_2_zero),
+        "MT" => ie_mt(&ie),
+        "MS" => ie_ms(&ie),
+        "MG" => ie_mg(&ie),
+        "PA" => ie.starts_with("15") && ie_modulo11(&ie, resto_menor_2_zero),
+        "PR" => ie_pr(&ie),
+        "PE" => ie_pe(&ie),
+        "RJ" => ie_rj(&ie),
+        "RN" => ie_rn(&ie),
+        "RS" => ie_rs(&ie),
+        "RO" => ie_ro(&ie),
+        "RR" => ie_rr(&ie),
+        "SP" => ie_sp(&ie),
+        "TO" => ie_to(&ie),
+        uf => return Err(format!("UF desconhecida para validação da IE: {}", uf)),
+    };
+
+    if !valida {
+        return Err(format!("IE {} inválida para a UF {}", ie, sigla_uf));
+    }
+
+    Ok(())
+}
+
+/// Soma ponderada dos dígitos
+fn soma(digitos: &str, pesos: &[u32]) -> u32 {
+    digitos
+        .bytes()
+        .zip(pesos)
+        .map(|(d, p)| (d - b'0') as u32 * p)
+        .sum()
+}
+
+/// Dígito na posição informada
+fn digito(ie: &str, posicao: usize) -> u32 {
+    (ie.as_bytes()[posicao] - b'0') as u32
+}
+
+/// 11 menos o resto, sendo 0 quando o resultado for 10 ou 11
+fn maior_ou_igual_10_zero(soma: u32) -> u32 {
+    match 11 - soma % 11 {
+        d if d >= 10 => 0,
+        d => d,
+    }
+}
+
+/// 11 menos o resto, sendo 0 quando o resto for 0 ou 1
+fn resto_menor_2_zero(soma: u32) -> u32 {
+    match soma % 11 {
+        0 | 1 => 0,
+        resto => 11 - resto,
+    }
+}
+
+/// Formato mais comum: 8 dígitos e o verificador, com pesos de 9 a 2
+fn ie_modulo11(ie: &str, dv: fn(u32) -> u32) -> bool {
+    ie.len() == 9 && digito(ie, 8) == dv(soma(&ie[..8], &[9, 8, 7, 6, 5, 4, 3, 2]))
+}
+
+fn ie_ac_df(ie: &str, prefixo: &str) -> bool {
+    if ie.len() != 13 || !ie.starts_with(prefixo) {
+        return false;
+    }
+
+    let dv1 = maior_ou_igual_10_zero(soma(&ie[..11], &[4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2]));
+    let dv2 = maior_ou_igual_10_zero(soma(&ie[..12], &[5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2]));
+
+    digito(ie, 11) == dv1 && digito(ie, 12) == dv2
+}
+
+fn ie_al(ie: &str) -> bool {
+    if ie.len() != 9 || !ie.starts_with("24") {
+        return false;
+    }
+
+    let dv = match soma(&ie[..8], &[9, 8, 7, 6, 5, 4, 3, 2]) * 10 % 11 {
+        10 => 0,
+        d => d,
+    };
+
+    digito(ie, 8) == dv
+}
+
+fn ie_ap(ie: &str) -> bool {
+    if ie.len() != 9 || !ie.starts_with("03") {
+        return false;
+    }
+
+    let numero: u32 = ie[..8].parse().unwrap_or(0);
+    let (p, d) = match numero {
+        3_000_001..=3_017_000 => (5, 0),
+        3_017_001..=3_019_022 => (9, 1),
+        _ => (0, 0),
+    };
+
+    let dv = match 11 - (p + soma(&ie[..8], &[9, 8, 7, 6, 5, 4, 3, 2])) % 11 {
+        10 => 0,
+        11 => d,
+        dv => dv,
+    };
+
+    digito(ie, 8) == dv
+}
+
+fn ie_am(ie: &str) -> bool {
+    if ie.len() != 9 {
+        return false;
+    }
+
+    let soma = soma(&ie[..8], &[9, 8, 7, 6, 5, 4, 3, 2]);
+    let dv = if soma < 11 {
+        11 - soma
+    } else {
+        resto_menor_2_zero(soma)
+    };
+
+    digito(ie, 8) == dv
+}
+
+fn ie_ba(ie: &str) -> bool {
+    let tamanho = ie.len();
+    if tamanho != 8 && tamanho != 9 {
+        return false;
+    }
+
+    // O dígito que define o módulo é o primeiro nas IEs de 8 dígitos
+    // e o segundo nas de 9 dígitos
+    let referencia = digito(ie, tamanho - 8);
+    let modulo10 = matches!(referencia, 0 | 1 | 2 | 3 | 4 | 5 | 8);
+    let dv = |soma: u32| {
+        if modulo10 {
+            (10 - soma % 10) % 10
+        } else {
+            resto_menor_2_zero(soma)
+        }
+    };
+
+    let base = &ie[..tamanho - 2];
+    let pesos: Vec<u32> = (2..=tamanho as u32).rev().collect();
+
+    // O segundo dígito é calculado antes do primeiro
+    let dv2 = dv(soma(base, &pesos[1..]));
+    let dv1 = dv(soma(&format!("{}{}", base, dv2), &pesos));
+
+    digito(ie, tamanho - 2) == dv1 && digito(ie, tamanho - 1) == dv2
+}
+
+fn ie_go(ie: &str) -> bool {
+    if ie.len() != 9 {
+        return false;
+    }
+
+    let prefixo: u32 = ie[..2].parse().unwrap_or(0);
+    if !matches!(prefixo, 10 | 11 | 15 | 20..=29) {
+        return false;
+    }
+
+    if &ie[..8] == "11094402" {
+        return digito(ie, 8) <= 1;
+    }
+
+    let numero: u32 = ie[..8].parse().unwrap_or(0);
+    let dv = match soma(&ie[..8], &[9, 8, 7, 6, 5, 4, 3, 2]) % 11 {
+        0 => 0,
+        1 if (10_103_105..=10_119_997).contains(&numero) => 1,
+        1 => 0,
+        resto => 11 - resto,
+    };
+
+    digito(ie, 8) == dv
+}
+
+fn ie_mt(ie: &str) -> bool {
+    if ie.len() > 11 {
+        return false;
+    }
+
+    let ie = format!("{:0>11}", ie);
+    let dv = resto_menor_2_zero(soma(&ie[..10], &[3, 2, 9, 8, 7, 6, 5, 4, 3, 2]));
+
+    digito(&ie, 10) == dv
+}
+
+fn ie_ms(ie: &str) -> bool {
+    if ie.len() != 9 || !(ie.starts_with("28") || ie.starts_with("50")) {
+        return false;
+    }
+
+    let dv = match soma(&ie[..8], &[9, 8, 7, 6, 5, 4, 3, 2]) % 11 {
+        0 => 0,
+        resto if 11 - resto > 9 => 0,
+        resto => 11 - resto,
+    };
+
+    digito(ie, 8) == dv
+}
+
+fn ie_mg(ie: &str) -> bool {
+    if ie.len() != 13 {
+        return false;
+    }
+
+    // O primeiro dígito considera um zero após o código do município
+    let base = format!("{}0{}", &ie[..3], &ie[3..11]);
+    let soma1: u32 = base
+        .bytes()
+        .zip([1, 2].iter().cycle())
+        .map(|(d, p)| {
+            let produto = (d - b'0') as u32 * p;
+            produto / 10 + produto % 10
+        })
+        .sum();
+    let dv1 = (10 - soma1 % 10) % 10;
+
+    let dv2 = resto_menor_2_zero(soma(
+        &format!("{}{}", &ie[..11], dv1),
+        &[3, 2, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2],
+    ));
+
+    digito(ie, 11) == dv1 && digito(ie, 12) == dv2
+}
+
+fn ie_pr(ie: &str) -> bool {
+    if ie.len() != 10 {
+        return false;
+    }
+
+    let dv1 = resto_menor_2_zero(soma(&ie[..8], &[3, 2, 7, 6, 5, 4, 3, 2]));
+    let dv2 = resto_menor_2_zero(soma(&ie[..9], &[4, 3, 2, 7, 6, 5, 4, 3, 2]));
+
+    digito(ie, 8) == dv1 && digito(ie, 9) == dv2
+}
+
+fn ie_pe(ie: &str) -> bool {
+    match ie.len() {
+        // e-Fisco
+        9 => {
+            let dv1 = resto_menor_2_zero(soma(&ie[..7], &[8, 7, 6, 5, 4, 3, 2]));
+            let dv2 = resto_menor_2_zero(soma(&ie[..8], &[9, 8, 7, 6, 5, 4, 3, 2]));
+
+            digito(ie, 7) == dv1 && digito(ie, 8) == dv2
+        }
+        // Antigo CACEPE
+        14 => {
+            let dv = match 11 - soma(&ie[..13], &[5, 4, 3, 2, 1, 9, 8, 7, 6, 5, 4, 3, 2]) % 11 {
+                d if d > 9 => d - 10,
+                d => d,
+            };
+
+            digito(ie, 13) == dv
+        }
+        _ => false,
+    }
+}
+
+fn ie_rj(ie: &str) -> bool {
+    ie.len() == 8 && digito(ie, 7) == resto_menor_2_zero(soma(&ie[..7], &[2, 7, 6, 5, 4, 3, 2]))
+}
+
+fn ie_rn(ie: &str) -> bool {
+    let tamanho = ie.len();
+    if (tamanho != 9 && tamanho != 10) || !ie.starts_with("20") {
+        return false;
+    }
+
+    let pesos: Vec<u32> = (2..=tamanho as u32).rev().collect();
+    let dv = match soma(&ie[..tamanho - 1], &pesos) * 10 % 11 {
+        10 => 0,
+        d => d,
+    };
+
+    digito(ie, tamanho - 1) == dv
+}
+
+fn ie_rs(ie: &str) -> bool {
+    ie.len() == 10
+        && digito(ie, 9) == maior_ou_igual_10_zero(soma(&ie[..9], &[2, 9, 8, 7, 6, 5, 4, 3, 2]))
+}
+
+fn ie_ro(ie: &str) -> bool {
+    let soma = match ie.len() {
+        14 => soma(&ie[..13], &[6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2]),
+        // Formato anterior a 2000: município, empresa e verificador
+        9 => soma(&ie[3..8], &[6, 5, 4, 3, 2]),
+        _ => return false,
+    };
+
+    let dv = match 11 - soma % 11 {
+        d if d >= 10 => d - 10,
+        d => d,
+    };
+
+    digito(ie, ie.len() - 1) == dv
+}
+
+fn ie_rr(ie: &str) -> bool {
+    ie.len() == 9
+        && ie.starts_with("24")
+        && digito(ie, 8) == soma(&ie[..8], &[1, 2, 3, 4, 5, 6, 7, 8]) % 9
+}
+
+fn ie_sp(ie: &str) -> bool {
+    if ie.len() != 12 {
+        return false;
+    }
+
+    let dv1 = soma(&ie[..8], &[1, 3, 4, 5, 6, 7, 8, 10]) % 11 % 10;
+    let dv2 = soma(&ie[..11], &[3, 2, 10, 9, 8, 7, 6, 5, 4, 3, 2]) % 11 % 10;
+
+    digito(ie, 8) == dv1 && digito(ie, 11) == dv2
+}
+
+/// IE de produtor rural de SP, no formato P0MMMSSSSD000
+fn ie_sp_produtor_rural(ie: &str) -> bool {
+    ie.len() == 12
+        && ie.chars().all(|c| c.is_ascii_digit())
+        && digito(ie, 8) == soma(&ie[..8], &[1, 3, 4, 5, 6, 7, 8, 10]) % 11 % 10
+}
+
+fn ie_to(ie: &str) -> bool {
+    let base = match ie.len() {
+        // O 3º e 4º dígitos indicam o tipo da empresa e não entram no cálculo
+        11 if matches!(&ie[2..4], "01" | "02" | "03" | "99") => {
+            format!("{}{}", &ie[..2], &ie[4..10])
+        }
+        9 => ie[..8].to_string(),
+        _ => return false,
+    };
+
+    digito(ie, ie.len() - 1) == resto_menor_2_zero(soma(&base, &[9, 8, 7, 6, 5, 4, 3, 2]))
+}
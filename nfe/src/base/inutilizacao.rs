@@ -0,0 +1,491 @@
+//! Inutilização de numeração da NF-e e da NFC-e
+//!
+//! Pedido(inutNFe), retorno da SEFAZ(retInutNFe) e documento para
+//! distribuição(procInutNFe).
+
+use super::assinatura::{self, Assinatura};
+use super::certificado::CertificadoA1;
+use super::documento::{Cnpj, Cpf, Documento};
+use super::evento::validar_justificativa;
+use super::ide::{ModeloDocumentoFiscal, TipoAmbiente};
+use super::{Error, VersaoLayout, NAMESPACE_NFE};
+use chrono::prelude::*;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Serviço solicitado(xServ)
+const SERVICO_INUTILIZAR: &str = "INUTILIZAR";
+
+/// Pedido de inutilização de uma faixa de numeração, a tag <inutNFe>
+#[derive(Debug, PartialEq, Clone)]
+pub struct Inutilizacao {
+    pub ambiente: TipoAmbiente,
+    /// Código IBGE da UF do emitente
+    pub codigo_uf: u8,
+    /// Ano da inutilização, com dois dígitos
+    pub ano: u8,
+    /// CNPJ ou CPF do emitente
+    pub documento: Documento,
+    pub modelo: ModeloDocumentoFiscal,
+    pub serie: u16,
+    /// Número inicial da faixa(nNFIni)
+    pub numero_inicial: u32,
+    /// Número final da faixa(nNFFin). Igual ao inicial para um único número
+    pub numero_final: u32,
+    /// Motivo da inutilização(xJust)
+    pub justificativa: String,
+    /// Assinatura digital do pedido
+    pub assinatura: Option<Assinatura>,
+}
+
+/// Resultado do pedido de inutilização, a tag <retInutNFe>
+///
+/// Nas rejeições a SEFAZ pode omitir os dados da faixa
+#[derive(Debug, PartialEq, Clone)]
+pub struct RetornoInutilizacao {
+    pub versao: VersaoLayout,
+    /// Identificador da tag <infInut> do retorno
+    pub id: Option<String>,
+    pub ambiente: TipoAmbiente,
+    /// Versão do aplicativo que processou o pedido
+    pub versao_aplicativo: String,
+    /// Código do status(cStat)
+    pub status: u16,
+    /// Descrição do status
+    pub motivo: String,
+    pub codigo_uf: u8,
+    pub ano: Option<u8>,
+    /// CNPJ ou CPF do emitente
+    pub documento: Option<Documento>,
+    pub modelo: Option<ModeloDocumentoFiscal>,
+    pub serie: Option<u16>,
+    pub numero_inicial: Option<u32>,
+    pub numero_final: Option<u32>,
+    /// Data e hora do processamento
+    pub recebimento: DateTime<FixedOffset>,
+    /// Número do protocolo. Não informado nas rejeições
+    pub protocolo: Option<String>,
+}
+
+/// Inutilização com o seu retorno homologado, formato usado na distribuição
+#[derive(Debug, PartialEq, Clone)]
+pub struct ProcInutilizacao {
+    pub inutilizacao: Inutilizacao,
+    pub retorno: RetornoInutilizacao,
+}
+
+impl Inutilizacao {
+    /// Valor do atributo Id da tag <infInut>
+    ///
+    /// Formato: ID + cUF + ano + CNPJ/CPF + modelo + série + nNFIni + nNFFin.
+    /// O CPF é completado com zeros à esquerda até 14 dígitos
+    pub fn id(&self) -> String {
+        format!(
+            "ID{:02}{:02}{:0>14}{:02}{:03}{:09}{:09}",
+            self.codigo_uf,
+            self.ano,
+            self.documento.as_str(),
+            self.modelo as u8,
+            self.serie,
+            self.numero_inicial,
+            self.numero_final
+        )
+    }
+
+    /// Valida a faixa de numeração e a justificativa
+    pub fn validar(&self) -> Result<(), Error> {
+        if self.ano > 99 {
+            return Err(Error::InutilizacaoInvalida(format!(
+                "O ano deve possuir dois dígitos: {}",
+                self.ano
+            )));
+        }
+
+        if self.serie > 999 {
+            return Err(Error::InutilizacaoInvalida(format!(
+                "Série fora do limite: {}",
+                self.serie
+            )));
+        }
+
+        if self.numero_inicial == 0
+            || self.numero_final > 999_999_999
+            || self.numero_inicial > self.numero_final
+        {
+            return Err(Error::InutilizacaoInvalida(format!(
+                "Faixa de numeração inválida: {} a {}",
+                self.numero_inicial, self.numero_final
+            )));
+        }
+
+        validar_justificativa(&self.justificativa).map_err(|e| match e {
+            Error::EventoInvalido(motivo) => Error::InutilizacaoInvalida(motivo),
+            e => e,
+        })
+    }
+
+    /// Assina o grupo infInut com o certificado do emitente
+    pub fn assinar(&mut self, certificado: &CertificadoA1) -> Result<(), Error> {
+        self.validar()?;
+        self.assinatura = None;
+
+        let xml = self.to_string();
+        self.assinatura = Some(assinatura::assinar(&xml, &self.id(), certificado)?);
+
+        Ok(())
+    }
+}
+
+impl RetornoInutilizacao {
+    /// Indica se a inutilização foi homologada
+    pub fn homologada(&self) -> bool {
+        self.status == 102
+    }
+}
+
+impl ProcInutilizacao {
+    /// Vincula o retorno ao pedido
+    ///
+    /// O retorno deve estar homologado e ser da mesma faixa de numeração
+    pub fn new(inutilizacao: Inutilizacao, retorno: RetornoInutilizacao) -> Result<Self, Error> {
+        if !retorno.homologada() {
+            return Err(Error::InutilizacaoInvalida(format!(
+                "Inutilização não homologada: {} - {}",
+                retorno.status, retorno.motivo
+            )));
+        }
+
+        let mesma_faixa = retorno.documento.as_ref() == Some(&inutilizacao.documento)
+            && retorno.modelo == Some(inutilizacao.modelo)
+            && retorno.serie == Some(inutilizacao.serie)
+            && retorno.numero_inicial == Some(inutilizacao.numero_inicial)
+            && retorno.numero_final == Some(inutilizacao.numero_final);
+
+        if !mesma_faixa {
+            return Err(Error::InutilizacaoInvalida(format!(
+                "Retorno de outra faixa de numeração que não a do pedido {}",
+                inutilizacao.id()
+            )));
+        }
+
+        Ok(Self {
+            inutilizacao,
+            retorno,
+        })
+    }
+}
+
+impl FromStr for Inutilizacao {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut inutilizacao: Inutilizacao = quick_xml::de::from_str(s)?;
+        inutilizacao.assinatura = assinatura::extrair(s, "</infInut>")?;
+
+        Ok(inutilizacao)
+    }
+}
+
+impl fmt::Display for Inutilizacao {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar a inutilização");
+
+        // A assinatura é reescrita como lida, antes do fechamento da tag <inutNFe>
+        match (&self.assinatura, xml.rfind("</inutNFe>")) {
+            (Some(assinatura), Some(fim)) => {
+                write!(f, "{}{}{}", &xml[..fim], assinatura, &xml[fim..])
+            }
+            _ => f.write_str(&xml),
+        }
+    }
+}
+
+impl FromStr for RetornoInutilizacao {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl fmt::Display for RetornoInutilizacao {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar o retorno");
+
+        f.write_str(&xml)
+    }
+}
+
+impl FromStr for ProcInutilizacao {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let proc: ProcInutContainer = quick_xml::de::from_str(s)?;
+
+        let mut inutilizacao = proc.inutilizacao;
+        inutilizacao.assinatura = assinatura::extrair(s, "</infInut>")?;
+
+        Ok(Self {
+            inutilizacao,
+            retorno: proc.retorno,
+        })
+    }
+}
+
+impl fmt::Display for ProcInutilizacao {
+    /// O pedido é escrito pelo seu próprio Display, preservando a assinatura
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "<procInutNFe xmlns=\"{}\" versao=\"{}\">{}{}</procInutNFe>",
+            NAMESPACE_NFE,
+            self.retorno.versao.as_str(),
+            self.inutilizacao,
+            self.retorno
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for Inutilizacao {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let inut = InutNfeContainer::deserialize(deserializer)?;
+        let inf = inut.inf;
+
+        Ok(Self {
+            ambiente: inf.ambiente,
+            codigo_uf: inf.codigo_uf,
+            ano: inf.ano,
+            documento: Documento::from_tags(inf.cnpj, inf.cpf, None).map_err(de::Error::custom)?,
+            modelo: inf.modelo,
+            serie: inf.serie,
+            numero_inicial: inf.numero_inicial,
+            numero_final: inf.numero_final,
+            justificativa: inf.justificativa,
+            // Apenas o FromStr tem acesso ao XML original da assinatura
+            assinatura: None,
+        })
+    }
+}
+
+impl Serialize for Inutilizacao {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let inut = InutNfeContainer {
+            namespace: NAMESPACE_NFE.to_string(),
+            versao: VersaoLayout::V4_00,
+            inf: InfInutContainer {
+                id: self.id(),
+                ambiente: self.ambiente,
+                servico: SERVICO_INUTILIZAR.to_string(),
+                codigo_uf: self.codigo_uf,
+                ano: self.ano,
+                cnpj: self.documento.cnpj().cloned(),
+                cpf: self.documento.cpf().cloned(),
+                modelo: self.modelo,
+                serie: self.serie,
+                numero_inicial: self.numero_inicial,
+                numero_final: self.numero_final,
+                justificativa: self.justificativa.clone(),
+            },
+        };
+
+        inut.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for RetornoInutilizacao {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ret = RetInutNfeContainer::deserialize(deserializer)?;
+        let inf = ret.inf;
+
+        Ok(Self {
+            versao: ret.versao,
+            id: inf.id,
+            ambiente: inf.ambiente,
+            versao_aplicativo: inf.versao_aplicativo,
+            status: inf.status,
+            motivo: inf.motivo,
+            codigo_uf: inf.codigo_uf,
+            ano: inf.ano,
+            documento: match (inf.cnpj, inf.cpf) {
+                (None, None) => None,
+                (cnpj, cpf) => {
+                    Some(Documento::from_tags(cnpj, cpf, None).map_err(de::Error::custom)?)
+                }
+            },
+            modelo: inf.modelo,
+            serie: inf.serie,
+            numero_inicial: inf.numero_inicial,
+            numero_final: inf.numero_final,
+            recebimento: inf.recebimento,
+            protocolo: inf.protocolo,
+        })
+    }
+}
+
+impl Serialize for RetornoInutilizacao {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let ret = RetInutNfeContainer {
+            versao: self.versao,
+            inf: InfRetInutContainer {
+                id: self.id.clone(),
+                ambiente: self.ambiente,
+                versao_aplicativo: self.versao_aplicativo.clone(),
+                status: self.status,
+                motivo: self.motivo.clone(),
+                codigo_uf: self.codigo_uf,
+                ano: self.ano,
+                cnpj: self.documento.as_ref().and_then(Documento::cnpj).cloned(),
+                cpf: self.documento.as_ref().and_then(Documento::cpf).cloned(),
+                modelo: self.modelo,
+                serie: self.serie,
+                numero_inicial: self.numero_inicial,
+                numero_final: self.numero_final,
+                recebimento: self.recebimento,
+                protocolo: self.protocolo.clone(),
+            },
+        };
+
+        ret.serialize(serializer)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "procInutNFe")]
+struct ProcInutContainer {
+    #[serde(rename = "inutNFe")]
+    pub inutilizacao: Inutilizacao,
+    #[serde(rename = "retInutNFe")]
+    pub retorno: RetornoInutilizacao,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "inutNFe")]
+struct InutNfeContainer {
+    #[serde(rename = "xmlns")]
+    #[serde(skip_deserializing)]
+    pub namespace: String,
+    #[serde(rename = "versao")]
+    pub versao: VersaoLayout,
+    #[serde(rename = "infInut")]
+    pub inf: InfInutContainer,
+}
+
+#[derive(Deserialize, Serialize)]
+struct InfInutContainer {
+    #[serde(rename = "Id")]
+    pub id: String,
+    #[serde(rename = "$unflatten=tpAmb")]
+    pub ambiente: TipoAmbiente,
+    #[serde(rename = "$unflatten=xServ")]
+    pub servico: String,
+    #[serde(rename = "$unflatten=cUF")]
+    pub codigo_uf: u8,
+    #[serde(rename = "$unflatten=ano")]
+    #[serde(serialize_with = "serialize_ano")]
+    pub ano: u8,
+    #[serde(rename = "$unflatten=CNPJ")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cnpj: Option<Cnpj>,
+    #[serde(rename = "$unflatten=CPF")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpf: Option<Cpf>,
+    #[serde(rename = "$unflatten=mod")]
+    pub modelo: ModeloDocumentoFiscal,
+    #[serde(rename = "$unflatten=serie")]
+    pub serie: u16,
+    #[serde(rename = "$unflatten=nNFIni")]
+    pub numero_inicial: u32,
+    #[serde(rename = "$unflatten=nNFFin")]
+    pub numero_final: u32,
+    #[serde(rename = "$unflatten=xJust")]
+    pub justificativa: String,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "retInutNFe")]
+struct RetInutNfeContainer {
+    #[serde(rename = "versao")]
+    pub versao: VersaoLayout,
+    #[serde(rename = "infInut")]
+    pub inf: InfRetInutContainer,
+}
+
+#[derive(Deserialize, Serialize)]
+struct InfRetInutContainer {
+    #[serde(rename = "Id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "$unflatten=tpAmb")]
+    pub ambiente: TipoAmbiente,
+    #[serde(rename = "$unflatten=verAplic")]
+    pub versao_aplicativo: String,
+    #[serde(rename = "$unflatten=cStat")]
+    pub status: u16,
+    #[serde(rename = "$unflatten=xMotivo")]
+    pub motivo: String,
+    #[serde(rename = "$unflatten=cUF")]
+    pub codigo_uf: u8,
+    #[serde(rename = "$unflatten=ano")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "serialize_ano_op")]
+    pub ano: Option<u8>,
+    #[serde(rename = "$unflatten=CNPJ")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cnpj: Option<Cnpj>,
+    #[serde(rename = "$unflatten=CPF")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpf: Option<Cpf>,
+    #[serde(rename = "$unflatten=mod")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modelo: Option<ModeloDocumentoFiscal>,
+    #[serde(rename = "$unflatten=serie")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serie: Option<u16>,
+    #[serde(rename = "$unflatten=nNFIni")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub numero_inicial: Option<u32>,
+    #[serde(rename = "$unflatten=nNFFin")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub numero_final: Option<u32>,
+    #[serde(rename = "$unflatten=dhRecbto")]
+    #[serde(serialize_with = "serialize_horario")]
+    pub recebimento: DateTime<FixedOffset>,
+    #[serde(rename = "$unflatten=nProt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocolo: Option<String>,
+}
+
+/// O ano é sempre escrito com dois dígitos. Ex.: 09
+fn serialize_ano<S>(ano: &u8, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&format!("{:02}", ano))
+}
+
+fn serialize_ano_op<S>(ano: &Option<u8>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serialize_ano(&ano.unwrap_or_default(), serializer)
+}
+
+fn serialize_horario<S>(date: &DateTime<FixedOffset>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&date.to_rfc3339())
+}
@@ -0,0 +1,33 @@
+//! CEST - Código Especificador da Substituição Tributária
+//!
+//! O código é validado pelo formato e pelo segmento do Convênio ICMS
+//! 142/2018. Sem a tabela CEST x NCM completa embarcada, a relação do
+//! CEST com o NCM do item não é conferida.
+
+use super::Error;
+
+/// Último segmento do anexo do Convênio ICMS 142/2018
+pub const ULTIMO_SEGMENTO_CEST: u8 = 28;
+
+/// Valida o formato e o segmento do CEST, representado pelos 2 primeiros dígitos
+pub fn validar_cest(codigo: &str) -> Result<(), Error> {
+    let cest = codigo.trim().replace('.', "");
+
+    if cest.len() != 7 || !cest.chars().all(|c| c.is_ascii_digit()) {
+        return Err(Error::CestInvalido(format!(
+            "Código deve possuir 7 dígitos: {}",
+            codigo
+        )));
+    }
+
+    let segmento: u8 = cest[0..2].parse().expect("Apenas dígitos");
+
+    if !(1..=ULTIMO_SEGMENTO_CEST).contains(&segmento) {
+        return Err(Error::CestInvalido(format!(
+            "Segmento {:02} inexistente: {}",
+            segmento, codigo
+        )));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,2679 @@
+//! CFOP - Código Fiscal de Operações e Prestações
+
+use super::Error;
+use crate::base::ide::{DestinoOperacao, Operacao, TipoOperacao};
+use std::fmt;
+use std::str::FromStr;
+
+/// Código Fiscal de Operações e Prestações
+///
+/// Tabela do Convênio SINIEF s/nº de 1970. O primeiro dígito indica o
+/// sentido e a abrangência da operação: 1, 2 e 3 para entradas e 5, 6 e 7
+/// para saídas, sendo internas, interestaduais e com o exterior, respectivamente.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+#[repr(u16)]
+pub enum Cfop {
+    /// Compra para industrialização ou produção rural
+    Cfop1101 = 1101,
+    /// Compra para comercialização
+    Cfop1102 = 1102,
+    /// Compra para industrialização de mercadoria recebida anteriormente em consignação industrial
+    Cfop1111 = 1111,
+    /// Compra para comercialização, de mercadoria recebida anteriormente em consignação mercantil
+    Cfop1113 = 1113,
+    /// Compra para industrialização ou produção rural originada de encomenda para recebimento futuro
+    Cfop1116 = 1116,
+    /// Compra para comercialização originada de encomenda para recebimento futuro
+    Cfop1117 = 1117,
+    /// Compra de mercadoria para comercialização pelo adquirente originário, entregue pelo vendedor remetente ao destinatário, em venda à ordem
+    Cfop1118 = 1118,
+    /// Compra para industrialização, em venda à ordem, já recebida do vendedor remetente
+    Cfop1120 = 1120,
+    /// Compra para comercialização, em venda à ordem, já recebida do vendedor remetente
+    Cfop1121 = 1121,
+    /// Compra para industrialização em que a mercadoria foi remetida pelo fornecedor ao industrializador sem transitar pelo estabelecimento adquirente
+    Cfop1122 = 1122,
+    /// Industrialização efetuada por outra empresa
+    Cfop1124 = 1124,
+    /// Industrialização efetuada por outra empresa quando a mercadoria remetida para utilização no processo de industrialização não transitou pelo estabelecimento adquirente da mercadoria
+    Cfop1125 = 1125,
+    /// Compra para utilização na prestação de serviço sujeita ao ICMS
+    Cfop1126 = 1126,
+    /// Compra para utilização na prestação de serviço sujeita ao ISSQN
+    Cfop1128 = 1128,
+    /// Entrada de mercadoria com previsão de posterior ajuste ou fixação de preço, decorrente de operação de ato cooperativo
+    Cfop1131 = 1131,
+    /// Fixação de preço de produção do estabelecimento produtor, inclusive quando remetidas anteriormente com previsão de posterior ajuste ou fixação de preço, em ato cooperativo, para comercialização
+    Cfop1132 = 1132,
+    /// Fixação de preço de produção do estabelecimento produtor, inclusive quando remetidas anteriormente com previsão de posterior ajuste ou fixação de preço, em ato cooperativo, para industrialização
+    Cfop1135 = 1135,
+    /// Transferência para industrialização ou produção rural
+    Cfop1151 = 1151,
+    /// Transferência para comercialização
+    Cfop1152 = 1152,
+    /// Transferência de energia elétrica para distribuição
+    Cfop1153 = 1153,
+    /// Transferência para utilização na prestação de serviço
+    Cfop1154 = 1154,
+    /// Entrada decorrente do fornecimento de produto ou mercadoria de ato cooperativo
+    Cfop1159 = 1159,
+    /// Devolução de venda de produção do estabelecimento
+    Cfop1201 = 1201,
+    /// Devolução de venda de mercadoria adquirida ou recebida de terceiros
+    Cfop1202 = 1202,
+    /// Devolução de venda de produção do estabelecimento, destinada à Zona Franca de Manaus ou Áreas de Livre Comércio
+    Cfop1203 = 1203,
+    /// Devolução de venda de mercadoria adquirida ou recebida de terceiros, destinada à Zona Franca de Manaus ou Áreas de Livre Comércio
+    Cfop1204 = 1204,
+    /// Anulação de valor relativo à prestação de serviço de comunicação
+    Cfop1205 = 1205,
+    /// Anulação de valor relativo à prestação de serviço de transporte
+    Cfop1206 = 1206,
+    /// Anulação de valor relativo à venda de energia elétrica
+    Cfop1207 = 1207,
+    /// Devolução de produção do estabelecimento, remetida em transferência
+    Cfop1208 = 1208,
+    /// Devolução de mercadoria adquirida ou recebida de terceiros, remetida em transferência
+    Cfop1209 = 1209,
+    /// Devolução de venda de mercadoria industrializada e insumo importado sob o Regime Aduaneiro Especial de Entreposto Industrial sob Controle Informatizado do Sistema Integrado de Comércio Exterior (Recof-Sped)
+    Cfop1212 = 1212,
+    /// Devolução de remessa de produção do estabelecimento com previsão de posterior ajuste ou fixação de preço, em ato cooperativo
+    Cfop1213 = 1213,
+    /// Devolução de fixação de preço de produção do estabelecimento produtor, de ato cooperativo, para comercialização
+    Cfop1214 = 1214,
+    /// Devolução de fixação de preço de produção do estabelecimento produtor, de ato cooperativo, para industrialização
+    Cfop1215 = 1215,
+    /// Compra de energia elétrica para distribuição ou comercialização
+    Cfop1251 = 1251,
+    /// Compra de energia elétrica por estabelecimento industrial
+    Cfop1252 = 1252,
+    /// Compra de energia elétrica por estabelecimento comercial
+    Cfop1253 = 1253,
+    /// Compra de energia elétrica por estabelecimento prestador de serviço de transporte
+    Cfop1254 = 1254,
+    /// Compra de energia elétrica por estabelecimento prestador de serviço de comunicação
+    Cfop1255 = 1255,
+    /// Compra de energia elétrica por estabelecimento de produtor rural
+    Cfop1256 = 1256,
+    /// Compra de energia elétrica para consumo por demanda contratada
+    Cfop1257 = 1257,
+    /// Aquisição de serviço de comunicação para execução de serviço da mesma natureza
+    Cfop1301 = 1301,
+    /// Aquisição de serviço de comunicação por estabelecimento industrial
+    Cfop1302 = 1302,
+    /// Aquisição de serviço de comunicação por estabelecimento comercial
+    Cfop1303 = 1303,
+    /// Aquisição de serviço de comunicação por estabelecimento de prestador de serviço de transporte
+    Cfop1304 = 1304,
+    /// Aquisição de serviço de comunicação por estabelecimento de geradora ou de distribuidora de energia elétrica
+    Cfop1305 = 1305,
+    /// Aquisição de serviço de comunicação por estabelecimento de produtor rural
+    Cfop1306 = 1306,
+    /// Aquisição de serviço de transporte para execução de serviço da mesma natureza
+    Cfop1351 = 1351,
+    /// Aquisição de serviço de transporte por estabelecimento industrial
+    Cfop1352 = 1352,
+    /// Aquisição de serviço de transporte por estabelecimento comercial
+    Cfop1353 = 1353,
+    /// Aquisição de serviço de transporte por estabelecimento de prestador de serviço de comunicação
+    Cfop1354 = 1354,
+    /// Aquisição de serviço de transporte por estabelecimento de geradora ou de distribuidora de energia elétrica
+    Cfop1355 = 1355,
+    /// Aquisição de serviço de transporte por estabelecimento de produtor rural
+    Cfop1356 = 1356,
+    /// Aquisição de serviço de transporte por contribuinte substituto em relação ao serviço de transporte
+    Cfop1360 = 1360,
+    /// Compra para industrialização ou produção rural em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop1401 = 1401,
+    /// Compra para comercialização em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop1403 = 1403,
+    /// Compra de bem para o ativo imobilizado cuja mercadoria está sujeita ao regime de substituição tributária
+    Cfop1406 = 1406,
+    /// Compra de mercadoria para uso ou consumo cuja mercadoria está sujeita ao regime de substituição tributária
+    Cfop1407 = 1407,
+    /// Transferência para industrialização ou produção rural em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop1408 = 1408,
+    /// Transferência para comercialização em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop1409 = 1409,
+    /// Devolução de venda de produção do estabelecimento em operação com produto sujeito ao regime de substituição tributária
+    Cfop1410 = 1410,
+    /// Devolução de venda de mercadoria adquirida ou recebida de terceiros em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop1411 = 1411,
+    /// Retorno de produção do estabelecimento, remetida para venda fora do estabelecimento em operação com produto sujeito ao regime de substituição tributária
+    Cfop1414 = 1414,
+    /// Retorno de mercadoria adquirida ou recebida de terceiros, remetida para venda fora do estabelecimento em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop1415 = 1415,
+    /// Retorno de animal do estabelecimento produtor
+    Cfop1451 = 1451,
+    /// Retorno de insumo não utilizado na produção
+    Cfop1452 = 1452,
+    /// Entrada de mercadoria recebida com fim específico de exportação
+    Cfop1501 = 1501,
+    /// Entrada decorrente de devolução de produto remetido com fim específico de exportação, de produção do estabelecimento
+    Cfop1503 = 1503,
+    /// Entrada decorrente de devolução de mercadoria remetida com fim específico de exportação, adquirida ou recebida de terceiros
+    Cfop1504 = 1504,
+    /// Entrada decorrente de devolução simbólica de mercadorias remetidas para formação de lote de exportação, de produtos industrializados ou produzidos pelo próprio estabelecimento
+    Cfop1505 = 1505,
+    /// Entrada decorrente de devolução simbólica de mercadorias, adquiridas ou recebidas de terceiros, remetidas para formação de lote de exportação
+    Cfop1506 = 1506,
+    /// Compra de bem para o ativo imobilizado
+    Cfop1551 = 1551,
+    /// Transferência de bem do ativo imobilizado
+    Cfop1552 = 1552,
+    /// Devolução de venda de bem do ativo imobilizado
+    Cfop1553 = 1553,
+    /// Retorno de bem do ativo imobilizado remetido para uso fora do estabelecimento
+    Cfop1554 = 1554,
+    /// Entrada de bem do ativo imobilizado de terceiro, remetido para uso no estabelecimento
+    Cfop1555 = 1555,
+    /// Compra de material para uso ou consumo
+    Cfop1556 = 1556,
+    /// Transferência de material para uso ou consumo
+    Cfop1557 = 1557,
+    /// Recebimento, por transferência, de crédito de ICMS
+    Cfop1601 = 1601,
+    /// Recebimento, por transferência, de saldo credor de ICMS de outro estabelecimento da mesma empresa, para compensação de saldo devedor de ICMS
+    Cfop1602 = 1602,
+    /// Ressarcimento de ICMS retido por substituição tributária
+    Cfop1603 = 1603,
+    /// Lançamento do crédito relativo à compra de bem para o ativo imobilizado
+    Cfop1604 = 1604,
+    /// Recebimento, por transferência, de saldo devedor de ICMS de outro estabelecimento da mesma empresa
+    Cfop1605 = 1605,
+    /// Compra de combustível ou lubrificante para industrialização subseqüente
+    Cfop1651 = 1651,
+    /// Compra de combustível ou lubrificante para comercialização
+    Cfop1652 = 1652,
+    /// Compra de combustível ou lubrificante por consumidor ou usuário final
+    Cfop1653 = 1653,
+    /// Transferência de combustível e lubrificante para industrialização
+    Cfop1658 = 1658,
+    /// Transferência de combustível e lubrificante para comercialização
+    Cfop1659 = 1659,
+    /// Devolução de venda de combustível ou lubrificante destinado à industrialização subseqüente
+    Cfop1660 = 1660,
+    /// Devolução de venda de combustível ou lubrificante destinado à comercialização
+    Cfop1661 = 1661,
+    /// Devolução de venda de combustível ou lubrificante destinado a consumidor ou usuário final
+    Cfop1662 = 1662,
+    /// Entrada de combustível ou lubrificante para armazenagem
+    Cfop1663 = 1663,
+    /// Retorno de combustível ou lubrificante remetido para armazenagem
+    Cfop1664 = 1664,
+    /// Entrada para industrialização por encomenda
+    Cfop1901 = 1901,
+    /// Retorno de mercadoria remetida para industrialização por encomenda
+    Cfop1902 = 1902,
+    /// Entrada de mercadoria remetida para industrialização e não aplicada no referido processo
+    Cfop1903 = 1903,
+    /// Retorno de remessa para venda fora do estabelecimento
+    Cfop1904 = 1904,
+    /// Entrada de mercadoria recebida para depósito em depósito fechado ou armazém geral
+    Cfop1905 = 1905,
+    /// Retorno de mercadoria remetida para depósito fechado ou armazém geral
+    Cfop1906 = 1906,
+    /// Retorno simbólico de mercadoria remetida para depósito fechado ou armazém geral
+    Cfop1907 = 1907,
+    /// Entrada de bem por conta de contrato de comodato
+    Cfop1908 = 1908,
+    /// Retorno de bem remetido por conta de contrato de comodato
+    Cfop1909 = 1909,
+    /// Entrada de bonificação, doação ou brinde
+    Cfop1910 = 1910,
+    /// Entrada de amostra grátis
+    Cfop1911 = 1911,
+    /// Entrada de mercadoria ou bem recebido para demonstração ou mostruário
+    Cfop1912 = 1912,
+    /// Retorno de mercadoria ou bem remetido para demonstração, mostruário ou treinamento
+    Cfop1913 = 1913,
+    /// Retorno de mercadoria ou bem remetido para exposição ou feira
+    Cfop1914 = 1914,
+    /// Entrada de mercadoria ou bem recebido para conserto ou reparo
+    Cfop1915 = 1915,
+    /// Retorno de mercadoria ou bem remetido para conserto ou reparo
+    Cfop1916 = 1916,
+    /// Entrada de mercadoria recebida em consignação mercantil ou industrial
+    Cfop1917 = 1917,
+    /// Devolução de mercadoria remetida em consignação mercantil ou industrial
+    Cfop1918 = 1918,
+    /// Devolução simbólica de mercadoria vendida ou utilizada em processo industrial, remetida anteriormente em consignação mercantil ou industrial
+    Cfop1919 = 1919,
+    /// Entrada de vasilhame ou sacaria
+    Cfop1920 = 1920,
+    /// Retorno de vasilhame ou sacaria
+    Cfop1921 = 1921,
+    /// Lançamento efetuado a título de simples faturamento decorrente de compra para recebimento futuro
+    Cfop1922 = 1922,
+    /// Entrada de mercadoria recebida do vendedor remetente, em venda à ordem
+    Cfop1923 = 1923,
+    /// Entrada para industrialização por conta e ordem do adquirente da mercadoria, quando esta não transitar pelo estabelecimento do adquirente
+    Cfop1924 = 1924,
+    /// Retorno de mercadoria remetida para industrialização por conta e ordem do adquirente da mercadoria, quando esta não transitar pelo estabelecimento do adquirente
+    Cfop1925 = 1925,
+    /// Lançamento efetuado a título de reclassificação de mercadoria decorrente de formação de kit ou de sua desagregação
+    Cfop1926 = 1926,
+    /// Lançamento efetuado pelo tomador do serviço de transporte quando a responsabilidade de retenção do imposto for atribuída ao remetente ou alienante da mercadoria, pelo serviço de transporte realizado por transportador autônomo ou por transportador não inscrito na unidade da Federação onde iniciado o serviço
+    Cfop1931 = 1931,
+    /// Aquisição de serviço de transporte iniciado em unidade da Federação diversa daquela onde inscrito o prestador
+    Cfop1932 = 1932,
+    /// Aquisição de serviço tributado pelo ISSQN
+    Cfop1933 = 1933,
+    /// Entrada simbólica de mercadoria recebida para depósito fechado ou armazém geral
+    Cfop1934 = 1934,
+    /// Outra entrada de mercadoria ou prestação de serviço não especificada
+    Cfop1949 = 1949,
+    /// Compra para industrialização ou produção rural
+    Cfop2101 = 2101,
+    /// Compra para comercialização
+    Cfop2102 = 2102,
+    /// Compra para industrialização de mercadoria recebida anteriormente em consignação industrial
+    Cfop2111 = 2111,
+    /// Compra para comercialização, de mercadoria recebida anteriormente em consignação mercantil
+    Cfop2113 = 2113,
+    /// Compra para industrialização ou produção rural originada de encomenda para recebimento futuro
+    Cfop2116 = 2116,
+    /// Compra para comercialização originada de encomenda para recebimento futuro
+    Cfop2117 = 2117,
+    /// Compra de mercadoria para comercialização pelo adquirente originário, entregue pelo vendedor remetente ao destinatário, em venda à ordem
+    Cfop2118 = 2118,
+    /// Compra para industrialização, em venda à ordem, já recebida do vendedor remetente
+    Cfop2120 = 2120,
+    /// Compra para comercialização, em venda à ordem, já recebida do vendedor remetente
+    Cfop2121 = 2121,
+    /// Compra para industrialização em que a mercadoria foi remetida pelo fornecedor ao industrializador sem transitar pelo estabelecimento adquirente
+    Cfop2122 = 2122,
+    /// Industrialização efetuada por outra empresa
+    Cfop2124 = 2124,
+    /// Industrialização efetuada por outra empresa quando a mercadoria remetida para utilização no processo de industrialização não transitou pelo estabelecimento adquirente da mercadoria
+    Cfop2125 = 2125,
+    /// Compra para utilização na prestação de serviço sujeita ao ICMS
+    Cfop2126 = 2126,
+    /// Compra para utilização na prestação de serviço sujeita ao ISSQN
+    Cfop2128 = 2128,
+    /// Entrada de mercadoria com previsão de posterior ajuste ou fixação de preço, decorrente de operação de ato cooperativo
+    Cfop2131 = 2131,
+    /// Fixação de preço de produção do estabelecimento produtor, inclusive quando remetidas anteriormente com previsão de posterior ajuste ou fixação de preço, em ato cooperativo, para comercialização
+    Cfop2132 = 2132,
+    /// Fixação de preço de produção do estabelecimento produtor, inclusive quando remetidas anteriormente com previsão de posterior ajuste ou fixação de preço, em ato cooperativo, para industrialização
+    Cfop2135 = 2135,
+    /// Transferência para industrialização ou produção rural
+    Cfop2151 = 2151,
+    /// Transferência para comercialização
+    Cfop2152 = 2152,
+    /// Transferência de energia elétrica para distribuição
+    Cfop2153 = 2153,
+    /// Transferência para utilização na prestação de serviço
+    Cfop2154 = 2154,
+    /// Entrada decorrente do fornecimento de produto ou mercadoria de ato cooperativo
+    Cfop2159 = 2159,
+    /// Devolução de venda de produção do estabelecimento
+    Cfop2201 = 2201,
+    /// Devolução de venda de mercadoria adquirida ou recebida de terceiros
+    Cfop2202 = 2202,
+    /// Devolução de venda de produção do estabelecimento, destinada à Zona Franca de Manaus ou Áreas de Livre Comércio
+    Cfop2203 = 2203,
+    /// Devolução de venda de mercadoria adquirida ou recebida de terceiros, destinada à Zona Franca de Manaus ou Áreas de Livre Comércio
+    Cfop2204 = 2204,
+    /// Anulação de valor relativo à prestação de serviço de comunicação
+    Cfop2205 = 2205,
+    /// Anulação de valor relativo à prestação de serviço de transporte
+    Cfop2206 = 2206,
+    /// Anulação de valor relativo à venda de energia elétrica
+    Cfop2207 = 2207,
+    /// Devolução de produção do estabelecimento, remetida em transferência
+    Cfop2208 = 2208,
+    /// Devolução de mercadoria adquirida ou recebida de terceiros, remetida em transferência
+    Cfop2209 = 2209,
+    /// Devolução de venda de mercadoria industrializada e insumo importado sob o Regime Aduaneiro Especial de Entreposto Industrial sob Controle Informatizado do Sistema Integrado de Comércio Exterior (Recof-Sped)
+    Cfop2212 = 2212,
+    /// Devolução de remessa de produção do estabelecimento com previsão de posterior ajuste ou fixação de preço, em ato cooperativo
+    Cfop2213 = 2213,
+    /// Devolução de fixação de preço de produção do estabelecimento produtor, de ato cooperativo, para comercialização
+    Cfop2214 = 2214,
+    /// Devolução de fixação de preço de produção do estabelecimento produtor, de ato cooperativo, para industrialização
+    Cfop2215 = 2215,
+    /// Compra de energia elétrica para distribuição ou comercialização
+    Cfop2251 = 2251,
+    /// Compra de energia elétrica por estabelecimento industrial
+    Cfop2252 = 2252,
+    /// Compra de energia elétrica por estabelecimento comercial
+    Cfop2253 = 2253,
+    /// Compra de energia elétrica por estabelecimento prestador de serviço de transporte
+    Cfop2254 = 2254,
+    /// Compra de energia elétrica por estabelecimento prestador de serviço de comunicação
+    Cfop2255 = 2255,
+    /// Compra de energia elétrica por estabelecimento de produtor rural
+    Cfop2256 = 2256,
+    /// Compra de energia elétrica para consumo por demanda contratada
+    Cfop2257 = 2257,
+    /// Aquisição de serviço de comunicação para execução de serviço da mesma natureza
+    Cfop2301 = 2301,
+    /// Aquisição de serviço de comunicação por estabelecimento industrial
+    Cfop2302 = 2302,
+    /// Aquisição de serviço de comunicação por estabelecimento comercial
+    Cfop2303 = 2303,
+    /// Aquisição de serviço de comunicação por estabelecimento de prestador de serviço de transporte
+    Cfop2304 = 2304,
+    /// Aquisição de serviço de comunicação por estabelecimento de geradora ou de distribuidora de energia elétrica
+    Cfop2305 = 2305,
+    /// Aquisição de serviço de comunicação por estabelecimento de produtor rural
+    Cfop2306 = 2306,
+    /// Aquisição de serviço de transporte para execução de serviço da mesma natureza
+    Cfop2351 = 2351,
+    /// Aquisição de serviço de transporte por estabelecimento industrial
+    Cfop2352 = 2352,
+    /// Aquisição de serviço de transporte por estabelecimento comercial
+    Cfop2353 = 2353,
+    /// Aquisição de serviço de transporte por estabelecimento de prestador de serviço de comunicação
+    Cfop2354 = 2354,
+    /// Aquisição de serviço de transporte por estabelecimento de geradora ou de distribuidora de energia elétrica
+    Cfop2355 = 2355,
+    /// Aquisição de serviço de transporte por estabelecimento de produtor rural
+    Cfop2356 = 2356,
+    /// Aquisição de serviço de transporte por contribuinte substituto em relação ao serviço de transporte
+    Cfop2360 = 2360,
+    /// Compra para industrialização ou produção rural em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop2401 = 2401,
+    /// Compra para comercialização em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop2403 = 2403,
+    /// Compra de bem para o ativo imobilizado cuja mercadoria está sujeita ao regime de substituição tributária
+    Cfop2406 = 2406,
+    /// Compra de mercadoria para uso ou consumo cuja mercadoria está sujeita ao regime de substituição tributária
+    Cfop2407 = 2407,
+    /// Transferência para industrialização ou produção rural em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop2408 = 2408,
+    /// Transferência para comercialização em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop2409 = 2409,
+    /// Devolução de venda de produção do estabelecimento em operação com produto sujeito ao regime de substituição tributária
+    Cfop2410 = 2410,
+    /// Devolução de venda de mercadoria adquirida ou recebida de terceiros em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop2411 = 2411,
+    /// Retorno de produção do estabelecimento, remetida para venda fora do estabelecimento em operação com produto sujeito ao regime de substituição tributária
+    Cfop2414 = 2414,
+    /// Retorno de mercadoria adquirida ou recebida de terceiros, remetida para venda fora do estabelecimento em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop2415 = 2415,
+    /// Entrada de mercadoria recebida com fim específico de exportação
+    Cfop2501 = 2501,
+    /// Entrada decorrente de devolução de produto remetido com fim específico de exportação, de produção do estabelecimento
+    Cfop2503 = 2503,
+    /// Entrada decorrente de devolução de mercadoria remetida com fim específico de exportação, adquirida ou recebida de terceiros
+    Cfop2504 = 2504,
+    /// Entrada decorrente de devolução simbólica de mercadorias remetidas para formação de lote de exportação, de produtos industrializados ou produzidos pelo próprio estabelecimento
+    Cfop2505 = 2505,
+    /// Entrada decorrente de devolução simbólica de mercadorias, adquiridas ou recebidas de terceiros, remetidas para formação de lote de exportação
+    Cfop2506 = 2506,
+    /// Compra de bem para o ativo imobilizado
+    Cfop2551 = 2551,
+    /// Transferência de bem do ativo imobilizado
+    Cfop2552 = 2552,
+    /// Devolução de venda de bem do ativo imobilizado
+    Cfop2553 = 2553,
+    /// Retorno de bem do ativo imobilizado remetido para uso fora do estabelecimento
+    Cfop2554 = 2554,
+    /// Entrada de bem do ativo imobilizado de terceiro, remetido para uso no estabelecimento
+    Cfop2555 = 2555,
+    /// Compra de material para uso ou consumo
+    Cfop2556 = 2556,
+    /// Transferência de material para uso ou consumo
+    Cfop2557 = 2557,
+    /// Ressarcimento de ICMS retido por substituição tributária
+    Cfop2603 = 2603,
+    /// Compra de combustível ou lubrificante para industrialização subseqüente
+    Cfop2651 = 2651,
+    /// Compra de combustível ou lubrificante para comercialização
+    Cfop2652 = 2652,
+    /// Compra de combustível ou lubrificante por consumidor ou usuário final
+    Cfop2653 = 2653,
+    /// Transferência de combustível e lubrificante para industrialização
+    Cfop2658 = 2658,
+    /// Transferência de combustível e lubrificante para comercialização
+    Cfop2659 = 2659,
+    /// Devolução de venda de combustível ou lubrificante destinado à industrialização subseqüente
+    Cfop2660 = 2660,
+    /// Devolução de venda de combustível ou lubrificante destinado à comercialização
+    Cfop2661 = 2661,
+    /// Devolução de venda de combustível ou lubrificante destinado a consumidor ou usuário final
+    Cfop2662 = 2662,
+    /// Entrada de combustível ou lubrificante para armazenagem
+    Cfop2663 = 2663,
+    /// Retorno de combustível ou lubrificante remetido para armazenagem
+    Cfop2664 = 2664,
+    /// Entrada para industrialização por encomenda
+    Cfop2901 = 2901,
+    /// Retorno de mercadoria remetida para industrialização por encomenda
+    Cfop2902 = 2902,
+    /// Entrada de mercadoria remetida para industrialização e não aplicada no referido processo
+    Cfop2903 = 2903,
+    /// Retorno de remessa para venda fora do estabelecimento
+    Cfop2904 = 2904,
+    /// Entrada de mercadoria recebida para depósito em depósito fechado ou armazém geral
+    Cfop2905 = 2905,
+    /// Retorno de mercadoria remetida para depósito fechado ou armazém geral
+    Cfop2906 = 2906,
+    /// Retorno simbólico de mercadoria remetida para depósito fechado ou armazém geral
+    Cfop2907 = 2907,
+    /// Entrada de bem por conta de contrato de comodato
+    Cfop2908 = 2908,
+    /// Retorno de bem remetido por conta de contrato de comodato
+    Cfop2909 = 2909,
+    /// Entrada de bonificação, doação ou brinde
+    Cfop2910 = 2910,
+    /// Entrada de amostra grátis
+    Cfop2911 = 2911,
+    /// Entrada de mercadoria ou bem recebido para demonstração ou mostruário
+    Cfop2912 = 2912,
+    /// Retorno de mercadoria ou bem remetido para demonstração, mostruário ou treinamento
+    Cfop2913 = 2913,
+    /// Retorno de mercadoria ou bem remetido para exposição ou feira
+    Cfop2914 = 2914,
+    /// Entrada de mercadoria ou bem recebido para conserto ou reparo
+    Cfop2915 = 2915,
+    /// Retorno de mercadoria ou bem remetido para conserto ou reparo
+    Cfop2916 = 2916,
+    /// Entrada de mercadoria recebida em consignação mercantil ou industrial
+    Cfop2917 = 2917,
+    /// Devolução de mercadoria remetida em consignação mercantil ou industrial
+    Cfop2918 = 2918,
+    /// Devolução simbólica de mercadoria vendida ou utilizada em processo industrial, remetida anteriormente em consignação mercantil ou industrial
+    Cfop2919 = 2919,
+    /// Entrada de vasilhame ou sacaria
+    Cfop2920 = 2920,
+    /// Retorno de vasilhame ou sacaria
+    Cfop2921 = 2921,
+    /// Lançamento efetuado a título de simples faturamento decorrente de compra para recebimento futuro
+    Cfop2922 = 2922,
+    /// Entrada de mercadoria recebida do vendedor remetente, em venda à ordem
+    Cfop2923 = 2923,
+    /// Entrada para industrialização por conta e ordem do adquirente da mercadoria, quando esta não transitar pelo estabelecimento do adquirente
+    Cfop2924 = 2924,
+    /// Retorno de mercadoria remetida para industrialização por conta e ordem do adquirente da mercadoria, quando esta não transitar pelo estabelecimento do adquirente
+    Cfop2925 = 2925,
+    /// Lançamento efetuado pelo tomador do serviço de transporte quando a responsabilidade de retenção do imposto for atribuída ao remetente ou alienante da mercadoria, pelo serviço de transporte realizado por transportador autônomo ou por transportador não inscrito na unidade da Federação onde iniciado o serviço
+    Cfop2931 = 2931,
+    /// Aquisição de serviço de transporte iniciado em unidade da Federação diversa daquela onde inscrito o prestador
+    Cfop2932 = 2932,
+    /// Aquisição de serviço tributado pelo ISSQN
+    Cfop2933 = 2933,
+    /// Entrada simbólica de mercadoria recebida para depósito fechado ou armazém geral
+    Cfop2934 = 2934,
+    /// Outra entrada de mercadoria ou prestação de serviço não especificada
+    Cfop2949 = 2949,
+    /// Compra para industrialização ou produção rural
+    Cfop3101 = 3101,
+    /// Compra para comercialização
+    Cfop3102 = 3102,
+    /// Compra para utilização na prestação de serviço sujeita ao ICMS
+    Cfop3126 = 3126,
+    /// Compra para industrialização sob o regime de drawback
+    Cfop3127 = 3127,
+    /// Compra para utilização na prestação de serviço sujeita ao ISSQN
+    Cfop3128 = 3128,
+    /// Compra para industrialização sob o Regime Aduaneiro Especial de Entreposto Industrial sob Controle Informatizado do Sistema Integrado de Comércio Exterior (Recof-Sped)
+    Cfop3129 = 3129,
+    /// Devolução de venda de produção do estabelecimento
+    Cfop3201 = 3201,
+    /// Devolução de venda de mercadoria adquirida ou recebida de terceiros
+    Cfop3202 = 3202,
+    /// Anulação de valor relativo à prestação de serviço de comunicação
+    Cfop3205 = 3205,
+    /// Anulação de valor relativo à prestação de serviço de transporte
+    Cfop3206 = 3206,
+    /// Anulação de valor relativo à venda de energia elétrica
+    Cfop3207 = 3207,
+    /// Devolução de venda de produção do estabelecimento sob o regime de drawback
+    Cfop3211 = 3211,
+    /// Devolução de venda de mercadoria industrializada e insumo importado sob o Regime Aduaneiro Especial de Entreposto Industrial sob Controle Informatizado do Sistema Integrado de Comércio Exterior (Recof-Sped)
+    Cfop3212 = 3212,
+    /// Compra de energia elétrica para distribuição ou comercialização
+    Cfop3251 = 3251,
+    /// Aquisição de serviço de comunicação para execução de serviço da mesma natureza
+    Cfop3301 = 3301,
+    /// Aquisição de serviço de transporte para execução de serviço da mesma natureza
+    Cfop3351 = 3351,
+    /// Aquisição de serviço de transporte por estabelecimento industrial
+    Cfop3352 = 3352,
+    /// Aquisição de serviço de transporte por estabelecimento comercial
+    Cfop3353 = 3353,
+    /// Aquisição de serviço de transporte por estabelecimento de prestador de serviço de comunicação
+    Cfop3354 = 3354,
+    /// Aquisição de serviço de transporte por estabelecimento de geradora ou de distribuidora de energia elétrica
+    Cfop3355 = 3355,
+    /// Aquisição de serviço de transporte por estabelecimento de produtor rural
+    Cfop3356 = 3356,
+    /// Devolução de mercadoria exportada que tenha sido recebida com fim específico de exportação
+    Cfop3503 = 3503,
+    /// Compra de bem para o ativo imobilizado
+    Cfop3551 = 3551,
+    /// Devolução de venda de bem do ativo imobilizado
+    Cfop3553 = 3553,
+    /// Compra de material para uso ou consumo
+    Cfop3556 = 3556,
+    /// Compra de combustível ou lubrificante para industrialização subseqüente
+    Cfop3651 = 3651,
+    /// Compra de combustível ou lubrificante para comercialização
+    Cfop3652 = 3652,
+    /// Compra de combustível ou lubrificante por consumidor ou usuário final
+    Cfop3653 = 3653,
+    /// Lançamento efetuado a título de entrada de bem sob amparo de regime especial aduaneiro de admissão temporária
+    Cfop3930 = 3930,
+    /// Outra entrada de mercadoria ou prestação de serviço não especificada
+    Cfop3949 = 3949,
+    /// Venda de produção do estabelecimento
+    Cfop5101 = 5101,
+    /// Venda de mercadoria adquirida ou recebida de terceiros
+    Cfop5102 = 5102,
+    /// Venda de produção do estabelecimento, efetuada fora do estabelecimento
+    Cfop5103 = 5103,
+    /// Venda de mercadoria adquirida ou recebida de terceiros, efetuada fora do estabelecimento
+    Cfop5104 = 5104,
+    /// Venda de produção do estabelecimento que não deva por ele transitar
+    Cfop5105 = 5105,
+    /// Venda de mercadoria adquirida ou recebida de terceiros, que não deva por ele transitar
+    Cfop5106 = 5106,
+    /// Venda de produção do estabelecimento, destinada à Zona Franca de Manaus ou Áreas de Livre Comércio
+    Cfop5109 = 5109,
+    /// Venda de mercadoria adquirida ou recebida de terceiros, destinada à Zona Franca de Manaus ou Áreas de Livre Comércio
+    Cfop5110 = 5110,
+    /// Venda de produção do estabelecimento remetida anteriormente em consignação industrial
+    Cfop5111 = 5111,
+    /// Venda de mercadoria adquirida ou recebida de terceiros remetida anteriormente em consignação industrial
+    Cfop5112 = 5112,
+    /// Venda de produção do estabelecimento remetida anteriormente em consignação mercantil
+    Cfop5113 = 5113,
+    /// Venda de mercadoria adquirida ou recebida de terceiros remetida anteriormente em consignação mercantil
+    Cfop5114 = 5114,
+    /// Venda de mercadoria adquirida ou recebida de terceiros, recebida anteriormente em consignação mercantil
+    Cfop5115 = 5115,
+    /// Venda de produção do estabelecimento originada de encomenda para entrega futura
+    Cfop5116 = 5116,
+    /// Venda de mercadoria adquirida ou recebida de terceiros, originada de encomenda para entrega futura
+    Cfop5117 = 5117,
+    /// Venda de produção do estabelecimento entregue ao destinatário por conta e ordem do adquirente originário, em venda à ordem
+    Cfop5118 = 5118,
+    /// Venda de mercadoria adquirida ou recebida de terceiros entregue ao destinatário por conta e ordem do adquirente originário, em venda à ordem
+    Cfop5119 = 5119,
+    /// Venda de mercadoria adquirida ou recebida de terceiros entregue ao destinatário pelo vendedor remetente, em venda à ordem
+    Cfop5120 = 5120,
+    /// Venda de produção do estabelecimento remetida para industrialização, por conta e ordem do adquirente, sem transitar pelo estabelecimento do adquirente
+    Cfop5122 = 5122,
+    /// Venda de mercadoria adquirida ou recebida de terceiros remetida para industrialização, por conta e ordem do adquirente, sem transitar pelo estabelecimento do adquirente
+    Cfop5123 = 5123,
+    /// Industrialização efetuada para outra empresa
+    Cfop5124 = 5124,
+    /// Industrialização efetuada para outra empresa quando a mercadoria recebida para utilização no processo de industrialização não transitar pelo estabelecimento adquirente da mercadoria
+    Cfop5125 = 5125,
+    /// Venda de insumo importado e de mercadoria industrializada sob o Regime Aduaneiro Especial de Entreposto Industrial sob Controle Informatizado do Sistema Integrado de Comércio Exterior (Recof-Sped)
+    Cfop5129 = 5129,
+    /// Remessa de produção do estabelecimento, com previsão de posterior ajuste ou fixação de preço, de ato cooperativo
+    Cfop5131 = 5131,
+    /// Fixação de preço de produção do estabelecimento, inclusive quando remetidas anteriormente com previsão de posterior ajuste ou fixação de preço, de ato cooperativo
+    Cfop5132 = 5132,
+    /// Transferência de produção do estabelecimento
+    Cfop5151 = 5151,
+    /// Transferência de mercadoria adquirida ou recebida de terceiros
+    Cfop5152 = 5152,
+    /// Transferência de energia elétrica
+    Cfop5153 = 5153,
+    /// Transferência de produção do estabelecimento, que não deva por ele transitar
+    Cfop5155 = 5155,
+    /// Transferência de mercadoria adquirida ou recebida de terceiros, que não deva por ele transitar
+    Cfop5156 = 5156,
+    /// Fornecimento de produção do estabelecimento de ato cooperativo
+    Cfop5159 = 5159,
+    /// Fornecimento de mercadoria adquirida ou recebida de terceiros de ato cooperativo
+    Cfop5160 = 5160,
+    /// Devolução de compra para industrialização ou produção rural
+    Cfop5201 = 5201,
+    /// Devolução de compra para comercialização
+    Cfop5202 = 5202,
+    /// Anulação de valor relativo a aquisição de serviço de comunicação
+    Cfop5205 = 5205,
+    /// Anulação de valor relativo a aquisição de serviço de transporte
+    Cfop5206 = 5206,
+    /// Anulação de valor relativo à compra de energia elétrica
+    Cfop5207 = 5207,
+    /// Devolução de mercadoria recebida em transferência para industrialização ou produção rural
+    Cfop5208 = 5208,
+    /// Devolução de mercadoria recebida em transferência para comercialização
+    Cfop5209 = 5209,
+    /// Devolução de compra para utilização na prestação de serviço
+    Cfop5210 = 5210,
+    /// Devolução de entrada de mercadoria com previsão de posterior ajuste ou fixação de preço, em ato cooperativo
+    Cfop5213 = 5213,
+    /// Devolução referente a fixação de preço de produção do estabelecimento produtor, de ato cooperativo, para comercialização
+    Cfop5214 = 5214,
+    /// Devolução referente a fixação de preço de produção do estabelecimento produtor, de ato cooperativo, para industrialização
+    Cfop5215 = 5215,
+    /// Venda de energia elétrica para distribuição ou comercialização
+    Cfop5251 = 5251,
+    /// Venda de energia elétrica para estabelecimento industrial
+    Cfop5252 = 5252,
+    /// Venda de energia elétrica para estabelecimento comercial
+    Cfop5253 = 5253,
+    /// Venda de energia elétrica para estabelecimento prestador de serviço de transporte
+    Cfop5254 = 5254,
+    /// Venda de energia elétrica para estabelecimento prestador de serviço de comunicação
+    Cfop5255 = 5255,
+    /// Venda de energia elétrica para estabelecimento de produtor rural
+    Cfop5256 = 5256,
+    /// Venda de energia elétrica para consumo por demanda contratada
+    Cfop5257 = 5257,
+    /// Venda de energia elétrica a não contribuinte
+    Cfop5258 = 5258,
+    /// Prestação de serviço de comunicação para execução de serviço da mesma natureza
+    Cfop5301 = 5301,
+    /// Prestação de serviço de comunicação a estabelecimento industrial
+    Cfop5302 = 5302,
+    /// Prestação de serviço de comunicação a estabelecimento comercial
+    Cfop5303 = 5303,
+    /// Prestação de serviço de comunicação a estabelecimento de prestador de serviço de transporte
+    Cfop5304 = 5304,
+    /// Prestação de serviço de comunicação a estabelecimento de geradora ou de distribuidora de energia elétrica
+    Cfop5305 = 5305,
+    /// Prestação de serviço de comunicação a estabelecimento de produtor rural
+    Cfop5306 = 5306,
+    /// Prestação de serviço de comunicação a não contribuinte
+    Cfop5307 = 5307,
+    /// Prestação de serviço de transporte para execução de serviço da mesma natureza
+    Cfop5351 = 5351,
+    /// Prestação de serviço de transporte a estabelecimento industrial
+    Cfop5352 = 5352,
+    /// Prestação de serviço de transporte a estabelecimento comercial
+    Cfop5353 = 5353,
+    /// Prestação de serviço de transporte a estabelecimento de prestador de serviço de comunicação
+    Cfop5354 = 5354,
+    /// Prestação de serviço de transporte a estabelecimento de geradora ou de distribuidora de energia elétrica
+    Cfop5355 = 5355,
+    /// Prestação de serviço de transporte a estabelecimento de produtor rural
+    Cfop5356 = 5356,
+    /// Prestação de serviço de transporte a não contribuinte
+    Cfop5357 = 5357,
+    /// Prestação de serviço de transporte a contribuinte ou a não contribuinte quando a mercadoria transportada está dispensada de emissão de nota fiscal
+    Cfop5359 = 5359,
+    /// Prestação de serviço de transporte a contribuinte substituto em relação ao serviço de transporte
+    Cfop5360 = 5360,
+    /// Venda de produção do estabelecimento em operação com produto sujeito ao regime de substituição tributária, na condição de contribuinte substituto
+    Cfop5401 = 5401,
+    /// Venda de produção do estabelecimento de produto sujeito ao regime de substituição tributária, em operação entre contribuintes substitutos do mesmo produto
+    Cfop5402 = 5402,
+    /// Venda de mercadoria adquirida ou recebida de terceiros em operação com mercadoria sujeita ao regime de substituição tributária, na condição de contribuinte substituto
+    Cfop5403 = 5403,
+    /// Venda de mercadoria adquirida ou recebida de terceiros em operação com mercadoria sujeita ao regime de substituição tributária, na condição de contribuinte substituído
+    Cfop5405 = 5405,
+    /// Transferência de produção do estabelecimento em operação com produto sujeito ao regime de substituição tributária
+    Cfop5408 = 5408,
+    /// Transferência de mercadoria adquirida ou recebida de terceiros em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop5409 = 5409,
+    /// Devolução de compra para industrialização ou produção rural em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop5410 = 5410,
+    /// Devolução de compra para comercialização em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop5411 = 5411,
+    /// Devolução de bem do ativo imobilizado, em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop5412 = 5412,
+    /// Devolução de mercadoria destinada ao uso ou consumo, em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop5413 = 5413,
+    /// Remessa de produção do estabelecimento para venda fora do estabelecimento em operação com produto sujeito ao regime de substituição tributária
+    Cfop5414 = 5414,
+    /// Remessa de mercadoria adquirida ou recebida de terceiros para venda fora do estabelecimento, em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop5415 = 5415,
+    /// Remessa de animal e de insumo para estabelecimento produtor
+    Cfop5451 = 5451,
+    /// Remessa de produção do estabelecimento, com fim específico de exportação
+    Cfop5501 = 5501,
+    /// Remessa de mercadoria adquirida ou recebida de terceiros, com fim específico de exportação
+    Cfop5502 = 5502,
+    /// Devolução de mercadoria recebida com fim específico de exportação
+    Cfop5503 = 5503,
+    /// Remessa de mercadoria para formação de lote de exportação, de produtos industrializados ou produzidos pelo próprio estabelecimento
+    Cfop5504 = 5504,
+    /// Remessa de mercadoria, adquirida ou recebida de terceiros, para formação de lote de exportação
+    Cfop5505 = 5505,
+    /// Venda de bem do ativo imobilizado
+    Cfop5551 = 5551,
+    /// Transferência de bem do ativo imobilizado
+    Cfop5552 = 5552,
+    /// Devolução de compra de bem para o ativo imobilizado
+    Cfop5553 = 5553,
+    /// Remessa de bem do ativo imobilizado para uso fora do estabelecimento
+    Cfop5554 = 5554,
+    /// Devolução de bem do ativo imobilizado de terceiro, recebido para uso no estabelecimento
+    Cfop5555 = 5555,
+    /// Devolução de compra de material de uso ou consumo
+    Cfop5556 = 5556,
+    /// Transferência de material de uso ou consumo
+    Cfop5557 = 5557,
+    /// Transferência de crédito de ICMS acumulado
+    Cfop5601 = 5601,
+    /// Transferência de saldo credor de ICMS para outro estabelecimento da mesma empresa, destinado à compensação de saldo devedor de ICMS
+    Cfop5602 = 5602,
+    /// Ressarcimento de ICMS retido por substituição tributária
+    Cfop5603 = 5603,
+    /// Transferência de saldo devedor de ICMS de outro estabelecimento da mesma empresa
+    Cfop5605 = 5605,
+    /// Utilização de saldo credor de ICMS para extinção por compensação de débitos fiscais
+    Cfop5606 = 5606,
+    /// Venda de combustível ou lubrificante de produção do estabelecimento destinado à industrialização subseqüente
+    Cfop5651 = 5651,
+    /// Venda de combustível ou lubrificante de produção do estabelecimento destinado à comercialização
+    Cfop5652 = 5652,
+    /// Venda de combustível ou lubrificante de produção do estabelecimento destinado a consumidor ou usuário final
+    Cfop5653 = 5653,
+    /// Venda de combustível ou lubrificante adquirido ou recebido de terceiros destinado à industrialização subseqüente
+    Cfop5654 = 5654,
+    /// Venda de combustível ou lubrificante adquirido ou recebido de terceiros destinado à comercialização
+    Cfop5655 = 5655,
+    /// Venda de combustível ou lubrificante adquirido ou recebido de terceiros destinado a consumidor ou usuário final
+    Cfop5656 = 5656,
+    /// Remessa de combustível ou lubrificante adquirido ou recebido de terceiros para venda fora do estabelecimento
+    Cfop5657 = 5657,
+    /// Transferência de combustível ou lubrificante de produção do estabelecimento
+    Cfop5658 = 5658,
+    /// Transferência de combustível ou lubrificante adquirido ou recebido de terceiro
+    Cfop5659 = 5659,
+    /// Devolução de compra de combustível ou lubrificante adquirido para industrialização subseqüente
+    Cfop5660 = 5660,
+    /// Devolução de compra de combustível ou lubrificante adquirido para comercialização
+    Cfop5661 = 5661,
+    /// Devolução de compra de combustível ou lubrificante adquirido por consumidor ou usuário final
+    Cfop5662 = 5662,
+    /// Remessa para armazenagem de combustível ou lubrificante
+    Cfop5663 = 5663,
+    /// Retorno de combustível ou lubrificante recebido para armazenagem
+    Cfop5664 = 5664,
+    /// Retorno simbólico de combustível ou lubrificante recebido para armazenagem
+    Cfop5665 = 5665,
+    /// Remessa por conta e ordem de terceiros de combustível ou lubrificante recebido para armazenagem
+    Cfop5666 = 5666,
+    /// Venda de combustível ou lubrificante a consumidor ou usuário final estabelecido em outra unidade da Federação
+    Cfop5667 = 5667,
+    /// Remessa para industrialização por encomenda
+    Cfop5901 = 5901,
+    /// Retorno de mercadoria utilizada na industrialização por encomenda
+    Cfop5902 = 5902,
+    /// Retorno de mercadoria recebida para industrialização e não aplicada no referido processo
+    Cfop5903 = 5903,
+    /// Remessa para venda fora do estabelecimento
+    Cfop5904 = 5904,
+    /// Remessa para depósito fechado ou armazém geral
+    Cfop5905 = 5905,
+    /// Retorno de mercadoria depositada em depósito fechado ou armazém geral
+    Cfop5906 = 5906,
+    /// Retorno simbólico de mercadoria depositada em depósito fechado ou armazém geral
+    Cfop5907 = 5907,
+    /// Remessa de bem por conta de contrato de comodato
+    Cfop5908 = 5908,
+    /// Retorno de bem recebido por conta de contrato de comodato
+    Cfop5909 = 5909,
+    /// Remessa em bonificação, doação ou brinde
+    Cfop5910 = 5910,
+    /// Remessa de amostra grátis
+    Cfop5911 = 5911,
+    /// Remessa de mercadoria ou bem para demonstração, mostruário ou treinamento
+    Cfop5912 = 5912,
+    /// Retorno de mercadoria ou bem recebido para demonstração ou mostruário
+    Cfop5913 = 5913,
+    /// Remessa de mercadoria ou bem para exposição ou feira
+    Cfop5914 = 5914,
+    /// Remessa de mercadoria ou bem para conserto ou reparo
+    Cfop5915 = 5915,
+    /// Retorno de mercadoria ou bem recebido para conserto ou reparo
+    Cfop5916 = 5916,
+    /// Remessa de mercadoria em consignação mercantil ou industrial
+    Cfop5917 = 5917,
+    /// Devolução de mercadoria recebida em consignação mercantil ou industrial
+    Cfop5918 = 5918,
+    /// Devolução simbólica de mercadoria vendida ou utilizada em processo industrial, recebida anteriormente em consignação mercantil ou industrial
+    Cfop5919 = 5919,
+    /// Remessa de vasilhame ou sacaria
+    Cfop5920 = 5920,
+    /// Devolução de vasilhame ou sacaria
+    Cfop5921 = 5921,
+    /// Lançamento efetuado a título de simples faturamento decorrente de venda para entrega futura
+    Cfop5922 = 5922,
+    /// Remessa de mercadoria por conta e ordem de terceiros, em venda à ordem ou em operações com armazém geral ou depósito fechado
+    Cfop5923 = 5923,
+    /// Remessa para industrialização por conta e ordem do adquirente da mercadoria, quando esta não transitar pelo estabelecimento do adquirente
+    Cfop5924 = 5924,
+    /// Retorno de mercadoria recebida para industrialização por conta e ordem do adquirente da mercadoria, quando aquela não transitar pelo estabelecimento do adquirente
+    Cfop5925 = 5925,
+    /// Lançamento efetuado a título de reclassificação de mercadoria decorrente de formação de kit ou de sua desagregação
+    Cfop5926 = 5926,
+    /// Lançamento efetuado a título de baixa de estoque decorrente de perda, roubo ou deterioração
+    Cfop5927 = 5927,
+    /// Lançamento efetuado a título de baixa de estoque decorrente do encerramento da atividade da empresa
+    Cfop5928 = 5928,
+    /// Lançamento efetuado em decorrência de emissão de documento fiscal relativo a operação ou prestação também registrada em equipamento Emissor de Cupom Fiscal - ECF
+    Cfop5929 = 5929,
+    /// Lançamento efetuado em decorrência da responsabilidade de retenção do imposto por substituição tributária, atribuída ao remetente ou alienante da mercadoria, pelo serviço de transporte realizado por transportador autônomo ou por transportador não inscrito na unidade da Federação onde iniciado o serviço
+    Cfop5931 = 5931,
+    /// Prestação de serviço de transporte iniciada em unidade da Federação diversa daquela onde inscrito o prestador
+    Cfop5932 = 5932,
+    /// Prestação de serviço tributado pelo ISSQN
+    Cfop5933 = 5933,
+    /// Remessa simbólica de mercadoria depositada em armazém geral ou depósito fechado
+    Cfop5934 = 5934,
+    /// Outra saída de mercadoria ou prestação de serviço não especificado
+    Cfop5949 = 5949,
+    /// Venda de produção do estabelecimento
+    Cfop6101 = 6101,
+    /// Venda de mercadoria adquirida ou recebida de terceiros
+    Cfop6102 = 6102,
+    /// Venda de produção do estabelecimento, efetuada fora do estabelecimento
+    Cfop6103 = 6103,
+    /// Venda de mercadoria adquirida ou recebida de terceiros, efetuada fora do estabelecimento
+    Cfop6104 = 6104,
+    /// Venda de produção do estabelecimento que não deva por ele transitar
+    Cfop6105 = 6105,
+    /// Venda de mercadoria adquirida ou recebida de terceiros, que não deva por ele transitar
+    Cfop6106 = 6106,
+    /// Venda de produção do estabelecimento, destinada a não contribuinte
+    Cfop6107 = 6107,
+    /// Venda de mercadoria adquirida ou recebida de terceiros, destinada a não contribuinte
+    Cfop6108 = 6108,
+    /// Venda de produção do estabelecimento, destinada à Zona Franca de Manaus ou Áreas de Livre Comércio
+    Cfop6109 = 6109,
+    /// Venda de mercadoria adquirida ou recebida de terceiros, destinada à Zona Franca de Manaus ou Áreas de Livre Comércio
+    Cfop6110 = 6110,
+    /// Venda de produção do estabelecimento remetida anteriormente em consignação industrial
+    Cfop6111 = 6111,
+    /// Venda de mercadoria adquirida ou recebida de terceiros remetida anteriormente em consignação industrial
+    Cfop6112 = 6112,
+    /// Venda de produção do estabelecimento remetida anteriormente em consignação mercantil
+    Cfop6113 = 6113,
+    /// Venda de mercadoria adquirida ou recebida de terceiros remetida anteriormente em consignação mercantil
+    Cfop6114 = 6114,
+    /// Venda de mercadoria adquirida ou recebida de terceiros, recebida anteriormente em consignação mercantil
+    Cfop6115 = 6115,
+    /// Venda de produção do estabelecimento originada de encomenda para entrega futura
+    Cfop6116 = 6116,
+    /// Venda de mercadoria adquirida ou recebida de terceiros, originada de encomenda para entrega futura
+    Cfop6117 = 6117,
+    /// Venda de produção do estabelecimento entregue ao destinatário por conta e ordem do adquirente originário, em venda à ordem
+    Cfop6118 = 6118,
+    /// Venda de mercadoria adquirida ou recebida de terceiros entregue ao destinatário por conta e ordem do adquirente originário, em venda à ordem
+    Cfop6119 = 6119,
+    /// Venda de mercadoria adquirida ou recebida de terceiros entregue ao destinatário pelo vendedor remetente, em venda à ordem
+    Cfop6120 = 6120,
+    /// Venda de produção do estabelecimento remetida para industrialização, por conta e ordem do adquirente, sem transitar pelo estabelecimento do adquirente
+    Cfop6122 = 6122,
+    /// Venda de mercadoria adquirida ou recebida de terceiros remetida para industrialização, por conta e ordem do adquirente, sem transitar pelo estabelecimento do adquirente
+    Cfop6123 = 6123,
+    /// Industrialização efetuada para outra empresa
+    Cfop6124 = 6124,
+    /// Industrialização efetuada para outra empresa quando a mercadoria recebida para utilização no processo de industrialização não transitar pelo estabelecimento adquirente da mercadoria
+    Cfop6125 = 6125,
+    /// Venda de insumo importado e de mercadoria industrializada sob o Regime Aduaneiro Especial de Entreposto Industrial sob Controle Informatizado do Sistema Integrado de Comércio Exterior (Recof-Sped)
+    Cfop6129 = 6129,
+    /// Remessa de produção do estabelecimento, com previsão de posterior ajuste ou fixação de preço, de ato cooperativo
+    Cfop6131 = 6131,
+    /// Fixação de preço de produção do estabelecimento, inclusive quando remetidas anteriormente com previsão de posterior ajuste ou fixação de preço, de ato cooperativo
+    Cfop6132 = 6132,
+    /// Transferência de produção do estabelecimento
+    Cfop6151 = 6151,
+    /// Transferência de mercadoria adquirida ou recebida de terceiros
+    Cfop6152 = 6152,
+    /// Transferência de energia elétrica
+    Cfop6153 = 6153,
+    /// Transferência de produção do estabelecimento, que não deva por ele transitar
+    Cfop6155 = 6155,
+    /// Transferência de mercadoria adquirida ou recebida de terceiros, que não deva por ele transitar
+    Cfop6156 = 6156,
+    /// Fornecimento de produção do estabelecimento de ato cooperativo
+    Cfop6159 = 6159,
+    /// Fornecimento de mercadoria adquirida ou recebida de terceiros de ato cooperativo
+    Cfop6160 = 6160,
+    /// Devolução de compra para industrialização ou produção rural
+    Cfop6201 = 6201,
+    /// Devolução de compra para comercialização
+    Cfop6202 = 6202,
+    /// Anulação de valor relativo a aquisição de serviço de comunicação
+    Cfop6205 = 6205,
+    /// Anulação de valor relativo a aquisição de serviço de transporte
+    Cfop6206 = 6206,
+    /// Anulação de valor relativo à compra de energia elétrica
+    Cfop6207 = 6207,
+    /// Devolução de mercadoria recebida em transferência para industrialização ou produção rural
+    Cfop6208 = 6208,
+    /// Devolução de mercadoria recebida em transferência para comercialização
+    Cfop6209 = 6209,
+    /// Devolução de compra para utilização na prestação de serviço
+    Cfop6210 = 6210,
+    /// Devolução de entrada de mercadoria com previsão de posterior ajuste ou fixação de preço, em ato cooperativo
+    Cfop6213 = 6213,
+    /// Devolução referente a fixação de preço de produção do estabelecimento produtor, de ato cooperativo, para comercialização
+    Cfop6214 = 6214,
+    /// Devolução referente a fixação de preço de produção do estabelecimento produtor, de ato cooperativo, para industrialização
+    Cfop6215 = 6215,
+    /// Venda de energia elétrica para distribuição ou comercialização
+    Cfop6251 = 6251,
+    /// Venda de energia elétrica para estabelecimento industrial
+    Cfop6252 = 6252,
+    /// Venda de energia elétrica para estabelecimento comercial
+    Cfop6253 = 6253,
+    /// Venda de energia elétrica para estabelecimento prestador de serviço de transporte
+    Cfop6254 = 6254,
+    /// Venda de energia elétrica para estabelecimento prestador de serviço de comunicação
+    Cfop6255 = 6255,
+    /// Venda de energia elétrica para estabelecimento de produtor rural
+    Cfop6256 = 6256,
+    /// Venda de energia elétrica para consumo por demanda contratada
+    Cfop6257 = 6257,
+    /// Venda de energia elétrica a não contribuinte
+    Cfop6258 = 6258,
+    /// Prestação de serviço de comunicação para execução de serviço da mesma natureza
+    Cfop6301 = 6301,
+    /// Prestação de serviço de comunicação a estabelecimento industrial
+    Cfop6302 = 6302,
+    /// Prestação de serviço de comunicação a estabelecimento comercial
+    Cfop6303 = 6303,
+    /// Prestação de serviço de comunicação a estabelecimento de prestador de serviço de transporte
+    Cfop6304 = 6304,
+    /// Prestação de serviço de comunicação a estabelecimento de geradora ou de distribuidora de energia elétrica
+    Cfop6305 = 6305,
+    /// Prestação de serviço de comunicação a estabelecimento de produtor rural
+    Cfop6306 = 6306,
+    /// Prestação de serviço de comunicação a não contribuinte
+    Cfop6307 = 6307,
+    /// Prestação de serviço de transporte para execução de serviço da mesma natureza
+    Cfop6351 = 6351,
+    /// Prestação de serviço de transporte a estabelecimento industrial
+    Cfop6352 = 6352,
+    /// Prestação de serviço de transporte a estabelecimento comercial
+    Cfop6353 = 6353,
+    /// Prestação de serviço de transporte a estabelecimento de prestador de serviço de comunicação
+    Cfop6354 = 6354,
+    /// Prestação de serviço de transporte a estabelecimento de geradora ou de distribuidora de energia elétrica
+    Cfop6355 = 6355,
+    /// Prestação de serviço de transporte a estabelecimento de produtor rural
+    Cfop6356 = 6356,
+    /// Prestação de serviço de transporte a não contribuinte
+    Cfop6357 = 6357,
+    /// Prestação de serviço de transporte a contribuinte ou a não contribuinte quando a mercadoria transportada está dispensada de emissão de nota fiscal
+    Cfop6359 = 6359,
+    /// Prestação de serviço de transporte a contribuinte substituto em relação ao serviço de transporte
+    Cfop6360 = 6360,
+    /// Venda de produção do estabelecimento em operação com produto sujeito ao regime de substituição tributária, na condição de contribuinte substituto
+    Cfop6401 = 6401,
+    /// Venda de produção do estabelecimento de produto sujeito ao regime de substituição tributária, em operação entre contribuintes substitutos do mesmo produto
+    Cfop6402 = 6402,
+    /// Venda de mercadoria adquirida ou recebida de terceiros em operação com mercadoria sujeita ao regime de substituição tributária, na condição de contribuinte substituto
+    Cfop6403 = 6403,
+    /// Venda de mercadoria sujeita ao regime de substituição tributária, cujo imposto já tenha sido retido anteriormente
+    Cfop6404 = 6404,
+    /// Transferência de produção do estabelecimento em operação com produto sujeito ao regime de substituição tributária
+    Cfop6408 = 6408,
+    /// Transferência de mercadoria adquirida ou recebida de terceiros em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop6409 = 6409,
+    /// Devolução de compra para industrialização ou produção rural em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop6410 = 6410,
+    /// Devolução de compra para comercialização em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop6411 = 6411,
+    /// Devolução de bem do ativo imobilizado, em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop6412 = 6412,
+    /// Devolução de mercadoria destinada ao uso ou consumo, em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop6413 = 6413,
+    /// Remessa de produção do estabelecimento para venda fora do estabelecimento em operação com produto sujeito ao regime de substituição tributária
+    Cfop6414 = 6414,
+    /// Remessa de mercadoria adquirida ou recebida de terceiros para venda fora do estabelecimento, em operação com mercadoria sujeita ao regime de substituição tributária
+    Cfop6415 = 6415,
+    /// Remessa de produção do estabelecimento, com fim específico de exportação
+    Cfop6501 = 6501,
+    /// Remessa de mercadoria adquirida ou recebida de terceiros, com fim específico de exportação
+    Cfop6502 = 6502,
+    /// Devolução de mercadoria recebida com fim específico de exportação
+    Cfop6503 = 6503,
+    /// Remessa de mercadoria para formação de lote de exportação, de produtos industrializados ou produzidos pelo próprio estabelecimento
+    Cfop6504 = 6504,
+    /// Remessa de mercadoria, adquirida ou recebida de terceiros, para formação de lote de exportação
+    Cfop6505 = 6505,
+    /// Venda de bem do ativo imobilizado
+    Cfop6551 = 6551,
+    /// Transferência de bem do ativo imobilizado
+    Cfop6552 = 6552,
+    /// Devolução de compra de bem para o ativo imobilizado
+    Cfop6553 = 6553,
+    /// Remessa de bem do ativo imobilizado para uso fora do estabelecimento
+    Cfop6554 = 6554,
+    /// Devolução de bem do ativo imobilizado de terceiro, recebido para uso no estabelecimento
+    Cfop6555 = 6555,
+    /// Devolução de compra de material de uso ou consumo
+    Cfop6556 = 6556,
+    /// Transferência de material de uso ou consumo
+    Cfop6557 = 6557,
+    /// Ressarcimento de ICMS retido por substituição tributária
+    Cfop6603 = 6603,
+    /// Venda de combustível ou lubrificante de produção do estabelecimento destinado à industrialização subseqüente
+    Cfop6651 = 6651,
+    /// Venda de combustível ou lubrificante de produção do estabelecimento destinado à comercialização
+    Cfop6652 = 6652,
+    /// Venda de combustível ou lubrificante de produção do estabelecimento destinado a consumidor ou usuário final
+    Cfop6653 = 6653,
+    /// Venda de combustível ou lubrificante adquirido ou recebido de terceiros destinado à industrialização subseqüente
+    Cfop6654 = 6654,
+    /// Venda de combustível ou lubrificante adquirido ou recebido de terceiros destinado à comercialização
+    Cfop6655 = 6655,
+    /// Venda de combustível ou lubrificante adquirido ou recebido de terceiros destinado a consumidor ou usuário final
+    Cfop6656 = 6656,
+    /// Remessa de combustível ou lubrificante adquirido ou recebido de terceiros para venda fora do estabelecimento
+    Cfop6657 = 6657,
+    /// Transferência de combustível ou lubrificante de produção do estabelecimento
+    Cfop6658 = 6658,
+    /// Transferência de combustível ou lubrificante adquirido ou recebido de terceiro
+    Cfop6659 = 6659,
+    /// Devolução de compra de combustível ou lubrificante adquirido para industrialização subseqüente
+    Cfop6660 = 6660,
+    /// Devolução de compra de combustível ou lubrificante adquirido para comercialização
+    Cfop6661 = 6661,
+    /// Devolução de compra de combustível ou lubrificante adquirido por consumidor ou usuário final
+    Cfop6662 = 6662,
+    /// Remessa para armazenagem de combustível ou lubrificante
+    Cfop6663 = 6663,
+    /// Retorno de combustível ou lubrificante recebido para armazenagem
+    Cfop6664 = 6664,
+    /// Retorno simbólico de combustível ou lubrificante recebido para armazenagem
+    Cfop6665 = 6665,
+    /// Remessa por conta e ordem de terceiros de combustível ou lubrificante recebido para armazenagem
+    Cfop6666 = 6666,
+    /// Venda de combustível ou lubrificante a consumidor ou usuário final estabelecido em outra unidade da Federação
+    Cfop6667 = 6667,
+    /// Remessa para industrialização por encomenda
+    Cfop6901 = 6901,
+    /// Retorno de mercadoria utilizada na industrialização por encomenda
+    Cfop6902 = 6902,
+    /// Retorno de mercadoria recebida para industrialização e não aplicada no referido processo
+    Cfop6903 = 6903,
+    /// Remessa para venda fora do estabelecimento
+    Cfop6904 = 6904,
+    /// Remessa para depósito fechado ou armazém geral
+    Cfop6905 = 6905,
+    /// Retorno de mercadoria depositada em depósito fechado ou armazém geral
+    Cfop6906 = 6906,
+    /// Retorno simbólico de mercadoria depositada em depósito fechado ou armazém geral
+    Cfop6907 = 6907,
+    /// Remessa de bem por conta de contrato de comodato
+    Cfop6908 = 6908,
+    /// Retorno de bem recebido por conta de contrato de comodato
+    Cfop6909 = 6909,
+    /// Remessa em bonificação, doação ou brinde
+    Cfop6910 = 6910,
+    /// Remessa de amostra grátis
+    Cfop6911 = 6911,
+    /// Remessa de mercadoria ou bem para demonstração, mostruário ou treinamento
+    Cfop6912 = 6912,
+    /// Retorno de mercadoria ou bem recebido para demonstração ou mostruário
+    Cfop6913 = 6913,
+    /// Remessa de mercadoria ou bem para exposição ou feira
+    Cfop6914 = 6914,
+    /// Remessa de mercadoria ou bem para conserto ou reparo
+    Cfop6915 = 6915,
+    /// Retorno de mercadoria ou bem recebido para conserto ou reparo
+    Cfop6916 = 6916,
+    /// Remessa de mercadoria em consignação mercantil ou industrial
+    Cfop6917 = 6917,
+    /// Devolução de mercadoria recebida em consignação mercantil ou industrial
+    Cfop6918 = 6918,
+    /// Devolução simbólica de mercadoria vendida ou utilizada em processo industrial, recebida anteriormente em consignação mercantil ou industrial
+    Cfop6919 = 6919,
+    /// Remessa de vasilhame ou sacaria
+    Cfop6920 = 6920,
+    /// Devolução de vasilhame ou sacaria
+    Cfop6921 = 6921,
+    /// Lançamento efetuado a título de simples faturamento decorrente de venda para entrega futura
+    Cfop6922 = 6922,
+    /// Remessa de mercadoria por conta e ordem de terceiros, em venda à ordem ou em operações com armazém geral ou depósito fechado
+    Cfop6923 = 6923,
+    /// Remessa para industrialização por conta e ordem do adquirente da mercadoria, quando esta não transitar pelo estabelecimento do adquirente
+    Cfop6924 = 6924,
+    /// Retorno de mercadoria recebida para industrialização por conta e ordem do adquirente da mercadoria, quando aquela não transitar pelo estabelecimento do adquirente
+    Cfop6925 = 6925,
+    /// Lançamento efetuado em decorrência de emissão de documento fiscal relativo a operação ou prestação também registrada em equipamento Emissor de Cupom Fiscal - ECF
+    Cfop6929 = 6929,
+    /// Lançamento efetuado em decorrência da responsabilidade de retenção do imposto por substituição tributária, atribuída ao remetente ou alienante da mercadoria, pelo serviço de transporte realizado por transportador autônomo ou por transportador não inscrito na unidade da Federação onde iniciado o serviço
+    Cfop6931 = 6931,
+    /// Prestação de serviço de transporte iniciada em unidade da Federação diversa daquela onde inscrito o prestador
+    Cfop6932 = 6932,
+    /// Prestação de serviço tributado pelo ISSQN
+    Cfop6933 = 6933,
+    /// Remessa simbólica de mercadoria depositada em armazém geral ou depósito fechado
+    Cfop6934 = 6934,
+    /// Outra saída de mercadoria ou prestação de serviço não especificado
+    Cfop6949 = 6949,
+    /// Venda de produção do estabelecimento
+    Cfop7101 = 7101,
+    /// Venda de mercadoria adquirida ou recebida de terceiros
+    Cfop7102 = 7102,
+    /// Venda de produção do estabelecimento que não deva por ele transitar
+    Cfop7105 = 7105,
+    /// Venda de mercadoria adquirida ou recebida de terceiros, que não deva por ele transitar
+    Cfop7106 = 7106,
+    /// Venda de produção do estabelecimento sob o regime de drawback
+    Cfop7127 = 7127,
+    /// Venda de produção do estabelecimento ao mercado externo de mercadoria industrializada sob o Regime Aduaneiro Especial de Entreposto Industrial sob Controle Informatizado do Sistema Integrado de Comércio Exterior (Recof-Sped)
+    Cfop7129 = 7129,
+    /// Devolução de compra para industrialização ou produção rural
+    Cfop7201 = 7201,
+    /// Devolução de compra para comercialização
+    Cfop7202 = 7202,
+    /// Anulação de valor relativo a aquisição de serviço de comunicação
+    Cfop7205 = 7205,
+    /// Anulação de valor relativo a aquisição de serviço de transporte
+    Cfop7206 = 7206,
+    /// Anulação de valor relativo à compra de energia elétrica
+    Cfop7207 = 7207,
+    /// Devolução de compra para utilização na prestação de serviço
+    Cfop7210 = 7210,
+    /// Devolução de compras para industrialização sob o regime de drawback
+    Cfop7211 = 7211,
+    /// Devolução de compras para industrialização sob o Regime Aduaneiro Especial de Entreposto Industrial sob Controle Informatizado do Sistema Integrado de Comércio Exterior (Recof-Sped)
+    Cfop7212 = 7212,
+    /// Venda de energia elétrica para distribuição ou comercialização
+    Cfop7251 = 7251,
+    /// Prestação de serviço de comunicação para execução de serviço da mesma natureza
+    Cfop7301 = 7301,
+    /// Prestação de serviço de transporte
+    Cfop7358 = 7358,
+    /// Exportação de mercadorias recebidas com fim específico de exportação
+    Cfop7501 = 7501,
+    /// Exportação de mercadoria que foi objeto de formação de lote de exportação
+    Cfop7504 = 7504,
+    /// Venda de bem do ativo imobilizado
+    Cfop7551 = 7551,
+    /// Devolução de compra de bem para o ativo imobilizado
+    Cfop7553 = 7553,
+    /// Devolução de compra de material de uso ou consumo
+    Cfop7556 = 7556,
+    /// Venda de combustível ou lubrificante de produção do estabelecimento destinado à industrialização subseqüente
+    Cfop7651 = 7651,
+    /// Venda de combustível ou lubrificante adquirido ou recebido de terceiros destinado à industrialização subseqüente
+    Cfop7654 = 7654,
+    /// Venda de combustível ou lubrificante a consumidor ou usuário final estabelecido em outra unidade da Federação
+    Cfop7667 = 7667,
+    /// Lançamento efetuado a título de devolução de bem cuja entrada tenha ocorrido sob amparo de regime especial aduaneiro de admissão temporária
+    Cfop7930 = 7930,
+    /// Outra saída de mercadoria ou prestação de serviço não especificado
+    Cfop7949 = 7949,
+}
+
+impl Cfop {
+    /// Todos os CFOPs, em ordem crescente
+    pub const TODOS: [Cfop; 590] = [
+        Cfop::Cfop1101,
+        Cfop::Cfop1102,
+        Cfop::Cfop1111,
+        Cfop::Cfop1113,
+        Cfop::Cfop1116,
+        Cfop::Cfop1117,
+        Cfop::Cfop1118,
+        Cfop::Cfop1120,
+        Cfop::Cfop1121,
+        Cfop::Cfop1122,
+        Cfop::Cfop1124,
+        Cfop::Cfop1125,
+        Cfop::Cfop1126,
+        Cfop::Cfop1128,
+        Cfop::Cfop1131,
+        Cfop::Cfop1132,
+        Cfop::Cfop1135,
+        Cfop::Cfop1151,
+        Cfop::Cfop1152,
+        Cfop::Cfop1153,
+        Cfop::Cfop1154,
+        Cfop::Cfop1159,
+        Cfop::Cfop1201,
+        Cfop::Cfop1202,
+        Cfop::Cfop1203,
+        Cfop::Cfop1204,
+        Cfop::Cfop1205,
+        Cfop::Cfop1206,
+        Cfop::Cfop1207,
+        Cfop::Cfop1208,
+        Cfop::Cfop1209,
+        Cfop::Cfop1212,
+        Cfop::Cfop1213,
+        Cfop::Cfop1214,
+        Cfop::Cfop1215,
+        Cfop::Cfop1251,
+        Cfop::Cfop1252,
+        Cfop::Cfop1253,
+        Cfop::Cfop1254,
+        Cfop::Cfop1255,
+        Cfop::Cfop1256,
+        Cfop::Cfop1257,
+        Cfop::Cfop1301,
+        Cfop::Cfop1302,
+        Cfop::Cfop1303,
+        Cfop::Cfop1304,
+        Cfop::Cfop1305,
+        Cfop::Cfop1306,
+        Cfop::Cfop1351,
+        Cfop::Cfop1352,
+        Cfop::Cfop1353,
+        Cfop::Cfop1354,
+        Cfop::Cfop1355,
+        Cfop::Cfop1356,
+        Cfop::Cfop1360,
+        Cfop::Cfop1401,
+        Cfop::Cfop1403,
+        Cfop::Cfop1406,
+        Cfop::Cfop1407,
+        Cfop::Cfop1408,
+        Cfop::Cfop1409,
+        Cfop::Cfop1410,
+        Cfop::Cfop1411,
+        Cfop::Cfop1414,
+        Cfop::Cfop1415,
+        Cfop::Cfop1451,
+        Cfop::Cfop1452,
+        Cfop::Cfop1501,
+        Cfop::Cfop1503,
+        Cfop::Cfop1504,
+        Cfop::Cfop1505,
+        Cfop::Cfop1506,
+        Cfop::Cfop1551,
+        Cfop::Cfop1552,
+        Cfop::Cfop1553,
+        Cfop::Cfop1554,
+        Cfop::Cfop1555,
+        Cfop::Cfop1556,
+        Cfop::Cfop1557,
+        Cfop::Cfop1601,
+        Cfop::Cfop1602,
+        Cfop::Cfop1603,
+        Cfop::Cfop1604,
+        Cfop::Cfop1605,
+        Cfop::Cfop1651,
+        Cfop::Cfop1652,
+        Cfop::Cfop1653,
+        Cfop::Cfop1658,
+        Cfop::Cfop1659,
+        Cfop::Cfop1660,
+        Cfop::Cfop1661,
+        Cfop::Cfop1662,
+        Cfop::Cfop1663,
+        Cfop::Cfop1664,
+        Cfop::Cfop1901,
+        Cfop::Cfop1902,
+        Cfop::Cfop1903,
+        Cfop::Cfop1904,
+        Cfop::Cfop1905,
+        Cfop::Cfop1906,
+        Cfop::Cfop1907,
+        Cfop::Cfop1908,
+        Cfop::Cfop1909,
+        Cfop::Cfop1910,
+        Cfop::Cfop1911,
+        Cfop::Cfop1912,
+        Cfop::Cfop1913,
+        Cfop::Cfop1914,
+        Cfop::Cfop1915,
+        Cfop::Cfop1916,
+        Cfop::Cfop1917,
+        Cfop::Cfop1918,
+        Cfop::Cfop1919,
+        Cfop::Cfop1920,
+        Cfop::Cfop1921,
+        Cfop::Cfop1922,
+        Cfop::Cfop1923,
+        Cfop::Cfop1924,
+        Cfop::Cfop1925,
+        Cfop::Cfop1926,
+        Cfop::Cfop1931,
+        Cfop::Cfop1932,
+        Cfop::Cfop1933,
+        Cfop::Cfop1934,
+        Cfop::Cfop1949,
+        Cfop::Cfop2101,
+        Cfop::Cfop2102,
+        Cfop::Cfop2111,
+        Cfop::Cfop2113,
+        Cfop::Cfop2116,
+        Cfop::Cfop2117,
+        Cfop::Cfop2118,
+        Cfop::Cfop2120,
+        Cfop::Cfop2121,
+        Cfop::Cfop2122,
+        Cfop::Cfop2124,
+        Cfop::Cfop2125,
+        Cfop::Cfop2126,
+        Cfop::Cfop2128,
+        Cfop::Cfop2131,
+        Cfop::Cfop2132,
+        Cfop::Cfop2135,
+        Cfop::Cfop2151,
+        Cfop::Cfop2152,
+        Cfop::Cfop2153,
+        Cfop::Cfop2154,
+        Cfop::Cfop2159,
+        Cfop::Cfop2201,
+        Cfop::Cfop2202,
+        Cfop::Cfop2203,
+        Cfop::Cfop2204,
+        Cfop::Cfop2205,
+        Cfop::Cfop2206,
+        Cfop::Cfop2207,
+        Cfop::Cfop2208,
+        Cfop::Cfop2209,
+        Cfop::Cfop2212,
+        Cfop::Cfop2213,
+        Cfop::Cfop2214,
+        Cfop::Cfop2215,
+        Cfop::Cfop2251,
+        Cfop::Cfop2252,
+        Cfop::Cfop2253,
+        Cfop::Cfop2254,
+        Cfop::Cfop2255,
+        Cfop::Cfop2256,
+        Cfop::Cfop2257,
+        Cfop::Cfop2301,
+        Cfop::Cfop2302,
+        Cfop::Cfop2303,
+        Cfop::Cfop2304,
+        Cfop::Cfop2305,
+        Cfop::Cfop2306,
+        Cfop::Cfop2351,
+        Cfop::Cfop2352,
+        Cfop::Cfop2353,
+        Cfop::Cfop2354,
+        Cfop::Cfop2355,
+        Cfop::Cfop2356,
+        Cfop::Cfop2360,
+        Cfop::Cfop2401,
+        Cfop::Cfop2403,
+        Cfop::Cfop2406,
+        Cfop::Cfop2407,
+        Cfop::Cfop2408,
+        Cfop::Cfop2409,
+        Cfop::Cfop2410,
+        Cfop::Cfop2411,
+        Cfop::Cfop2414,
+        Cfop::Cfop2415,
+        Cfop::Cfop2501,
+        Cfop::Cfop2503,
+        Cfop::Cfop2504,
+        Cfop::Cfop2505,
+        Cfop::Cfop2506,
+        Cfop::Cfop2551,
+        Cfop::Cfop2552,
+        Cfop::Cfop2553,
+        Cfop::Cfop2554,
+        Cfop::Cfop2555,
+        Cfop::Cfop2556,
+        Cfop::Cfop2557,
+        Cfop::Cfop2603,
+        Cfop::Cfop2651,
+        Cfop::Cfop2652,
+        Cfop::Cfop2653,
+        Cfop::Cfop2658,
+        Cfop::Cfop2659,
+        Cfop::Cfop2660,
+        Cfop::Cfop2661,
+        Cfop::Cfop2662,
+        Cfop::Cfop2663,
+        Cfop::Cfop2664,
+        Cfop::Cfop2901,
+        Cfop::Cfop2902,
+        Cfop::Cfop2903,
+        Cfop::Cfop2904,
+        Cfop::Cfop2905,
+        Cfop::Cfop2906,
+        Cfop::Cfop2907,
+        Cfop::Cfop2908,
+        Cfop::Cfop2909,
+        Cfop::Cfop2910,
+        Cfop::Cfop2911,
+        Cfop::Cfop2912,
+        Cfop::Cfop2913,
+        Cfop::Cfop2914,
+        Cfop::Cfop2915,
+        Cfop::Cfop2916,
+        Cfop::Cfop2917,
+        Cfop::Cfop2918,
+        Cfop::Cfop2919,
+        Cfop::Cfop2920,
+        Cfop::Cfop2921,
+        Cfop::Cfop2922,
+        Cfop::Cfop2923,
+        Cfop::Cfop2924,
+        Cfop::Cfop2925,
+        Cfop::Cfop2931,
+        Cfop::Cfop2932,
+        Cfop::Cfop2933,
+        Cfop::Cfop2934,
+        Cfop::Cfop2949,
+        Cfop::Cfop3101,
+        Cfop::Cfop3102,
+        Cfop::Cfop3126,
+        Cfop::Cfop3127,
+        Cfop::Cfop3128,
+        Cfop::Cfop3129,
+        Cfop::Cfop3201,
+        Cfop::Cfop3202,
+        Cfop::Cfop3205,
+        Cfop::Cfop3206,
+        Cfop::Cfop3207,
+        Cfop::Cfop3211,
+        Cfop::Cfop3212,
+        Cfop::Cfop3251,
+        Cfop::Cfop3301,
+        Cfop::Cfop3351,
+        Cfop::Cfop3352,
+        Cfop::Cfop3353,
+        Cfop::Cfop3354,
+        Cfop::Cfop3355,
+        Cfop::Cfop3356,
+        Cfop::Cfop3503,
+        Cfop::Cfop3551,
+        Cfop::Cfop3553,
+        Cfop::Cfop3556,
+        Cfop::Cfop3651,
+        Cfop::Cfop3652,
+        Cfop::Cfop3653,
+        Cfop::Cfop3930,
+        Cfop::Cfop3949,
+        Cfop::Cfop5101,
+        Cfop::Cfop5102,
+        Cfop::Cfop5103,
+        Cfop::Cfop5104,
+        Cfop::Cfop5105,
+        Cfop::Cfop5106,
+        Cfop::Cfop5109,
+        Cfop::Cfop5110,
+        Cfop::Cfop5111,
+        Cfop::Cfop5112,
+        Cfop::Cfop5113,
+        Cfop::Cfop5114,
+        Cfop::Cfop5115,
+        Cfop::Cfop5116,
+        Cfop::Cfop5117,
+        Cfop::Cfop5118,
+        Cfop::Cfop5119,
+        Cfop::Cfop5120,
+        Cfop::Cfop5122,
+        Cfop::Cfop5123,
+        Cfop::Cfop5124,
+        Cfop::Cfop5125,
+        Cfop::Cfop5129,
+        Cfop::Cfop5131,
+        Cfop::Cfop5132,
+        Cfop::Cfop5151,
+        Cfop::Cfop5152,
+        Cfop::Cfop5153,
+        Cfop::Cfop5155,
+        Cfop::Cfop5156,
+        Cfop::Cfop5159,
+        Cfop::Cfop5160,
+        Cfop::Cfop5201,
+        Cfop::Cfop5202,
+        Cfop::Cfop5205,
+        Cfop::Cfop5206,
+        Cfop::Cfop5207,
+        Cfop::Cfop5208,
+        Cfop::Cfop5209,
+        Cfop::Cfop5210,
+        Cfop::Cfop5213,
+        Cfop::Cfop5214,
+        Cfop::Cfop5215,
+        Cfop::Cfop5251,
+        Cfop::Cfop5252,
+        Cfop::Cfop5253,
+        Cfop::Cfop5254,
+        Cfop::Cfop5255,
+        Cfop::Cfop5256,
+        Cfop::Cfop5257,
+        Cfop::Cfop5258,
+        Cfop::Cfop5301,
+        Cfop::Cfop5302,
+        Cfop::Cfop5303,
+        Cfop::Cfop5304,
+        Cfop::Cfop5305,
+        Cfop::Cfop5306,
+        Cfop::Cfop5307,
+        Cfop::Cfop5351,
+        Cfop::Cfop5352,
+        Cfop::Cfop5353,
+        Cfop::Cfop5354,
+        Cfop::Cfop5355,
+        Cfop::Cfop5356,
+        Cfop::Cfop5357,
+        Cfop::Cfop5359,
+        Cfop::Cfop5360,
+        Cfop::Cfop5401,
+        Cfop::Cfop5402,
+        Cfop::Cfop5403,
+        Cfop::Cfop5405,
+        Cfop::Cfop5408,
+        Cfop::Cfop5409,
+        Cfop::Cfop5410,
+        Cfop::Cfop5411,
+        Cfop::Cfop5412,
+        Cfop::Cfop5413,
+        Cfop::Cfop5414,
+        Cfop::Cfop5415,
+        Cfop::Cfop5451,
+        Cfop::Cfop5501,
+        Cfop::Cfop5502,
+        Cfop::Cfop5503,
+        Cfop::Cfop5504,
+        Cfop::Cfop5505,
+        Cfop::Cfop5551,
+        Cfop::Cfop5552,
+        Cfop::Cfop5553,
+        Cfop::Cfop5554,
+        Cfop::Cfop5555,
+        Cfop::Cfop5556,
+        Cfop::Cfop5557,
+        Cfop::Cfop5601,
+        Cfop::Cfop5602,
+        Cfop::Cfop5603,
+        Cfop::Cfop5605,
+        Cfop::Cfop5606,
+        Cfop::Cfop5651,
+        Cfop::Cfop5652,
+        Cfop::Cfop5653,
+        Cfop::Cfop5654,
+        Cfop::Cfop5655,
+        Cfop::Cfop5656,
+        Cfop::Cfop5657,
+        Cfop::Cfop5658,
+        Cfop::Cfop5659,
+        Cfop::Cfop5660,
+        Cfop::Cfop5661,
+        Cfop::Cfop5662,
+        Cfop::Cfop5663,
+        Cfop::Cfop5664,
+        Cfop::Cfop5665,
+        Cfop::Cfop5666,
+        Cfop::Cfop5667,
+        Cfop::Cfop5901,
+        Cfop::Cfop5902,
+        Cfop::Cfop5903,
+        Cfop::Cfop5904,
+        Cfop::Cfop5905,
+        Cfop::Cfop5906,
+        Cfop::Cfop5907,
+        Cfop::Cfop5908,
+        Cfop::Cfop5909,
+        Cfop::Cfop5910,
+        Cfop::Cfop5911,
+        Cfop::Cfop5912,
+        Cfop::Cfop5913,
+        Cfop::Cfop5914,
+        Cfop::Cfop5915,
+        Cfop::Cfop5916,
+        Cfop::Cfop5917,
+        Cfop::Cfop5918,
+        Cfop::Cfop5919,
+        Cfop::Cfop5920,
+        Cfop::Cfop5921,
+        Cfop::Cfop5922,
+        Cfop::Cfop5923,
+        Cfop::Cfop5924,
+        Cfop::Cfop5925,
+        Cfop::Cfop5926,
+        Cfop::Cfop5927,
+        Cfop::Cfop5928,
+        Cfop::Cfop5929,
+        Cfop::Cfop5931,
+        Cfop::Cfop5932,
+        Cfop::Cfop5933,
+        Cfop::Cfop5934,
+        Cfop::Cfop5949,
+        Cfop::Cfop6101,
+        Cfop::Cfop6102,
+        Cfop::Cfop6103,
+        Cfop::Cfop6104,
+        Cfop::Cfop6105,
+        Cfop::Cfop6106,
+        Cfop::Cfop6107,
+        Cfop::Cfop6108,
+        Cfop::Cfop6109,
+        Cfop::Cfop6110,
+        Cfop::Cfop6111,
+        Cfop::Cfop6112,
+        Cfop::Cfop6113,
+        Cfop::Cfop6114,
+        Cfop::Cfop6115,
+        Cfop::Cfop6116,
+        Cfop::Cfop6117,
+        Cfop::Cfop6118,
+        Cfop::Cfop6119,
+        Cfop::Cfop6120,
+        Cfop::Cfop6122,
+        Cfop::Cfop6123,
+        Cfop::Cfop6124,
+        Cfop::Cfop6125,
+        Cfop::Cfop6129,
+        Cfop::Cfop6131,
+        Cfop::Cfop6132,
+        Cfop::Cfop6151,
+        Cfop::Cfop6152,
+        Cfop::Cfop6153,
+        Cfop::Cfop6155,
+        Cfop::Cfop6156,
+        Cfop::Cfop6159,
+        Cfop::Cfop6160,
+        Cfop::Cfop6201,
+        Cfop::Cfop6202,
+        Cfop::Cfop6205,
+        Cfop::Cfop6206,
+        Cfop::Cfop6207,
+        Cfop::Cfop6208,
+        Cfop::Cfop6209,
+        Cfop::Cfop6210,
+        Cfop::Cfop6213,
+        Cfop::Cfop6214,
+        Cfop::Cfop6215,
+        Cfop::Cfop6251,
+        Cfop::Cfop6252,
+        Cfop::Cfop6253,
+        Cfop::Cfop6254,
+        Cfop::Cfop6255,
+        Cfop::Cfop6256,
+        Cfop::Cfop6257,
+        Cfop::Cfop6258,
+        Cfop::Cfop6301,
+        Cfop::Cfop6302,
+        Cfop::Cfop6303,
+        Cfop::Cfop6304,
+        Cfop::Cfop6305,
+        Cfop::Cfop6306,
+        Cfop::Cfop6307,
+        Cfop::Cfop6351,
+        Cfop::Cfop6352,
+        Cfop::Cfop6353,
+        Cfop::Cfop6354,
+        Cfop::Cfop6355,
+        Cfop::Cfop6356,
+        Cfop::Cfop6357,
+        Cfop::Cfop6359,
+        Cfop::Cfop6360,
+        Cfop::Cfop6401,
+        Cfop::Cfop6402,
+        Cfop::Cfop6403,
+        Cfop::Cfop6404,
+        Cfop::Cfop6408,
+        Cfop::Cfop6409,
+        Cfop::Cfop6410,
+        Cfop::Cfop6411,
+        Cfop::Cfop6412,
+        Cfop::Cfop6413,
+        Cfop::Cfop6414,
+        Cfop::Cfop6415,
+        Cfop::Cfop6501,
+        Cfop::Cfop6502,
+        Cfop::Cfop6503,
+        Cfop::Cfop6504,
+        Cfop::Cfop6505,
+        Cfop::Cfop6551,
+        Cfop::Cfop6552,
+        Cfop::Cfop6553,
+        Cfop::Cfop6554,
+        Cfop::Cfop6555,
+        Cfop::Cfop6556,
+        Cfop::Cfop6557,
+        Cfop::Cfop6603,
+        Cfop::Cfop6651,
+        Cfop::Cfop6652,
+        Cfop::Cfop6653,
+        Cfop::Cfop6654,
+        Cfop::Cfop6655,
+        Cfop::Cfop6656,
+        Cfop::Cfop6657,
+        Cfop::Cfop6658,
+        Cfop::Cfop6659,
+        Cfop::Cfop6660,
+        Cfop::Cfop6661,
+        Cfop::Cfop6662,
+        Cfop::Cfop6663,
+        Cfop::Cfop6664,
+        Cfop::Cfop6665,
+        Cfop::Cfop6666,
+        Cfop::Cfop6667,
+        Cfop::Cfop6901,
+        Cfop::Cfop6902,
+        Cfop::Cfop6903,
+        Cfop::Cfop6904,
+        Cfop::Cfop6905,
+        Cfop::Cfop6906,
+        Cfop::Cfop6907,
+        Cfop::Cfop6908,
+        Cfop::Cfop6909,
+        Cfop::Cfop6910,
+        Cfop::Cfop6911,
+        Cfop::Cfop6912,
+        Cfop::Cfop6913,
+        Cfop::Cfop6914,
+        Cfop::Cfop6915,
+        Cfop::Cfop6916,
+        Cfop::Cfop6917,
+        Cfop::Cfop6918,
+        Cfop::Cfop6919,
+        Cfop::Cfop6920,
+        Cfop::Cfop6921,
+        Cfop::Cfop6922,
+        Cfop::Cfop6923,
+        Cfop::Cfop6924,
+        Cfop::Cfop6925,
+        Cfop::Cfop6929,
+        Cfop::Cfop6931,
+        Cfop::Cfop6932,
+        Cfop::Cfop6933,
+        Cfop::Cfop6934,
+        Cfop::Cfop6949,
+        Cfop::Cfop7101,
+        Cfop::Cfop7102,
+        Cfop::Cfop7105,
+        Cfop::Cfop7106,
+        Cfop::Cfop7127,
+        Cfop::Cfop7129,
+        Cfop::Cfop7201,
+        Cfop::Cfop7202,
+        Cfop::Cfop7205,
+        Cfop::Cfop7206,
+        Cfop::Cfop7207,
+        Cfop::Cfop7210,
+        Cfop::Cfop7211,
+        Cfop::Cfop7212,
+        Cfop::Cfop7251,
+        Cfop::Cfop7301,
+        Cfop::Cfop7358,
+        Cfop::Cfop7501,
+        Cfop::Cfop7504,
+        Cfop::Cfop7551,
+        Cfop::Cfop7553,
+        Cfop::Cfop7556,
+        Cfop::Cfop7651,
+        Cfop::Cfop7654,
+        Cfop::Cfop7667,
+        Cfop::Cfop7930,
+        Cfop::Cfop7949,
+    ];
+
+    /// Código com 4 dígitos
+    pub fn codigo(&self) -> u16 {
+        *self as u16
+    }
+
+    /// Sentido da operação: entrada ou saída
+    pub fn tipo(&self) -> TipoOperacao {
+        match self.codigo() / 1000 {
+            1..=3 => TipoOperacao::Entrada,
+            _ => TipoOperacao::Saida,
+        }
+    }
+
+    /// Abrangência da operação: interna, interestadual ou com o exterior
+    pub fn destino(&self) -> DestinoOperacao {
+        match self.codigo() / 1000 {
+            1 | 5 => DestinoOperacao::Interna,
+            2 | 6 => DestinoOperacao::Interestadual,
+            _ => DestinoOperacao::ComExterior,
+        }
+    }
+
+    /// Descrição oficial
+    pub fn descricao(&self) -> &'static str {
+        match self {
+            Cfop::Cfop1101 => "Compra para industrialização ou produção rural",
+            Cfop::Cfop1102 => "Compra para comercialização",
+            Cfop::Cfop1111 => "Compra para industrialização de mercadoria recebida anteriormente em consignação industrial",
+            Cfop::Cfop1113 => "Compra para comercialização, de mercadoria recebida anteriormente em consignação mercantil",
+            Cfop::Cfop1116 => "Compra para industrialização ou produção rural originada de encomenda para recebimento futuro",
+            Cfop::Cfop1117 => "Compra para comercialização originada de encomenda para recebimento futuro",
+            Cfop::Cfop1118 => "Compra de mercadoria para comercialização pelo adquirente originário, entregue pelo vendedor remetente ao destinatário, em venda à ordem",
+            Cfop::Cfop1120 => "Compra para industrialização, em venda à ordem, já recebida do vendedor remetente",
+            Cfop::Cfop1121 => "Compra para comercialização, em venda à ordem, já recebida do vendedor remetente",
+            Cfop::Cfop1122 => "Compra para industrialização em que a mercadoria foi remetida pelo fornecedor ao industrializador sem transitar pelo estabelecimento adquirente",
+            Cfop::Cfop1124 => "Industrialização efetuada por outra empresa",
+            Cfop::Cfop1125 => "Industrialização efetuada por outra empresa quando a mercadoria remetida para utilização no processo de industrialização não transitou pelo estabelecimento adquirente da mercadoria",
+            Cfop::Cfop1126 => "Compra para utilização na prestação de serviço sujeita ao ICMS",
+            Cfop::Cfop1128 => "Compra para utilização na prestação de serviço sujeita ao ISSQN",
+            Cfop::Cfop1131 => "Entrada de mercadoria com previsão de posterior ajuste ou fixação de preço, decorrente de operação de ato cooperativo",
+            Cfop::Cfop1132 => "Fixação de preço de produção do estabelecimento produtor, inclusive quando remetidas anteriormente com previsão de posterior ajuste ou fixação de preço, em ato cooperativo, para comercialização",
+            Cfop::Cfop1135 => "Fixação de preço de produção do estabelecimento produtor, inclusive quando remetidas anteriormente com previsão de posterior ajuste ou fixação de preço, em ato cooperativo, para industrialização",
+            Cfop::Cfop1151 => "Transferência para industrialização ou produção rural",
+            Cfop::Cfop1152 => "Transferência para comercialização",
+            Cfop::Cfop1153 => "Transferência de energia elétrica para distribuição",
+            Cfop::Cfop1154 => "Transferência para utilização na prestação de serviço",
+            Cfop::Cfop1159 => "Entrada decorrente do fornecimento de produto ou mercadoria de ato cooperativo",
+            Cfop::Cfop1201 => "Devolução de venda de produção do estabelecimento",
+            Cfop::Cfop1202 => "Devolução de venda de mercadoria adquirida ou recebida de terceiros",
+            Cfop::Cfop1203 => "Devolução de venda de produção do estabelecimento, destinada à Zona Franca de Manaus ou Áreas de Livre Comércio",
+            Cfop::Cfop1204 => "Devolução de venda de mercadoria adquirida ou recebida de terceiros, destinada à Zona Franca de Manaus ou Áreas de Livre Comércio",
+            Cfop::Cfop1205 => "Anulação de valor relativo à prestação de serviço de comunicação",
+            Cfop::Cfop1206 => "Anulação de valor relativo à prestação de serviço de transporte",
+            Cfop::Cfop1207 => "Anulação de valor relativo à venda de energia elétrica",
+            Cfop::Cfop1208 => "Devolução de produção do estabelecimento, remetida em transferência",
+            Cfop::Cfop1209 => "Devolução de mercadoria adquirida ou recebida de terceiros, remetida em transferência",
+            Cfop::Cfop1212 => "Devolução de venda de mercadoria industrializada e insumo importado sob o Regime Aduaneiro Especial de Entreposto Industrial sob Controle Informatizado do Sistema Integrado de Comércio Exterior (Recof-Sped)",
+            Cfop::Cfop1213 => "Devolução de remessa de produção do estabelecimento com previsão de posterior ajuste ou fixação de preço, em ato cooperativo",
+            Cfop::Cfop1214 => "Devolução de fixação de preço de produção do estabelecimento produtor, de ato cooperativo, para comercialização",
+            Cfop::Cfop1215 => "Devolução de fixação de preço de produção do estabelecimento produtor, de ato cooperativo, para industrialização",
+            Cfop::Cfop1251 => "Compra de energia elétrica para distribuição ou comercialização",
+            Cfop::Cfop1252 => "Compra de energia elétrica por estabelecimento industrial",
+            Cfop::Cfop1253 => "Compra de energia elétrica por estabelecimento comercial",
+            Cfop::Cfop1254 => "Compra de energia elétrica por estabelecimento prestador de serviço de transporte",
+            Cfop::Cfop1255 => "Compra de energia elétrica por estabelecimento prestador de serviço de comunicação",
+            Cfop::Cfop1256 => "Compra de energia elétrica por estabelecimento de produtor rural",
+            Cfop::Cfop1257 => "Compra de energia elétrica para consumo por demanda contratada",
+            Cfop::Cfop1301 => "Aquisição de serviço de comunicação para execução de serviço da mesma natureza",
+            Cfop::Cfop1302 => "Aquisição de serviço de comunicação por estabelecimento industrial",
+            Cfop::Cfop1303 => "Aquisição de serviço de comunicação por estabelecimento comercial",
+            Cfop::Cfop1304 => "Aquisição de serviço de comunicação por estabelecimento de prestador de serviço de transporte",
+            Cfop::Cfop1305 => "Aquisição de serviço de comunicação por estabelecimento de geradora ou de distribuidora de energia elétrica",
+            Cfop::Cfop1306 => "Aquisição de serviço de comunicação por estabelecimento de produtor rural",
+            Cfop::Cfop1351 => "Aquisição de serviço de transporte para execução de serviço da mesma natureza",
+            Cfop::Cfop1352 => "Aquisição de serviço de transporte por estabelecimento industrial",
+            Cfop::Cfop1353 => "Aquisição de serviço de transporte por estabelecimento comercial",
+            Cfop::Cfop1354 => "Aquisição de serviço de transporte por estabelecimento de prestador de serviço de comunicação",
+            Cfop::Cfop1355 => "Aquisição de serviço de transporte por estabelecimento de geradora ou de distribuidora de energia elétrica",
+            Cfop::Cfop1356 => "Aquisição de serviço de transporte por estabelecimento de produtor rural",
+            Cfop::Cfop1360 => "Aquisição de serviço de transporte por contribuinte substituto em relação ao serviço de transporte",
+            Cfop::Cfop1401 => "Compra para industrialização ou produção rural em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop1403 => "Compra para comercialização em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop1406 => "Compra de bem para o ativo imobilizado cuja mercadoria está sujeita ao regime de substituição tributária",
+            Cfop::Cfop1407 => "Compra de mercadoria para uso ou consumo cuja mercadoria está sujeita ao regime de substituição tributária",
+            Cfop::Cfop1408 => "Transferência para industrialização ou produção rural em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop1409 => "Transferência para comercialização em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop1410 => "Devolução de venda de produção do estabelecimento em operação com produto sujeito ao regime de substituição tributária",
+            Cfop::Cfop1411 => "Devolução de venda de mercadoria adquirida ou recebida de terceiros em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop1414 => "Retorno de produção do estabelecimento, remetida para venda fora do estabelecimento em operação com produto sujeito ao regime de substituição tributária",
+            Cfop::Cfop1415 => "Retorno de mercadoria adquirida ou recebida de terceiros, remetida para venda fora do estabelecimento em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop1451 => "Retorno de animal do estabelecimento produtor",
+            Cfop::Cfop1452 => "Retorno de insumo não utilizado na produção",
+            Cfop::Cfop1501 => "Entrada de mercadoria recebida com fim específico de exportação",
+            Cfop::Cfop1503 => "Entrada decorrente de devolução de produto remetido com fim específico de exportação, de produção do estabelecimento",
+            Cfop::Cfop1504 => "Entrada decorrente de devolução de mercadoria remetida com fim específico de exportação, adquirida ou recebida de terceiros",
+            Cfop::Cfop1505 => "Entrada decorrente de devolução simbólica de mercadorias remetidas para formação de lote de exportação, de produtos industrializados ou produzidos pelo próprio estabelecimento",
+            Cfop::Cfop1506 => "Entrada decorrente de devolução simbólica de mercadorias, adquiridas ou recebidas de terceiros, remetidas para formação de lote de exportação",
+            Cfop::Cfop1551 => "Compra de bem para o ativo imobilizado",
+            Cfop::Cfop1552 => "Transferência de bem do ativo imobilizado",
+            Cfop::Cfop1553 => "Devolução de venda de bem do ativo imobilizado",
+            Cfop::Cfop1554 => "Retorno de bem do ativo imobilizado remetido para uso fora do estabelecimento",
+            Cfop::Cfop1555 => "Entrada de bem do ativo imobilizado de terceiro, remetido para uso no estabelecimento",
+            Cfop::Cfop1556 => "Compra de material para uso ou consumo",
+            Cfop::Cfop1557 => "Transferência de material para uso ou consumo",
+            Cfop::Cfop1601 => "Recebimento, por transferência, de crédito de ICMS",
+            Cfop::Cfop1602 => "Recebimento, por transferência, de saldo credor de ICMS de outro estabelecimento da mesma empresa, para compensação de saldo devedor de ICMS",
+            Cfop::Cfop1603 => "Ressarcimento de ICMS retido por substituição tributária",
+            Cfop::Cfop1604 => "Lançamento do crédito relativo à compra de bem para o ativo imobilizado",
+            Cfop::Cfop1605 => "Recebimento, por transferência, de saldo devedor de ICMS de outro estabelecimento da mesma empresa",
+            Cfop::Cfop1651 => "Compra de combustível ou lubrificante para industrialização subseqüente",
+            Cfop::Cfop1652 => "Compra de combustível ou lubrificante para comercialização",
+            Cfop::Cfop1653 => "Compra de combustível ou lubrificante por consumidor ou usuário final",
+            Cfop::Cfop1658 => "Transferência de combustível e lubrificante para industrialização",
+            Cfop::Cfop1659 => "Transferência de combustível e lubrificante para comercialização",
+            Cfop::Cfop1660 => "Devolução de venda de combustível ou lubrificante destinado à industrialização subseqüente",
+            Cfop::Cfop1661 => "Devolução de venda de combustível ou lubrificante destinado à comercialização",
+            Cfop::Cfop1662 => "Devolução de venda de combustível ou lubrificante destinado a consumidor ou usuário final",
+            Cfop::Cfop1663 => "Entrada de combustível ou lubrificante para armazenagem",
+            Cfop::Cfop1664 => "Retorno de combustível ou lubrificante remetido para armazenagem",
+            Cfop::Cfop1901 => "Entrada para industrialização por encomenda",
+            Cfop::Cfop1902 => "Retorno de mercadoria remetida para industrialização por encomenda",
+            Cfop::Cfop1903 => "Entrada de mercadoria remetida para industrialização e não aplicada no referido processo",
+            Cfop::Cfop1904 => "Retorno de remessa para venda fora do estabelecimento",
+            Cfop::Cfop1905 => "Entrada de mercadoria recebida para depósito em depósito fechado ou armazém geral",
+            Cfop::Cfop1906 => "Retorno de mercadoria remetida para depósito fechado ou armazém geral",
+            Cfop::Cfop1907 => "Retorno simbólico de mercadoria remetida para depósito fechado ou armazém geral",
+            Cfop::Cfop1908 => "Entrada de bem por conta de contrato de comodato",
+            Cfop::Cfop1909 => "Retorno de bem remetido por conta de contrato de comodato",
+            Cfop::Cfop1910 => "Entrada de bonificação, doação ou brinde",
+            Cfop::Cfop1911 => "Entrada de amostra grátis",
+            Cfop::Cfop1912 => "Entrada de mercadoria ou bem recebido para demonstração ou mostruário",
+            Cfop::Cfop1913 => "Retorno de mercadoria ou bem remetido para demonstração, mostruário ou treinamento",
+            Cfop::Cfop1914 => "Retorno de mercadoria ou bem remetido para exposição ou feira",
+            Cfop::Cfop1915 => "Entrada de mercadoria ou bem recebido para conserto ou reparo",
+            Cfop::Cfop1916 => "Retorno de mercadoria ou bem remetido para conserto ou reparo",
+            Cfop::Cfop1917 => "Entrada de mercadoria recebida em consignação mercantil ou industrial",
+            Cfop::Cfop1918 => "Devolução de mercadoria remetida em consignação mercantil ou industrial",
+            Cfop::Cfop1919 => "Devolução simbólica de mercadoria vendida ou utilizada em processo industrial, remetida anteriormente em consignação mercantil ou industrial",
+            Cfop::Cfop1920 => "Entrada de vasilhame ou sacaria",
+            Cfop::Cfop1921 => "Retorno de vasilhame ou sacaria",
+            Cfop::Cfop1922 => "Lançamento efetuado a título de simples faturamento decorrente de compra para recebimento futuro",
+            Cfop::Cfop1923 => "Entrada de mercadoria recebida do vendedor remetente, em venda à ordem",
+            Cfop::Cfop1924 => "Entrada para industrialização por conta e ordem do adquirente da mercadoria, quando esta não transitar pelo estabelecimento do adquirente",
+            Cfop::Cfop1925 => "Retorno de mercadoria remetida para industrialização por conta e ordem do adquirente da mercadoria, quando esta não transitar pelo estabelecimento do adquirente",
+            Cfop::Cfop1926 => "Lançamento efetuado a título de reclassificação de mercadoria decorrente de formação de kit ou de sua desagregação",
+            Cfop::Cfop1931 => "Lançamento efetuado pelo tomador do serviço de transporte quando a responsabilidade de retenção do imposto for atribuída ao remetente ou alienante da mercadoria, pelo serviço de transporte realizado por transportador autônomo ou por transportador não inscrito na unidade da Federação onde iniciado o serviço",
+            Cfop::Cfop1932 => "Aquisição de serviço de transporte iniciado em unidade da Federação diversa daquela onde inscrito o prestador",
+            Cfop::Cfop1933 => "Aquisição de serviço tributado pelo ISSQN",
+            Cfop::Cfop1934 => "Entrada simbólica de mercadoria recebida para depósito fechado ou armazém geral",
+            Cfop::Cfop1949 => "Outra entrada de mercadoria ou prestação de serviço não especificada",
+            Cfop::Cfop2101 => "Compra para industrialização ou produção rural",
+            Cfop::Cfop2102 => "Compra para comercialização",
+            Cfop::Cfop2111 => "Compra para industrialização de mercadoria recebida anteriormente em consignação industrial",
+            Cfop::Cfop2113 => "Compra para comercialização, de mercadoria recebida anteriormente em consignação mercantil",
+            Cfop::Cfop2116 => "Compra para industrialização ou produção rural originada de encomenda para recebimento futuro",
+            Cfop::Cfop2117 => "Compra para comercialização originada de encomenda para recebimento futuro",
+            Cfop::Cfop2118 => "Compra de mercadoria para comercialização pelo adquirente originário, entregue pelo vendedor remetente ao destinatário, em venda à ordem",
+            Cfop::Cfop2120 => "Compra para industrialização, em venda à ordem, já recebida do vendedor remetente",
+            Cfop::Cfop2121 => "Compra para comercialização, em venda à ordem, já recebida do vendedor remetente",
+            Cfop::Cfop2122 => "Compra para industrialização em que a mercadoria foi remetida pelo fornecedor ao industrializador sem transitar pelo estabelecimento adquirente",
+            Cfop::Cfop2124 => "Industrialização efetuada por outra empresa",
+            Cfop::Cfop2125 => "Industrialização efetuada por outra empresa quando a mercadoria remetida para utilização no processo de industrialização não transitou pelo estabelecimento adquirente da mercadoria",
+            Cfop::Cfop2126 => "Compra para utilização na prestação de serviço sujeita ao ICMS",
+            Cfop::Cfop2128 => "Compra para utilização na prestação de serviço sujeita ao ISSQN",
+            Cfop::Cfop2131 => "Entrada de mercadoria com previsão de posterior ajuste ou fixação de preço, decorrente de operação de ato cooperativo",
+            Cfop::Cfop2132 => "Fixação de preço de produção do estabelecimento produtor, inclusive quando remetidas anteriormente com previsão de posterior ajuste ou fixação de preço, em ato cooperativo, para comercialização",
+            Cfop::Cfop2135 => "Fixação de preço de produção do estabelecimento produtor, inclusive quando remetidas anteriormente com previsão de posterior ajuste ou fixação de preço, em ato cooperativo, para industrialização",
+            Cfop::Cfop2151 => "Transferência para industrialização ou produção rural",
+            Cfop::Cfop2152 => "Transferência para comercialização",
+            Cfop::Cfop2153 => "Transferência de energia elétrica para distribuição",
+            Cfop::Cfop2154 => "Transferência para utilização na prestação de serviço",
+            Cfop::Cfop2159 => "Entrada decorrente do fornecimento de produto ou mercadoria de ato cooperativo",
+            Cfop::Cfop2201 => "Devolução de venda de produção do estabelecimento",
+            Cfop::Cfop2202 => "Devolução de venda de mercadoria adquirida ou recebida de terceiros",
+            Cfop::Cfop2203 => "Devolução de venda de produção do estabelecimento, destinada à Zona Franca de Manaus ou Áreas de Livre Comércio",
+            Cfop::Cfop2204 => "Devolução de venda de mercadoria adquirida ou recebida de terceiros, destinada à Zona Franca de Manaus ou Áreas de Livre Comércio",
+            Cfop::Cfop2205 => "Anulação de valor relativo à prestação de serviço de comunicação",
+            Cfop::Cfop2206 => "Anulação de valor relativo à prestação de serviço de transporte",
+            Cfop::Cfop2207 => "Anulação de valor relativo à venda de energia elétrica",
+            Cfop::Cfop2208 => "Devolução de produção do estabelecimento, remetida em transferência",
+            Cfop::Cfop2209 => "Devolução de mercadoria adquirida ou recebida de terceiros, remetida em transferência",
+            Cfop::Cfop2212 => "Devolução de venda de mercadoria industrializada e insumo importado sob o Regime Aduaneiro Especial de Entreposto Industrial sob Controle Informatizado do Sistema Integrado de Comércio Exterior (Recof-Sped)",
+            Cfop::Cfop2213 => "Devolução de remessa de produção do estabelecimento com previsão de posterior ajuste ou fixação de preço, em ato cooperativo",
+            Cfop::Cfop2214 => "Devolução de fixação de preço de produção do estabelecimento produtor, de ato cooperativo, para comercialização",
+            Cfop::Cfop2215 => "Devolução de fixação de preço de produção do estabelecimento produtor, de ato cooperativo, para industrialização",
+            Cfop::Cfop2251 => "Compra de energia elétrica para distribuição ou comercialização",
+            Cfop::Cfop2252 => "Compra de energia elétrica por estabelecimento industrial",
+            Cfop::Cfop2253 => "Compra de energia elétrica por estabelecimento comercial",
+            Cfop::Cfop2254 => "Compra de energia elétrica por estabelecimento prestador de serviço de transporte",
+            Cfop::Cfop2255 => "Compra de energia elétrica por estabelecimento prestador de serviço de comunicação",
+            Cfop::Cfop2256 => "Compra de energia elétrica por estabelecimento de produtor rural",
+            Cfop::Cfop2257 => "Compra de energia elétrica para consumo por demanda contratada",
+            Cfop::Cfop2301 => "Aquisição de serviço de comunicação para execução de serviço da mesma natureza",
+            Cfop::Cfop2302 => "Aquisição de serviço de comunicação por estabelecimento industrial",
+            Cfop::Cfop2303 => "Aquisição de serviço de comunicação por estabelecimento comercial",
+            Cfop::Cfop2304 => "Aquisição de serviço de comunicação por estabelecimento de prestador de serviço de transporte",
+            Cfop::Cfop2305 => "Aquisição de serviço de comunicação por estabelecimento de geradora ou de distribuidora de energia elétrica",
+            Cfop::Cfop2306 => "Aquisição de serviço de comunicação por estabelecimento de produtor rural",
+            Cfop::Cfop2351 => "Aquisição de serviço de transporte para execução de serviço da mesma natureza",
+            Cfop::Cfop2352 => "Aquisição de serviço de transporte por estabelecimento industrial",
+            Cfop::Cfop2353 => "Aquisição de serviço de transporte por estabelecimento comercial",
+            Cfop::Cfop2354 => "Aquisição de serviço de transporte por estabelecimento de prestador de serviço de comunicação",
+            Cfop::Cfop2355 => "Aquisição de serviço de transporte por estabelecimento de geradora ou de distribuidora de energia elétrica",
+            Cfop::Cfop2356 => "Aquisição de serviço de transporte por estabelecimento de produtor rural",
+            Cfop::Cfop2360 => "Aquisição de serviço de transporte por contribuinte substituto em relação ao serviço de transporte",
+            Cfop::Cfop2401 => "Compra para industrialização ou produção rural em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop2403 => "Compra para comercialização em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop2406 => "Compra de bem para o ativo imobilizado cuja mercadoria está sujeita ao regime de substituição tributária",
+            Cfop::Cfop2407 => "Compra de mercadoria para uso ou consumo cuja mercadoria está sujeita ao regime de substituição tributária",
+            Cfop::Cfop2408 => "Transferência para industrialização ou produção rural em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop2409 => "Transferência para comercialização em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop2410 => "Devolução de venda de produção do estabelecimento em operação com produto sujeito ao regime de substituição tributária",
+            Cfop::Cfop2411 => "Devolução de venda de mercadoria adquirida ou recebida de terceiros em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop2414 => "Retorno de produção do estabelecimento, remetida para venda fora do estabelecimento em operação com produto sujeito ao regime de substituição tributária",
+            Cfop::Cfop2415 => "Retorno de mercadoria adquirida ou recebida de terceiros, remetida para venda fora do estabelecimento em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop2501 => "Entrada de mercadoria recebida com fim específico de exportação",
+            Cfop::Cfop2503 => "Entrada decorrente de devolução de produto remetido com fim específico de exportação, de produção do estabelecimento",
+            Cfop::Cfop2504 => "Entrada decorrente de devolução de mercadoria remetida com fim específico de exportação, adquirida ou recebida de terceiros",
+            Cfop::Cfop2505 => "Entrada decorrente de devolução simbólica de mercadorias remetidas para formação de lote de exportação, de produtos industrializados ou produzidos pelo próprio estabelecimento",
+            Cfop::Cfop2506 => "Entrada decorrente de devolução simbólica de mercadorias, adquiridas ou recebidas de terceiros, remetidas para formação de lote de exportação",
+            Cfop::Cfop2551 => "Compra de bem para o ativo imobilizado",
+            Cfop::Cfop2552 => "Transferência de bem do ativo imobilizado",
+            Cfop::Cfop2553 => "Devolução de venda de bem do ativo imobilizado",
+            Cfop::Cfop2554 => "Retorno de bem do ativo imobilizado remetido para uso fora do estabelecimento",
+            Cfop::Cfop2555 => "Entrada de bem do ativo imobilizado de terceiro, remetido para uso no estabelecimento",
+            Cfop::Cfop2556 => "Compra de material para uso ou consumo",
+            Cfop::Cfop2557 => "Transferência de material para uso ou consumo",
+            Cfop::Cfop2603 => "Ressarcimento de ICMS retido por substituição tributária",
+            Cfop::Cfop2651 => "Compra de combustível ou lubrificante para industrialização subseqüente",
+            Cfop::Cfop2652 => "Compra de combustível ou lubrificante para comercialização",
+            Cfop::Cfop2653 => "Compra de combustível ou lubrificante por consumidor ou usuário final",
+            Cfop::Cfop2658 => "Transferência de combustível e lubrificante para industrialização",
+            Cfop::Cfop2659 => "Transferência de combustível e lubrificante para comercialização",
+            Cfop::Cfop2660 => "Devolução de venda de combustível ou lubrificante destinado à industrialização subseqüente",
+            Cfop::Cfop2661 => "Devolução de venda de combustível ou lubrificante destinado à comercialização",
+            Cfop::Cfop2662 => "Devolução de venda de combustível ou lubrificante destinado a consumidor ou usuário final",
+            Cfop::Cfop2663 => "Entrada de combustível ou lubrificante para armazenagem",
+            Cfop::Cfop2664 => "Retorno de combustível ou lubrificante remetido para armazenagem",
+            Cfop::Cfop2901 => "Entrada para industrialização por encomenda",
+            Cfop::Cfop2902 => "Retorno de mercadoria remetida para industrialização por encomenda",
+            Cfop::Cfop2903 => "Entrada de mercadoria remetida para industrialização e não aplicada no referido processo",
+            Cfop::Cfop2904 => "Retorno de remessa para venda fora do estabelecimento",
+            Cfop::Cfop2905 => "Entrada de mercadoria recebida para depósito em depósito fechado ou armazém geral",
+            Cfop::Cfop2906 => "Retorno de mercadoria remetida para depósito fechado ou armazém geral",
+            Cfop::Cfop2907 => "Retorno simbólico de mercadoria remetida para depósito fechado ou armazém geral",
+            Cfop::Cfop2908 => "Entrada de bem por conta de contrato de comodato",
+            Cfop::Cfop2909 => "Retorno de bem remetido por conta de contrato de comodato",
+            Cfop::Cfop2910 => "Entrada de bonificação, doação ou brinde",
+            Cfop::Cfop2911 => "Entrada de amostra grátis",
+            Cfop::Cfop2912 => "Entrada de mercadoria ou bem recebido para demonstração ou mostruário",
+            Cfop::Cfop2913 => "Retorno de mercadoria ou bem remetido para demonstração, mostruário ou treinamento",
+            Cfop::Cfop2914 => "Retorno de mercadoria ou bem remetido para exposição ou feira",
+            Cfop::Cfop2915 => "Entrada de mercadoria ou bem recebido para conserto ou reparo",
+            Cfop::Cfop2916 => "Retorno de mercadoria ou bem remetido para conserto ou reparo",
+            Cfop::Cfop2917 => "Entrada de mercadoria recebida em consignação mercantil ou industrial",
+            Cfop::Cfop2918 => "Devolução de mercadoria remetida em consignação mercantil ou industrial",
+            Cfop::Cfop2919 => "Devolução simbólica de mercadoria vendida ou utilizada em processo industrial, remetida anteriormente em consignação mercantil ou industrial",
+            Cfop::Cfop2920 => "Entrada de vasilhame ou sacaria",
+            Cfop::Cfop2921 => "Retorno de vasilhame ou sacaria",
+            Cfop::Cfop2922 => "Lançamento efetuado a título de simples faturamento decorrente de compra para recebimento futuro",
+            Cfop::Cfop2923 => "Entrada de mercadoria recebida do vendedor remetente, em venda à ordem",
+            Cfop::Cfop2924 => "Entrada para industrialização por conta e ordem do adquirente da mercadoria, quando esta não transitar pelo estabelecimento do adquirente",
+            Cfop::Cfop2925 => "Retorno de mercadoria remetida para industrialização por conta e ordem do adquirente da mercadoria, quando esta não transitar pelo estabelecimento do adquirente",
+            Cfop::Cfop2931 => "Lançamento efetuado pelo tomador do serviço de transporte quando a responsabilidade de retenção do imposto for atribuída ao remetente ou alienante da mercadoria, pelo serviço de transporte realizado por transportador autônomo ou por transportador não inscrito na unidade da Federação onde iniciado o serviço",
+            Cfop::Cfop2932 => "Aquisição de serviço de transporte iniciado em unidade da Federação diversa daquela onde inscrito o prestador",
+            Cfop::Cfop2933 => "Aquisição de serviço tributado pelo ISSQN",
+            Cfop::Cfop2934 => "Entrada simbólica de mercadoria recebida para depósito fechado ou armazém geral",
+            Cfop::Cfop2949 => "Outra entrada de mercadoria ou prestação de serviço não especificada",
+            Cfop::Cfop3101 => "Compra para industrialização ou produção rural",
+            Cfop::Cfop3102 => "Compra para comercialização",
+            Cfop::Cfop3126 => "Compra para utilização na prestação de serviço sujeita ao ICMS",
+            Cfop::Cfop3127 => "Compra para industrialização sob o regime de drawback",
+            Cfop::Cfop3128 => "Compra para utilização na prestação de serviço sujeita ao ISSQN",
+            Cfop::Cfop3129 => "Compra para industrialização sob o Regime Aduaneiro Especial de Entreposto Industrial sob Controle Informatizado do Sistema Integrado de Comércio Exterior (Recof-Sped)",
+            Cfop::Cfop3201 => "Devolução de venda de produção do estabelecimento",
+            Cfop::Cfop3202 => "Devolução de venda de mercadoria adquirida ou recebida de terceiros",
+            Cfop::Cfop3205 => "Anulação de valor relativo à prestação de serviço de comunicação",
+            Cfop::Cfop3206 => "Anulação de valor relativo à prestação de serviço de transporte",
+            Cfop::Cfop3207 => "Anulação de valor relativo à venda de energia elétrica",
+            Cfop::Cfop3211 => "Devolução de venda de produção do estabelecimento sob o regime de drawback",
+            Cfop::Cfop3212 => "Devolução de venda de mercadoria industrializada e insumo importado sob o Regime Aduaneiro Especial de Entreposto Industrial sob Controle Informatizado do Sistema Integrado de Comércio Exterior (Recof-Sped)",
+            Cfop::Cfop3251 => "Compra de energia elétrica para distribuição ou comercialização",
+            Cfop::Cfop3301 => "Aquisição de serviço de comunicação para execução de serviço da mesma natureza",
+            Cfop::Cfop3351 => "Aquisição de serviço de transporte para execução de serviço da mesma natureza",
+            Cfop::Cfop3352 => "Aquisição de serviço de transporte por estabelecimento industrial",
+            Cfop::Cfop3353 => "Aquisição de serviço de transporte por estabelecimento comercial",
+            Cfop::Cfop3354 => "Aquisição de serviço de transporte por estabelecimento de prestador de serviço de comunicação",
+            Cfop::Cfop3355 => "Aquisição de serviço de transporte por estabelecimento de geradora ou de distribuidora de energia elétrica",
+            Cfop::Cfop3356 => "Aquisição de serviço de transporte por estabelecimento de produtor rural",
+            Cfop::Cfop3503 => "Devolução de mercadoria exportada que tenha sido recebida com fim específico de exportação",
+            Cfop::Cfop3551 => "Compra de bem para o ativo imobilizado",
+            Cfop::Cfop3553 => "Devolução de venda de bem do ativo imobilizado",
+            Cfop::Cfop3556 => "Compra de material para uso ou consumo",
+            Cfop::Cfop3651 => "Compra de combustível ou lubrificante para industrialização subseqüente",
+            Cfop::Cfop3652 => "Compra de combustível ou lubrificante para comercialização",
+            Cfop::Cfop3653 => "Compra de combustível ou lubrificante por consumidor ou usuário final",
+            Cfop::Cfop3930 => "Lançamento efetuado a título de entrada de bem sob amparo de regime especial aduaneiro de admissão temporária",
+            Cfop::Cfop3949 => "Outra entrada de mercadoria ou prestação de serviço não especificada",
+            Cfop::Cfop5101 => "Venda de produção do estabelecimento",
+            Cfop::Cfop5102 => "Venda de mercadoria adquirida ou recebida de terceiros",
+            Cfop::Cfop5103 => "Venda de produção do estabelecimento, efetuada fora do estabelecimento",
+            Cfop::Cfop5104 => "Venda de mercadoria adquirida ou recebida de terceiros, efetuada fora do estabelecimento",
+            Cfop::Cfop5105 => "Venda de produção do estabelecimento que não deva por ele transitar",
+            Cfop::Cfop5106 => "Venda de mercadoria adquirida ou recebida de terceiros, que não deva por ele transitar",
+            Cfop::Cfop5109 => "Venda de produção do estabelecimento, destinada à Zona Franca de Manaus ou Áreas de Livre Comércio",
+            Cfop::Cfop5110 => "Venda de mercadoria adquirida ou recebida de terceiros, destinada à Zona Franca de Manaus ou Áreas de Livre Comércio",
+            Cfop::Cfop5111 => "Venda de produção do estabelecimento remetida anteriormente em consignação industrial",
+            Cfop::Cfop5112 => "Venda de mercadoria adquirida ou recebida de terceiros remetida anteriormente em consignação industrial",
+            Cfop::Cfop5113 => "Venda de produção do estabelecimento remetida anteriormente em consignação mercantil",
+            Cfop::Cfop5114 => "Venda de mercadoria adquirida ou recebida de terceiros remetida anteriormente em consignação mercantil",
+            Cfop::Cfop5115 => "Venda de mercadoria adquirida ou recebida de terceiros, recebida anteriormente em consignação mercantil",
+            Cfop::Cfop5116 => "Venda de produção do estabelecimento originada de encomenda para entrega futura",
+            Cfop::Cfop5117 => "Venda de mercadoria adquirida ou recebida de terceiros, originada de encomenda para entrega futura",
+            Cfop::Cfop5118 => "Venda de produção do estabelecimento entregue ao destinatário por conta e ordem do adquirente originário, em venda à ordem",
+            Cfop::Cfop5119 => "Venda de mercadoria adquirida ou recebida de terceiros entregue ao destinatário por conta e ordem do adquirente originário, em venda à ordem",
+            Cfop::Cfop5120 => "Venda de mercadoria adquirida ou recebida de terceiros entregue ao destinatário pelo vendedor remetente, em venda à ordem",
+            Cfop::Cfop5122 => "Venda de produção do estabelecimento remetida para industrialização, por conta e ordem do adquirente, sem transitar pelo estabelecimento do adquirente",
+            Cfop::Cfop5123 => "Venda de mercadoria adquirida ou recebida de terceiros remetida para industrialização, por conta e ordem do adquirente, sem transitar pelo estabelecimento do adquirente",
+            Cfop::Cfop5124 => "Industrialização efetuada para outra empresa",
+            Cfop::Cfop5125 => "Industrialização efetuada para outra empresa quando a mercadoria recebida para utilização no processo de industrialização não transitar pelo estabelecimento adquirente da mercadoria",
+            Cfop::Cfop5129 => "Venda de insumo importado e de mercadoria industrializada sob o Regime Aduaneiro Especial de Entreposto Industrial sob Controle Informatizado do Sistema Integrado de Comércio Exterior (Recof-Sped)",
+            Cfop::Cfop5131 => "Remessa de produção do estabelecimento, com previsão de posterior ajuste ou fixação de preço, de ato cooperativo",
+            Cfop::Cfop5132 => "Fixação de preço de produção do estabelecimento, inclusive quando remetidas anteriormente com previsão de posterior ajuste ou fixação de preço, de ato cooperativo",
+            Cfop::Cfop5151 => "Transferência de produção do estabelecimento",
+            Cfop::Cfop5152 => "Transferência de mercadoria adquirida ou recebida de terceiros",
+            Cfop::Cfop5153 => "Transferência de energia elétrica",
+            Cfop::Cfop5155 => "Transferência de produção do estabelecimento, que não deva por ele transitar",
+            Cfop::Cfop5156 => "Transferência de mercadoria adquirida ou recebida de terceiros, que não deva por ele transitar",
+            Cfop::Cfop5159 => "Fornecimento de produção do estabelecimento de ato cooperativo",
+            Cfop::Cfop5160 => "Fornecimento de mercadoria adquirida ou recebida de terceiros de ato cooperativo",
+            Cfop::Cfop5201 => "Devolução de compra para industrialização ou produção rural",
+            Cfop::Cfop5202 => "Devolução de compra para comercialização",
+            Cfop::Cfop5205 => "Anulação de valor relativo a aquisição de serviço de comunicação",
+            Cfop::Cfop5206 => "Anulação de valor relativo a aquisição de serviço de transporte",
+            Cfop::Cfop5207 => "Anulação de valor relativo à compra de energia elétrica",
+            Cfop::Cfop5208 => "Devolução de mercadoria recebida em transferência para industrialização ou produção rural",
+            Cfop::Cfop5209 => "Devolução de mercadoria recebida em transferência para comercialização",
+            Cfop::Cfop5210 => "Devolução de compra para utilização na prestação de serviço",
+            Cfop::Cfop5213 => "Devolução de entrada de mercadoria com previsão de posterior ajuste ou fixação de preço, em ato cooperativo",
+            Cfop::Cfop5214 => "Devolução referente a fixação de preço de produção do estabelecimento produtor, de ato cooperativo, para comercialização",
+            Cfop::Cfop5215 => "Devolução referente a fixação de preço de produção do estabelecimento produtor, de ato cooperativo, para industrialização",
+            Cfop::Cfop5251 => "Venda de energia elétrica para distribuição ou comercialização",
+            Cfop::Cfop5252 => "Venda de energia elétrica para estabelecimento industrial",
+            Cfop::Cfop5253 => "Venda de energia elétrica para estabelecimento comercial",
+            Cfop::Cfop5254 => "Venda de energia elétrica para estabelecimento prestador de serviço de transporte",
+            Cfop::Cfop5255 => "Venda de energia elétrica para estabelecimento prestador de serviço de comunicação",
+            Cfop::Cfop5256 => "Venda de energia elétrica para estabelecimento de produtor rural",
+            Cfop::Cfop5257 => "Venda de energia elétrica para consumo por demanda contratada",
+            Cfop::Cfop5258 => "Venda de energia elétrica a não contribuinte",
+            Cfop::Cfop5301 => "Prestação de serviço de comunicação para execução de serviço da mesma natureza",
+            Cfop::Cfop5302 => "Prestação de serviço de comunicação a estabelecimento industrial",
+            Cfop::Cfop5303 => "Prestação de serviço de comunicação a estabelecimento comercial",
+            Cfop::Cfop5304 => "Prestação de serviço de comunicação a estabelecimento de prestador de serviço de transporte",
+            Cfop::Cfop5305 => "Prestação de serviço de comunicação a estabelecimento de geradora ou de distribuidora de energia elétrica",
+            Cfop::Cfop5306 => "Prestação de serviço de comunicação a estabelecimento de produtor rural",
+            Cfop::Cfop5307 => "Prestação de serviço de comunicação a não contribuinte",
+            Cfop::Cfop5351 => "Prestação de serviço de transporte para execução de serviço da mesma natureza",
+            Cfop::Cfop5352 => "Prestação de serviço de transporte a estabelecimento industrial",
+            Cfop::Cfop5353 => "Prestação de serviço de transporte a estabelecimento comercial",
+            Cfop::Cfop5354 => "Prestação de serviço de transporte a estabelecimento de prestador de serviço de comunicação",
+            Cfop::Cfop5355 => "Prestação de serviço de transporte a estabelecimento de geradora ou de distribuidora de energia elétrica",
+            Cfop::Cfop5356 => "Prestação de serviço de transporte a estabelecimento de produtor rural",
+            Cfop::Cfop5357 => "Prestação de serviço de transporte a não contribuinte",
+            Cfop::Cfop5359 => "Prestação de serviço de transporte a contribuinte ou a não contribuinte quando a mercadoria transportada está dispensada de emissão de nota fiscal",
+            Cfop::Cfop5360 => "Prestação de serviço de transporte a contribuinte substituto em relação ao serviço de transporte",
+            Cfop::Cfop5401 => "Venda de produção do estabelecimento em operação com produto sujeito ao regime de substituição tributária, na condição de contribuinte substituto",
+            Cfop::Cfop5402 => "Venda de produção do estabelecimento de produto sujeito ao regime de substituição tributária, em operação entre contribuintes substitutos do mesmo produto",
+            Cfop::Cfop5403 => "Venda de mercadoria adquirida ou recebida de terceiros em operação com mercadoria sujeita ao regime de substituição tributária, na condição de contribuinte substituto",
+            Cfop::Cfop5405 => "Venda de mercadoria adquirida ou recebida de terceiros em operação com mercadoria sujeita ao regime de substituição tributária, na condição de contribuinte substituído",
+            Cfop::Cfop5408 => "Transferência de produção do estabelecimento em operação com produto sujeito ao regime de substituição tributária",
+            Cfop::Cfop5409 => "Transferência de mercadoria adquirida ou recebida de terceiros em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop5410 => "Devolução de compra para industrialização ou produção rural em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop5411 => "Devolução de compra para comercialização em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop5412 => "Devolução de bem do ativo imobilizado, em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop5413 => "Devolução de mercadoria destinada ao uso ou consumo, em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop5414 => "Remessa de produção do estabelecimento para venda fora do estabelecimento em operação com produto sujeito ao regime de substituição tributária",
+            Cfop::Cfop5415 => "Remessa de mercadoria adquirida ou recebida de terceiros para venda fora do estabelecimento, em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop5451 => "Remessa de animal e de insumo para estabelecimento produtor",
+            Cfop::Cfop5501 => "Remessa de produção do estabelecimento, com fim específico de exportação",
+            Cfop::Cfop5502 => "Remessa de mercadoria adquirida ou recebida de terceiros, com fim específico de exportação",
+            Cfop::Cfop5503 => "Devolução de mercadoria recebida com fim específico de exportação",
+            Cfop::Cfop5504 => "Remessa de mercadoria para formação de lote de exportação, de produtos industrializados ou produzidos pelo próprio estabelecimento",
+            Cfop::Cfop5505 => "Remessa de mercadoria, adquirida ou recebida de terceiros, para formação de lote de exportação",
+            Cfop::Cfop5551 => "Venda de bem do ativo imobilizado",
+            Cfop::Cfop5552 => "Transferência de bem do ativo imobilizado",
+            Cfop::Cfop5553 => "Devolução de compra de bem para o ativo imobilizado",
+            Cfop::Cfop5554 => "Remessa de bem do ativo imobilizado para uso fora do estabelecimento",
+            Cfop::Cfop5555 => "Devolução de bem do ativo imobilizado de terceiro, recebido para uso no estabelecimento",
+            Cfop::Cfop5556 => "Devolução de compra de material de uso ou consumo",
+            Cfop::Cfop5557 => "Transferência de material de uso ou consumo",
+            Cfop::Cfop5601 => "Transferência de crédito de ICMS acumulado",
+            Cfop::Cfop5602 => "Transferência de saldo credor de ICMS para outro estabelecimento da mesma empresa, destinado à compensação de saldo devedor de ICMS",
+            Cfop::Cfop5603 => "Ressarcimento de ICMS retido por substituição tributária",
+            Cfop::Cfop5605 => "Transferência de saldo devedor de ICMS de outro estabelecimento da mesma empresa",
+            Cfop::Cfop5606 => "Utilização de saldo credor de ICMS para extinção por compensação de débitos fiscais",
+            Cfop::Cfop5651 => "Venda de combustível ou lubrificante de produção do estabelecimento destinado à industrialização subseqüente",
+            Cfop::Cfop5652 => "Venda de combustível ou lubrificante de produção do estabelecimento destinado à comercialização",
+            Cfop::Cfop5653 => "Venda de combustível ou lubrificante de produção do estabelecimento destinado a consumidor ou usuário final",
+            Cfop::Cfop5654 => "Venda de combustível ou lubrificante adquirido ou recebido de terceiros destinado à industrialização subseqüente",
+            Cfop::Cfop5655 => "Venda de combustível ou lubrificante adquirido ou recebido de terceiros destinado à comercialização",
+            Cfop::Cfop5656 => "Venda de combustível ou lubrificante adquirido ou recebido de terceiros destinado a consumidor ou usuário final",
+            Cfop::Cfop5657 => "Remessa de combustível ou lubrificante adquirido ou recebido de terceiros para venda fora do estabelecimento",
+            Cfop::Cfop5658 => "Transferência de combustível ou lubrificante de produção do estabelecimento",
+            Cfop::Cfop5659 => "Transferência de combustível ou lubrificante adquirido ou recebido de terceiro",
+            Cfop::Cfop5660 => "Devolução de compra de combustível ou lubrificante adquirido para industrialização subseqüente",
+            Cfop::Cfop5661 => "Devolução de compra de combustível ou lubrificante adquirido para comercialização",
+            Cfop::Cfop5662 => "Devolução de compra de combustível ou lubrificante adquirido por consumidor ou usuário final",
+            Cfop::Cfop5663 => "Remessa para armazenagem de combustível ou lubrificante",
+            Cfop::Cfop5664 => "Retorno de combustível ou lubrificante recebido para armazenagem",
+            Cfop::Cfop5665 => "Retorno simbólico de combustível ou lubrificante recebido para armazenagem",
+            Cfop::Cfop5666 => "Remessa por conta e ordem de terceiros de combustível ou lubrificante recebido para armazenagem",
+            Cfop::Cfop5667 => "Venda de combustível ou lubrificante a consumidor ou usuário final estabelecido em outra unidade da Federação",
+            Cfop::Cfop5901 => "Remessa para industrialização por encomenda",
+            Cfop::Cfop5902 => "Retorno de mercadoria utilizada na industrialização por encomenda",
+            Cfop::Cfop5903 => "Retorno de mercadoria recebida para industrialização e não aplicada no referido processo",
+            Cfop::Cfop5904 => "Remessa para venda fora do estabelecimento",
+            Cfop::Cfop5905 => "Remessa para depósito fechado ou armazém geral",
+            Cfop::Cfop5906 => "Retorno de mercadoria depositada em depósito fechado ou armazém geral",
+            Cfop::Cfop5907 => "Retorno simbólico de mercadoria depositada em depósito fechado ou armazém geral",
+            Cfop::Cfop5908 => "Remessa de bem por conta de contrato de comodato",
+            Cfop::Cfop5909 => "Retorno de bem recebido por conta de contrato de comodato",
+            Cfop::Cfop5910 => "Remessa em bonificação, doação ou brinde",
+            Cfop::Cfop5911 => "Remessa de amostra grátis",
+            Cfop::Cfop5912 => "Remessa de mercadoria ou bem para demonstração, mostruário ou treinamento",
+            Cfop::Cfop5913 => "Retorno de mercadoria ou bem recebido para demonstração ou mostruário",
+            Cfop::Cfop5914 => "Remessa de mercadoria ou bem para exposição ou feira",
+            Cfop::Cfop5915 => "Remessa de mercadoria ou bem para conserto ou reparo",
+            Cfop::Cfop5916 => "Retorno de mercadoria ou bem recebido para conserto ou reparo",
+            Cfop::Cfop5917 => "Remessa de mercadoria em consignação mercantil ou industrial",
+            Cfop::Cfop5918 => "Devolução de mercadoria recebida em consignação mercantil ou industrial",
+            Cfop::Cfop5919 => "Devolução simbólica de mercadoria vendida ou utilizada em processo industrial, recebida anteriormente em consignação mercantil ou industrial",
+            Cfop::Cfop5920 => "Remessa de vasilhame ou sacaria",
+            Cfop::Cfop5921 => "Devolução de vasilhame ou sacaria",
+            Cfop::Cfop5922 => "Lançamento efetuado a título de simples faturamento decorrente de venda para entrega futura",
+            Cfop::Cfop5923 => "Remessa de mercadoria por conta e ordem de terceiros, em venda à ordem ou em operações com armazém geral ou depósito fechado",
+            Cfop::Cfop5924 => "Remessa para industrialização por conta e ordem do adquirente da mercadoria, quando esta não transitar pelo estabelecimento do adquirente",
+            Cfop::Cfop5925 => "Retorno de mercadoria recebida para industrialização por conta e ordem do adquirente da mercadoria, quando aquela não transitar pelo estabelecimento do adquirente",
+            Cfop::Cfop5926 => "Lançamento efetuado a título de reclassificação de mercadoria decorrente de formação de kit ou de sua desagregação",
+            Cfop::Cfop5927 => "Lançamento efetuado a título de baixa de estoque decorrente de perda, roubo ou deterioração",
+            Cfop::Cfop5928 => "Lançamento efetuado a título de baixa de estoque decorrente do encerramento da atividade da empresa",
+            Cfop::Cfop5929 => "Lançamento efetuado em decorrência de emissão de documento fiscal relativo a operação ou prestação também registrada em equipamento Emissor de Cupom Fiscal - ECF",
+            Cfop::Cfop5931 => "Lançamento efetuado em decorrência da responsabilidade de retenção do imposto por substituição tributária, atribuída ao remetente ou alienante da mercadoria, pelo serviço de transporte realizado por transportador autônomo ou por transportador não inscrito na unidade da Federação onde iniciado o serviço",
+            Cfop::Cfop5932 => "Prestação de serviço de transporte iniciada em unidade da Federação diversa daquela onde inscrito o prestador",
+            Cfop::Cfop5933 => "Prestação de serviço tributado pelo ISSQN",
+            Cfop::Cfop5934 => "Remessa simbólica de mercadoria depositada em armazém geral ou depósito fechado",
+            Cfop::Cfop5949 => "Outra saída de mercadoria ou prestação de serviço não especificado",
+            Cfop::Cfop6101 => "Venda de produção do estabelecimento",
+            Cfop::Cfop6102 => "Venda de mercadoria adquirida ou recebida de terceiros",
+            Cfop::Cfop6103 => "Venda de produção do estabelecimento, efetuada fora do estabelecimento",
+            Cfop::Cfop6104 => "Venda de mercadoria adquirida ou recebida de terceiros, efetuada fora do estabelecimento",
+            Cfop::Cfop6105 => "Venda de produção do estabelecimento que não deva por ele transitar",
+            Cfop::Cfop6106 => "Venda de mercadoria adquirida ou recebida de terceiros, que não deva por ele transitar",
+            Cfop::Cfop6107 => "Venda de produção do estabelecimento, destinada a não contribuinte",
+            Cfop::Cfop6108 => "Venda de mercadoria adquirida ou recebida de terceiros, destinada a não contribuinte",
+            Cfop::Cfop6109 => "Venda de produção do estabelecimento, destinada à Zona Franca de Manaus ou Áreas de Livre Comércio",
+            Cfop::Cfop6110 => "Venda de mercadoria adquirida ou recebida de terceiros, destinada à Zona Franca de Manaus ou Áreas de Livre Comércio",
+            Cfop::Cfop6111 => "Venda de produção do estabelecimento remetida anteriormente em consignação industrial",
+            Cfop::Cfop6112 => "Venda de mercadoria adquirida ou recebida de terceiros remetida anteriormente em consignação industrial",
+            Cfop::Cfop6113 => "Venda de produção do estabelecimento remetida anteriormente em consignação mercantil",
+            Cfop::Cfop6114 => "Venda de mercadoria adquirida ou recebida de terceiros remetida anteriormente em consignação mercantil",
+            Cfop::Cfop6115 => "Venda de mercadoria adquirida ou recebida de terceiros, recebida anteriormente em consignação mercantil",
+            Cfop::Cfop6116 => "Venda de produção do estabelecimento originada de encomenda para entrega futura",
+            Cfop::Cfop6117 => "Venda de mercadoria adquirida ou recebida de terceiros, originada de encomenda para entrega futura",
+            Cfop::Cfop6118 => "Venda de produção do estabelecimento entregue ao destinatário por conta e ordem do adquirente originário, em venda à ordem",
+            Cfop::Cfop6119 => "Venda de mercadoria adquirida ou recebida de terceiros entregue ao destinatário por conta e ordem do adquirente originário, em venda à ordem",
+            Cfop::Cfop6120 => "Venda de mercadoria adquirida ou recebida de terceiros entregue ao destinatário pelo vendedor remetente, em venda à ordem",
+            Cfop::Cfop6122 => "Venda de produção do estabelecimento remetida para industrialização, por conta e ordem do adquirente, sem transitar pelo estabelecimento do adquirente",
+            Cfop::Cfop6123 => "Venda de mercadoria adquirida ou recebida de terceiros remetida para industrialização, por conta e ordem do adquirente, sem transitar pelo estabelecimento do adquirente",
+            Cfop::Cfop6124 => "Industrialização efetuada para outra empresa",
+            Cfop::Cfop6125 => "Industrialização efetuada para outra empresa quando a mercadoria recebida para utilização no processo de industrialização não transitar pelo estabelecimento adquirente da mercadoria",
+            Cfop::Cfop6129 => "Venda de insumo importado e de mercadoria industrializada sob o Regime Aduaneiro Especial de Entreposto Industrial sob Controle Informatizado do Sistema Integrado de Comércio Exterior (Recof-Sped)",
+            Cfop::Cfop6131 => "Remessa de produção do estabelecimento, com previsão de posterior ajuste ou fixação de preço, de ato cooperativo",
+            Cfop::Cfop6132 => "Fixação de preço de produção do estabelecimento, inclusive quando remetidas anteriormente com previsão de posterior ajuste ou fixação de preço, de ato cooperativo",
+            Cfop::Cfop6151 => "Transferência de produção do estabelecimento",
+            Cfop::Cfop6152 => "Transferência de mercadoria adquirida ou recebida de terceiros",
+            Cfop::Cfop6153 => "Transferência de energia elétrica",
+            Cfop::Cfop6155 => "Transferência de produção do estabelecimento, que não deva por ele transitar",
+            Cfop::Cfop6156 => "Transferência de mercadoria adquirida ou recebida de terceiros, que não deva por ele transitar",
+            Cfop::Cfop6159 => "Fornecimento de produção do estabelecimento de ato cooperativo",
+            Cfop::Cfop6160 => "Fornecimento de mercadoria adquirida ou recebida de terceiros de ato cooperativo",
+            Cfop::Cfop6201 => "Devolução de compra para industrialização ou produção rural",
+            Cfop::Cfop6202 => "Devolução de compra para comercialização",
+            Cfop::Cfop6205 => "Anulação de valor relativo a aquisição de serviço de comunicação",
+            Cfop::Cfop6206 => "Anulação de valor relativo a aquisição de serviço de transporte",
+            Cfop::Cfop6207 => "Anulação de valor relativo à compra de energia elétrica",
+            Cfop::Cfop6208 => "Devolução de mercadoria recebida em transferência para industrialização ou produção rural",
+            Cfop::Cfop6209 => "Devolução de mercadoria recebida em transferência para comercialização",
+            Cfop::Cfop6210 => "Devolução de compra para utilização na prestação de serviço",
+            Cfop::Cfop6213 => "Devolução de entrada de mercadoria com previsão de posterior ajuste ou fixação de preço, em ato cooperativo",
+            Cfop::Cfop6214 => "Devolução referente a fixação de preço de produção do estabelecimento produtor, de ato cooperativo, para comercialização",
+            Cfop::Cfop6215 => "Devolução referente a fixação de preço de produção do estabelecimento produtor, de ato cooperativo, para industrialização",
+            Cfop::Cfop6251 => "Venda de energia elétrica para distribuição ou comercialização",
+            Cfop::Cfop6252 => "Venda de energia elétrica para estabelecimento industrial",
+            Cfop::Cfop6253 => "Venda de energia elétrica para estabelecimento comercial",
+            Cfop::Cfop6254 => "Venda de energia elétrica para estabelecimento prestador de serviço de transporte",
+            Cfop::Cfop6255 => "Venda de energia elétrica para estabelecimento prestador de serviço de comunicação",
+            Cfop::Cfop6256 => "Venda de energia elétrica para estabelecimento de produtor rural",
+            Cfop::Cfop6257 => "Venda de energia elétrica para consumo por demanda contratada",
+            Cfop::Cfop6258 => "Venda de energia elétrica a não contribuinte",
+            Cfop::Cfop6301 => "Prestação de serviço de comunicação para execução de serviço da mesma natureza",
+            Cfop::Cfop6302 => "Prestação de serviço de comunicação a estabelecimento industrial",
+            Cfop::Cfop6303 => "Prestação de serviço de comunicação a estabelecimento comercial",
+            Cfop::Cfop6304 => "Prestação de serviço de comunicação a estabelecimento de prestador de serviço de transporte",
+            Cfop::Cfop6305 => "Prestação de serviço de comunicação a estabelecimento de geradora ou de distribuidora de energia elétrica",
+            Cfop::Cfop6306 => "Prestação de serviço de comunicação a estabelecimento de produtor rural",
+            Cfop::Cfop6307 => "Prestação de serviço de comunicação a não contribuinte",
+            Cfop::Cfop6351 => "Prestação de serviço de transporte para execução de serviço da mesma natureza",
+            Cfop::Cfop6352 => "Prestação de serviço de transporte a estabelecimento industrial",
+            Cfop::Cfop6353 => "Prestação de serviço de transporte a estabelecimento comercial",
+            Cfop::Cfop6354 => "Prestação de serviço de transporte a estabelecimento de prestador de serviço de comunicação",
+            Cfop::Cfop6355 => "Prestação de serviço de transporte a estabelecimento de geradora ou de distribuidora de energia elétrica",
+            Cfop::Cfop6356 => "Prestação de serviço de transporte a estabelecimento de produtor rural",
+            Cfop::Cfop6357 => "Prestação de serviço de transporte a não contribuinte",
+            Cfop::Cfop6359 => "Prestação de serviço de transporte a contribuinte ou a não contribuinte quando a mercadoria transportada está dispensada de emissão de nota fiscal",
+            Cfop::Cfop6360 => "Prestação de serviço de transporte a contribuinte substituto em relação ao serviço de transporte",
+            Cfop::Cfop6401 => "Venda de produção do estabelecimento em operação com produto sujeito ao regime de substituição tributária, na condição de contribuinte substituto",
+            Cfop::Cfop6402 => "Venda de produção do estabelecimento de produto sujeito ao regime de substituição tributária, em operação entre contribuintes substitutos do mesmo produto",
+            Cfop::Cfop6403 => "Venda de mercadoria adquirida ou recebida de terceiros em operação com mercadoria sujeita ao regime de substituição tributária, na condição de contribuinte substituto",
+            Cfop::Cfop6404 => "Venda de mercadoria sujeita ao regime de substituição tributária, cujo imposto já tenha sido retido anteriormente",
+            Cfop::Cfop6408 => "Transferência de produção do estabelecimento em operação com produto sujeito ao regime de substituição tributária",
+            Cfop::Cfop6409 => "Transferência de mercadoria adquirida ou recebida de terceiros em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop6410 => "Devolução de compra para industrialização ou produção rural em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop6411 => "Devolução de compra para comercialização em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop6412 => "Devolução de bem do ativo imobilizado, em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop6413 => "Devolução de mercadoria destinada ao uso ou consumo, em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop6414 => "Remessa de produção do estabelecimento para venda fora do estabelecimento em operação com produto sujeito ao regime de substituição tributária",
+            Cfop::Cfop6415 => "Remessa de mercadoria adquirida ou recebida de terceiros para venda fora do estabelecimento, em operação com mercadoria sujeita ao regime de substituição tributária",
+            Cfop::Cfop6501 => "Remessa de produção do estabelecimento, com fim específico de exportação",
+            Cfop::Cfop6502 => "Remessa de mercadoria adquirida ou recebida de terceiros, com fim específico de exportação",
+            Cfop::Cfop6503 => "Devolução de mercadoria recebida com fim específico de exportação",
+            Cfop::Cfop6504 => "Remessa de mercadoria para formação de lote de exportação, de produtos industrializados ou produzidos pelo próprio estabelecimento",
+            Cfop::Cfop6505 => "Remessa de mercadoria, adquirida ou recebida de terceiros, para formação de lote de exportação",
+            Cfop::Cfop6551 => "Venda de bem do ativo imobilizado",
+            Cfop::Cfop6552 => "Transferência de bem do ativo imobilizado",
+            Cfop::Cfop6553 => "Devolução de compra de bem para o ativo imobilizado",
+            Cfop::Cfop6554 => "Remessa de bem do ativo imobilizado para uso fora do estabelecimento",
+            Cfop::Cfop6555 => "Devolução de bem do ativo imobilizado de terceiro, recebido para uso no estabelecimento",
+            Cfop::Cfop6556 => "Devolução de compra de material de uso ou consumo",
+            Cfop::Cfop6557 => "Transferência de material de uso ou consumo",
+            Cfop::Cfop6603 => "Ressarcimento de ICMS retido por substituição tributária",
+            Cfop::Cfop6651 => "Venda de combustível ou lubrificante de produção do estabelecimento destinado à industrialização subseqüente",
+            Cfop::Cfop6652 => "Venda de combustível ou lubrificante de produção do estabelecimento destinado à comercialização",
+            Cfop::Cfop6653 => "Venda de combustível ou lubrificante de produção do estabelecimento destinado a consumidor ou usuário final",
+            Cfop::Cfop6654 => "Venda de combustível ou lubrificante adquirido ou recebido de terceiros destinado à industrialização subseqüente",
+            Cfop::Cfop6655 => "Venda de combustível ou lubrificante adquirido ou recebido de terceiros destinado à comercialização",
+            Cfop::Cfop6656 => "Venda de combustível ou lubrificante adquirido ou recebido de terceiros destinado a consumidor ou usuário final",
+            Cfop::Cfop6657 => "Remessa de combustível ou lubrificante adquirido ou recebido de terceiros para venda fora do estabelecimento",
+            Cfop::Cfop6658 => "Transferência de combustível ou lubrificante de produção do estabelecimento",
+            Cfop::Cfop6659 => "Transferência de combustível ou lubrificante adquirido ou recebido de terceiro",
+            Cfop::Cfop6660 => "Devolução de compra de combustível ou lubrificante adquirido para industrialização subseqüente",
+            Cfop::Cfop6661 => "Devolução de compra de combustível ou lubrificante adquirido para comercialização",
+            Cfop::Cfop6662 => "Devolução de compra de combustível ou lubrificante adquirido por consumidor ou usuário final",
+            Cfop::Cfop6663 => "Remessa para armazenagem de combustível ou lubrificante",
+            Cfop::Cfop6664 => "Retorno de combustível ou lubrificante recebido para armazenagem",
+            Cfop::Cfop6665 => "Retorno simbólico de combustível ou lubrificante recebido para armazenagem",
+            Cfop::Cfop6666 => "Remessa por conta e ordem de terceiros de combustível ou lubrificante recebido para armazenagem",
+            Cfop::Cfop6667 => "Venda de combustível ou lubrificante a consumidor ou usuário final estabelecido em outra unidade da Federação",
+            Cfop::Cfop6901 => "Remessa para industrialização por encomenda",
+            Cfop::Cfop6902 => "Retorno de mercadoria utilizada na industrialização por encomenda",
+            Cfop::Cfop6903 => "Retorno de mercadoria recebida para industrialização e não aplicada no referido processo",
+            Cfop::Cfop6904 => "Remessa para venda fora do estabelecimento",
+            Cfop::Cfop6905 => "Remessa para depósito fechado ou armazém geral",
+            Cfop::Cfop6906 => "Retorno de mercadoria depositada em depósito fechado ou armazém geral",
+            Cfop::Cfop6907 => "Retorno simbólico de mercadoria depositada em depósito fechado ou armazém geral",
+            Cfop::Cfop6908 => "Remessa de bem por conta de contrato de comodato",
+            Cfop::Cfop6909 => "Retorno de bem recebido por conta de contrato de comodato",
+            Cfop::Cfop6910 => "Remessa em bonificação, doação ou brinde",
+            Cfop::Cfop6911 => "Remessa de amostra grátis",
+            Cfop::Cfop6912 => "Remessa de mercadoria ou bem para demonstração, mostruário ou treinamento",
+            Cfop::Cfop6913 => "Retorno de mercadoria ou bem recebido para demonstração ou mostruário",
+            Cfop::Cfop6914 => "Remessa de mercadoria ou bem para exposição ou feira",
+            Cfop::Cfop6915 => "Remessa de mercadoria ou bem para conserto ou reparo",
+            Cfop::Cfop6916 => "Retorno de mercadoria ou bem recebido para conserto ou reparo",
+            Cfop::Cfop6917 => "Remessa de mercadoria em consignação mercantil ou industrial",
+            Cfop::Cfop6918 => "Devolução de mercadoria recebida em consignação mercantil ou industrial",
+            Cfop::Cfop6919 => "Devolução simbólica de mercadoria vendida ou utilizada em processo industrial, recebida anteriormente em consignação mercantil ou industrial",
+            Cfop::Cfop6920 => "Remessa de vasilhame ou sacaria",
+            Cfop::Cfop6921 => "Devolução de vasilhame ou sacaria",
+            Cfop::Cfop6922 => "Lançamento efetuado a título de simples faturamento decorrente de venda para entrega futura",
+            Cfop::Cfop6923 => "Remessa de mercadoria por conta e ordem de terceiros, em venda à ordem ou em operações com armazém geral ou depósito fechado",
+            Cfop::Cfop6924 => "Remessa para industrialização por conta e ordem do adquirente da mercadoria, quando esta não transitar pelo estabelecimento do adquirente",
+            Cfop::Cfop6925 => "Retorno de mercadoria recebida para industrialização por conta e ordem do adquirente da mercadoria, quando aquela não transitar pelo estabelecimento do adquirente",
+            Cfop::Cfop6929 => "Lançamento efetuado em decorrência de emissão de documento fiscal relativo a operação ou prestação também registrada em equipamento Emissor de Cupom Fiscal - ECF",
+            Cfop::Cfop6931 => "Lançamento efetuado em decorrência da responsabilidade de retenção do imposto por substituição tributária, atribuída ao remetente ou alienante da mercadoria, pelo serviço de transporte realizado por transportador autônomo ou por transportador não inscrito na unidade da Federação onde iniciado o serviço",
+            Cfop::Cfop6932 => "Prestação de serviço de transporte iniciada em unidade da Federação diversa daquela onde inscrito o prestador",
+            Cfop::Cfop6933 => "Prestação de serviço tributado pelo ISSQN",
+            Cfop::Cfop6934 => "Remessa simbólica de mercadoria depositada em armazém geral ou depósito fechado",
+            Cfop::Cfop6949 => "Outra saída de mercadoria ou prestação de serviço não especificado",
+            Cfop::Cfop7101 => "Venda de produção do estabelecimento",
+            Cfop::Cfop7102 => "Venda de mercadoria adquirida ou recebida de terceiros",
+            Cfop::Cfop7105 => "Venda de produção do estabelecimento que não deva por ele transitar",
+            Cfop::Cfop7106 => "Venda de mercadoria adquirida ou recebida de terceiros, que não deva por ele transitar",
+            Cfop::Cfop7127 => "Venda de produção do estabelecimento sob o regime de drawback",
+            Cfop::Cfop7129 => "Venda de produção do estabelecimento ao mercado externo de mercadoria industrializada sob o Regime Aduaneiro Especial de Entreposto Industrial sob Controle Informatizado do Sistema Integrado de Comércio Exterior (Recof-Sped)",
+            Cfop::Cfop7201 => "Devolução de compra para industrialização ou produção rural",
+            Cfop::Cfop7202 => "Devolução de compra para comercialização",
+            Cfop::Cfop7205 => "Anulação de valor relativo a aquisição de serviço de comunicação",
+            Cfop::Cfop7206 => "Anulação de valor relativo a aquisição de serviço de transporte",
+            Cfop::Cfop7207 => "Anulação de valor relativo à compra de energia elétrica",
+            Cfop::Cfop7210 => "Devolução de compra para utilização na prestação de serviço",
+            Cfop::Cfop7211 => "Devolução de compras para industrialização sob o regime de drawback",
+            Cfop::Cfop7212 => "Devolução de compras para industrialização sob o Regime Aduaneiro Especial de Entreposto Industrial sob Controle Informatizado do Sistema Integrado de Comércio Exterior (Recof-Sped)",
+            Cfop::Cfop7251 => "Venda de energia elétrica para distribuição ou comercialização",
+            Cfop::Cfop7301 => "Prestação de serviço de comunicação para execução de serviço da mesma natureza",
+            Cfop::Cfop7358 => "Prestação de serviço de transporte",
+            Cfop::Cfop7501 => "Exportação de mercadorias recebidas com fim específico de exportação",
+            Cfop::Cfop7504 => "Exportação de mercadoria que foi objeto de formação de lote de exportação",
+            Cfop::Cfop7551 => "Venda de bem do ativo imobilizado",
+            Cfop::Cfop7553 => "Devolução de compra de bem para o ativo imobilizado",
+            Cfop::Cfop7556 => "Devolução de compra de material de uso ou consumo",
+            Cfop::Cfop7651 => "Venda de combustível ou lubrificante de produção do estabelecimento destinado à industrialização subseqüente",
+            Cfop::Cfop7654 => "Venda de combustível ou lubrificante adquirido ou recebido de terceiros destinado à industrialização subseqüente",
+            Cfop::Cfop7667 => "Venda de combustível ou lubrificante a consumidor ou usuário final estabelecido em outra unidade da Federação",
+            Cfop::Cfop7930 => "Lançamento efetuado a título de devolução de bem cuja entrada tenha ocorrido sob amparo de regime especial aduaneiro de admissão temporária",
+            Cfop::Cfop7949 => "Outra saída de mercadoria ou prestação de serviço não especificado",
+        }
+    }
+
+    /// Indica se é uma operação de devolução
+    pub fn devolucao(&self) -> bool {
+        matches!(
+            self,
+            Cfop::Cfop1201
+                | Cfop::Cfop1202
+                | Cfop::Cfop1203
+                | Cfop::Cfop1204
+                | Cfop::Cfop1208
+                | Cfop::Cfop1209
+                | Cfop::Cfop1212
+                | Cfop::Cfop1213
+                | Cfop::Cfop1214
+                | Cfop::Cfop1215
+                | Cfop::Cfop1410
+                | Cfop::Cfop1411
+                | Cfop::Cfop1503
+                | Cfop::Cfop1504
+                | Cfop::Cfop1505
+                | Cfop::Cfop1506
+                | Cfop::Cfop1553
+                | Cfop::Cfop1660
+                | Cfop::Cfop1661
+                | Cfop::Cfop1662
+                | Cfop::Cfop1918
+                | Cfop::Cfop1919
+                | Cfop::Cfop2201
+                | Cfop::Cfop2202
+                | Cfop::Cfop2203
+                | Cfop::Cfop2204
+                | Cfop::Cfop2208
+                | Cfop::Cfop2209
+                | Cfop::Cfop2212
+                | Cfop::Cfop2213
+                | Cfop::Cfop2214
+                | Cfop::Cfop2215
+                | Cfop::Cfop2410
+                | Cfop::Cfop2411
+                | Cfop::Cfop2503
+                | Cfop::Cfop2504
+                | Cfop::Cfop2505
+                | Cfop::Cfop2506
+                | Cfop::Cfop2553
+                | Cfop::Cfop2660
+                | Cfop::Cfop2661
+                | Cfop::Cfop2662
+                | Cfop::Cfop2918
+                | Cfop::Cfop2919
+                | Cfop::Cfop3201
+                | Cfop::Cfop3202
+                | Cfop::Cfop3211
+                | Cfop::Cfop3212
+                | Cfop::Cfop3503
+                | Cfop::Cfop3553
+                | Cfop::Cfop5201
+                | Cfop::Cfop5202
+                | Cfop::Cfop5208
+                | Cfop::Cfop5209
+                | Cfop::Cfop5210
+                | Cfop::Cfop5213
+                | Cfop::Cfop5214
+                | Cfop::Cfop5215
+                | Cfop::Cfop5410
+                | Cfop::Cfop5411
+                | Cfop::Cfop5412
+                | Cfop::Cfop5413
+                | Cfop::Cfop5503
+                | Cfop::Cfop5553
+                | Cfop::Cfop5555
+                | Cfop::Cfop5556
+                | Cfop::Cfop5660
+                | Cfop::Cfop5661
+                | Cfop::Cfop5662
+                | Cfop::Cfop5918
+                | Cfop::Cfop5919
+                | Cfop::Cfop5921
+                | Cfop::Cfop6201
+                | Cfop::Cfop6202
+                | Cfop::Cfop6208
+                | Cfop::Cfop6209
+                | Cfop::Cfop6210
+                | Cfop::Cfop6213
+                | Cfop::Cfop6214
+                | Cfop::Cfop6215
+                | Cfop::Cfop6410
+                | Cfop::Cfop6411
+                | Cfop::Cfop6412
+                | Cfop::Cfop6413
+                | Cfop::Cfop6503
+                | Cfop::Cfop6553
+                | Cfop::Cfop6555
+                | Cfop::Cfop6556
+                | Cfop::Cfop6660
+                | Cfop::Cfop6661
+                | Cfop::Cfop6662
+                | Cfop::Cfop6918
+                | Cfop::Cfop6919
+                | Cfop::Cfop6921
+                | Cfop::Cfop7201
+                | Cfop::Cfop7202
+                | Cfop::Cfop7210
+                | Cfop::Cfop7211
+                | Cfop::Cfop7212
+                | Cfop::Cfop7553
+                | Cfop::Cfop7556
+        )
+    }
+
+    /// Indica se é uma operação de remessa
+    pub fn remessa(&self) -> bool {
+        matches!(
+            self,
+            Cfop::Cfop1131
+                | Cfop::Cfop1159
+                | Cfop::Cfop1501
+                | Cfop::Cfop1555
+                | Cfop::Cfop1663
+                | Cfop::Cfop1901
+                | Cfop::Cfop1905
+                | Cfop::Cfop1908
+                | Cfop::Cfop1910
+                | Cfop::Cfop1911
+                | Cfop::Cfop1912
+                | Cfop::Cfop1915
+                | Cfop::Cfop1917
+                | Cfop::Cfop1920
+                | Cfop::Cfop1923
+                | Cfop::Cfop1924
+                | Cfop::Cfop1934
+                | Cfop::Cfop2131
+                | Cfop::Cfop2159
+                | Cfop::Cfop2501
+                | Cfop::Cfop2555
+                | Cfop::Cfop2663
+                | Cfop::Cfop2901
+                | Cfop::Cfop2905
+                | Cfop::Cfop2908
+                | Cfop::Cfop2910
+                | Cfop::Cfop2911
+                | Cfop::Cfop2912
+                | Cfop::Cfop2915
+                | Cfop::Cfop2917
+                | Cfop::Cfop2920
+                | Cfop::Cfop2923
+                | Cfop::Cfop2924
+                | Cfop::Cfop2934
+                | Cfop::Cfop5131
+                | Cfop::Cfop5414
+                | Cfop::Cfop5415
+                | Cfop::Cfop5451
+                | Cfop::Cfop5501
+                | Cfop::Cfop5502
+                | Cfop::Cfop5504
+                | Cfop::Cfop5505
+                | Cfop::Cfop5554
+                | Cfop::Cfop5657
+                | Cfop::Cfop5663
+                | Cfop::Cfop5666
+                | Cfop::Cfop5901
+                | Cfop::Cfop5904
+                | Cfop::Cfop5905
+                | Cfop::Cfop5908
+                | Cfop::Cfop5910
+                | Cfop::Cfop5911
+                | Cfop::Cfop5912
+                | Cfop::Cfop5914
+                | Cfop::Cfop5915
+                | Cfop::Cfop5917
+                | Cfop::Cfop5920
+                | Cfop::Cfop5923
+                | Cfop::Cfop5924
+                | Cfop::Cfop5934
+                | Cfop::Cfop6131
+                | Cfop::Cfop6414
+                | Cfop::Cfop6415
+                | Cfop::Cfop6501
+                | Cfop::Cfop6502
+                | Cfop::Cfop6504
+                | Cfop::Cfop6505
+                | Cfop::Cfop6554
+                | Cfop::Cfop6657
+                | Cfop::Cfop6663
+                | Cfop::Cfop6666
+                | Cfop::Cfop6901
+                | Cfop::Cfop6904
+                | Cfop::Cfop6905
+                | Cfop::Cfop6908
+                | Cfop::Cfop6910
+                | Cfop::Cfop6911
+                | Cfop::Cfop6912
+                | Cfop::Cfop6914
+                | Cfop::Cfop6915
+                | Cfop::Cfop6917
+                | Cfop::Cfop6920
+                | Cfop::Cfop6923
+                | Cfop::Cfop6924
+                | Cfop::Cfop6934
+        )
+    }
+
+    /// Confere o CFOP com o tipo(tpNF) e o destino(idDest) da operação
+    pub fn conferir(&self, operacao: &Operacao) -> Result<(), Error> {
+        if self.tipo() != operacao.tipo {
+            return Err(Error::CfopInvalido(format!(
+                "CFOP {} de {:?} em nota de {:?}",
+                self,
+                self.tipo(),
+                operacao.tipo
+            )));
+        }
+
+        if self.destino() != operacao.destino {
+            return Err(Error::CfopInvalido(format!(
+                "CFOP {} de operação {:?} em nota com destino {:?}",
+                self,
+                self.destino(),
+                operacao.destino
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// CFOPs da tabela, em ordem crescente
+pub fn cfops() -> impl Iterator<Item = Cfop> {
+    Cfop::TODOS.iter().copied()
+}
+
+impl FromStr for Cfop {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cfop = s.trim().replace('.', "");
+
+        let valido = cfop.len() == 4
+            && cfop.chars().all(|c| c.is_ascii_digit())
+            && matches!(cfop.as_bytes()[0], b'1'..=b'3' | b'5'..=b'7');
+
+        if !valido {
+            return Err(Error::CfopInvalido(format!(
+                "Código deve possuir 4 dígitos e iniciar com 1, 2, 3, 5, 6 ou 7: {}",
+                s
+            )));
+        }
+
+        let codigo: u16 = cfop.parse().expect("Apenas dígitos");
+
+        Cfop::TODOS
+            .binary_search_by_key(&codigo, Cfop::codigo)
+            .map(|i| Cfop::TODOS[i])
+            .map_err(|_| Error::CfopInvalido(format!("Código {} não consta na tabela de CFOPs", s)))
+    }
+}
+
+impl fmt::Display for Cfop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.codigo())
+    }
+}
+
+impl PartialEq<&str> for Cfop {
+    fn eq(&self, other: &&str) -> bool {
+        other
+            .parse::<u16>()
+            .map(|c| c == self.codigo())
+            .unwrap_or(false)
+    }
+}
@@ -0,0 +1,121 @@
+//! GTIN - Global Trade Item Number
+
+use super::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// GTIN(antigo EAN) nos formatos GTIN-8, GTIN-12, GTIN-13 e GTIN-14
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub struct Gtin(String);
+
+/// Formato do GTIN, de acordo com a quantidade de dígitos
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum FormatoGtin {
+    Gtin8,
+    Gtin12,
+    Gtin13,
+    Gtin14,
+}
+
+impl Gtin {
+    /// GTIN sem formatação
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Formato do GTIN
+    pub fn formato(&self) -> FormatoGtin {
+        match self.0.len() {
+            8 => FormatoGtin::Gtin8,
+            12 => FormatoGtin::Gtin12,
+            13 => FormatoGtin::Gtin13,
+            _ => FormatoGtin::Gtin14,
+        }
+    }
+
+    /// Prefixo GS1 com 3 dígitos, que identifica a organização emissora
+    ///
+    /// Não se aplica ao GTIN-8, que possui numeração própria.
+    pub fn prefixo(&self) -> Option<String> {
+        match self.formato() {
+            FormatoGtin::Gtin8 => None,
+            _ => Some(self.corpo()[0..3].to_string()),
+        }
+    }
+
+    /// Indica se o GTIN foi emitido pela GS1 Brasil(prefixos 789 e 790)
+    pub fn brasileiro(&self) -> bool {
+        matches!(self.prefixo().as_deref(), Some("789") | Some("790"))
+    }
+
+    /// Indica se o GTIN é de circulação restrita
+    ///
+    /// Códigos de uso interno, como os de produtos pesados na loja, não
+    /// identificam o produto fora da empresa e são rejeitados pela SEFAZ.
+    pub fn circulacao_restrita(&self) -> bool {
+        match self.formato() {
+            FormatoGtin::Gtin8 => self.0.starts_with('0') || self.0.starts_with('2'),
+            _ => self.corpo().starts_with('2') || self.corpo().starts_with("02"),
+        }
+    }
+
+    /// GTIN com 13 dígitos, sem o indicador de embalagem do GTIN-14
+    fn corpo(&self) -> String {
+        match self.formato() {
+            FormatoGtin::Gtin14 => self.0[1..].to_string(),
+            _ => format!("{:0>13}", self.0),
+        }
+    }
+}
+
+/// Calcula o dígito verificador do GTIN
+///
+/// Pesos 3 e 1 alternados, da direita para a esquerda, sobre
+/// os dígitos sem o verificador.
+pub fn calcular_digito_gtin(base: &str) -> u8 {
+    let soma: u32 = base
+        .bytes()
+        .rev()
+        .zip([3, 1].iter().cycle())
+        .map(|(b, p)| (b - b'0') as u32 * p)
+        .sum();
+
+    ((10 - soma % 10) % 10) as u8
+}
+
+impl FromStr for Gtin {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let gtin = s.trim();
+
+        let invalido = |motivo: &str| Err(Error::GtinInvalido(format!("{}: {}", motivo, s)));
+
+        if !matches!(gtin.len(), 8 | 12 | 13 | 14) || !gtin.chars().all(|c| c.is_ascii_digit()) {
+            return invalido("O GTIN deve possuir 8, 12, 13 ou 14 dígitos");
+        }
+
+        if gtin.chars().all(|c| c == '0') {
+            return invalido("GTIN zerado");
+        }
+
+        let (base, digito) = gtin.split_at(gtin.len() - 1);
+        if calcular_digito_gtin(base).to_string() != digito {
+            return invalido("Dígito verificador incorreto");
+        }
+
+        Ok(Self(gtin.to_string()))
+    }
+}
+
+impl fmt::Display for Gtin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl PartialEq<&str> for Gtin {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
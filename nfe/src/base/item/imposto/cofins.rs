@@ -1,4 +1,5 @@
 /// Grupos de COFINS
+use super::cst::CstPisCofins;
 use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 
 /// COFINS
@@ -80,7 +81,7 @@ struct GrupoCofinsContainer {
 pub struct GrupoCofinsOutr {
     /// CST - Código de Situação Tributária do COFINS
     #[serde(rename = "$unflatten=CST")]
-    pub codigo_situacao: String,
+    pub codigo_situacao: CstPisCofins,
     /// Valor da base de cálculo do COFINS
     #[serde(rename = "$unflatten=vBC")]
     pub valor_base_calculo: f32,
@@ -94,7 +95,7 @@ pub struct GrupoCofinsOutr {
 pub struct GrupoCofinsNt {
     /// CST - Código de Situação Tributária do COFINS
     #[serde(rename = "$unflatten=CST")]
-    pub codigo_situacao: String,
+    pub codigo_situacao: CstPisCofins,
 }
 
 /// Grupo COFINS Aliq - Aliq Operações
@@ -102,7 +103,7 @@ pub struct GrupoCofinsNt {
 pub struct GrupoCofinsAliq {
     /// CST - Código de Situação Tributária do COFINS
     #[serde(rename = "$unflatten=CST")]
-    pub codigo_situacao: String,
+    pub codigo_situacao: CstPisCofins,
     /// Valor da base de cálculo do COFINS
     #[serde(rename = "$unflatten=vBC")]
     pub valor_base_calculo: f32,
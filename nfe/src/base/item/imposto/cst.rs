@@ -0,0 +1,699 @@
+//! CST - Código de Situação Tributária
+//!
+//! Cada código pertence a um grupo específico do layout. Ex.: o CST 01
+//! do PIS só pode ser informado no grupo PISAliq.
+
+use crate::base::Error;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// CST do ICMS
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum CstIcms {
+    /// Tributada integralmente
+    Cst00,
+    /// Tributação monofásica própria sobre combustíveis
+    Cst02,
+    /// Tributada e com cobrança do ICMS por substituição tributária
+    Cst10,
+    /// Tributação monofásica própria e com responsabilidade pela retenção sobre combustíveis
+    Cst15,
+    /// Com redução de base de cálculo
+    Cst20,
+    /// Isenta ou não tributada e com cobrança do ICMS por substituição tributária
+    Cst30,
+    /// Isenta
+    Cst40,
+    /// Não tributada
+    Cst41,
+    /// Suspensão
+    Cst50,
+    /// Diferimento
+    Cst51,
+    /// Tributação monofásica sobre combustíveis com recolhimento diferido
+    Cst53,
+    /// ICMS cobrado anteriormente por substituição tributária
+    Cst60,
+    /// Tributação monofásica sobre combustíveis cobrada anteriormente
+    Cst61,
+    /// Com redução de base de cálculo e cobrança do ICMS por substituição tributária
+    Cst70,
+    /// Outras
+    Cst90,
+}
+
+impl CstIcms {
+    /// Todos os códigos
+    pub const TODOS: [CstIcms; 15] = [
+        CstIcms::Cst00,
+        CstIcms::Cst02,
+        CstIcms::Cst10,
+        CstIcms::Cst15,
+        CstIcms::Cst20,
+        CstIcms::Cst30,
+        CstIcms::Cst40,
+        CstIcms::Cst41,
+        CstIcms::Cst50,
+        CstIcms::Cst51,
+        CstIcms::Cst53,
+        CstIcms::Cst60,
+        CstIcms::Cst61,
+        CstIcms::Cst70,
+        CstIcms::Cst90,
+    ];
+
+    /// Código como informado no XML
+    pub fn codigo(&self) -> &'static str {
+        match self {
+            CstIcms::Cst00 => "00",
+            CstIcms::Cst02 => "02",
+            CstIcms::Cst10 => "10",
+            CstIcms::Cst15 => "15",
+            CstIcms::Cst20 => "20",
+            CstIcms::Cst30 => "30",
+            CstIcms::Cst40 => "40",
+            CstIcms::Cst41 => "41",
+            CstIcms::Cst50 => "50",
+            CstIcms::Cst51 => "51",
+            CstIcms::Cst53 => "53",
+            CstIcms::Cst60 => "60",
+            CstIcms::Cst61 => "61",
+            CstIcms::Cst70 => "70",
+            CstIcms::Cst90 => "90",
+        }
+    }
+
+    /// Descrição do código
+    pub fn descricao(&self) -> &'static str {
+        match self {
+            CstIcms::Cst00 => "Tributada integralmente",
+            CstIcms::Cst02 => "Tributação monofásica própria sobre combustíveis",
+            CstIcms::Cst10 => "Tributada e com cobrança do ICMS por substituição tributária",
+            CstIcms::Cst15 => "Tributação monofásica própria e com responsabilidade pela retenção sobre combustíveis",
+            CstIcms::Cst20 => "Com redução de base de cálculo",
+            CstIcms::Cst30 => "Isenta ou não tributada e com cobrança do ICMS por substituição tributária",
+            CstIcms::Cst40 => "Isenta",
+            CstIcms::Cst41 => "Não tributada",
+            CstIcms::Cst50 => "Suspensão",
+            CstIcms::Cst51 => "Diferimento",
+            CstIcms::Cst53 => "Tributação monofásica sobre combustíveis com recolhimento diferido",
+            CstIcms::Cst60 => "ICMS cobrado anteriormente por substituição tributária",
+            CstIcms::Cst61 => "Tributação monofásica sobre combustíveis cobrada anteriormente",
+            CstIcms::Cst70 => "Com redução de base de cálculo e cobrança do ICMS por substituição tributária",
+            CstIcms::Cst90 => "Outras",
+        }
+    }
+
+    /// Grupo do ICMS em que o código deve ser informado. Ex.: ICMS40
+    pub fn grupo(&self) -> &'static str {
+        match self {
+            CstIcms::Cst00 => "ICMS00",
+            CstIcms::Cst02 => "ICMS02",
+            CstIcms::Cst10 => "ICMS10",
+            CstIcms::Cst15 => "ICMS15",
+            CstIcms::Cst20 => "ICMS20",
+            CstIcms::Cst30 => "ICMS30",
+            CstIcms::Cst40 | CstIcms::Cst41 | CstIcms::Cst50 => "ICMS40",
+            CstIcms::Cst51 => "ICMS51",
+            CstIcms::Cst53 => "ICMS53",
+            CstIcms::Cst60 => "ICMS60",
+            CstIcms::Cst61 => "ICMS61",
+            CstIcms::Cst70 => "ICMS70",
+            CstIcms::Cst90 => "ICMS90",
+        }
+    }
+}
+
+impl FromStr for CstIcms {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CstIcms::TODOS
+            .iter()
+            .find(|c| c.codigo() == s.trim())
+            .copied()
+            .ok_or_else(|| Error::CstInvalido(format!("CST do ICMS desconhecido: {}", s)))
+    }
+}
+
+impl fmt::Display for CstIcms {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.codigo())
+    }
+}
+
+impl<'de> Deserialize<'de> for CstIcms {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let codigo = String::deserialize(deserializer)?;
+
+        codigo.parse().map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for CstIcms {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.codigo())
+    }
+}
+
+/// CSOSN - Código de Situação da Operação no Simples Nacional
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum Csosn {
+    /// Tributada pelo Simples Nacional com permissão de crédito
+    Csosn101,
+    /// Tributada pelo Simples Nacional sem permissão de crédito
+    Csosn102,
+    /// Isenção do ICMS no Simples Nacional para faixa de receita bruta
+    Csosn103,
+    /// Tributada pelo Simples Nacional com permissão de crédito e com cobrança do ICMS por substituição tributária
+    Csosn201,
+    /// Tributada pelo Simples Nacional sem permissão de crédito e com cobrança do ICMS por substituição tributária
+    Csosn202,
+    /// Isenção do ICMS no Simples Nacional para faixa de receita bruta e com cobrança do ICMS por substituição tributária
+    Csosn203,
+    /// Imune
+    Csosn300,
+    /// Não tributada pelo Simples Nacional
+    Csosn400,
+    /// ICMS cobrado anteriormente por substituição tributária(substituído) ou por antecipação
+    Csosn500,
+    /// Outros
+    Csosn900,
+}
+
+impl Csosn {
+    /// Todos os códigos
+    pub const TODOS: [Csosn; 10] = [
+        Csosn::Csosn101,
+        Csosn::Csosn102,
+        Csosn::Csosn103,
+        Csosn::Csosn201,
+        Csosn::Csosn202,
+        Csosn::Csosn203,
+        Csosn::Csosn300,
+        Csosn::Csosn400,
+        Csosn::Csosn500,
+        Csosn::Csosn900,
+    ];
+
+    /// Código como informado no XML
+    pub fn codigo(&self) -> &'static str {
+        match self {
+            Csosn::Csosn101 => "101",
+            Csosn::Csosn102 => "102",
+            Csosn::Csosn103 => "103",
+            Csosn::Csosn201 => "201",
+            Csosn::Csosn202 => "202",
+            Csosn::Csosn203 => "203",
+            Csosn::Csosn300 => "300",
+            Csosn::Csosn400 => "400",
+            Csosn::Csosn500 => "500",
+            Csosn::Csosn900 => "900",
+        }
+    }
+
+    /// Descrição do código
+    pub fn descricao(&self) -> &'static str {
+        match self {
+            Csosn::Csosn101 => "Tributada pelo Simples Nacional com permissão de crédito",
+            Csosn::Csosn102 => "Tributada pelo Simples Nacional sem permissão de crédito",
+            Csosn::Csosn103 => "Isenção do ICMS no Simples Nacional para faixa de receita bruta",
+            Csosn::Csosn201 => "Tributada pelo Simples Nacional com permissão de crédito e com cobrança do ICMS por substituição tributária",
+            Csosn::Csosn202 => "Tributada pelo Simples Nacional sem permissão de crédito e com cobrança do ICMS por substituição tributária",
+            Csosn::Csosn203 => "Isenção do ICMS no Simples Nacional para faixa de receita bruta e com cobrança do ICMS por substituição tributária",
+            Csosn::Csosn300 => "Imune",
+            Csosn::Csosn400 => "Não tributada pelo Simples Nacional",
+            Csosn::Csosn500 => "ICMS cobrado anteriormente por substituição tributária(substituído) ou por antecipação",
+            Csosn::Csosn900 => "Outros",
+        }
+    }
+
+    /// Grupo do ICMS em que o código deve ser informado. Ex.: ICMSSN202
+    pub fn grupo(&self) -> &'static str {
+        match self {
+            Csosn::Csosn101 => "ICMSSN101",
+            Csosn::Csosn102 | Csosn::Csosn103 | Csosn::Csosn300 | Csosn::Csosn400 => "ICMSSN102",
+            Csosn::Csosn201 => "ICMSSN201",
+            Csosn::Csosn202 | Csosn::Csosn203 => "ICMSSN202",
+            Csosn::Csosn500 => "ICMSSN500",
+            Csosn::Csosn900 => "ICMSSN900",
+        }
+    }
+}
+
+impl FromStr for Csosn {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Csosn::TODOS
+            .iter()
+            .find(|c| c.codigo() == s.trim())
+            .copied()
+            .ok_or_else(|| Error::CstInvalido(format!("CSOSN desconhecido: {}", s)))
+    }
+}
+
+impl fmt::Display for Csosn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.codigo())
+    }
+}
+
+impl<'de> Deserialize<'de> for Csosn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let codigo = String::deserialize(deserializer)?;
+
+        codigo.parse().map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Csosn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.codigo())
+    }
+}
+
+/// CST do PIS e da COFINS
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum CstPisCofins {
+    /// Operação Tributável com Alíquota Básica
+    Cst01,
+    /// Operação Tributável com Alíquota Diferenciada
+    Cst02,
+    /// Operação Tributável com Alíquota por Unidade de Medida de Produto
+    Cst03,
+    /// Operação Tributável Monofásica - Revenda a Alíquota Zero
+    Cst04,
+    /// Operação Tributável por Substituição Tributária
+    Cst05,
+    /// Operação Tributável a Alíquota Zero
+    Cst06,
+    /// Operação Isenta da Contribuição
+    Cst07,
+    /// Operação sem Incidência da Contribuição
+    Cst08,
+    /// Operação com Suspensão da Contribuição
+    Cst09,
+    /// Outras Operações de Saída
+    Cst49,
+    /// Operação com Direito a Crédito - Vinculada Exclusivamente a Receita Tributada no Mercado Interno
+    Cst50,
+    /// Operação com Direito a Crédito - Vinculada Exclusivamente a Receita Não Tributada no Mercado Interno
+    Cst51,
+    /// Operação com Direito a Crédito - Vinculada Exclusivamente a Receita de Exportação
+    Cst52,
+    /// Operação com Direito a Crédito - Vinculada a Receitas Tributadas e Não-Tributadas no Mercado Interno
+    Cst53,
+    /// Operação com Direito a Crédito - Vinculada a Receitas Tributadas no Mercado Interno e de Exportação
+    Cst54,
+    /// Operação com Direito a Crédito - Vinculada a Receitas Não-Tributadas no Mercado Interno e de Exportação
+    Cst55,
+    /// Operação com Direito a Crédito - Vinculada a Receitas Tributadas e Não-Tributadas no Mercado Interno, e de Exportação
+    Cst56,
+    /// Crédito Presumido - Operação de Aquisição Vinculada Exclusivamente a Receita Tributada no Mercado Interno
+    Cst60,
+    /// Crédito Presumido - Operação de Aquisição Vinculada Exclusivamente a Receita Não-Tributada no Mercado Interno
+    Cst61,
+    /// Crédito Presumido - Operação de Aquisição Vinculada Exclusivamente a Receita de Exportação
+    Cst62,
+    /// Crédito Presumido - Operação de Aquisição Vinculada a Receitas Tributadas e Não-Tributadas no Mercado Interno
+    Cst63,
+    /// Crédito Presumido - Operação de Aquisição Vinculada a Receitas Tributadas no Mercado Interno e de Exportação
+    Cst64,
+    /// Crédito Presumido - Operação de Aquisição Vinculada a Receitas Não-Tributadas no Mercado Interno e de Exportação
+    Cst65,
+    /// Crédito Presumido - Operação de Aquisição Vinculada a Receitas Tributadas e Não-Tributadas no Mercado Interno, e de Exportação
+    Cst66,
+    /// Crédito Presumido - Outras Operações
+    Cst67,
+    /// Operação de Aquisição sem Direito a Crédito
+    Cst70,
+    /// Operação de Aquisição com Isenção
+    Cst71,
+    /// Operação de Aquisição com Suspensão
+    Cst72,
+    /// Operação de Aquisição a Alíquota Zero
+    Cst73,
+    /// Operação de Aquisição sem Incidência da Contribuição
+    Cst74,
+    /// Operação de Aquisição por Substituição Tributária
+    Cst75,
+    /// Outras Operações de Entrada
+    Cst98,
+    /// Outras Operações
+    Cst99,
+}
+
+impl CstPisCofins {
+    /// Todos os códigos
+    pub const TODOS: [CstPisCofins; 33] = [
+        CstPisCofins::Cst01,
+        CstPisCofins::Cst02,
+        CstPisCofins::Cst03,
+        CstPisCofins::Cst04,
+        CstPisCofins::Cst05,
+        CstPisCofins::Cst06,
+        CstPisCofins::Cst07,
+        CstPisCofins::Cst08,
+        CstPisCofins::Cst09,
+        CstPisCofins::Cst49,
+        CstPisCofins::Cst50,
+        CstPisCofins::Cst51,
+        CstPisCofins::Cst52,
+        CstPisCofins::Cst53,
+        CstPisCofins::Cst54,
+        CstPisCofins::Cst55,
+        CstPisCofins::Cst56,
+        CstPisCofins::Cst60,
+        CstPisCofins::Cst61,
+        CstPisCofins::Cst62,
+        CstPisCofins::Cst63,
+        CstPisCofins::Cst64,
+        CstPisCofins::Cst65,
+        CstPisCofins::Cst66,
+        CstPisCofins::Cst67,
+        CstPisCofins::Cst70,
+        CstPisCofins::Cst71,
+        CstPisCofins::Cst72,
+        CstPisCofins::Cst73,
+        CstPisCofins::Cst74,
+        CstPisCofins::Cst75,
+        CstPisCofins::Cst98,
+        CstPisCofins::Cst99,
+    ];
+
+    /// Código como informado no XML
+    pub fn codigo(&self) -> &'static str {
+        match self {
+            CstPisCofins::Cst01 => "01",
+            CstPisCofins::Cst02 => "02",
+            CstPisCofins::Cst03 => "03",
+            CstPisCofins::Cst04 => "04",
+            CstPisCofins::Cst05 => "05",
+            CstPisCofins::Cst06 => "06",
+            CstPisCofins::Cst07 => "07",
+            CstPisCofins::Cst08 => "08",
+            CstPisCofins::Cst09 => "09",
+            CstPisCofins::Cst49 => "49",
+            CstPisCofins::Cst50 => "50",
+            CstPisCofins::Cst51 => "51",
+            CstPisCofins::Cst52 => "52",
+            CstPisCofins::Cst53 => "53",
+            CstPisCofins::Cst54 => "54",
+            CstPisCofins::Cst55 => "55",
+            CstPisCofins::Cst56 => "56",
+            CstPisCofins::Cst60 => "60",
+            CstPisCofins::Cst61 => "61",
+            CstPisCofins::Cst62 => "62",
+            CstPisCofins::Cst63 => "63",
+            CstPisCofins::Cst64 => "64",
+            CstPisCofins::Cst65 => "65",
+            CstPisCofins::Cst66 => "66",
+            CstPisCofins::Cst67 => "67",
+            CstPisCofins::Cst70 => "70",
+            CstPisCofins::Cst71 => "71",
+            CstPisCofins::Cst72 => "72",
+            CstPisCofins::Cst73 => "73",
+            CstPisCofins::Cst74 => "74",
+            CstPisCofins::Cst75 => "75",
+            CstPisCofins::Cst98 => "98",
+            CstPisCofins::Cst99 => "99",
+        }
+    }
+
+    /// Descrição do código
+    pub fn descricao(&self) -> &'static str {
+        match self {
+            CstPisCofins::Cst01 => "Operação Tributável com Alíquota Básica",
+            CstPisCofins::Cst02 => "Operação Tributável com Alíquota Diferenciada",
+            CstPisCofins::Cst03 => "Operação Tributável com Alíquota por Unidade de Medida de Produto",
+            CstPisCofins::Cst04 => "Operação Tributável Monofásica - Revenda a Alíquota Zero",
+            CstPisCofins::Cst05 => "Operação Tributável por Substituição Tributária",
+            CstPisCofins::Cst06 => "Operação Tributável a Alíquota Zero",
+            CstPisCofins::Cst07 => "Operação Isenta da Contribuição",
+            CstPisCofins::Cst08 => "Operação sem Incidência da Contribuição",
+            CstPisCofins::Cst09 => "Operação com Suspensão da Contribuição",
+            CstPisCofins::Cst49 => "Outras Operações de Saída",
+            CstPisCofins::Cst50 => "Operação com Direito a Crédito - Vinculada Exclusivamente a Receita Tributada no Mercado Interno",
+            CstPisCofins::Cst51 => "Operação com Direito a Crédito - Vinculada Exclusivamente a Receita Não Tributada no Mercado Interno",
+            CstPisCofins::Cst52 => "Operação com Direito a Crédito - Vinculada Exclusivamente a Receita de Exportação",
+            CstPisCofins::Cst53 => "Operação com Direito a Crédito - Vinculada a Receitas Tributadas e Não-Tributadas no Mercado Interno",
+            CstPisCofins::Cst54 => "Operação com Direito a Crédito - Vinculada a Receitas Tributadas no Mercado Interno e de Exportação",
+            CstPisCofins::Cst55 => "Operação com Direito a Crédito - Vinculada a Receitas Não-Tributadas no Mercado Interno e de Exportação",
+            CstPisCofins::Cst56 => "Operação com Direito a Crédito - Vinculada a Receitas Tributadas e Não-Tributadas no Mercado Interno, e de Exportação",
+            CstPisCofins::Cst60 => "Crédito Presumido - Operação de Aquisição Vinculada Exclusivamente a Receita Tributada no Mercado Interno",
+            CstPisCofins::Cst61 => "Crédito Presumido - Operação de Aquisição Vinculada Exclusivamente a Receita Não-Tributada no Mercado Interno",
+            CstPisCofins::Cst62 => "Crédito Presumido - Operação de Aquisição Vinculada Exclusivamente a Receita de Exportação",
+            CstPisCofins::Cst63 => "Crédito Presumido - Operação de Aquisição Vinculada a Receitas Tributadas e Não-Tributadas no Mercado Interno",
+            CstPisCofins::Cst64 => "Crédito Presumido - Operação de Aquisição Vinculada a Receitas Tributadas no Mercado Interno e de Exportação",
+            CstPisCofins::Cst65 => "Crédito Presumido - Operação de Aquisição Vinculada a Receitas Não-Tributadas no Mercado Interno e de Exportação",
+            CstPisCofins::Cst66 => "Crédito Presumido - Operação de Aquisição Vinculada a Receitas Tributadas e Não-Tributadas no Mercado Interno, e de Exportação",
+            CstPisCofins::Cst67 => "Crédito Presumido - Outras Operações",
+            CstPisCofins::Cst70 => "Operação de Aquisição sem Direito a Crédito",
+            CstPisCofins::Cst71 => "Operação de Aquisição com Isenção",
+            CstPisCofins::Cst72 => "Operação de Aquisição com Suspensão",
+            CstPisCofins::Cst73 => "Operação de Aquisição a Alíquota Zero",
+            CstPisCofins::Cst74 => "Operação de Aquisição sem Incidência da Contribuição",
+            CstPisCofins::Cst75 => "Operação de Aquisição por Substituição Tributária",
+            CstPisCofins::Cst98 => "Outras Operações de Entrada",
+            CstPisCofins::Cst99 => "Outras Operações",
+        }
+    }
+
+    /// Sufixo do grupo em que o código deve ser informado: Aliq, Qtde, NT ou Outr
+    pub fn grupo(&self) -> &'static str {
+        match self {
+            CstPisCofins::Cst01 | CstPisCofins::Cst02 => "Aliq",
+            CstPisCofins::Cst03 => "Qtde",
+            CstPisCofins::Cst04
+            | CstPisCofins::Cst05
+            | CstPisCofins::Cst06
+            | CstPisCofins::Cst07
+            | CstPisCofins::Cst08
+            | CstPisCofins::Cst09 => "NT",
+            CstPisCofins::Cst49
+            | CstPisCofins::Cst50
+            | CstPisCofins::Cst51
+            | CstPisCofins::Cst52
+            | CstPisCofins::Cst53
+            | CstPisCofins::Cst54
+            | CstPisCofins::Cst55
+            | CstPisCofins::Cst56
+            | CstPisCofins::Cst60
+            | CstPisCofins::Cst61
+            | CstPisCofins::Cst62
+            | CstPisCofins::Cst63
+            | CstPisCofins::Cst64
+            | CstPisCofins::Cst65
+            | CstPisCofins::Cst66
+            | CstPisCofins::Cst67
+            | CstPisCofins::Cst70
+            | CstPisCofins::Cst71
+            | CstPisCofins::Cst72
+            | CstPisCofins::Cst73
+            | CstPisCofins::Cst74
+            | CstPisCofins::Cst75
+            | CstPisCofins::Cst98
+            | CstPisCofins::Cst99 => "Outr",
+        }
+    }
+}
+
+impl FromStr for CstPisCofins {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CstPisCofins::TODOS
+            .iter()
+            .find(|c| c.codigo() == s.trim())
+            .copied()
+            .ok_or_else(|| Error::CstInvalido(format!("CST do PIS/COFINS desconhecido: {}", s)))
+    }
+}
+
+impl fmt::Display for CstPisCofins {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.codigo())
+    }
+}
+
+impl<'de> Deserialize<'de> for CstPisCofins {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let codigo = String::deserialize(deserializer)?;
+
+        codigo.parse().map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for CstPisCofins {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.codigo())
+    }
+}
+
+/// CST do IPI
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum CstIpi {
+    /// Entrada com recuperação de crédito
+    Cst00,
+    /// Entrada tributada com alíquota zero
+    Cst01,
+    /// Entrada isenta
+    Cst02,
+    /// Entrada não-tributada
+    Cst03,
+    /// Entrada imune
+    Cst04,
+    /// Entrada com suspensão
+    Cst05,
+    /// Outras entradas
+    Cst49,
+    /// Saída tributada
+    Cst50,
+    /// Saída tributada com alíquota zero
+    Cst51,
+    /// Saída isenta
+    Cst52,
+    /// Saída não-tributada
+    Cst53,
+    /// Saída imune
+    Cst54,
+    /// Saída com suspensão
+    Cst55,
+    /// Outras saídas
+    Cst99,
+}
+
+impl CstIpi {
+    /// Todos os códigos
+    pub const TODOS: [CstIpi; 14] = [
+        CstIpi::Cst00,
+        CstIpi::Cst01,
+        CstIpi::Cst02,
+        CstIpi::Cst03,
+        CstIpi::Cst04,
+        CstIpi::Cst05,
+        CstIpi::Cst49,
+        CstIpi::Cst50,
+        CstIpi::Cst51,
+        CstIpi::Cst52,
+        CstIpi::Cst53,
+        CstIpi::Cst54,
+        CstIpi::Cst55,
+        CstIpi::Cst99,
+    ];
+
+    /// Código como informado no XML
+    pub fn codigo(&self) -> &'static str {
+        match self {
+            CstIpi::Cst00 => "00",
+            CstIpi::Cst01 => "01",
+            CstIpi::Cst02 => "02",
+            CstIpi::Cst03 => "03",
+            CstIpi::Cst04 => "04",
+            CstIpi::Cst05 => "05",
+            CstIpi::Cst49 => "49",
+            CstIpi::Cst50 => "50",
+            CstIpi::Cst51 => "51",
+            CstIpi::Cst52 => "52",
+            CstIpi::Cst53 => "53",
+            CstIpi::Cst54 => "54",
+            CstIpi::Cst55 => "55",
+            CstIpi::Cst99 => "99",
+        }
+    }
+
+    /// Descrição do código
+    pub fn descricao(&self) -> &'static str {
+        match self {
+            CstIpi::Cst00 => "Entrada com recuperação de crédito",
+            CstIpi::Cst01 => "Entrada tributada com alíquota zero",
+            CstIpi::Cst02 => "Entrada isenta",
+            CstIpi::Cst03 => "Entrada não-tributada",
+            CstIpi::Cst04 => "Entrada imune",
+            CstIpi::Cst05 => "Entrada com suspensão",
+            CstIpi::Cst49 => "Outras entradas",
+            CstIpi::Cst50 => "Saída tributada",
+            CstIpi::Cst51 => "Saída tributada com alíquota zero",
+            CstIpi::Cst52 => "Saída isenta",
+            CstIpi::Cst53 => "Saída não-tributada",
+            CstIpi::Cst54 => "Saída imune",
+            CstIpi::Cst55 => "Saída com suspensão",
+            CstIpi::Cst99 => "Outras saídas",
+        }
+    }
+
+    /// Grupo do IPI em que o código deve ser informado: IPITrib ou IPINT
+    pub fn grupo(&self) -> &'static str {
+        match self {
+            CstIpi::Cst00 | CstIpi::Cst49 | CstIpi::Cst50 | CstIpi::Cst99 => "IPITrib",
+            CstIpi::Cst01
+            | CstIpi::Cst02
+            | CstIpi::Cst03
+            | CstIpi::Cst04
+            | CstIpi::Cst05
+            | CstIpi::Cst51
+            | CstIpi::Cst52
+            | CstIpi::Cst53
+            | CstIpi::Cst54
+            | CstIpi::Cst55 => "IPINT",
+        }
+    }
+}
+
+impl FromStr for CstIpi {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CstIpi::TODOS
+            .iter()
+            .find(|c| c.codigo() == s.trim())
+            .copied()
+            .ok_or_else(|| Error::CstInvalido(format!("CST do IPI desconhecido: {}", s)))
+    }
+}
+
+impl fmt::Display for CstIpi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.codigo())
+    }
+}
+
+impl<'de> Deserialize<'de> for CstIpi {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let codigo = String::deserialize(deserializer)?;
+
+        codigo.parse().map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for CstIpi {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.codigo())
+    }
+}
@@ -1,5 +1,6 @@
 //! Grupos de ICMS
 
+use super::cst::{Csosn, CstIcms};
 use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
@@ -67,6 +68,9 @@ pub struct GrupoIcms60 {
     /// Origem da mercadoria
     #[serde(rename = "$unflatten=orig")]
     pub origem: OrigemMercadoria,
+    /// CST - Código de Situação Tributária do ICMS
+    #[serde(rename = "$unflatten=CST")]
+    pub codigo_situacao: CstIcms,
     /// Valor da base de cálculo do ICMS ST retido
     #[serde(rename = "$unflatten=vBCSTRet")]
     pub valor_base_calculo: f32,
@@ -86,7 +90,7 @@ pub struct GrupoIcmsSn202 {
     pub origem: OrigemMercadoria,
     /// Código de Situação da Operação – Simples Nacional
     #[serde(rename = "$unflatten=CSOSN")]
-    pub codigo_situacao: String,
+    pub codigo_situacao: Csosn,
     /// Modalidade de determinação da BC do ICMS ST
     #[serde(rename = "$unflatten=modBCST")]
     pub base_calculo: ModalidadeBaseCalculoIcmsSt,
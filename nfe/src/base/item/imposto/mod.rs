@@ -2,13 +2,16 @@
 
 use super::Error;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 
 mod cofins;
+mod cst;
 mod icms;
 mod pis;
 
 pub use cofins::*;
+pub use cst::*;
 pub use icms::*;
 pub use pis::*;
 
@@ -30,6 +33,65 @@ pub struct Imposto {
     pub cofins: Option<GrupoCofins>,
 }
 
+impl Imposto {
+    /// Valida se cada CST foi informado no grupo ao qual pertence
+    pub fn validar(&self) -> Result<(), Error> {
+        match &self.icms {
+            Some(GrupoIcms::IcmsSn202(g)) => {
+                conferir_grupo(g.codigo_situacao, g.codigo_situacao.grupo(), "ICMSSN202")?
+            }
+            Some(GrupoIcms::Icms60(g)) => {
+                conferir_grupo(g.codigo_situacao, g.codigo_situacao.grupo(), "ICMS60")?
+            }
+            None => {}
+        }
+
+        let pis = match &self.pis {
+            Some(GrupoPis::PisOutr(g)) => Some((g.codigo_situacao, "Outr")),
+            Some(GrupoPis::PisNt(g)) => Some((g.codigo_situacao, "NT")),
+            Some(GrupoPis::PisAliq(g)) => Some((g.codigo_situacao, "Aliq")),
+            None => None,
+        };
+
+        if let Some((cst, grupo)) = pis {
+            conferir_grupo(
+                cst,
+                &format!("PIS{}", cst.grupo()),
+                &format!("PIS{}", grupo),
+            )?;
+        }
+
+        let cofins = match &self.cofins {
+            Some(GrupoCofins::CofinsOutr(g)) => Some((g.codigo_situacao, "Outr")),
+            Some(GrupoCofins::CofinsNt(g)) => Some((g.codigo_situacao, "NT")),
+            Some(GrupoCofins::CofinsAliq(g)) => Some((g.codigo_situacao, "Aliq")),
+            None => None,
+        };
+
+        if let Some((cst, grupo)) = cofins {
+            conferir_grupo(
+                cst,
+                &format!("COFINS{}", cst.grupo()),
+                &format!("COFINS{}", grupo),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Confere se o grupo em que o código foi informado é o esperado
+fn conferir_grupo(codigo: impl fmt::Display, esperado: &str, informado: &str) -> Result<(), Error> {
+    if esperado != informado {
+        return Err(Error::CstInvalido(format!(
+            "Código {} informado no grupo {}, mas pertence ao grupo {}",
+            codigo, informado, esperado
+        )));
+    }
+
+    Ok(())
+}
+
 impl FromStr for Imposto {
     type Err = Error;
 
@@ -38,8 +100,10 @@ impl FromStr for Imposto {
     }
 }
 
-impl ToString for Imposto {
-    fn to_string(&self) -> String {
-        quick_xml::se::to_string(self).expect("Falha ao serializar o imposto")
+impl fmt::Display for Imposto {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar o imposto");
+
+        f.write_str(&xml)
     }
 }
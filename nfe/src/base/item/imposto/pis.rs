@@ -1,4 +1,5 @@
 /// Grupos de PIS
+use super::cst::CstPisCofins;
 use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 
 /// PIS
@@ -80,7 +81,7 @@ struct GrupoPisContainer {
 pub struct GrupoPisOutr {
     /// CST - Código de Situação Tributária do PIS
     #[serde(rename = "$unflatten=CST")]
-    pub codigo_situacao: String,
+    pub codigo_situacao: CstPisCofins,
     /// Valor da base de cálculo do PIS
     #[serde(rename = "$unflatten=vBC")]
     pub valor_base_calculo: f32,
@@ -94,7 +95,7 @@ pub struct GrupoPisOutr {
 pub struct GrupoPisNt {
     /// CST - Código de Situação Tributária do PIS
     #[serde(rename = "$unflatten=CST")]
-    pub codigo_situacao: String,
+    pub codigo_situacao: CstPisCofins,
 }
 
 /// Grupo PIS Aliq - Aliq Operações
@@ -102,7 +103,7 @@ pub struct GrupoPisNt {
 pub struct GrupoPisAliq {
     /// CST - Código de Situação Tributária do PIS
     #[serde(rename = "$unflatten=CST")]
-    pub codigo_situacao: String,
+    pub codigo_situacao: CstPisCofins,
     /// Valor da base de cálculo do PIS
     #[serde(rename = "$unflatten=vBC")]
     pub valor_base_calculo: f32,
@@ -1,13 +1,23 @@
 //! Detalhamento de produtos e serviços
 
+use super::ide::Identificacao;
 use super::Error;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 
+mod cest;
+mod cfop;
+mod gtin;
 mod imposto;
+mod ncm;
 mod produto;
 
+pub use cest::*;
+pub use cfop::*;
+pub use gtin::*;
 pub use imposto::*;
+pub use ncm::*;
 pub use produto::*;
 
 /// Item da nota
@@ -22,6 +32,33 @@ pub struct Item {
     pub imposto: Imposto,
 }
 
+impl Item {
+    /// Valida o GTIN, o CFOP e a classificação fiscal do produto
+    ///
+    /// Os erros indicam o número do item
+    pub fn validar(&self, ide: &Identificacao) -> Result<(), Error> {
+        self.validar_produto(ide).map_err(|e| match e {
+            Error::GtinInvalido(e) => Error::GtinInvalido(self.identificar(e)),
+            Error::CfopInvalido(e) => Error::CfopInvalido(self.identificar(e)),
+            Error::NcmInvalido(e) => Error::NcmInvalido(self.identificar(e)),
+            Error::CestInvalido(e) => Error::CestInvalido(self.identificar(e)),
+            Error::CstInvalido(e) => Error::CstInvalido(self.identificar(e)),
+            e => e,
+        })
+    }
+
+    fn validar_produto(&self, ide: &Identificacao) -> Result<(), Error> {
+        self.produto.validar_gtin()?;
+        self.produto.cfop()?.conferir(&ide.operacao)?;
+        self.produto.validar_classificacao()?;
+        self.imposto.validar()
+    }
+
+    fn identificar(&self, mensagem: String) -> String {
+        format!("Item {}: {}", self.numero, mensagem)
+    }
+}
+
 impl FromStr for Item {
     type Err = Error;
 
@@ -30,8 +67,10 @@ impl FromStr for Item {
     }
 }
 
-impl ToString for Item {
-    fn to_string(&self) -> String {
-        quick_xml::se::to_string(self).expect("Falha ao serializar o item")
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar o item");
+
+        f.write_str(&xml)
     }
 }
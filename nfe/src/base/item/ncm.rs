@@ -0,0 +1,44 @@
+//! NCM - Nomenclatura Comum do Mercosul
+//!
+//! O código é validado pelo formato e pelo capítulo. Sem a tabela completa
+//! da Receita Federal embarcada, a existência e a vigência do código não
+//! são conferidas.
+
+use super::Error;
+
+/// NCM informado para serviços e itens que não são mercadorias
+pub const NCM_SERVICO: &str = "00";
+
+/// Capítulo da NCM, representado pelos 2 primeiros dígitos
+///
+/// Os capítulos vão de 01 a 97, sendo o 77 reservado para uso futuro.
+pub fn capitulo_ncm_valido(capitulo: u8) -> bool {
+    (1..=97).contains(&capitulo) && capitulo != 77
+}
+
+/// Valida o formato e o capítulo do NCM
+pub fn validar_ncm(codigo: &str) -> Result<(), Error> {
+    let ncm = codigo.trim().replace('.', "");
+
+    if ncm == NCM_SERVICO {
+        return Ok(());
+    }
+
+    if ncm.len() != 8 || !ncm.chars().all(|c| c.is_ascii_digit()) {
+        return Err(Error::NcmInvalido(format!(
+            "Código deve possuir 8 dígitos: {}",
+            codigo
+        )));
+    }
+
+    let capitulo = ncm[0..2].parse().expect("Apenas dígitos");
+
+    if !capitulo_ncm_valido(capitulo) {
+        return Err(Error::NcmInvalido(format!(
+            "Capítulo {:02} inexistente: {}",
+            capitulo, codigo
+        )));
+    }
+
+    Ok(())
+}
@@ -1,8 +1,14 @@
 //! Produtos
 
+use super::cest::validar_cest;
+use super::cfop::Cfop;
+use super::gtin::Gtin;
+use super::ncm::validar_ncm;
 use super::Error;
+use crate::base::documento::Cnpj;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::fmt;
 use std::str::FromStr;
 
 /// Detalhamento do produto do item
@@ -17,7 +23,7 @@ pub struct Produto {
     /// NCM - Nomenclatura Comum do Mercosul
     pub ncm: String,
     /// CNPJ do Fabricante da Mercadoria
-    pub fabricante_cnpj: Option<String>,
+    pub fabricante_cnpj: Option<Cnpj>,
     /// Dados sobre a tributação do produto
     pub tributacao: ProdutoTributacao,
     /// Unidade de medida da comercialização
@@ -71,6 +77,79 @@ pub enum EscalaRelevante {
     Nao = 2,
 }
 
+impl Produto {
+    /// CFOP do item, com o formato validado
+    pub fn cfop(&self) -> Result<Cfop, Error> {
+        self.tributacao.cfop.parse()
+    }
+
+    /// Valida o formato do NCM e do CEST
+    pub fn validar_classificacao(&self) -> Result<(), Error> {
+        validar_ncm(&self.ncm)?;
+
+        if let Some(cest) = &self.tributacao.cest {
+            validar_cest(cest)?;
+        }
+
+        Ok(())
+    }
+
+    /// GTIN da unidade comercial(cEAN), com o dígito verificador validado
+    pub fn gtin_comercial(&self) -> Result<Option<Gtin>, Error> {
+        self.gtin.as_deref().map(str::parse).transpose()
+    }
+
+    /// GTIN da unidade tributável(cEANTrib), com o dígito verificador validado
+    pub fn gtin_tributavel(&self) -> Result<Option<Gtin>, Error> {
+        self.tributacao.gtin.as_deref().map(str::parse).transpose()
+    }
+
+    /// Valida o cEAN e o cEANTrib, conforme as rejeições 611 e 883 da SEFAZ
+    ///
+    /// Os dois GTINs devem ser válidos e fora da circulação restrita. Ou
+    /// ambos são informados ou nenhum, e com a mesma unidade comercial e
+    /// tributável devem ser iguais.
+    pub fn validar_gtin(&self) -> Result<(), Error> {
+        let validar = |campo: &str, gtin: Result<Option<Gtin>, Error>| match gtin {
+            Ok(Some(gtin)) if gtin.circulacao_restrita() => Err(Error::GtinInvalido(format!(
+                "{} de circulação restrita: {}",
+                campo, gtin
+            ))),
+            Ok(gtin) => Ok(gtin),
+            Err(Error::GtinInvalido(e)) => Err(Error::GtinInvalido(format!("{}: {}", campo, e))),
+            Err(e) => Err(e),
+        };
+
+        let comercial = validar("cEAN", self.gtin_comercial())?;
+        let tributavel = validar("cEANTrib", self.gtin_tributavel())?;
+
+        match (comercial, tributavel) {
+            (Some(comercial), Some(tributavel)) => {
+                let mesma_unidade = self
+                    .unidade
+                    .trim()
+                    .eq_ignore_ascii_case(self.tributacao.unidade.trim());
+
+                if mesma_unidade && comercial != tributavel {
+                    return Err(Error::GtinInvalido(format!(
+                        "cEAN({}) e cEANTrib({}) diferentes para a mesma unidade {}",
+                        comercial, tributavel, self.unidade
+                    )));
+                }
+
+                Ok(())
+            }
+            (Some(_), None) => Err(Error::GtinInvalido(
+                "cEAN informado sem o cEANTrib".to_string(),
+            )),
+            (None, Some(_)) => Err(Error::GtinInvalido(
+                "cEANTrib informado sem o cEAN".to_string(),
+            )),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
 impl FromStr for Produto {
     type Err = Error;
 
@@ -79,9 +158,11 @@ impl FromStr for Produto {
     }
 }
 
-impl ToString for Produto {
-    fn to_string(&self) -> String {
-        quick_xml::se::to_string(self).expect("Falha ao serializar o produto")
+impl fmt::Display for Produto {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar o produto");
+
+        f.write_str(&xml)
     }
 }
 
@@ -146,8 +227,8 @@ impl Serialize for Produto {
             ncm: self.ncm.clone(),
             fabricante_cnpj: self.fabricante_cnpj.clone(),
             unidade: self.unidade.clone(),
-            quantidade: self.quantidade.clone(),
-            valor_unitario: self.valor_unitario.clone(),
+            quantidade: self.quantidade,
+            valor_unitario: self.valor_unitario,
             valor_bruto: self.valor_bruto,
             valor_frete: self.valor_frete,
             valor_seguro: self.valor_seguro,
@@ -185,7 +266,7 @@ struct ProdContainer {
     pub ncm: String,
     #[serde(rename = "$unflatten=CNPJFab")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fabricante_cnpj: Option<String>,
+    pub fabricante_cnpj: Option<Cnpj>,
     #[serde(rename = "$unflatten=uCom")]
     pub unidade: String,
     #[serde(rename = "$unflatten=qCom")]
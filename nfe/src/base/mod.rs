@@ -3,27 +3,61 @@
 //! Tipos e estruturas para tratamento da NF-e sem
 //! distinção dos modelos.
 
+use openssl::x509::store::X509StoreRef;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::convert::TryFrom;
+use std::fmt;
 use std::fs::File;
 use std::io::Read;
 use std::str::FromStr;
+pub mod assinatura;
+pub mod autorizacao;
+pub mod c14n;
+pub mod cadastro;
+pub mod cana;
+pub mod certificado;
+pub mod chave;
+pub mod compra;
 pub mod dest;
+pub mod distribuicao;
+pub mod documento;
 pub mod emit;
 pub mod endereco;
 mod error;
+pub mod evento;
+pub mod exporta;
 pub mod ide;
+pub mod ie;
+pub mod inutilizacao;
 pub mod item;
+pub mod municipio;
+pub mod pagamento;
+pub mod pais;
+pub mod protocolo;
+pub mod situacao;
+pub mod status;
 pub mod totais;
 pub mod transporte;
+pub mod uf;
+pub mod webservice;
+use assinatura::{Assinatura, VerificacaoAssinatura};
+use cana::Cana;
+use certificado::CertificadoA1;
+use chave::ChaveAcesso;
+use compra::Compra;
 use dest::Destinatario;
 use emit::Emitente;
 pub use error::Error;
-use ide::Identificacao;
+use exporta::Exportacao;
+use ide::{ComposicaoChaveAcesso, DestinoOperacao, Identificacao};
 use item::Item;
+use pagamento::Pagamento;
 use totais::Totalizacao;
 use transporte::Transporte;
 
+/// Namespace dos documentos da NF-e
+pub const NAMESPACE_NFE: &str = "http://www.portalfiscal.inf.br/nfe";
+
 /// Base da Nota Fiscal Eletrônica
 ///
 /// Representa o documento ainda sem a interface
@@ -38,8 +72,18 @@ pub struct Nfe {
     pub itens: Vec<Item>,
     pub totais: Totalizacao,
     pub transporte: Transporte,
+    /// Formas de pagamento, obrigatórias desde o leiaute 4.00
+    pub pagamento: Option<Pagamento>,
     /// Informações complementares de interesse do contribuinte
     pub informacao_complementar: Option<String>,
+    /// Informações de comércio exterior
+    pub exportacao: Option<Exportacao>,
+    /// Informações de compras públicas e privadas
+    pub compra: Option<Compra>,
+    /// Registro de aquisição de cana
+    pub cana: Option<Cana>,
+    /// Assinatura digital do documento
+    pub assinatura: Option<Assinatura>,
 }
 
 /// Versão do layout da NF-e
@@ -49,11 +93,184 @@ pub enum VersaoLayout {
     V4_00 = 4,
 }
 
+impl Nfe {
+    /// Chave de acesso do documento, com o dígito verificador validado
+    pub fn chave(&self) -> Result<ChaveAcesso, Error> {
+        self.chave_acesso.parse()
+    }
+
+    /// Gera uma nova chave de acesso a partir da identificação e do emitente
+    ///
+    /// Preenche a chave_acesso e os campos cNF e cDV da identificação
+    pub fn gerar_chave_acesso(&mut self) -> Result<ChaveAcesso, Error> {
+        let chave = ChaveAcesso::gerar(
+            self.ide.codigo_uf,
+            self.ide.emissao.horario,
+            self.emit.documento.as_str(),
+            self.ide.modelo,
+            self.ide.serie,
+            self.ide.numero,
+            self.ide.emissao.tipo,
+        )?;
+
+        self.chave_acesso = chave.to_string();
+        self.ide.chave = ComposicaoChaveAcesso {
+            codigo: chave.codigo.clone(),
+            digito_verificador: chave.digito_verificador,
+        };
+
+        Ok(chave)
+    }
+
+    /// Assina o grupo infNFe com o certificado do emitente
+    ///
+    /// Substitui a assinatura existente. Qualquer alteração posterior
+    /// na nota invalida a assinatura, que deixa de ser escrita no XML.
+    pub fn assinar(&mut self, certificado: &CertificadoA1) -> Result<(), Error> {
+        self.assinatura = None;
+
+        let xml = self.to_string();
+        let id = format!("NFe{}", self.chave_acesso);
+        let assinatura = assinatura::assinar(&xml, &id, certificado)?;
+        self.assinatura = Some(match trecho_assinado(&xml) {
+            Some(trecho) => assinatura.com_original(trecho, xml.clone()),
+            None => assinatura,
+        });
+
+        Ok(())
+    }
+
+    /// Lê a assinatura do XML original da nota
+    ///
+    /// O infNFe é guardado como assinado, para ser reescrito sem alterações
+    /// enquanto a nota não for modificada.
+    pub(crate) fn ler_assinatura(&mut self, xml: &str) -> Result<(), Error> {
+        self.assinatura = None;
+
+        if let Some(assinatura) = assinatura::extrair(xml, "</infNFe>")? {
+            self.assinatura = Some(match trecho_assinado(xml) {
+                Some(trecho) => assinatura.com_original(trecho, self.to_string()),
+                None => assinatura,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Verifica a assinatura de uma nota recebida, a partir do seu XML original
+    ///
+    /// Confere o digest do infNFe, o SignatureValue, o CNPJ do certificado
+    /// com o do emitente e a validade do certificado na emissão. Qualquer
+    /// alteração no XML após a assinatura, inclusive de formatação, a invalida.
+    ///
+    /// A cadeia do certificado não é conferida, ver [`Nfe::verificar_assinatura_cadeia`].
+    pub fn verificar_assinatura(xml: &str) -> Result<VerificacaoAssinatura, Error> {
+        Self::verificar(xml, None)
+    }
+
+    /// Verifica a assinatura e a cadeia do certificado do assinante
+    ///
+    /// Além de [`Nfe::verificar_assinatura`], confere se o certificado foi
+    /// emitido por uma das autoridades em `confiaveis`, como as da ICP-Brasil.
+    pub fn verificar_assinatura_cadeia(
+        xml: &str,
+        confiaveis: &X509StoreRef,
+    ) -> Result<VerificacaoAssinatura, Error> {
+        Self::verificar(xml, Some(confiaveis))
+    }
+
+    fn verificar(
+        xml: &str,
+        confiaveis: Option<&X509StoreRef>,
+    ) -> Result<VerificacaoAssinatura, Error> {
+        let nfe: Nfe = xml.parse()?;
+
+        let assinatura = nfe
+            .assinatura
+            .as_ref()
+            .ok_or_else(|| Error::AssinaturaInvalida("Nota sem assinatura".to_string()))?;
+
+        assinatura.verificar(
+            xml,
+            &format!("NFe{}", nfe.chave_acesso),
+            &nfe.emit.documento,
+            nfe.ide.emissao.horario,
+            confiaveis,
+        )
+    }
+
+    /// Valida a consistência do documento
+    ///
+    /// Retorna o primeiro problema encontrado
+    pub fn validar(&self) -> Result<(), Error> {
+        self.chave()?.conferir(&self.ide, &self.emit)?;
+
+        let uf = self.ide.uf()?;
+        if self.emit.endereco.uf()? != uf {
+            return Err(Error::UfInvalida(format!(
+                "UF do emitente({}) difere do cUF({})",
+                self.emit.endereco.sigla_uf, self.ide.codigo_uf
+            )));
+        }
+
+        municipio::validar_municipio(self.ide.codigo_municipio, uf)?;
+        self.emit.endereco.validar_municipio()?;
+        self.emit.endereco.validar_pais()?;
+
+        if let Some(dest) = &self.dest {
+            if let Some(endereco) = &dest.endereco {
+                endereco.validar_municipio()?;
+                endereco.validar_pais()?;
+
+                let com_exterior = self.ide.operacao.destino == DestinoOperacao::ComExterior;
+                if endereco.exterior() != com_exterior {
+                    return Err(Error::PaisInvalido(format!(
+                        "Destino da operação({:?}) incompatível com a UF do destinatário({})",
+                        self.ide.operacao.destino, endereco.sigla_uf
+                    )));
+                }
+
+                // Destinatário do exterior é identificado pelo idEstrangeiro
+                if endereco.exterior() != dest.documento.estrangeiro().is_some() {
+                    return Err(Error::DocumentoInvalido(format!(
+                        "Documento do destinatário({}) incompatível com a UF({})",
+                        dest.documento, endereco.sigla_uf
+                    )));
+                }
+            }
+        }
+
+        for item in &self.itens {
+            item.validar(&self.ide)?;
+        }
+
+        if let Some(pagamento) = &self.pagamento {
+            pagamento.validar()?;
+        }
+
+        ie::validar_ie_de("Emitente", &self.emit.endereco.sigla_uf, &self.emit.ie)?;
+
+        if let Some(dest) = &self.dest {
+            dest.validar_ie()?;
+        }
+
+        Ok(())
+    }
+}
+
 impl FromStr for Nfe {
     type Err = Error;
 
+    /// Aceita a NF-e avulsa(<NFe>) ou com o protocolo(<nfeProc>)
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        quick_xml::de::from_str(s).map_err(|e| e.into())
+        if c14n::elemento_raiz(s)? == "nfeProc" {
+            return Ok(s.parse::<protocolo::NfeProc>()?.nfe);
+        }
+
+        let mut nfe: Nfe = quick_xml::de::from_str(s)?;
+        nfe.ler_assinatura(s)?;
+
+        Ok(nfe)
     }
 }
 
@@ -62,18 +279,49 @@ impl TryFrom<File> for Nfe {
 
     fn try_from(mut f: File) -> Result<Self, Self::Error> {
         let mut xml = String::new();
-        f.read_to_string(&mut xml).map_err(|e| Error::Io(e))?;
+        f.read_to_string(&mut xml).map_err(Error::Io)?;
 
         xml.parse::<Nfe>()
     }
 }
 
-impl ToString for Nfe {
-    fn to_string(&self) -> String {
-        quick_xml::se::to_string(self).expect("Falha ao serializar a nota")
+impl fmt::Display for Nfe {
+    /// Nota sem alterações desde a leitura ou a assinatura é escrita como
+    /// assinada. Se alterada, a assinatura só é mantida se o DigestValue
+    /// ainda conferir com o infNFe gerado.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar a nota");
+
+        let assinatura = match &self.assinatura {
+            Some(assinatura) => assinatura,
+            None => return f.write_str(&xml),
+        };
+
+        if let Some(original) = assinatura.original(&xml) {
+            return write!(f, "{}{}</NFe>", original, assinatura);
+        }
+
+        // A assinatura é escrita antes do fechamento da tag <NFe>
+        match (c14n::digest(&xml, "infNFe"), xml.rfind("</NFe>")) {
+            (Ok(digest), Some(fim)) if digest == assinatura.digest() => {
+                write!(f, "{}{}{}", &xml[..fim], assinatura, &xml[fim..])
+            }
+            _ => f.write_str(&xml),
+        }
     }
 }
 
+/// Trecho da nota da abertura da tag <NFe> ao fechamento do </infNFe>
+fn trecho_assinado(xml: &str) -> Option<&str> {
+    let inicio = xml
+        .match_indices("<NFe")
+        .map(|(pos, _)| pos)
+        .find(|pos| matches!(xml[pos + 4..].chars().next(), Some(' ') | Some('>')))?;
+    let fim = inicio + xml[inicio..].find("</infNFe>")? + "</infNFe>".len();
+
+    Some(&xml[inicio..fim])
+}
+
 impl<'de> Deserialize<'de> for Nfe {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -90,10 +338,16 @@ impl<'de> Deserialize<'de> for Nfe {
             itens: nfe.inf.itens,
             totais: nfe.inf.totais,
             transporte: nfe.inf.transporte,
+            pagamento: nfe.inf.pagamento,
             informacao_complementar: match nfe.inf.add {
                 Some(add) => add.informacao_complementar,
                 None => None,
             },
+            exportacao: nfe.inf.exportacao,
+            compra: nfe.inf.compra,
+            cana: nfe.inf.cana,
+            // Apenas o FromStr tem acesso ao XML original da assinatura
+            assinatura: None,
         })
     }
 }
@@ -112,34 +366,51 @@ impl Serialize for Nfe {
             itens: self.itens.clone(),
             totais: self.totais.clone(),
             transporte: self.transporte.clone(),
-            add: match self.informacao_complementar.clone() {
-                Some(ic) => Some(InfAddContainer {
+            pagamento: self.pagamento.clone(),
+            add: self
+                .informacao_complementar
+                .clone()
+                .map(|ic| InfAddContainer {
                     informacao_complementar: Some(ic),
                 }),
-                None => None,
-            },
+            exportacao: self.exportacao.clone(),
+            compra: self.compra.clone(),
+            cana: self.cana.clone(),
         };
 
-        let root = NfeRootContainer { inf };
+        let root = NfeRootContainer {
+            namespace: NAMESPACE_NFE.to_string(),
+            inf,
+        };
 
         root.serialize(serializer)
     }
 }
 
+impl VersaoLayout {
+    /// Versão como informada no XML
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VersaoLayout::V4_00 => "4.00",
+        }
+    }
+}
+
 impl Serialize for VersaoLayout {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_str(match self {
-            VersaoLayout::V4_00 => "4.00",
-        })
+        serializer.serialize_str(self.as_str())
     }
 }
 
 #[derive(Deserialize, Serialize)]
 #[serde(rename = "NFe")]
 struct NfeRootContainer {
+    #[serde(rename = "xmlns")]
+    #[serde(skip_deserializing)]
+    pub namespace: String,
     #[serde(rename = "infNFe")]
     pub inf: NfeInfContainer,
 }
@@ -168,6 +439,18 @@ struct NfeInfContainer {
     pub totais: Totalizacao,
     #[serde(rename = "transp")]
     pub transporte: Transporte,
+    #[serde(rename = "pag")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pagamento: Option<Pagamento>,
     #[serde(rename = "infAdic")]
     pub add: Option<InfAddContainer>,
+    #[serde(rename = "exporta")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exportacao: Option<Exportacao>,
+    #[serde(rename = "compra")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compra: Option<Compra>,
+    #[serde(rename = "cana")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cana: Option<Cana>,
 }
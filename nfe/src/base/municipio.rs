@@ -0,0 +1,61 @@
+//! Códigos de município do IBGE
+//!
+//! O código é validado pelo dígito verificador e pela UF. Sem a tabela
+//! completa do IBGE embarcada, o nome do município(xMun) não é conferido.
+
+use super::uf::Uf;
+use super::Error;
+
+/// Código do município usado nas operações com o exterior
+pub const CODIGO_EXTERIOR: u32 = 9_999_999;
+
+/// Códigos oficiais do IBGE cujo dígito verificador não confere com o cálculo
+const EXCECOES_DIGITO: [u32; 9] = [
+    2201919, 2201988, 2202251, 2611533, 3117836, 3152131, 4305871, 5203939, 5203962,
+];
+
+/// Calcula o dígito verificador do código do município
+///
+/// Pesos 1 e 2 alternados sobre os 6 primeiros dígitos, somando
+/// os algarismos de cada produto.
+pub fn calcular_digito_municipio(codigo: u32) -> u8 {
+    let soma: u32 = format!("{:06}", codigo / 10)
+        .bytes()
+        .map(|b| (b - b'0') as u32)
+        .zip([1, 2].iter().cycle())
+        .map(|(d, p)| {
+            let produto = d * p;
+            produto / 10 + produto % 10
+        })
+        .sum();
+
+    ((10 - soma % 10) % 10) as u8
+}
+
+/// Verifica o formato e o dígito verificador do código do município
+pub fn codigo_municipio_valido(codigo: u32) -> bool {
+    if !(1_000_000..=9_999_999).contains(&codigo) {
+        return false;
+    }
+
+    EXCECOES_DIGITO.contains(&codigo) || calcular_digito_municipio(codigo) as u32 == codigo % 10
+}
+
+/// Valida o código do município e a sua UF
+pub fn validar_municipio(codigo: u32, uf: Uf) -> Result<(), Error> {
+    if !codigo_municipio_valido(codigo) {
+        return Err(Error::MunicipioInvalido(format!(
+            "Código {} com dígito verificador inválido",
+            codigo
+        )));
+    }
+
+    if codigo / 100_000 != uf.codigo() as u32 {
+        return Err(Error::MunicipioInvalido(format!(
+            "Código {} não pertence à UF {}",
+            codigo, uf
+        )));
+    }
+
+    Ok(())
+}
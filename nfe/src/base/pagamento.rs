@@ -0,0 +1,222 @@
+//! Informações de pagamento da nota
+
+use super::Error;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::fmt;
+use std::str::FromStr;
+
+/// Quantidade máxima de formas de pagamento(detPag)
+pub const MAXIMO_DETALHES_PAGAMENTO: usize = 100;
+
+/// Pagamento da nota, a tag <pag>
+///
+/// Obrigatório desde o leiaute 4.00. Notas de ajuste ou devolução
+/// informam o meio `SemPagamento`.
+#[derive(Debug, Deserialize, PartialEq, Serialize, Clone)]
+#[serde(rename = "pag")]
+pub struct Pagamento {
+    /// Formas de pagamento
+    #[serde(rename = "detPag")]
+    pub detalhes: Vec<DetalhePagamento>,
+    /// Valor do troco
+    #[serde(rename = "$unflatten=vTroco")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub troco: Option<f32>,
+}
+
+/// Forma de pagamento, a tag <detPag>
+#[derive(Debug, Deserialize, PartialEq, Serialize, Clone)]
+pub struct DetalhePagamento {
+    /// Pagamento à vista ou a prazo
+    #[serde(rename = "$unflatten=indPag")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indicador: Option<IndicadorPagamento>,
+    #[serde(rename = "$unflatten=tPag")]
+    pub meio: MeioPagamento,
+    /// Descrição do meio de pagamento, obrigatória para `Outros`
+    #[serde(rename = "$unflatten=xPag")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descricao: Option<String>,
+    /// Valor do pagamento
+    #[serde(rename = "$unflatten=vPag")]
+    pub valor: f32,
+}
+
+/// Indicador da forma de pagamento
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Deserialize_repr, Serialize_repr)]
+#[repr(u8)]
+pub enum IndicadorPagamento {
+    AVista = 0,
+    APrazo = 1,
+}
+
+/// Meio de pagamento(tPag)
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum MeioPagamento {
+    Dinheiro,
+    Cheque,
+    CartaoCredito,
+    CartaoDebito,
+    CreditoLoja,
+    ValeAlimentacao,
+    ValeRefeicao,
+    ValePresente,
+    ValeCombustivel,
+    BoletoBancario,
+    DepositoBancario,
+    /// Pagamento instantâneo(PIX) dinâmico
+    Pix,
+    /// Transferência bancária ou carteira digital
+    Transferencia,
+    /// Programa de fidelidade, cashback ou crédito virtual
+    Fidelidade,
+    /// Pagamento instantâneo(PIX) estático
+    PixEstatico,
+    /// Crédito em loja por devolução de mercadoria
+    CreditoDevolucao,
+    /// Pagamento eletrônico não informado, por falha no equipamento
+    EletronicoNaoInformado,
+    /// Notas de ajuste ou devolução, sem pagamento
+    SemPagamento,
+    Outros,
+}
+
+impl Pagamento {
+    /// Valida as formas de pagamento
+    pub fn validar(&self) -> Result<(), Error> {
+        if self.detalhes.is_empty() || self.detalhes.len() > MAXIMO_DETALHES_PAGAMENTO {
+            return Err(Error::PagamentoInvalido(format!(
+                "Informe de 1 a {} formas de pagamento",
+                MAXIMO_DETALHES_PAGAMENTO
+            )));
+        }
+
+        for detalhe in &self.detalhes {
+            let sem_descricao = detalhe
+                .descricao
+                .as_ref()
+                .map(|d| d.trim().is_empty())
+                .unwrap_or(true);
+
+            if detalhe.meio == MeioPagamento::Outros && sem_descricao {
+                return Err(Error::PagamentoInvalido(
+                    "Meio de pagamento 99(Outros) sem a descrição(xPag)".to_string(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Soma dos valores pagos, sem descontar o troco
+    pub fn valor_pago(&self) -> f32 {
+        self.detalhes.iter().map(|d| d.valor).sum()
+    }
+}
+
+impl MeioPagamento {
+    /// Todos os meios
+    pub const TODOS: [MeioPagamento; 19] = [
+        MeioPagamento::Dinheiro,
+        MeioPagamento::Cheque,
+        MeioPagamento::CartaoCredito,
+        MeioPagamento::CartaoDebito,
+        MeioPagamento::CreditoLoja,
+        MeioPagamento::ValeAlimentacao,
+        MeioPagamento::ValeRefeicao,
+        MeioPagamento::ValePresente,
+        MeioPagamento::ValeCombustivel,
+        MeioPagamento::BoletoBancario,
+        MeioPagamento::DepositoBancario,
+        MeioPagamento::Pix,
+        MeioPagamento::Transferencia,
+        MeioPagamento::Fidelidade,
+        MeioPagamento::PixEstatico,
+        MeioPagamento::CreditoDevolucao,
+        MeioPagamento::EletronicoNaoInformado,
+        MeioPagamento::SemPagamento,
+        MeioPagamento::Outros,
+    ];
+
+    /// Código como informado no XML
+    pub fn codigo(&self) -> &'static str {
+        match self {
+            MeioPagamento::Dinheiro => "01",
+            MeioPagamento::Cheque => "02",
+            MeioPagamento::CartaoCredito => "03",
+            MeioPagamento::CartaoDebito => "04",
+            MeioPagamento::CreditoLoja => "05",
+            MeioPagamento::ValeAlimentacao => "10",
+            MeioPagamento::ValeRefeicao => "11",
+            MeioPagamento::ValePresente => "12",
+            MeioPagamento::ValeCombustivel => "13",
+            MeioPagamento::BoletoBancario => "15",
+            MeioPagamento::DepositoBancario => "16",
+            MeioPagamento::Pix => "17",
+            MeioPagamento::Transferencia => "18",
+            MeioPagamento::Fidelidade => "19",
+            MeioPagamento::PixEstatico => "20",
+            MeioPagamento::CreditoDevolucao => "21",
+            MeioPagamento::EletronicoNaoInformado => "22",
+            MeioPagamento::SemPagamento => "90",
+            MeioPagamento::Outros => "99",
+        }
+    }
+}
+
+impl FromStr for Pagamento {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl fmt::Display for Pagamento {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar o pagamento");
+
+        f.write_str(&xml)
+    }
+}
+
+impl FromStr for MeioPagamento {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MeioPagamento::TODOS
+            .iter()
+            .find(|m| m.codigo() == s.trim())
+            .copied()
+            .ok_or_else(|| {
+                Error::PagamentoInvalido(format!("Meio de pagamento desconhecido: {}", s))
+            })
+    }
+}
+
+impl fmt::Display for MeioPagamento {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.codigo())
+    }
+}
+
+impl<'de> Deserialize<'de> for MeioPagamento {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let codigo = String::deserialize(deserializer)?;
+
+        codigo.parse().map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for MeioPagamento {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.codigo())
+    }
+}
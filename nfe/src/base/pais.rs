@@ -0,0 +1,107 @@
+//! Países da tabela do BACEN
+
+use super::Error;
+use std::sync::OnceLock;
+
+/// Código do Brasil na tabela do BACEN
+pub const CODIGO_BRASIL: u32 = 1058;
+
+/// Nome do Brasil na tabela do BACEN
+pub const NOME_BRASIL: &str = "BRASIL";
+
+/// Tabela de países embarcada, no formato `codigo;nome`
+///
+/// A tabela não contém os códigos mais recentes do BACEN. Para os códigos
+/// ausentes, o nome informado não é conferido.
+const TABELA: &str = include_str!("../../dados/paises.csv");
+
+/// País da tabela do BACEN
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct Pais {
+    /// Código com até 4 dígitos
+    pub codigo: u32,
+    pub nome: &'static str,
+}
+
+/// Países presentes na tabela embarcada, em ordem de código
+pub fn paises() -> impl Iterator<Item = Pais> {
+    tabela().iter().copied()
+}
+
+/// Busca o país na tabela embarcada pelo código
+pub fn buscar_pais(codigo: u32) -> Option<Pais> {
+    let paises = tabela();
+
+    paises
+        .binary_search_by_key(&codigo, |p| p.codigo)
+        .ok()
+        .map(|i| paises[i])
+}
+
+/// Busca o país na tabela embarcada pelo nome, ignorando acentos e caixa
+pub fn buscar_pais_por_nome(nome: &str) -> Option<Pais> {
+    let nome = normalizar_nome(nome);
+
+    paises().find(|p| normalizar_nome(p.nome) == nome)
+}
+
+/// Valida o código e, quando presente na tabela, o nome do país
+pub fn validar_pais(codigo: u32, nome: &str) -> Result<(), Error> {
+    if codigo == 0 || codigo > 9999 {
+        return Err(Error::PaisInvalido(format!(
+            "Código deve possuir até 4 dígitos: {}",
+            codigo
+        )));
+    }
+
+    if let Some(pais) = buscar_pais(codigo) {
+        if normalizar_nome(nome) != normalizar_nome(pais.nome) {
+            return Err(Error::PaisInvalido(format!(
+                "Nome {} difere do código {}({})",
+                nome, codigo, pais.nome
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Tabela lida uma única vez, na primeira consulta
+fn tabela() -> &'static [Pais] {
+    static PAISES: OnceLock<Vec<Pais>> = OnceLock::new();
+
+    PAISES.get_or_init(|| {
+        let mut paises: Vec<Pais> = TABELA
+            .lines()
+            .skip(1)
+            .filter_map(|linha| {
+                let mut campos = linha.splitn(2, ';');
+                let codigo = campos.next()?.trim().parse().ok()?;
+                let nome = campos.next()?.trim();
+
+                Some(Pais { codigo, nome })
+            })
+            .collect();
+
+        paises.sort_by_key(|p| p.codigo);
+        paises
+    })
+}
+
+/// Remove acentos, caixa e espaços repetidos para comparar nomes
+fn normalizar_nome(nome: &str) -> String {
+    nome.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ã' | 'ä' | 'Á' | 'À' | 'Â' | 'Ã' | 'Ä' => 'a',
+            'é' | 'è' | 'ê' | 'ë' | 'É' | 'È' | 'Ê' | 'Ë' => 'e',
+            'í' | 'ì' | 'î' | 'ï' | 'Í' | 'Ì' | 'Î' | 'Ï' => 'i',
+            'ó' | 'ò' | 'ô' | 'õ' | 'ö' | 'Ó' | 'Ò' | 'Ô' | 'Õ' | 'Ö' => 'o',
+            'ú' | 'ù' | 'û' | 'ü' | 'Ú' | 'Ù' | 'Û' | 'Ü' => 'u',
+            'ç' | 'Ç' => 'c',
+            c => c.to_ascii_lowercase(),
+        })
+        .collect()
+}
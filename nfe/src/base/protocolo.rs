@@ -0,0 +1,250 @@
+//! Protocolo de autorização e documento para distribuição(nfeProc)
+
+use super::c14n;
+use super::ide::TipoAmbiente;
+use super::{Error, Nfe, VersaoLayout, NAMESPACE_NFE};
+use chrono::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Protocolo de processamento da NF-e(protNFe) devolvido pela SEFAZ
+#[derive(Debug, PartialEq, Clone)]
+pub struct ProtocoloNfe {
+    pub versao: VersaoLayout,
+    /// Identificador da tag <infProt>
+    pub id: Option<String>,
+    pub ambiente: TipoAmbiente,
+    /// Versão do aplicativo que processou a NF-e
+    pub versao_aplicativo: String,
+    pub chave_acesso: String,
+    /// Data e hora do processamento
+    pub recebimento: DateTime<FixedOffset>,
+    /// Número do protocolo. Não informado nas rejeições
+    pub numero: Option<String>,
+    /// Digest value da NF-e processada
+    pub digest: Option<String>,
+    /// Código do status(cStat)
+    pub status: u16,
+    /// Descrição do status
+    pub motivo: String,
+}
+
+impl ProtocoloNfe {
+    /// Indica se o uso da NF-e foi autorizado, inclusive fora do prazo
+    pub fn autorizado(&self) -> bool {
+        matches!(self.status, 100 | 150)
+    }
+
+    /// Indica se o uso da NF-e foi denegado
+    pub fn denegado(&self) -> bool {
+        matches!(self.status, 110 | 301 | 302 | 303)
+    }
+
+    /// Confere o digVal do protocolo com o infNFe do XML original da nota
+    ///
+    /// Garante que o protocolo foi emitido para este conteúdo da nota e não
+    /// apenas para a mesma chave de acesso.
+    pub fn conferir_digest(&self, xml: &str) -> Result<bool, Error> {
+        let digest = c14n::digest(xml, "infNFe")?;
+
+        Ok(self.digest.as_deref() == Some(digest.as_str()))
+    }
+}
+
+/// NF-e com o seu protocolo de autorização, formato usado na distribuição
+#[derive(Debug, PartialEq)]
+pub struct NfeProc {
+    pub versao: VersaoLayout,
+    pub nfe: Nfe,
+    pub protocolo: ProtocoloNfe,
+}
+
+impl NfeProc {
+    /// Anexa o protocolo à nota
+    ///
+    /// O protocolo deve ser da mesma chave de acesso, de uso autorizado
+    /// ou denegado e o seu digVal deve conferir com o infNFe da nota.
+    pub fn new(nfe: Nfe, protocolo: ProtocoloNfe) -> Result<Self, Error> {
+        if protocolo.chave_acesso != nfe.chave_acesso {
+            return Err(Error::ProtocoloInvalido(format!(
+                "Chave do protocolo({}) difere da nota({})",
+                protocolo.chave_acesso, nfe.chave_acesso
+            )));
+        }
+
+        if !protocolo.autorizado() && !protocolo.denegado() {
+            return Err(Error::ProtocoloInvalido(format!(
+                "Protocolo sem autorização de uso: {} - {}",
+                protocolo.status, protocolo.motivo
+            )));
+        }
+
+        if !protocolo.conferir_digest(&nfe.to_string())? {
+            return Err(Error::ProtocoloInvalido(format!(
+                "digVal do protocolo({}) não confere com a nota",
+                protocolo.digest.as_deref().unwrap_or_default()
+            )));
+        }
+
+        Ok(Self {
+            versao: protocolo.versao,
+            nfe,
+            protocolo,
+        })
+    }
+}
+
+impl Nfe {
+    /// Anexa o protocolo de autorização, gerando o documento para distribuição
+    pub fn anexar_protocolo(self, protocolo: ProtocoloNfe) -> Result<NfeProc, Error> {
+        NfeProc::new(self, protocolo)
+    }
+}
+
+impl FromStr for ProtocoloNfe {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl fmt::Display for ProtocoloNfe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar o protocolo");
+
+        f.write_str(&xml)
+    }
+}
+
+impl FromStr for NfeProc {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let proc: NfeProcContainer = quick_xml::de::from_str(s)?;
+
+        let mut nfe = proc.nfe;
+        nfe.ler_assinatura(s)?;
+
+        Ok(Self {
+            versao: proc.versao,
+            nfe,
+            protocolo: proc.protocolo,
+        })
+    }
+}
+
+impl fmt::Display for NfeProc {
+    /// A NF-e é escrita pelo seu próprio Display, preservando a assinatura
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "<nfeProc xmlns=\"{}\" versao=\"{}\">{}{}</nfeProc>",
+            NAMESPACE_NFE,
+            self.versao.as_str(),
+            self.nfe,
+            self.protocolo
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for ProtocoloNfe {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let prot = ProtNfeContainer::deserialize(deserializer)?;
+        let inf = prot.inf;
+
+        Ok(Self {
+            versao: prot.versao,
+            id: inf.id,
+            ambiente: inf.ambiente,
+            versao_aplicativo: inf.versao_aplicativo,
+            chave_acesso: inf.chave_acesso,
+            recebimento: inf.recebimento,
+            numero: inf.numero,
+            digest: inf.digest,
+            status: inf.status,
+            motivo: inf.motivo,
+        })
+    }
+}
+
+impl Serialize for ProtocoloNfe {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let prot = ProtNfeContainer {
+            versao: self.versao,
+            inf: InfProtContainer {
+                id: self.id.clone(),
+                ambiente: self.ambiente,
+                versao_aplicativo: self.versao_aplicativo.clone(),
+                chave_acesso: self.chave_acesso.clone(),
+                recebimento: self.recebimento,
+                numero: self.numero.clone(),
+                digest: self.digest.clone(),
+                status: self.status,
+                motivo: self.motivo.clone(),
+            },
+        };
+
+        prot.serialize(serializer)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "nfeProc")]
+struct NfeProcContainer {
+    #[serde(rename = "versao")]
+    pub versao: VersaoLayout,
+    #[serde(rename = "NFe")]
+    pub nfe: Nfe,
+    #[serde(rename = "protNFe")]
+    pub protocolo: ProtocoloNfe,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "protNFe")]
+struct ProtNfeContainer {
+    #[serde(rename = "versao")]
+    pub versao: VersaoLayout,
+    #[serde(rename = "infProt")]
+    pub inf: InfProtContainer,
+}
+
+#[derive(Deserialize, Serialize)]
+struct InfProtContainer {
+    #[serde(rename = "Id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "$unflatten=tpAmb")]
+    pub ambiente: TipoAmbiente,
+    #[serde(rename = "$unflatten=verAplic")]
+    pub versao_aplicativo: String,
+    #[serde(rename = "$unflatten=chNFe")]
+    pub chave_acesso: String,
+    #[serde(rename = "$unflatten=dhRecbto")]
+    #[serde(serialize_with = "serialize_horario")]
+    pub recebimento: DateTime<FixedOffset>,
+    #[serde(rename = "$unflatten=nProt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub numero: Option<String>,
+    #[serde(rename = "$unflatten=digVal")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+    #[serde(rename = "$unflatten=cStat")]
+    pub status: u16,
+    #[serde(rename = "$unflatten=xMotivo")]
+    pub motivo: String,
+}
+
+fn serialize_horario<S>(date: &DateTime<FixedOffset>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&date.to_rfc3339())
+}
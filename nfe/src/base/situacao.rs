@@ -0,0 +1,200 @@
+//! Consulta da situação da nota pela chave de acesso(consSitNFe)
+
+use super::chave::ChaveAcesso;
+use super::evento::{ProcEvento, TipoEvento};
+use super::ide::TipoAmbiente;
+use super::protocolo::ProtocoloNfe;
+use super::{Error, VersaoLayout, NAMESPACE_NFE};
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+/// Consulta da situação da nota, a tag <consSitNFe>
+#[derive(Debug, PartialEq, Clone)]
+pub struct ConsultaSituacao {
+    pub ambiente: TipoAmbiente,
+    pub chave_acesso: String,
+}
+
+/// Situação da nota, a tag <retConsSitNFe>
+#[derive(Debug, PartialEq, Clone)]
+pub struct RetornoSituacao {
+    pub ambiente: TipoAmbiente,
+    pub versao_aplicativo: String,
+    /// Código do status(cStat), que indica a situação atual da nota
+    pub status: u16,
+    /// Descrição do status
+    pub motivo: String,
+    pub codigo_uf: u8,
+    /// Data e hora do processamento da consulta
+    pub recebimento: DateTime<FixedOffset>,
+    pub chave_acesso: Option<String>,
+    /// Protocolo de autorização ou denegação da nota
+    pub protocolo: Option<ProtocoloNfe>,
+    /// Eventos registrados para a nota
+    pub eventos: Vec<ProcEvento>,
+    /// Eventos de tipos não suportados, como os registrados pelo fisco
+    pub eventos_nao_suportados: Vec<EventoNaoSuportado>,
+}
+
+/// Evento de tipo não suportado, mantido como o XML(procEventoNFe) do retorno
+#[derive(Debug, PartialEq, Clone)]
+pub struct EventoNaoSuportado {
+    /// Código do tipo do evento(tpEvento)
+    pub tipo: Option<u32>,
+    pub xml: String,
+}
+
+impl ConsultaSituacao {
+    /// Valida o formato e o dígito verificador da chave de acesso
+    pub fn validar(&self) -> Result<(), Error> {
+        self.chave_acesso.parse::<ChaveAcesso>().map(|_| ())
+    }
+}
+
+impl RetornoSituacao {
+    /// Indica se a nota está autorizada, inclusive fora de prazo(150)
+    pub fn autorizada(&self) -> bool {
+        matches!(self.status, 100 | 150)
+    }
+
+    /// Indica se a nota foi cancelada, inclusive fora de prazo(151 e 155)
+    pub fn cancelada(&self) -> bool {
+        matches!(self.status, 101 | 151 | 155)
+    }
+
+    /// Indica se o uso da nota foi denegado
+    pub fn denegada(&self) -> bool {
+        matches!(self.status, 110 | 301 | 302 | 303)
+    }
+
+    /// Eventos do tipo informado(tpEvento), na ordem do retorno
+    pub fn eventos_do_tipo(&self, tipo: u32) -> Vec<&ProcEvento> {
+        self.eventos
+            .iter()
+            .filter(|e| e.evento.tipo().codigo() == tipo)
+            .collect()
+    }
+}
+
+impl fmt::Display for ConsultaSituacao {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let consulta = ConsSitNfeContainer {
+            namespace: NAMESPACE_NFE.to_string(),
+            versao: VersaoLayout::V4_00,
+            ambiente: self.ambiente,
+            servico: "CONSULTAR".to_string(),
+            chave_acesso: self.chave_acesso.clone(),
+        };
+
+        let xml = quick_xml::se::to_string(&consulta).expect("Falha ao serializar a consulta");
+
+        f.write_str(&xml)
+    }
+}
+
+impl FromStr for RetornoSituacao {
+    type Err = Error;
+
+    /// Os eventos são lidos pelo próprio FromStr, preservando a assinatura
+    ///
+    /// Eventos de tipos não suportados não impedem a leitura da situação
+    /// e são mantidos como XML.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ret: RetConsSitNfeContainer = quick_xml::de::from_str(s)?;
+
+        let mut eventos = vec![];
+        let mut eventos_nao_suportados = vec![];
+        for trecho in elementos(s, "procEventoNFe") {
+            let tipo = conteudo(trecho, "tpEvento").and_then(|t| t.trim().parse::<u32>().ok());
+
+            match tipo.map(TipoEvento::try_from) {
+                Some(Ok(_)) => eventos.push(trecho.parse::<ProcEvento>()?),
+                _ => eventos_nao_suportados.push(EventoNaoSuportado {
+                    tipo,
+                    xml: trecho.to_string(),
+                }),
+            }
+        }
+
+        Ok(Self {
+            ambiente: ret.ambiente,
+            versao_aplicativo: ret.versao_aplicativo,
+            status: ret.status,
+            motivo: ret.motivo,
+            codigo_uf: ret.codigo_uf,
+            recebimento: ret.recebimento,
+            chave_acesso: ret.chave_acesso,
+            protocolo: ret.protocolo,
+            eventos,
+            eventos_nao_suportados,
+        })
+    }
+}
+
+/// Trechos de cada ocorrência do elemento, da abertura ao fechamento
+fn elementos<'a>(xml: &'a str, nome: &str) -> Vec<&'a str> {
+    let abertura = format!("<{}", nome);
+    let fechamento = format!("</{}>", nome);
+    let mut trechos = vec![];
+    let mut pos = 0;
+
+    while let Some(inicio) = xml[pos..].find(&abertura).map(|i| pos + i) {
+        let fim = match xml[inicio..].find(&fechamento) {
+            Some(fim) => inicio + fim + fechamento.len(),
+            None => break,
+        };
+
+        trechos.push(&xml[inicio..fim]);
+        pos = fim;
+    }
+
+    trechos
+}
+
+/// Texto da primeira ocorrência do elemento
+fn conteudo<'a>(xml: &'a str, nome: &str) -> Option<&'a str> {
+    let abertura = format!("<{}>", nome);
+    let inicio = xml.find(&abertura)? + abertura.len();
+    let fim = inicio + xml[inicio..].find(&format!("</{}>", nome))?;
+
+    Some(&xml[inicio..fim])
+}
+
+#[derive(Serialize)]
+#[serde(rename = "consSitNFe")]
+struct ConsSitNfeContainer {
+    #[serde(rename = "xmlns")]
+    pub namespace: String,
+    #[serde(rename = "versao")]
+    pub versao: VersaoLayout,
+    #[serde(rename = "$unflatten=tpAmb")]
+    pub ambiente: TipoAmbiente,
+    #[serde(rename = "$unflatten=xServ")]
+    pub servico: String,
+    #[serde(rename = "$unflatten=chNFe")]
+    pub chave_acesso: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "retConsSitNFe")]
+struct RetConsSitNfeContainer {
+    #[serde(rename = "$unflatten=tpAmb")]
+    pub ambiente: TipoAmbiente,
+    #[serde(rename = "$unflatten=verAplic")]
+    pub versao_aplicativo: String,
+    #[serde(rename = "$unflatten=cStat")]
+    pub status: u16,
+    #[serde(rename = "$unflatten=xMotivo")]
+    pub motivo: String,
+    #[serde(rename = "$unflatten=cUF")]
+    pub codigo_uf: u8,
+    #[serde(rename = "$unflatten=dhRecbto")]
+    pub recebimento: DateTime<FixedOffset>,
+    #[serde(rename = "$unflatten=chNFe")]
+    pub chave_acesso: Option<String>,
+    #[serde(rename = "protNFe")]
+    pub protocolo: Option<ProtocoloNfe>,
+}
@@ -0,0 +1,143 @@
+//! Consulta do status do serviço de autorização(consStatServ)
+//!
+//! Usada no monitoramento do autorizador, para decidir a entrada e a
+//! saída da contingência.
+
+use super::ide::TipoAmbiente;
+use super::{Error, VersaoLayout, NAMESPACE_NFE};
+use chrono::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Consulta do status do serviço, a tag <consStatServ>
+#[derive(Debug, PartialEq, Clone)]
+pub struct ConsultaStatus {
+    pub ambiente: TipoAmbiente,
+    /// Código da UF consultada
+    pub codigo_uf: u8,
+}
+
+/// Status do serviço, a tag <retConsStatServ>
+#[derive(Debug, PartialEq, Clone)]
+pub struct RetornoStatus {
+    pub ambiente: TipoAmbiente,
+    pub versao_aplicativo: String,
+    /// Código do status(cStat)
+    pub status: u16,
+    /// Descrição do status
+    pub motivo: String,
+    pub codigo_uf: u8,
+    /// Data e hora do recebimento da consulta
+    pub recebimento: DateTime<FixedOffset>,
+    /// Tempo médio de resposta do serviço, em segundos
+    pub tempo_medio: Option<u32>,
+    /// Previsão de retorno do serviço paralisado
+    pub previsao_retorno: Option<DateTime<FixedOffset>>,
+    /// Observações da SEFAZ
+    pub observacao: Option<String>,
+}
+
+impl RetornoStatus {
+    /// Indica se o serviço está em operação
+    pub fn em_operacao(&self) -> bool {
+        self.status == 107
+    }
+
+    /// Indica se o serviço está paralisado, momentaneamente(108) ou sem previsão(109)
+    pub fn paralisado(&self) -> bool {
+        matches!(self.status, 108 | 109)
+    }
+}
+
+impl fmt::Display for ConsultaStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar a consulta");
+
+        f.write_str(&xml)
+    }
+}
+
+impl FromStr for RetornoStatus {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl Serialize for ConsultaStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let consulta = ConsStatServContainer {
+            namespace: NAMESPACE_NFE.to_string(),
+            versao: VersaoLayout::V4_00,
+            ambiente: self.ambiente,
+            codigo_uf: self.codigo_uf,
+            servico: "STATUS".to_string(),
+        };
+
+        consulta.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for RetornoStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ret = RetConsStatServContainer::deserialize(deserializer)?;
+
+        Ok(Self {
+            ambiente: ret.ambiente,
+            versao_aplicativo: ret.versao_aplicativo,
+            status: ret.status,
+            motivo: ret.motivo,
+            codigo_uf: ret.codigo_uf,
+            recebimento: ret.recebimento,
+            tempo_medio: ret.tempo_medio,
+            previsao_retorno: ret.previsao_retorno,
+            observacao: ret.observacao,
+        })
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename = "consStatServ")]
+struct ConsStatServContainer {
+    #[serde(rename = "xmlns")]
+    pub namespace: String,
+    #[serde(rename = "versao")]
+    pub versao: VersaoLayout,
+    #[serde(rename = "$unflatten=tpAmb")]
+    pub ambiente: TipoAmbiente,
+    #[serde(rename = "$unflatten=cUF")]
+    pub codigo_uf: u8,
+    #[serde(rename = "$unflatten=xServ")]
+    pub servico: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "retConsStatServ")]
+struct RetConsStatServContainer {
+    #[serde(rename = "$unflatten=tpAmb")]
+    pub ambiente: TipoAmbiente,
+    #[serde(rename = "$unflatten=verAplic")]
+    pub versao_aplicativo: String,
+    #[serde(rename = "$unflatten=cStat")]
+    pub status: u16,
+    #[serde(rename = "$unflatten=xMotivo")]
+    pub motivo: String,
+    #[serde(rename = "$unflatten=cUF")]
+    pub codigo_uf: u8,
+    #[serde(rename = "$unflatten=dhRecbto")]
+    pub recebimento: DateTime<FixedOffset>,
+    #[serde(rename = "$unflatten=tMed")]
+    pub tempo_medio: Option<u32>,
+    #[serde(rename = "$unflatten=dhRetorno")]
+    pub previsao_retorno: Option<DateTime<FixedOffset>>,
+    #[serde(rename = "$unflatten=xObs")]
+    pub observacao: Option<String>,
+}
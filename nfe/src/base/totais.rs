@@ -2,6 +2,7 @@
 
 use super::Error;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
 use std::str::FromStr;
 
 /// Totalização da nota fiscal
@@ -11,6 +12,8 @@ pub struct Totalizacao {
     pub valor_base_calculo: f32,
     /// Valor total do ICMS
     pub valor_icms: f32,
+    /// Valor total do ICMS ST
+    pub valor_icms_st: f32,
     /// Valor total dos produtos e serviços
     pub valor_produtos: f32,
     /// Valor total do frete
@@ -39,9 +42,11 @@ impl FromStr for Totalizacao {
     }
 }
 
-impl ToString for Totalizacao {
-    fn to_string(&self) -> String {
-        quick_xml::se::to_string(self).expect("Falha ao serializar a totalização")
+impl fmt::Display for Totalizacao {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar a totalização");
+
+        f.write_str(&xml)
     }
 }
 
@@ -53,6 +58,7 @@ impl Serialize for Totalizacao {
         let icms = IcmsTot {
             valor_base_calculo: self.valor_base_calculo,
             valor_icms: self.valor_icms,
+            valor_icms_st: self.valor_icms_st,
             valor_produtos: self.valor_produtos,
             valor_frete: self.valor_frete,
             valor_seguro: self.valor_seguro,
@@ -79,6 +85,7 @@ impl<'de> Deserialize<'de> for Totalizacao {
         Ok(Totalizacao {
             valor_base_calculo: helper.icms.valor_base_calculo,
             valor_icms: helper.icms.valor_icms,
+            valor_icms_st: helper.icms.valor_icms_st,
             valor_produtos: helper.icms.valor_produtos,
             valor_frete: helper.icms.valor_frete,
             valor_seguro: helper.icms.valor_seguro,
@@ -105,6 +112,8 @@ struct IcmsTot {
     valor_base_calculo: f32,
     #[serde(rename = "$unflatten=vICMS")]
     valor_icms: f32,
+    #[serde(rename = "$unflatten=vST")]
+    valor_icms_st: f32,
     #[serde(rename = "$unflatten=vProd")]
     valor_produtos: f32,
     #[serde(rename = "$unflatten=vFrete")]
@@ -3,6 +3,7 @@
 use super::Error;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::fmt;
 use std::str::FromStr;
 
 /// Transporte da nota
@@ -22,9 +23,11 @@ impl FromStr for Transporte {
     }
 }
 
-impl ToString for Transporte {
-    fn to_string(&self) -> String {
-        quick_xml::se::to_string(self).expect("Falha ao serializar o transporte")
+impl fmt::Display for Transporte {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar o transporte");
+
+        f.write_str(&xml)
     }
 }
 
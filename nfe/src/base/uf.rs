@@ -0,0 +1,177 @@
+//! Unidades federativas
+
+use super::Error;
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+/// Unidade federativa, identificada pelo código do IBGE
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone, Deserialize_repr, Serialize_repr)]
+#[repr(u8)]
+pub enum Uf {
+    Ro = 11,
+    Ac = 12,
+    Am = 13,
+    Rr = 14,
+    Pa = 15,
+    Ap = 16,
+    To = 17,
+    Ma = 21,
+    Pi = 22,
+    Ce = 23,
+    Rn = 24,
+    Pb = 25,
+    Pe = 26,
+    Al = 27,
+    Se = 28,
+    Ba = 29,
+    Mg = 31,
+    Es = 32,
+    Rj = 33,
+    Sp = 35,
+    Pr = 41,
+    Sc = 42,
+    Rs = 43,
+    Ms = 50,
+    Mt = 51,
+    Go = 52,
+    Df = 53,
+}
+
+impl Uf {
+    /// Todas as UFs, em ordem de código do IBGE
+    pub const TODAS: [Uf; 27] = [
+        Uf::Ro,
+        Uf::Ac,
+        Uf::Am,
+        Uf::Rr,
+        Uf::Pa,
+        Uf::Ap,
+        Uf::To,
+        Uf::Ma,
+        Uf::Pi,
+        Uf::Ce,
+        Uf::Rn,
+        Uf::Pb,
+        Uf::Pe,
+        Uf::Al,
+        Uf::Se,
+        Uf::Ba,
+        Uf::Mg,
+        Uf::Es,
+        Uf::Rj,
+        Uf::Sp,
+        Uf::Pr,
+        Uf::Sc,
+        Uf::Rs,
+        Uf::Ms,
+        Uf::Mt,
+        Uf::Go,
+        Uf::Df,
+    ];
+
+    /// Código do IBGE
+    pub fn codigo(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Sigla com duas letras
+    pub fn sigla(&self) -> &'static str {
+        match self {
+            Uf::Ro => "RO",
+            Uf::Ac => "AC",
+            Uf::Am => "AM",
+            Uf::Rr => "RR",
+            Uf::Pa => "PA",
+            Uf::Ap => "AP",
+            Uf::To => "TO",
+            Uf::Ma => "MA",
+            Uf::Pi => "PI",
+            Uf::Ce => "CE",
+            Uf::Rn => "RN",
+            Uf::Pb => "PB",
+            Uf::Pe => "PE",
+            Uf::Al => "AL",
+            Uf::Se => "SE",
+            Uf::Ba => "BA",
+            Uf::Mg => "MG",
+            Uf::Es => "ES",
+            Uf::Rj => "RJ",
+            Uf::Sp => "SP",
+            Uf::Pr => "PR",
+            Uf::Sc => "SC",
+            Uf::Rs => "RS",
+            Uf::Ms => "MS",
+            Uf::Mt => "MT",
+            Uf::Go => "GO",
+            Uf::Df => "DF",
+        }
+    }
+
+    /// Nome da UF
+    pub fn nome(&self) -> &'static str {
+        match self {
+            Uf::Ro => "Rondônia",
+            Uf::Ac => "Acre",
+            Uf::Am => "Amazonas",
+            Uf::Rr => "Roraima",
+            Uf::Pa => "Pará",
+            Uf::Ap => "Amapá",
+            Uf::To => "Tocantins",
+            Uf::Ma => "Maranhão",
+            Uf::Pi => "Piauí",
+            Uf::Ce => "Ceará",
+            Uf::Rn => "Rio Grande do Norte",
+            Uf::Pb => "Paraíba",
+            Uf::Pe => "Pernambuco",
+            Uf::Al => "Alagoas",
+            Uf::Se => "Sergipe",
+            Uf::Ba => "Bahia",
+            Uf::Mg => "Minas Gerais",
+            Uf::Es => "Espírito Santo",
+            Uf::Rj => "Rio de Janeiro",
+            Uf::Sp => "São Paulo",
+            Uf::Pr => "Paraná",
+            Uf::Sc => "Santa Catarina",
+            Uf::Rs => "Rio Grande do Sul",
+            Uf::Ms => "Mato Grosso do Sul",
+            Uf::Mt => "Mato Grosso",
+            Uf::Go => "Goiás",
+            Uf::Df => "Distrito Federal",
+        }
+    }
+}
+
+impl TryFrom<u8> for Uf {
+    type Error = Error;
+
+    fn try_from(codigo: u8) -> Result<Self, Self::Error> {
+        Uf::TODAS
+            .iter()
+            .find(|uf| uf.codigo() == codigo)
+            .copied()
+            .ok_or_else(|| Error::UfInvalida(format!("Código IBGE desconhecido: {}", codigo)))
+    }
+}
+
+impl FromStr for Uf {
+    type Err = Error;
+
+    /// Busca a UF pela sigla
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sigla = s.trim().to_uppercase();
+
+        Uf::TODAS
+            .iter()
+            .find(|uf| uf.sigla() == sigla)
+            .copied()
+            .ok_or_else(|| Error::UfInvalida(format!("Sigla desconhecida: {}", s)))
+    }
+}
+
+impl fmt::Display for Uf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.sigla())
+    }
+}
@@ -0,0 +1,371 @@
+//! Endereços dos web services da SEFAZ
+//!
+//! Cada UF usa o seu próprio autorizador ou um dos ambientes virtuais(SVRS e
+//! SVAN), com endereços distintos para NF-e e NFC-e, produção e homologação.
+
+use super::evento::ORGAO_AMBIENTE_NACIONAL;
+use super::ide::{ModeloDocumentoFiscal, TipoAmbiente};
+use super::Error;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Autorizador de cada UF, no formato `codigo_uf;nfe;nfce;contingencia`
+const AUTORIZADORES: &str = include_str!("../../dados/autorizadores.csv");
+
+/// Tabela de endereços embarcada, no formato `autorizador;modelo;ambiente;servico;url`
+///
+/// Os endereços mudam com as notas técnicas e devem ser conferidos com o
+/// portal da NF-e. Uma tabela atualizada pode ser informada em
+/// `WebServices::com_tabela`.
+const TABELA: &str = include_str!("../../dados/webservices.csv");
+
+/// Web service da SEFAZ
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum Servico {
+    /// Recepção do lote de notas(NFeAutorizacao4)
+    Autorizacao,
+    /// Consulta do processamento do lote(NFeRetAutorizacao4)
+    RetornoAutorizacao,
+    /// Inutilização de numeração(NFeInutilizacao4)
+    Inutilizacao,
+    /// Consulta da situação da nota(NFeConsultaProtocolo4)
+    ConsultaProtocolo,
+    /// Status do serviço(NFeStatusServico4)
+    StatusServico,
+    /// Recepção de eventos(NFeRecepcaoEvento4)
+    RecepcaoEvento,
+    /// Consulta do cadastro de contribuintes(CadConsultaCadastro4)
+    ConsultaCadastro,
+    /// Distribuição de documentos de interesse(NFeDistribuicaoDFe)
+    DistribuicaoDFe,
+}
+
+/// Autorizador dos documentos de uma UF
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum Autorizador {
+    Am,
+    Ba,
+    Go,
+    Mg,
+    Ms,
+    Mt,
+    Pe,
+    Pr,
+    Rs,
+    Sp,
+    /// Sefaz Virtual do Ambiente Nacional
+    Svan,
+    /// Sefaz Virtual do Rio Grande do Sul
+    Svrs,
+    /// Contingência do Ambiente Nacional
+    SvcAn,
+    /// Contingência do Rio Grande do Sul
+    SvcRs,
+    /// Ambiente Nacional, dos eventos do destinatário e da distribuição
+    An,
+}
+
+/// Endereços dos web services, com substituições para testes locais
+#[derive(Debug, Default, Clone)]
+pub struct WebServices {
+    /// Tabela no formato da embarcada, usada no lugar dela
+    tabela: Option<String>,
+    substituicoes: HashMap<(u8, ModeloDocumentoFiscal, TipoAmbiente, Servico), String>,
+    substituicoes_servico: HashMap<Servico, String>,
+}
+
+impl Servico {
+    /// Todos os serviços
+    pub const TODOS: [Servico; 8] = [
+        Servico::Autorizacao,
+        Servico::RetornoAutorizacao,
+        Servico::Inutilizacao,
+        Servico::ConsultaProtocolo,
+        Servico::StatusServico,
+        Servico::RecepcaoEvento,
+        Servico::ConsultaCadastro,
+        Servico::DistribuicaoDFe,
+    ];
+
+    /// Nome do serviço no WSDL
+    pub fn nome(&self) -> &'static str {
+        match self {
+            Servico::Autorizacao => "NFeAutorizacao4",
+            Servico::RetornoAutorizacao => "NFeRetAutorizacao4",
+            Servico::Inutilizacao => "NFeInutilizacao4",
+            Servico::ConsultaProtocolo => "NFeConsultaProtocolo4",
+            Servico::StatusServico => "NFeStatusServico4",
+            Servico::RecepcaoEvento => "NFeRecepcaoEvento4",
+            Servico::ConsultaCadastro => "CadConsultaCadastro4",
+            Servico::DistribuicaoDFe => "NFeDistribuicaoDFe",
+        }
+    }
+
+    /// Namespace do WSDL, usado no elemento <nfeDadosMsg>
+    pub fn namespace(&self) -> String {
+        format!("http://www.portalfiscal.inf.br/nfe/wsdl/{}", self.nome())
+    }
+
+    /// Ação SOAP da operação do serviço
+    pub fn acao(&self) -> String {
+        format!("{}/{}", self.namespace(), self.operacao())
+    }
+
+    /// Nome da operação do WSDL
+    pub fn operacao(&self) -> &'static str {
+        match self {
+            Servico::Autorizacao => "nfeAutorizacaoLote",
+            Servico::RetornoAutorizacao => "nfeRetAutorizacaoLote",
+            Servico::Inutilizacao => "nfeInutilizacaoNF",
+            Servico::ConsultaProtocolo => "nfeConsultaNF",
+            Servico::StatusServico => "nfeStatusServicoNF",
+            Servico::RecepcaoEvento => "nfeRecepcaoEvento",
+            Servico::ConsultaCadastro => "consultaCadastro",
+            Servico::DistribuicaoDFe => "nfeDistDFeInteresse",
+        }
+    }
+}
+
+impl Autorizador {
+    /// Todos os autorizadores
+    pub const TODOS: [Autorizador; 15] = [
+        Autorizador::Am,
+        Autorizador::Ba,
+        Autorizador::Go,
+        Autorizador::Mg,
+        Autorizador::Ms,
+        Autorizador::Mt,
+        Autorizador::Pe,
+        Autorizador::Pr,
+        Autorizador::Rs,
+        Autorizador::Sp,
+        Autorizador::Svan,
+        Autorizador::Svrs,
+        Autorizador::SvcAn,
+        Autorizador::SvcRs,
+        Autorizador::An,
+    ];
+
+    /// Sigla usada nas tabelas
+    pub fn sigla(&self) -> &'static str {
+        match self {
+            Autorizador::Am => "AM",
+            Autorizador::Ba => "BA",
+            Autorizador::Go => "GO",
+            Autorizador::Mg => "MG",
+            Autorizador::Ms => "MS",
+            Autorizador::Mt => "MT",
+            Autorizador::Pe => "PE",
+            Autorizador::Pr => "PR",
+            Autorizador::Rs => "RS",
+            Autorizador::Sp => "SP",
+            Autorizador::Svan => "SVAN",
+            Autorizador::Svrs => "SVRS",
+            Autorizador::SvcAn => "SVC-AN",
+            Autorizador::SvcRs => "SVC-RS",
+            Autorizador::An => "AN",
+        }
+    }
+
+    /// Autorizador dos documentos do modelo na UF
+    pub fn da_uf(codigo_uf: u8, modelo: ModeloDocumentoFiscal) -> Result<Self, Error> {
+        let coluna = match modelo {
+            ModeloDocumentoFiscal::Nfe => 1,
+            ModeloDocumentoFiscal::Nfce => 2,
+        };
+
+        Self::buscar_uf(codigo_uf, coluna)
+    }
+
+    /// Autorizador de contingência(SVC) da UF. Existe apenas para a NF-e
+    pub fn contingencia(codigo_uf: u8, modelo: ModeloDocumentoFiscal) -> Result<Self, Error> {
+        if modelo != ModeloDocumentoFiscal::Nfe {
+            return Err(Error::WebServiceNaoEncontrado(
+                "A NFC-e não possui contingência SVC".to_string(),
+            ));
+        }
+
+        Self::buscar_uf(codigo_uf, 3)
+    }
+
+    fn buscar_uf(codigo_uf: u8, coluna: usize) -> Result<Self, Error> {
+        AUTORIZADORES
+            .lines()
+            .skip(1)
+            .map(|linha| linha.split(';').map(str::trim).collect::<Vec<_>>())
+            .find(|campos| campos[0].parse() == Ok(codigo_uf))
+            .and_then(|campos| campos.get(coluna)?.parse().ok())
+            .ok_or_else(|| {
+                Error::WebServiceNaoEncontrado(format!("UF sem autorizador: {}", codigo_uf))
+            })
+    }
+}
+
+impl WebServices {
+    /// Endereços da tabela embarcada
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Endereços de uma tabela atualizada, no formato da embarcada
+    pub fn com_tabela(tabela: String) -> Self {
+        Self {
+            tabela: Some(tabela),
+            ..Self::default()
+        }
+    }
+
+    /// Substitui o endereço do serviço para a UF, o modelo e o ambiente
+    pub fn substituir(
+        &mut self,
+        codigo_uf: u8,
+        modelo: ModeloDocumentoFiscal,
+        ambiente: TipoAmbiente,
+        servico: Servico,
+        url: &str,
+    ) {
+        self.substituicoes
+            .insert((codigo_uf, modelo, ambiente, servico), url.to_string());
+    }
+
+    /// Substitui o endereço do serviço para todos os autorizadores
+    ///
+    /// Útil para apontar os testes para um servidor local
+    pub fn substituir_servico(&mut self, servico: Servico, url: &str) {
+        self.substituicoes_servico.insert(servico, url.to_string());
+    }
+
+    /// Endereço do serviço no autorizador da UF
+    ///
+    /// A distribuição de documentos é sempre atendida pelo Ambiente Nacional.
+    /// Na recepção de eventos, informe o órgão(cOrgao) do evento: os eventos
+    /// do Ambiente Nacional(91), como a manifestação do destinatário e o
+    /// EPEC, também são atendidos por ele.
+    pub fn url(
+        &self,
+        codigo_uf: u8,
+        modelo: ModeloDocumentoFiscal,
+        ambiente: TipoAmbiente,
+        servico: Servico,
+    ) -> Result<String, Error> {
+        if let Some(url) = self.substituicao(codigo_uf, modelo, ambiente, servico) {
+            return Ok(url);
+        }
+
+        let autorizador = match servico {
+            Servico::DistribuicaoDFe => Autorizador::An,
+            Servico::RecepcaoEvento if codigo_uf == ORGAO_AMBIENTE_NACIONAL => Autorizador::An,
+            _ => Autorizador::da_uf(codigo_uf, modelo)?,
+        };
+
+        self.url_autorizador(autorizador, modelo, ambiente, servico)
+    }
+
+    /// Endereço do serviço na contingência SVC da UF
+    ///
+    /// As substituições feitas para a UF também valem na contingência
+    pub fn url_contingencia(
+        &self,
+        codigo_uf: u8,
+        modelo: ModeloDocumentoFiscal,
+        ambiente: TipoAmbiente,
+        servico: Servico,
+    ) -> Result<String, Error> {
+        if let Some(url) = self.substituicao(codigo_uf, modelo, ambiente, servico) {
+            return Ok(url);
+        }
+
+        let autorizador = Autorizador::contingencia(codigo_uf, modelo)?;
+
+        self.url_autorizador(autorizador, modelo, ambiente, servico)
+    }
+
+    /// Endereço do serviço no autorizador informado
+    pub fn url_autorizador(
+        &self,
+        autorizador: Autorizador,
+        modelo: ModeloDocumentoFiscal,
+        ambiente: TipoAmbiente,
+        servico: Servico,
+    ) -> Result<String, Error> {
+        if let Some(url) = self.substituicoes_servico.get(&servico) {
+            return Ok(url.clone());
+        }
+
+        self.tabela
+            .as_deref()
+            .unwrap_or(TABELA)
+            .lines()
+            .skip(1)
+            .map(|linha| linha.split(';').map(str::trim).collect::<Vec<_>>())
+            .find(|campos| {
+                campos.len() == 5
+                    && campos[0] == autorizador.sigla()
+                    && campos[1].parse() == Ok(modelo as u8)
+                    && campos[2].parse() == Ok(ambiente as u8)
+                    && campos[3] == servico.nome()
+            })
+            .map(|campos| campos[4].to_string())
+            .ok_or_else(|| {
+                Error::WebServiceNaoEncontrado(format!(
+                    "{} sem o serviço {} para o modelo {} em {:?}",
+                    autorizador, servico, modelo as u8, ambiente
+                ))
+            })
+    }
+
+    fn substituicao(
+        &self,
+        codigo_uf: u8,
+        modelo: ModeloDocumentoFiscal,
+        ambiente: TipoAmbiente,
+        servico: Servico,
+    ) -> Option<String> {
+        self.substituicoes
+            .get(&(codigo_uf, modelo, ambiente, servico))
+            .cloned()
+    }
+}
+
+impl fmt::Display for Servico {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.nome())
+    }
+}
+
+impl FromStr for Servico {
+    type Err = Error;
+
+    /// Busca o serviço pelo nome no WSDL
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Servico::TODOS
+            .iter()
+            .find(|servico| servico.nome().eq_ignore_ascii_case(s.trim()))
+            .copied()
+            .ok_or_else(|| Error::WebServiceNaoEncontrado(format!("Serviço desconhecido: {}", s)))
+    }
+}
+
+impl fmt::Display for Autorizador {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.sigla())
+    }
+}
+
+impl FromStr for Autorizador {
+    type Err = Error;
+
+    /// Busca o autorizador pela sigla
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sigla = s.trim().to_uppercase();
+
+        Autorizador::TODOS
+            .iter()
+            .find(|autorizador| autorizador.sigla() == sigla)
+            .copied()
+            .ok_or_else(|| {
+                Error::WebServiceNaoEncontrado(format!("Autorizador desconhecido: {}", s))
+            })
+    }
+}
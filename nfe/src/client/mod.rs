@@ -0,0 +1,179 @@
+//! Cliente dos web services da SEFAZ
+//!
+//! Disponível com a feature `client`. As mensagens são enviadas no envelope
+//! SOAP 1.2, com autenticação TLS mútua usando o certificado A1 do emitente.
+
+use crate::base::autorizacao::*;
+use crate::base::cadastro::*;
+use crate::base::certificado::{Certificado, CertificadoA1};
+use crate::base::distribuicao::*;
+use crate::base::situacao::*;
+use crate::base::status::*;
+pub use crate::base::webservice::Servico;
+use crate::base::Error;
+use native_tls::{Identity, TlsConnector};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub mod soap;
+
+/// Tempo limite de cada requisição
+pub const TEMPO_LIMITE: Duration = Duration::from_secs(30);
+
+/// Cliente HTTPS autenticado com o certificado do emitente
+pub struct Cliente {
+    agente: ureq::Agent,
+}
+
+impl Cliente {
+    /// Cliente que confia apenas nas autoridades certificadoras do sistema
+    pub fn new(certificado: &CertificadoA1) -> Result<Self, Error> {
+        Self::com_raizes(certificado, &[])
+    }
+
+    /// Cliente que confia também nas autoridades informadas
+    ///
+    /// Os servidores da SEFAZ usam certificados da ICP-Brasil, geralmente
+    /// ausentes das autoridades do sistema.
+    pub fn com_raizes(certificado: &CertificadoA1, raizes: &[Certificado]) -> Result<Self, Error> {
+        let invalido = |e: openssl::error::ErrorStack| Error::CertificadoInvalido(e.to_string());
+
+        let identidade = Identity::from_pkcs8(
+            &certificado
+                .certificado()
+                .x509()
+                .to_pem()
+                .map_err(invalido)?,
+            &certificado
+                .chave()
+                .private_key_to_pem_pkcs8()
+                .map_err(invalido)?,
+        )
+        .map_err(|e| Error::CertificadoInvalido(e.to_string()))?;
+
+        let mut tls = TlsConnector::builder();
+        tls.identity(identidade);
+        for raiz in raizes {
+            let raiz = native_tls::Certificate::from_der(&raiz.der()?)
+                .map_err(|e| Error::CertificadoInvalido(e.to_string()))?;
+            tls.add_root_certificate(raiz);
+        }
+
+        let tls = tls.build().map_err(|e| Error::Comunicacao(e.to_string()))?;
+
+        let agente = ureq::AgentBuilder::new()
+            .tls_connector(Arc::new(tls))
+            .timeout(TEMPO_LIMITE)
+            .build();
+
+        Ok(Self { agente })
+    }
+
+    /// Envia a mensagem ao serviço, retornando o conteúdo do <nfeResultMsg>
+    pub fn chamar(&self, url: &str, servico: Servico, mensagem: &str) -> Result<String, Error> {
+        let tipo = format!(
+            "application/soap+xml; charset=utf-8; action=\"{}\"",
+            servico.acao()
+        );
+
+        let envelope = match servico {
+            Servico::DistribuicaoDFe => {
+                soap::envelope_operacao(&servico.namespace(), servico.operacao(), mensagem)
+            }
+            _ => soap::envelope(&servico.namespace(), mensagem),
+        };
+
+        let resposta = match self
+            .agente
+            .post(url)
+            .set("Content-Type", &tipo)
+            .send_string(&envelope)
+        {
+            Ok(resposta) => resposta,
+            Err(ureq::Error::Status(status, resposta)) => {
+                let corpo = resposta.into_string().unwrap_or_default();
+
+                return Err(Error::Comunicacao(match soap::falha(&corpo) {
+                    Some(motivo) => format!("HTTP {}: {}", status, motivo),
+                    None => format!("HTTP {}", status),
+                }));
+            }
+            Err(e) => return Err(Error::Comunicacao(e.to_string())),
+        };
+
+        let corpo = resposta
+            .into_string()
+            .map_err(|e| Error::Comunicacao(e.to_string()))?;
+
+        soap::resultado(&corpo)
+            .map(str::to_string)
+            .ok_or_else(|| Error::Comunicacao("Resposta sem o nfeResultMsg".to_string()))
+    }
+
+    /// Envia o lote de notas para autorização
+    pub fn autorizar(&self, url: &str, lote: &EnvioLote) -> Result<RetornoEnvioLote, Error> {
+        self.consultar(url, Servico::Autorizacao, &lote.to_string())
+    }
+
+    /// Consulta o processamento do lote enviado de forma assíncrona
+    pub fn consultar_recibo(
+        &self,
+        url: &str,
+        consulta: &ConsultaRecibo,
+    ) -> Result<RetornoConsultaRecibo, Error> {
+        self.consultar(url, Servico::RetornoAutorizacao, &consulta.to_string())
+    }
+
+    /// Consulta o status do serviço de autorização
+    pub fn status_servico(
+        &self,
+        url: &str,
+        consulta: &ConsultaStatus,
+    ) -> Result<RetornoStatus, Error> {
+        self.consultar(url, Servico::StatusServico, &consulta.to_string())
+    }
+
+    /// Consulta a situação da nota, com o protocolo e os eventos registrados
+    pub fn consultar_situacao(
+        &self,
+        url: &str,
+        consulta: &ConsultaSituacao,
+    ) -> Result<RetornoSituacao, Error> {
+        consulta.validar()?;
+
+        self.consultar(url, Servico::ConsultaProtocolo, &consulta.to_string())
+    }
+
+    /// Consulta o cadastro do contribuinte por CNPJ, CPF ou IE
+    pub fn consultar_cadastro(
+        &self,
+        url: &str,
+        consulta: &ConsultaCadastro,
+    ) -> Result<RetornoCadastro, Error> {
+        consulta.validar()?;
+
+        self.consultar(url, Servico::ConsultaCadastro, &consulta.to_string())
+    }
+
+    /// Consulta os documentos de interesse no Ambiente Nacional
+    ///
+    /// Os documentos retornados continuam compactados, ver
+    /// `RetornoDistribuicao::decodificar`.
+    pub fn distribuir(
+        &self,
+        url: &str,
+        distribuicao: &DistribuicaoDFe,
+    ) -> Result<RetornoDistribuicao, Error> {
+        distribuicao.validar()?;
+
+        self.consultar(url, Servico::DistribuicaoDFe, &distribuicao.to_string())
+    }
+
+    fn consultar<R>(&self, url: &str, servico: Servico, mensagem: &str) -> Result<R, Error>
+    where
+        R: FromStr<Err = Error>,
+    {
+        self.chamar(url, servico, mensagem)?.parse()
+    }
+}
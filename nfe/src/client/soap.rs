@@ -0,0 +1,78 @@
+//! Envelope SOAP 1.2 dos web services da SEFAZ
+
+/// Namespace do envelope SOAP 1.2
+pub const NAMESPACE_SOAP12: &str = "http://www.w3.org/2003/05/soap-envelope";
+
+/// Envelope com a mensagem no elemento <nfeDadosMsg> do serviço
+pub fn envelope(namespace_servico: &str, mensagem: &str) -> String {
+    format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+         <soap12:Envelope xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" \
+         xmlns:xsd=\"http://www.w3.org/2001/XMLSchema\" xmlns:soap12=\"{}\">\
+         <soap12:Body><nfeDadosMsg xmlns=\"{}\">{}</nfeDadosMsg></soap12:Body>\
+         </soap12:Envelope>",
+        NAMESPACE_SOAP12, namespace_servico, mensagem
+    )
+}
+
+/// Envelope com a mensagem dentro do elemento da operação
+///
+/// Usado pela distribuição do Ambiente Nacional, que espera o
+/// <nfeDadosMsg> dentro de <nfeDistDFeInteresse>.
+pub fn envelope_operacao(namespace_servico: &str, operacao: &str, mensagem: &str) -> String {
+    format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+         <soap12:Envelope xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" \
+         xmlns:xsd=\"http://www.w3.org/2001/XMLSchema\" xmlns:soap12=\"{}\">\
+         <soap12:Body><{} xmlns=\"{}\"><nfeDadosMsg>{}</nfeDadosMsg></{}></soap12:Body>\
+         </soap12:Envelope>",
+        NAMESPACE_SOAP12, operacao, namespace_servico, mensagem, operacao
+    )
+}
+
+/// Conteúdo do elemento <nfeResultMsg> da resposta
+///
+/// Na distribuição, o retorno vem em <nfeDistDFeInteresseResult>.
+pub fn resultado(resposta: &str) -> Option<&str> {
+    conteudo(resposta, "nfeResultMsg")
+        .or_else(|| conteudo(resposta, "nfeDistDFeInteresseResult"))
+        .map(str::trim)
+}
+
+/// Motivo da falha SOAP(Fault), se a resposta for uma
+pub fn falha(resposta: &str) -> Option<String> {
+    let falha = conteudo(resposta, "Fault")?;
+
+    // SOAP 1.2 usa <Reason><Text> e o 1.1, <faultstring>
+    conteudo(falha, "Text")
+        .or_else(|| conteudo(falha, "faultstring"))
+        .map(|motivo| motivo.trim().to_string())
+}
+
+/// Conteúdo do primeiro elemento com o nome local informado, com ou sem prefixo
+fn conteudo<'a>(xml: &'a str, nome: &str) -> Option<&'a str> {
+    let mut pos = 0;
+
+    while let Some(relativo) = xml[pos..].find('<') {
+        let inicio = pos + relativo + 1;
+        let tag = &xml[inicio..];
+        let fim_nome = tag.find(|c: char| c.is_whitespace() || c == '>' || c == '/')?;
+        let completo = &tag[..fim_nome];
+
+        if completo.rsplit(':').next() == Some(nome) {
+            let abertura = inicio + tag.find('>')?;
+            if xml[..abertura].ends_with('/') {
+                return Some("");
+            }
+
+            let fechamento = format!("</{}>", completo);
+            let fim = xml[abertura..].find(&fechamento)? + abertura;
+
+            return Some(&xml[abertura + 1..fim]);
+        }
+
+        pos = inicio;
+    }
+
+    None
+}
@@ -11,6 +11,8 @@
 //! criar uma interface em cima disso.
 
 pub mod base;
+#[cfg(feature = "client")]
+pub mod client;
 pub mod modelos;
 
 pub use crate::modelos::nfe::*;
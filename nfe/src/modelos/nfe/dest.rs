@@ -3,13 +3,16 @@
 use super::Error;
 use crate::base::dest::Destinatario as DestinatarioBase;
 pub use crate::base::dest::IndicadorContribuicaoIe;
+use crate::base::documento::Documento;
 pub use crate::base::endereco::Endereco;
 use std::convert::{TryFrom, TryInto};
+use std::fmt;
 use std::str::FromStr;
 
 /// Destinatário da NF-e
 pub struct Destinatario {
-    pub cnpj: String,
+    /// CNPJ, CPF ou idEstrangeiro do destinatário
+    pub documento: Documento,
     pub razao_social: String,
     pub endereco: Endereco,
     pub ie: Option<String>,
@@ -29,7 +32,7 @@ impl TryFrom<DestinatarioBase> for Destinatario {
             .ok_or_else(|| Error::DestinatarioInvalido("Endereço não informado".to_string()))?;
 
         Ok(Self {
-            cnpj: dest.cnpj.clone(),
+            documento: dest.documento.clone(),
             razao_social,
             endereco,
             ie: dest.ie.clone(),
@@ -41,7 +44,7 @@ impl TryFrom<DestinatarioBase> for Destinatario {
 impl From<&Destinatario> for DestinatarioBase {
     fn from(dest: &Destinatario) -> Self {
         Self {
-            cnpj: dest.cnpj.clone(),
+            documento: dest.documento.clone(),
             razao_social: Some(dest.razao_social.clone()),
             endereco: Some(dest.endereco.clone()),
             ie: dest.ie.clone(),
@@ -60,10 +63,10 @@ impl FromStr for Destinatario {
     }
 }
 
-impl ToString for Destinatario {
-    fn to_string(&self) -> String {
+impl fmt::Display for Destinatario {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let base: DestinatarioBase = self.into();
 
-        base.to_string()
+        base.fmt(f)
     }
 }
@@ -1,15 +1,38 @@
 //! Modelo 55 da NF-e
 
+pub use crate::base::assinatura::*;
+pub use crate::base::autorizacao::*;
+pub use crate::base::c14n;
+pub use crate::base::cadastro::*;
+pub use crate::base::cana::*;
+pub use crate::base::certificado::*;
+pub use crate::base::chave::*;
+pub use crate::base::compra::*;
 use crate::base::dest::Destinatario as DestinatarioBase;
+pub use crate::base::distribuicao::*;
+pub use crate::base::documento::*;
 pub use crate::base::emit::*;
 pub use crate::base::endereco::*;
+pub use crate::base::evento::*;
+pub use crate::base::exporta::*;
 pub use crate::base::ide::*;
+pub use crate::base::inutilizacao::*;
 pub use crate::base::item::*;
+pub use crate::base::municipio::*;
+pub use crate::base::pagamento::*;
+pub use crate::base::pais::*;
+pub use crate::base::protocolo::*;
+pub use crate::base::situacao::*;
+pub use crate::base::status::*;
 pub use crate::base::totais::*;
 pub use crate::base::transporte::*;
+pub use crate::base::uf::*;
+pub use crate::base::webservice::*;
 use crate::base::Nfe as NfeBase;
 pub use crate::base::VersaoLayout;
+use openssl::x509::store::X509StoreRef;
 use std::convert::{TryFrom, TryInto};
+use std::fmt;
 use std::fs::File;
 use std::str::FromStr;
 
@@ -30,8 +53,68 @@ pub struct Nfe {
     pub itens: Vec<Item>,
     pub totais: Totalizacao,
     pub transporte: Transporte,
+    /// Formas de pagamento, obrigatórias desde o leiaute 4.00
+    pub pagamento: Option<Pagamento>,
     /// Informações complementares de interesse do contribuinte
     pub informacao_complementar: Option<String>,
+    /// Informações de comércio exterior
+    pub exportacao: Option<Exportacao>,
+    /// Informações de compras públicas e privadas
+    pub compra: Option<Compra>,
+    /// Registro de aquisição de cana
+    pub cana: Option<Cana>,
+    /// Assinatura digital do documento
+    pub assinatura: Option<Assinatura>,
+}
+
+impl Nfe {
+    /// Chave de acesso do documento, com o dígito verificador validado
+    pub fn chave(&self) -> Result<ChaveAcesso, Error> {
+        Ok(self.chave_acesso.parse()?)
+    }
+
+    /// Gera uma nova chave de acesso a partir da identificação e do emitente
+    ///
+    /// Preenche a chave_acesso e os campos cNF e cDV da identificação
+    pub fn gerar_chave_acesso(&mut self) -> Result<ChaveAcesso, Error> {
+        let mut base: NfeBase = (&*self).into();
+        let chave = base.gerar_chave_acesso()?;
+        self.chave_acesso = base.chave_acesso;
+        self.ide.chave = base.ide.chave;
+
+        Ok(chave)
+    }
+
+    /// Assina o grupo infNFe com o certificado do emitente
+    pub fn assinar(&mut self, certificado: &CertificadoA1) -> Result<(), Error> {
+        let mut base: NfeBase = (&*self).into();
+        base.assinar(certificado)?;
+        self.assinatura = base.assinatura;
+
+        Ok(())
+    }
+
+    /// Verifica a assinatura de uma nota recebida, a partir do seu XML original
+    pub fn verificar_assinatura(xml: &str) -> Result<VerificacaoAssinatura, Error> {
+        Ok(NfeBase::verificar_assinatura(xml)?)
+    }
+
+    /// Verifica a assinatura e a cadeia do certificado do assinante
+    pub fn verificar_assinatura_cadeia(
+        xml: &str,
+        confiaveis: &X509StoreRef,
+    ) -> Result<VerificacaoAssinatura, Error> {
+        Ok(NfeBase::verificar_assinatura_cadeia(xml, confiaveis)?)
+    }
+
+    /// Valida a consistência do documento
+    ///
+    /// Retorna o primeiro problema encontrado
+    pub fn validar(&self) -> Result<(), Error> {
+        let base: NfeBase = self.into();
+
+        Ok(base.validar()?)
+    }
 }
 
 impl TryFrom<NfeBase> for Nfe {
@@ -56,7 +139,12 @@ impl TryFrom<NfeBase> for Nfe {
             itens: doc.itens,
             totais: doc.totais,
             transporte: doc.transporte,
+            pagamento: doc.pagamento,
             informacao_complementar: doc.informacao_complementar,
+            exportacao: doc.exportacao,
+            compra: doc.compra,
+            cana: doc.cana,
+            assinatura: doc.assinatura,
         })
     }
 }
@@ -66,7 +154,7 @@ impl From<&Nfe> for NfeBase {
         let dest: DestinatarioBase = (&doc.dest).into();
 
         Self {
-            versao: doc.versao.clone(),
+            versao: doc.versao,
             chave_acesso: doc.chave_acesso.clone(),
             ide: doc.ide.clone(),
             emit: doc.emit.clone(),
@@ -74,7 +162,12 @@ impl From<&Nfe> for NfeBase {
             itens: doc.itens.clone(),
             totais: doc.totais.clone(),
             transporte: doc.transporte.clone(),
+            pagamento: doc.pagamento.clone(),
             informacao_complementar: doc.informacao_complementar.clone(),
+            exportacao: doc.exportacao.clone(),
+            compra: doc.compra.clone(),
+            cana: doc.cana.clone(),
+            assinatura: doc.assinatura.clone(),
         }
     }
 }
@@ -95,10 +188,10 @@ impl TryFrom<File> for Nfe {
     }
 }
 
-impl ToString for Nfe {
-    fn to_string(&self) -> String {
+impl fmt::Display for Nfe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let base: NfeBase = self.into();
 
-        base.to_string()
+        base.fmt(f)
     }
 }
@@ -0,0 +1,276 @@
+//! Testes da assinatura digital e do certificado do assinante
+
+use chrono::prelude::*;
+use openssl::asn1::Asn1Time;
+use openssl::base64;
+use openssl::bn::BigNum;
+use openssl::hash::MessageDigest;
+use openssl::pkcs12::Pkcs12;
+use openssl::pkey::PKey;
+use openssl::rsa::Rsa;
+use openssl::sign::Verifier;
+use openssl::x509::{X509Name, X509};
+
+use super::ler;
+use crate::base::c14n;
+use crate::base::Nfe as NfeBase;
+use crate::*;
+
+/// Gera um certificado A1 autoassinado, no formato PKCS#12
+pub fn gerar_pkcs12(titular: &str, senha: &str) -> Vec<u8> {
+    let chave = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+
+    let mut nome = X509Name::builder().unwrap();
+    nome.append_entry_by_text("C", "BR").unwrap();
+    nome.append_entry_by_text("CN", titular).unwrap();
+    let nome = nome.build();
+
+    let mut x509 = X509::builder().unwrap();
+    x509.set_version(2).unwrap();
+    x509.set_serial_number(&BigNum::from_u32(1).unwrap().to_asn1_integer().unwrap())
+        .unwrap();
+    x509.set_subject_name(&nome).unwrap();
+    x509.set_issuer_name(&nome).unwrap();
+    x509.set_pubkey(&chave).unwrap();
+    x509.set_not_before(&Asn1Time::from_str("20180101000000Z").unwrap())
+        .unwrap();
+    x509.set_not_after(&Asn1Time::from_str("20301231235959Z").unwrap())
+        .unwrap();
+    x509.sign(&chave, MessageDigest::sha256()).unwrap();
+
+    Pkcs12::builder()
+        .name("teste")
+        .pkey(&chave)
+        .cert(&x509.build())
+        .build2(senha)
+        .unwrap()
+        .to_der()
+        .unwrap()
+}
+
+/// Certificado A1 de teste com o CNPJ do emitente das notas de exemplo
+pub fn certificado_teste() -> CertificadoA1 {
+    let der = gerar_pkcs12("EMPRESA DE TESTE LTDA:06929383000163", "1234");
+
+    CertificadoA1::from_pkcs12(&der, "1234").unwrap()
+}
+
+#[test]
+fn assinatura_preservada() -> Result<(), String> {
+    let xml = ler("xmls/nfe_layout4.xml")?;
+    let nfe = xml.parse::<NfeBase>().map_err(|e| e.to_string())?;
+
+    let assinatura = nfe.assinatura.as_ref().ok_or("Assinatura não lida")?;
+    assert_eq!(
+        "#NFe43180906929383000163550010000000261000010301",
+        assinatura.referencia()
+    );
+    assert_eq!(
+        "http://www.w3.org/TR/2001/REC-xml-c14n-20010315",
+        assinatura.metodo_canonicalizacao()
+    );
+    assert_eq!(
+        "http://www.w3.org/2000/09/xmldsig#rsa-sha1",
+        assinatura.metodo_assinatura()
+    );
+    assert_eq!(
+        "http://www.w3.org/2000/09/xmldsig#sha1",
+        assinatura.metodo_digest()
+    );
+    assert_eq!(2, assinatura.transformacoes().len());
+    assert_eq!("Hwr3X8T6PR7EUzdSHZYeHpn14e4=", assinatura.digest());
+    assert!(!assinatura.valor().contains(char::is_whitespace));
+
+    // O XML original da assinatura é reescrito sem alterações
+    assert!(xml.contains(assinatura.xml()));
+
+    let gerado = nfe.to_string();
+    assert!(gerado.contains(assinatura.xml()));
+    assert!(gerado.ends_with("</Signature></NFe>"));
+
+    let lido = gerado.parse::<NfeBase>().map_err(|e| e.to_string())?;
+    assert_eq!(nfe, lido);
+
+    Ok(())
+}
+
+#[test]
+fn assinatura_no_modelo() -> Result<(), String> {
+    let xml = ler("xmls/nfe_proc_layout4.xml")?;
+    let avulsa = ler("xmls/nfe_layout4.xml")?
+        .parse::<Nfe>()
+        .map_err(|e| e.to_string())?;
+
+    let proc = xml.parse::<NfeProc>().map_err(|e| e.to_string())?;
+    assert_eq!(avulsa.assinatura, proc.nfe.assinatura);
+    assert!(proc.to_string().contains("</Signature></NFe><protNFe"));
+
+    Ok(())
+}
+
+#[test]
+fn certificado_do_assinante() -> Result<(), String> {
+    let nfe = ler("xmls/nfe_layout4.xml")?
+        .parse::<NfeBase>()
+        .map_err(|e| e.to_string())?;
+    let assinatura = nfe.assinatura.ok_or("Assinatura não lida")?;
+
+    let certificado = assinatura.certificado().map_err(|e| e.to_string())?;
+    assert_eq!(
+        Some("DIGITINS SISTEMAS DE INFORMATICA LTDA:06929383000163".to_string()),
+        certificado.titular()
+    );
+    assert_eq!(
+        "06929383000163",
+        certificado.cnpj().ok_or("CNPJ não encontrado")?.as_str()
+    );
+
+    let inicio = certificado.validade_inicio().map_err(|e| e.to_string())?;
+    let fim = certificado.validade_fim().map_err(|e| e.to_string())?;
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2018, 2, 15),
+        Some(inicio.date_naive())
+    );
+    assert_eq!(NaiveDate::from_ymd_opt(2019, 2, 15), Some(fim.date_naive()));
+
+    let emissao = Utc.with_ymd_and_hms(2018, 9, 25, 13, 0, 0).unwrap();
+    assert!(certificado.valido_em(emissao).map_err(|e| e.to_string())?);
+    let vencido = Utc.with_ymd_and_hms(2019, 3, 1, 0, 0, 0).unwrap();
+    assert!(!certificado.valido_em(vencido).map_err(|e| e.to_string())?);
+
+    // O certificado reexportado é o mesmo informado na assinatura
+    assert_eq!(
+        assinatura.certificado_base64(),
+        certificado.base64().map_err(|e| e.to_string())?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sem_assinatura() -> Result<(), String> {
+    let xml = ler("xmls/nfe_layout4.xml")?;
+    let inicio = xml.find("<Signature").ok_or("Assinatura não encontrada")?;
+    let fim = xml
+        .find("</Signature>")
+        .ok_or("Assinatura não encontrada")?
+        + 12;
+    let sem = format!("{}{}", &xml[..inicio], &xml[fim..]);
+
+    let nfe = sem.parse::<NfeBase>().map_err(|e| e.to_string())?;
+    assert_eq!(None, nfe.assinatura);
+    assert!(nfe.to_string().ends_with("</infNFe></NFe>"));
+
+    // Assinatura sem fechamento
+    let truncada = &xml[..fim - 12];
+    assert!(truncada.parse::<Assinatura>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn carregar_pkcs12() -> Result<(), String> {
+    let der = gerar_pkcs12("EMPRESA DE TESTE LTDA:06929383000163", "senha");
+
+    let certificado = CertificadoA1::from_pkcs12(&der, "senha").map_err(|e| e.to_string())?;
+    assert_eq!(
+        "06929383000163",
+        certificado
+            .certificado()
+            .cnpj()
+            .ok_or("CNPJ não encontrado")?
+            .as_str()
+    );
+
+    assert!(CertificadoA1::from_pkcs12(&der, "errada")
+        .map_err(|e| e.to_string())
+        .unwrap_err()
+        .starts_with("Certificado inválido. Falha ao abrir o PKCS#12"));
+    assert!(CertificadoA1::from_pkcs12(b"nada", "senha").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn assinar_nfe() -> Result<(), String> {
+    let mut nfe = ler("xmls/nfe_layout4.xml")?
+        .parse::<NfeBase>()
+        .map_err(|e| e.to_string())?;
+    let original = nfe.assinatura.clone();
+
+    let certificado = certificado_teste();
+    nfe.assinar(&certificado).map_err(|e| e.to_string())?;
+
+    let assinatura = nfe.assinatura.clone().ok_or("Nota não assinada")?;
+    assert_ne!(original, Some(assinatura.clone()));
+    assert_eq!(
+        "#NFe43180906929383000163550010000000261000010301",
+        assinatura.referencia()
+    );
+    assert_eq!(ALGORITMO_C14N, assinatura.metodo_canonicalizacao());
+    assert_eq!(ALGORITMO_RSA_SHA1, assinatura.metodo_assinatura());
+    assert_eq!(ALGORITMO_SHA1, assinatura.metodo_digest());
+    assert_eq!(
+        vec![ALGORITMO_ENVELOPED, ALGORITMO_C14N],
+        assinatura.transformacoes()
+    );
+
+    let xml = nfe.to_string();
+    assert!(xml.starts_with("<NFe xmlns=\"http://www.portalfiscal.inf.br/nfe\"><infNFe"));
+    assert!(xml.contains("</infNFe><Signature xmlns=\"http://www.w3.org/2000/09/xmldsig#\">"));
+
+    // Digest do infNFe canonicalizado
+    let inf = c14n::canonicalizar(&xml, "infNFe").map_err(|e| e.to_string())?;
+    assert!(inf.starts_with("<infNFe xmlns=\"http://www.portalfiscal.inf.br/nfe\" Id=\"NFe4318"));
+    assert_eq!(
+        base64::encode_block(&openssl::sha::sha1(inf.as_bytes())),
+        assinatura.digest()
+    );
+
+    // Assinatura do SignedInfo com a chave do certificado
+    let signed_info = c14n::canonicalizar(&xml, "SignedInfo").map_err(|e| e.to_string())?;
+    let chave = certificado
+        .certificado()
+        .x509()
+        .public_key()
+        .map_err(|e| e.to_string())?;
+    let mut verifier = Verifier::new(MessageDigest::sha1(), &chave).map_err(|e| e.to_string())?;
+    let valor = base64::decode_block(assinatura.valor()).map_err(|e| e.to_string())?;
+    assert!(verifier
+        .verify_oneshot(&valor, signed_info.as_bytes())
+        .map_err(|e| e.to_string())?);
+
+    assert_eq!(
+        certificado
+            .certificado()
+            .base64()
+            .map_err(|e| e.to_string())?,
+        assinatura.certificado_base64()
+    );
+
+    let lida = xml.parse::<NfeBase>().map_err(|e| e.to_string())?;
+    assert_eq!(nfe, lida);
+
+    Ok(())
+}
+
+#[test]
+fn assinar_modelo() -> Result<(), String> {
+    let mut nfe = ler("xmls/nfe_layout4.xml")?
+        .parse::<Nfe>()
+        .map_err(|e| e.to_string())?;
+    let mut base = ler("xmls/nfe_layout4.xml")?
+        .parse::<NfeBase>()
+        .map_err(|e| e.to_string())?;
+
+    let certificado = certificado_teste();
+    nfe.assinar(&certificado).map_err(|e| e.to_string())?;
+    base.assinar(&certificado).map_err(|e| e.to_string())?;
+
+    // RSA-SHA1 é determinístico: a mesma nota gera a mesma assinatura
+    let assinatura = nfe.assinatura.as_ref().ok_or("Nota não assinada")?;
+    assert_eq!(base.assinatura.as_ref(), Some(assinatura));
+    assert!(nfe.to_string().contains(assinatura.xml()));
+
+    Ok(())
+}
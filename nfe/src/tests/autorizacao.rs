@@ -0,0 +1,198 @@
+//! Testes do lote de autorização e da consulta do seu processamento
+
+use super::assinatura::certificado_teste;
+use super::ler;
+use crate::base::Nfe as NfeBase;
+use crate::*;
+
+const CHAVE: &str = "43180906929383000163550010000000261000010301";
+
+fn nota_assinada() -> Result<NfeBase, String> {
+    let mut nfe = ler("xmls/nfe_layout4.xml")?
+        .parse::<NfeBase>()
+        .map_err(|e| e.to_string())?;
+    nfe.assinar(&certificado_teste())
+        .map_err(|e| e.to_string())?;
+
+    Ok(nfe)
+}
+
+fn protocolo(chave: &str, numero: &str) -> String {
+    format!(
+        "<protNFe versao=\"4.00\"><infProt><tpAmb>2</tpAmb><verAplic>RS20180920103458</verAplic>\
+         <chNFe>{}</chNFe><dhRecbto>2018-09-25T10:02:57-03:00</dhRecbto><nProt>{}</nProt>\
+         <digVal>Hwr3X8T6PR7EUzdSHZYeHpn14e4=</digVal><cStat>100</cStat>\
+         <xMotivo>Autorizado o uso da NF-e</xMotivo></infProt></protNFe>",
+        chave, numero
+    )
+}
+
+#[test]
+fn envio_lote() -> Result<(), String> {
+    let nfe = nota_assinada()?;
+    let xml_nota = nfe.to_string();
+
+    let lote = EnvioLote::new(42, true, vec![nfe]).map_err(|e| e.to_string())?;
+    let xml = lote.to_string();
+    assert!(xml.starts_with(
+        "<enviNFe xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"4.00\">\
+         <idLote>42</idLote><indSinc>1</indSinc><NFe"
+    ));
+    assert!(xml.ends_with("</NFe></enviNFe>"));
+
+    // A nota é enviada sem alterações, preservando a assinatura
+    assert!(xml.contains(&xml_nota));
+
+    Ok(())
+}
+
+#[test]
+fn envio_lote_invalido() -> Result<(), String> {
+    let erro =
+        |lote: Result<EnvioLote, crate::base::Error>| lote.map_err(|e| e.to_string()).unwrap_err();
+
+    assert_eq!(
+        "Lote inválido. O lote deve possuir de 1 a 50 notas",
+        erro(EnvioLote::new(1, false, vec![]))
+    );
+    assert_eq!(
+        "Lote inválido. O processamento síncrono aceita apenas uma nota",
+        erro(EnvioLote::new(
+            1,
+            true,
+            vec![nota_assinada()?, nota_assinada()?]
+        ))
+    );
+
+    let mut sem_assinatura = ler("xmls/nfe_layout4.xml")?
+        .parse::<NfeBase>()
+        .map_err(|e| e.to_string())?;
+    sem_assinatura.assinatura = None;
+    assert_eq!(
+        format!("Lote inválido. Nota sem assinatura: {}", CHAVE),
+        erro(EnvioLote::new(1, false, vec![sem_assinatura]))
+    );
+
+    // O exemplo foi formatado após a assinatura, que não confere mais com o infNFe
+    let formatada = ler("xmls/nfe_layout4.xml")?
+        .parse::<NfeBase>()
+        .map_err(|e| e.to_string())?;
+    assert!(
+        erro(EnvioLote::new(1, false, vec![formatada])).starts_with(&format!(
+            "Lote inválido. Assinatura não confere com a nota {}",
+            CHAVE
+        ))
+    );
+
+    let mut sem_pagamento = nota_assinada()?;
+    sem_pagamento.pagamento = None;
+    sem_pagamento
+        .assinar(&certificado_teste())
+        .map_err(|e| e.to_string())?;
+    assert_eq!(
+        format!(
+            "Lote inválido. Nota sem o grupo de pagamento(pag): {}",
+            CHAVE
+        ),
+        erro(EnvioLote::new(1, false, vec![sem_pagamento]))
+    );
+
+    // O assíncrono aceita várias notas
+    let lote = EnvioLote::new(2, false, vec![nota_assinada()?, nota_assinada()?])
+        .map_err(|e| e.to_string())?;
+    assert!(lote.to_string().contains("<indSinc>0</indSinc>"));
+
+    Ok(())
+}
+
+#[test]
+fn retorno_envio() -> Result<(), String> {
+    // Processamento síncrono, com o protocolo da nota
+    let xml = format!(
+        "<retEnviNFe xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"4.00\">\
+         <tpAmb>2</tpAmb><verAplic>RS20180920103458</verAplic><cStat>104</cStat>\
+         <xMotivo>Lote processado</xMotivo><cUF>43</cUF>\
+         <dhRecbto>2018-09-25T10:02:57-03:00</dhRecbto>{}</retEnviNFe>",
+        protocolo(CHAVE, "143180000001234")
+    );
+    let retorno = xml.parse::<RetornoEnvioLote>().map_err(|e| e.to_string())?;
+    assert!(retorno.processado());
+    assert!(!retorno.recebido());
+    assert_eq!(TipoAmbiente::Homologacao, retorno.ambiente);
+    assert_eq!(43, retorno.codigo_uf);
+    assert_eq!(None, retorno.recibo);
+
+    let protocolo = retorno.protocolo.ok_or("Retorno sem protocolo")?;
+    assert!(protocolo.autorizado());
+    assert_eq!(CHAVE, protocolo.chave_acesso);
+    assert_eq!(Some("143180000001234".to_string()), protocolo.numero);
+
+    // Processamento assíncrono, com o recibo do lote
+    let xml = "<retEnviNFe xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"4.00\">\
+               <tpAmb>2</tpAmb><verAplic>RS20180920103458</verAplic><cStat>103</cStat>\
+               <xMotivo>Lote recebido com sucesso</xMotivo><cUF>43</cUF>\
+               <dhRecbto>2018-09-25T10:02:57-03:00</dhRecbto>\
+               <infRec><nRec>431000012345678</nRec><tMed>1</tMed></infRec></retEnviNFe>";
+    let retorno = xml.parse::<RetornoEnvioLote>().map_err(|e| e.to_string())?;
+    assert!(retorno.recebido());
+    assert_eq!(None, retorno.protocolo);
+    assert_eq!(
+        Some(Recibo {
+            numero: "431000012345678".to_string(),
+            tempo_medio: 1,
+        }),
+        retorno.recibo
+    );
+
+    Ok(())
+}
+
+#[test]
+fn consulta_recibo() -> Result<(), String> {
+    let consulta = ConsultaRecibo {
+        ambiente: TipoAmbiente::Homologacao,
+        recibo: "431000012345678".to_string(),
+    };
+    assert_eq!(
+        "<consReciNFe xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"4.00\">\
+         <tpAmb>2</tpAmb><nRec>431000012345678</nRec></consReciNFe>",
+        consulta.to_string()
+    );
+
+    let outra = "43180906929383000163550010000000271000010308";
+    let xml = format!(
+        "<retConsReciNFe xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"4.00\">\
+         <tpAmb>2</tpAmb><verAplic>RS20180920103458</verAplic><nRec>431000012345678</nRec>\
+         <cStat>104</cStat><xMotivo>Lote processado</xMotivo><cUF>43</cUF>\
+         <dhRecbto>2018-09-25T10:02:58-03:00</dhRecbto>{}{}</retConsReciNFe>",
+        protocolo(CHAVE, "143180000001234"),
+        protocolo(outra, "143180000001235")
+    );
+    let retorno = xml
+        .parse::<RetornoConsultaRecibo>()
+        .map_err(|e| e.to_string())?;
+    assert!(retorno.processado());
+    assert_eq!("431000012345678", retorno.recibo);
+    assert_eq!(2, retorno.protocolos.len());
+    assert_eq!(
+        Some("143180000001235".to_string()),
+        retorno.protocolo(outra).and_then(|p| p.numero.clone())
+    );
+    assert_eq!(
+        None,
+        retorno.protocolo("43000000000000000000000000000000000000000000")
+    );
+
+    // Lote ainda em processamento, sem protocolos
+    let xml = "<retConsReciNFe xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"4.00\">\
+               <tpAmb>2</tpAmb><verAplic>RS20180920103458</verAplic><nRec>431000012345678</nRec>\
+               <cStat>105</cStat><xMotivo>Lote em processamento</xMotivo><cUF>43</cUF>\
+               <dhRecbto>2018-09-25T10:02:58-03:00</dhRecbto></retConsReciNFe>";
+    let retorno = xml
+        .parse::<RetornoConsultaRecibo>()
+        .map_err(|e| e.to_string())?;
+    assert!(retorno.em_processamento());
+    assert!(retorno.protocolos.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+//! Testes da canonicalização(C14N) dos documentos
+
+use super::{compactar, ler};
+use crate::*;
+
+#[test]
+fn digest_notas_reais() -> Result<(), String> {
+    let nfe = compactar(&ler("xmls/nfe_layout4.xml")?);
+    assert_eq!(
+        "Hwr3X8T6PR7EUzdSHZYeHpn14e4=",
+        c14n::digest(&nfe, "infNFe").map_err(|e| e.to_string())?
+    );
+
+    let nfce = compactar(&ler("xmls/nfce_layout4.xml")?);
+    assert_eq!(
+        "m9ZrQTKMxv7A1Blnf/nmNGVX+N8=",
+        c14n::digest(&nfce, "infNFe").map_err(|e| e.to_string())?
+    );
+
+    // O namespace da <NFe> é propagado ao infNFe
+    let canonico = c14n::canonicalizar(&nfe, "infNFe").map_err(|e| e.to_string())?;
+    assert!(canonico.starts_with(
+        "<infNFe xmlns=\"http://www.portalfiscal.inf.br/nfe\" \
+         Id=\"NFe43180906929383000163550010000000261000010301\" versao=\"4.00\"><ide>"
+    ));
+    assert!(canonico.ends_with("</pag></infNFe>"));
+
+    Ok(())
+}
+
+#[test]
+fn digest_do_protocolo() -> Result<(), String> {
+    let compacto = compactar(&ler("xmls/nfe_proc_layout4.xml")?);
+    let proc = compacto.parse::<NfeProc>().map_err(|e| e.to_string())?;
+
+    assert!(proc
+        .protocolo
+        .conferir_digest(&compacto)
+        .map_err(|e| e.to_string())?);
+
+    // A formatação altera o conteúdo autorizado
+    let formatado = ler("xmls/nfe_proc_layout4.xml")?;
+    assert!(!proc
+        .protocolo
+        .conferir_digest(&formatado)
+        .map_err(|e| e.to_string())?);
+
+    Ok(())
+}
+
+#[test]
+fn forma_canonica() -> Result<(), String> {
+    let xml = "<?xml version=\"1.0\"?>\r\n\
+        <raiz xmlns=\"urn:a\" xmlns:b=\"urn:b\"><!-- comentário -->\
+        <item z=\"1\" b:y=\"2\" a=\"x&amp;y&lt;&quot;\t\"  >\r\n\
+        <vazio/><texto>&lt;a&gt; &amp; <![CDATA[<b>]]></texto>\
+        </item></raiz>";
+
+    assert_eq!(
+        "<item xmlns=\"urn:a\" xmlns:b=\"urn:b\" a=\"x&amp;y&lt;&quot; \" z=\"1\" b:y=\"2\">\n\
+         <vazio></vazio><texto>&lt;a&gt; &amp; &lt;b&gt;</texto></item>",
+        c14n::canonicalizar(xml, "item").map_err(|e| e.to_string())?
+    );
+
+    // Na exclusiva, somente os namespaces usados
+    assert_eq!(
+        "<vazio xmlns=\"urn:a\"></vazio>",
+        c14n::canonicalizar_com(
+            xml,
+            c14n::Seletor::Tag("vazio"),
+            c14n::Canonicalizacao::Exclusiva
+        )
+        .map_err(|e| e.to_string())?
+    );
+    assert_eq!(
+        "<vazio xmlns=\"urn:a\" xmlns:b=\"urn:b\"></vazio>",
+        c14n::canonicalizar(xml, "vazio").map_err(|e| e.to_string())?
+    );
+
+    assert!(c14n::canonicalizar(xml, "inexistente").is_err());
+    assert!(c14n::canonicalizar("<a><b></a>", "a").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn namespaces_redeclarados() -> Result<(), String> {
+    let xml =
+        "<a xmlns=\"urn:a\"><b xmlns=\"urn:a\"><c xmlns=\"urn:c\"><d xmlns=\"\"/></c></b></a>";
+
+    // Declarações repetidas são omitidas e a remoção do padrão é mantida
+    assert_eq!(
+        "<b xmlns=\"urn:a\"><c xmlns=\"urn:c\"><d xmlns=\"\"></d></c></b>",
+        c14n::canonicalizar(xml, "b").map_err(|e| e.to_string())?
+    );
+    assert_eq!(
+        "<d></d>",
+        c14n::canonicalizar(xml, "d").map_err(|e| e.to_string())?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn eventos_e_inutilizacao() -> Result<(), String> {
+    // O mesmo nome de tag no pedido e no retorno: a seleção pelo Id é a assinada
+    let evento = "<procEventoNFe xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"1.00\">\
+        <evento versao=\"1.00\"><infEvento Id=\"ID1101114318090692938300016355001000000026100001030101\">\
+        <cOrgao>43</cOrgao></infEvento>\
+        <Signature xmlns=\"http://www.w3.org/2000/09/xmldsig#\"><SignedInfo/></Signature></evento>\
+        <retEvento versao=\"1.00\"><infEvento><cStat>135</cStat></infEvento></retEvento>\
+        </procEventoNFe>";
+
+    assert_eq!(
+        "<infEvento xmlns=\"http://www.portalfiscal.inf.br/nfe\" \
+         Id=\"ID1101114318090692938300016355001000000026100001030101\">\
+         <cOrgao>43</cOrgao></infEvento>",
+        c14n::canonicalizar_com(
+            evento,
+            c14n::Seletor::Id("ID1101114318090692938300016355001000000026100001030101"),
+            c14n::Canonicalizacao::Inclusiva
+        )
+        .map_err(|e| e.to_string())?
+    );
+
+    // A assinatura é removida do elemento que a contém(enveloped-signature)
+    assert_eq!(
+        "<evento xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"1.00\">\
+         <infEvento Id=\"ID1101114318090692938300016355001000000026100001030101\">\
+         <cOrgao>43</cOrgao></infEvento></evento>",
+        c14n::canonicalizar(evento, "evento").map_err(|e| e.to_string())?
+    );
+
+    let inutilizacao = "<inutNFe xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"4.00\">\
+        <infInut Id=\"ID43180692938300016355001000000001000000010\">\
+        <xJust>Falha no sistema &amp; queda de energia</xJust></infInut></inutNFe>";
+
+    assert_eq!(
+        "<infInut xmlns=\"http://www.portalfiscal.inf.br/nfe\" \
+         Id=\"ID43180692938300016355001000000001000000010\">\
+         <xJust>Falha no sistema &amp; queda de energia</xJust></infInut>",
+        c14n::canonicalizar(inutilizacao, "infInut").map_err(|e| e.to_string())?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+//! Testes da tag <cana>
+
+use crate::*;
+
+#[test]
+fn manual() -> Result<(), Error> {
+    let xml = "
+        <cana>
+            <safra>2021/2022</safra>
+            <ref>08/2021</ref>
+            <forDia dia=\"1\">
+                <qtde>1500.5</qtde>
+            </forDia>
+            <forDia dia=\"2\">
+                <qtde>1200</qtde>
+            </forDia>
+            <qTotMes>2700.5</qTotMes>
+            <qTotAnt>10000</qTotAnt>
+            <qTotGer>12700.5</qTotGer>
+            <deduc>
+                <xDed>FUNRURAL</xDed>
+                <vDed>35.20</vDed>
+            </deduc>
+            <vFor>1500.00</vFor>
+            <vTotDed>35.20</vTotDed>
+            <vLiqFor>1464.80</vLiqFor>
+        </cana>
+    ";
+
+    let cana = xml.parse::<Cana>()?;
+
+    assert_eq!("2021/2022", cana.safra);
+    assert_eq!("08/2021", cana.referencia);
+    assert_eq!(2, cana.fornecimentos.len());
+    assert_eq!(1, cana.fornecimentos[0].dia);
+    assert_eq!(1500.5, cana.fornecimentos[0].quantidade);
+    assert_eq!(2, cana.fornecimentos[1].dia);
+    assert_eq!(1200.0, cana.fornecimentos[1].quantidade);
+    assert_eq!(2700.5, cana.quantidade_total_mes);
+    assert_eq!(10000.0, cana.quantidade_total_anterior);
+    assert_eq!(12700.5, cana.quantidade_total_geral);
+    assert_eq!(1, cana.deducoes.len());
+    assert_eq!("FUNRURAL", cana.deducoes[0].descricao);
+    assert_eq!(35.20, cana.deducoes[0].valor);
+    assert_eq!(1500.00, cana.valor_fornecimentos);
+    assert_eq!(35.20, cana.valor_deducoes);
+    assert_eq!(1464.80, cana.valor_liquido);
+
+    Ok(())
+}
+
+#[test]
+fn sem_deducoes() -> Result<(), Error> {
+    let xml = "
+        <cana>
+            <safra>2021</safra>
+            <ref>08/2021</ref>
+            <forDia dia=\"31\">
+                <qtde>100</qtde>
+            </forDia>
+            <qTotMes>100</qTotMes>
+            <qTotAnt>0</qTotAnt>
+            <qTotGer>100</qTotGer>
+            <vFor>10.00</vFor>
+            <vTotDed>0.00</vTotDed>
+            <vLiqFor>10.00</vLiqFor>
+        </cana>
+    ";
+
+    let cana = xml.parse::<Cana>()?;
+
+    assert_eq!(1, cana.fornecimentos.len());
+    assert_eq!(31, cana.fornecimentos[0].dia);
+    assert!(cana.deducoes.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn to_string() -> Result<(), Error> {
+    let mut xml_original = "
+        <cana>
+            <safra>2021/2022</safra>
+            <ref>08/2021</ref>
+            <forDia dia=\"1\">
+                <qtde>1500.5</qtde>
+            </forDia>
+            <forDia dia=\"2\">
+                <qtde>1200</qtde>
+            </forDia>
+            <qTotMes>2700.5</qTotMes>
+            <qTotAnt>10000</qTotAnt>
+            <qTotGer>12700.5</qTotGer>
+            <deduc>
+                <xDed>FUNRURAL</xDed>
+                <vDed>35.2</vDed>
+            </deduc>
+            <vFor>1500</vFor>
+            <vTotDed>35.2</vTotDed>
+            <vLiqFor>1464.8</vLiqFor>
+        </cana>
+    "
+    .to_string();
+    xml_original.retain(|c| c != '\n' && c != ' ');
+    xml_original = xml_original.replace("forDiadia", "forDia dia");
+
+    let cana = xml_original.parse::<Cana>()?;
+    let xml_novo = cana.to_string();
+
+    assert_eq!(xml_original, xml_novo);
+
+    Ok(())
+}
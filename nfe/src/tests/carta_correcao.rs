@@ -0,0 +1,179 @@
+//! Testes da carta de correção eletrônica(CC-e)
+
+use chrono::prelude::*;
+
+use super::ler;
+use crate::base::Nfe as NfeBase;
+use crate::*;
+
+const CHAVE: &str = "43180906929383000163550010000000261000010301";
+const CORRECAO: &str =
+    "Onde se le: Volumes 1; leia-se: Volumes 2. Peso bruto de 10,000 kg para 12,500 kg.";
+
+fn nota() -> Result<NfeBase, String> {
+    ler("xmls/nfe_layout4.xml")?
+        .parse::<NfeBase>()
+        .map_err(|e| e.to_string())
+}
+
+fn horario() -> DateTime<FixedOffset> {
+    FixedOffset::west_opt(3 * 3600)
+        .unwrap()
+        .with_ymd_and_hms(2018, 9, 26, 9, 15, 0)
+        .unwrap()
+}
+
+fn proc_cce() -> Result<ProcEvento, String> {
+    ler("xmls/proc_evento_cce.xml")?
+        .parse::<ProcEvento>()
+        .map_err(|e| e.to_string())
+}
+
+#[test]
+fn carta_correcao() -> Result<(), String> {
+    let evento = Evento::carta_correcao(&nota()?, &format!("  {} ", CORRECAO), 3, horario())
+        .map_err(|e| e.to_string())?;
+
+    assert_eq!(TipoEvento::CartaCorrecao, evento.tipo());
+    assert_eq!(110110, evento.tipo().codigo());
+    assert_eq!(3, evento.sequencia);
+    assert_eq!(
+        "ID1101104318090692938300016355001000000026100001030103",
+        evento.id()
+    );
+    assert_eq!(
+        DetalheEvento::CartaCorrecao(CartaCorrecao {
+            correcao: CORRECAO.to_string(),
+        }),
+        evento.detalhe
+    );
+
+    let xml = evento.to_string();
+    assert!(xml.contains(&format!(
+        "<tpEvento>110110</tpEvento><nSeqEvento>3</nSeqEvento><verEvento>1.00</verEvento>\
+         <detEvento versao=\"1.00\"><descEvento>Carta de Correcao</descEvento>\
+         <xCorrecao>{}</xCorrecao><xCondUso>{}</xCondUso></detEvento>",
+        CORRECAO, CONDICAO_USO_CCE
+    )));
+    assert_eq!(evento, xml.parse::<Evento>().map_err(|e| e.to_string())?);
+
+    // O xCondUso é obrigatório na leitura
+    let sem_condicao = xml.replace(&format!("<xCondUso>{}</xCondUso>", CONDICAO_USO_CCE), "");
+    assert!(sem_condicao.parse::<Evento>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn carta_correcao_invalida() -> Result<(), String> {
+    let nfe = nota()?;
+    let erro = |correcao: &str, sequencia: u8| {
+        Evento::carta_correcao(&nfe, correcao, sequencia, horario())
+            .map_err(|e| e.to_string())
+            .unwrap_err()
+    };
+
+    assert_eq!(
+        "Evento inválido. A correção deve possuir de 15 a 1000 caracteres. Informado: 14",
+        erro(&"x".repeat(14), 1)
+    );
+    assert!(erro(&"x".repeat(1001), 1).contains("Informado: 1001"));
+    assert_eq!(
+        "Evento inválido. Limite de 20 CC-e por nota atingido",
+        erro(CORRECAO, 21)
+    );
+    assert_eq!(
+        "Evento inválido. O nSeqEvento deve iniciar em 1",
+        erro(CORRECAO, 0)
+    );
+
+    assert!(Evento::carta_correcao(&nfe, &"x".repeat(15), 1, horario()).is_ok());
+    assert!(Evento::carta_correcao(&nfe, &"x".repeat(1000), 20, horario()).is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn proc_evento_cce() -> Result<(), String> {
+    let proc = proc_cce()?;
+
+    assert_eq!(CHAVE, proc.evento.chave_acesso);
+    assert_eq!(2, proc.evento.sequencia);
+    assert_eq!(horario(), proc.evento.horario);
+    match &proc.evento.detalhe {
+        DetalheEvento::CartaCorrecao(carta) => assert_eq!(CORRECAO, carta.correcao),
+        outro => return Err(format!("Detalhe inesperado: {:?}", outro)),
+    }
+
+    assert!(proc.retorno.registrado());
+    assert_eq!(Some(110110), proc.retorno.tipo);
+    assert_eq!(Some(2), proc.retorno.sequencia);
+    assert_eq!(
+        Some("Carta de Correcao registrada".to_string()),
+        proc.retorno.descricao
+    );
+
+    let assinatura = proc
+        .evento
+        .assinatura
+        .as_ref()
+        .ok_or("Evento sem assinatura")?;
+    assert_eq!(
+        c14n::digest(&proc.evento.to_string(), "infEvento").map_err(|e| e.to_string())?,
+        assinatura.digest()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sequencia_cce() -> Result<(), String> {
+    let segunda = proc_cce()?;
+
+    assert_eq!(
+        1,
+        proxima_sequencia_cce(CHAVE, &[]).map_err(|e| e.to_string())?
+    );
+
+    let mut primeira = segunda.clone();
+    primeira.evento.sequencia = 1;
+    primeira.retorno.sequencia = Some(1);
+
+    let mut rejeitada = segunda.clone();
+    rejeitada.evento.sequencia = 3;
+    rejeitada.retorno.status = 573;
+
+    let mut outra_nota = segunda.clone();
+    outra_nota.evento.sequencia = 7;
+    outra_nota.evento.chave_acesso = "43180906929383000163550010000000271000010307".to_string();
+
+    let cancelamento = ler("xmls/proc_evento_cancelamento.xml")?
+        .parse::<ProcEvento>()
+        .map_err(|e| e.to_string())?;
+
+    let eventos = vec![
+        segunda.clone(),
+        rejeitada,
+        cancelamento,
+        outra_nota,
+        primeira.clone(),
+    ];
+
+    // Apenas as CC-e registradas da chave, em ordem de sequência
+    assert_eq!(vec![&primeira, &segunda], cartas_correcao(CHAVE, &eventos));
+    assert_eq!(
+        3,
+        proxima_sequencia_cce(CHAVE, &eventos).map_err(|e| e.to_string())?
+    );
+
+    let mut ultima = segunda;
+    ultima.evento.sequencia = 20;
+    assert_eq!(
+        "Evento inválido. Limite de 20 CC-e por nota atingido",
+        proxima_sequencia_cce(CHAVE, &[ultima])
+            .map_err(|e| e.to_string())
+            .unwrap_err()
+    );
+
+    Ok(())
+}
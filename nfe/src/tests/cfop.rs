@@ -0,0 +1,103 @@
+//! Testes da tabela de CFOPs
+
+use std::convert::TryFrom;
+use std::fs::File;
+
+use crate::base::Error as ErrorBase;
+use crate::base::Nfe as NfeBase;
+use crate::*;
+
+#[test]
+fn atributos() -> Result<(), String> {
+    let venda: Cfop = "6.101".parse().map_err(|e: ErrorBase| e.to_string())?;
+    assert_eq!(Cfop::Cfop6101, venda);
+    assert_eq!(6101, venda.codigo());
+    assert_eq!(TipoOperacao::Saida, venda.tipo());
+    assert_eq!(DestinoOperacao::Interestadual, venda.destino());
+    assert_eq!("Venda de produção do estabelecimento", venda.descricao());
+    assert!(!venda.devolucao());
+    assert!(!venda.remessa());
+
+    let devolucao: Cfop = "1202".parse().map_err(|e: ErrorBase| e.to_string())?;
+    assert_eq!(TipoOperacao::Entrada, devolucao.tipo());
+    assert_eq!(DestinoOperacao::Interna, devolucao.destino());
+    assert!(devolucao.devolucao());
+
+    let remessa: Cfop = "5915".parse().map_err(|e: ErrorBase| e.to_string())?;
+    assert!(remessa.remessa());
+
+    let exportacao: Cfop = "7101".parse().map_err(|e: ErrorBase| e.to_string())?;
+    assert_eq!(DestinoOperacao::ComExterior, exportacao.destino());
+
+    let servico: Cfop = "5933".parse().map_err(|e: ErrorBase| e.to_string())?;
+    assert_eq!(
+        "Prestação de serviço tributado pelo ISSQN",
+        servico.descricao()
+    );
+    assert!(!servico.remessa());
+
+    let retorno: Cfop = "1916".parse().map_err(|e: ErrorBase| e.to_string())?;
+    assert!(!retorno.remessa());
+    assert!(!retorno.devolucao());
+
+    Ok(())
+}
+
+#[test]
+fn invalidos() {
+    assert!("4101".parse::<Cfop>().is_err());
+    assert!("510".parse::<Cfop>().is_err());
+    assert!("51O1".parse::<Cfop>().is_err());
+
+    // Formato válido, mas fora da tabela
+    assert_eq!(
+        "CFOP inválido. Código 5.199 não consta na tabela de CFOPs",
+        "5.199".parse::<Cfop>().unwrap_err().to_string()
+    );
+    assert!("5100".parse::<Cfop>().is_err());
+}
+
+#[test]
+fn catalogo() {
+    let cfops: Vec<Cfop> = cfops().collect();
+
+    assert_eq!(Cfop::TODOS.len(), cfops.len());
+    assert!(cfops.len() > 500);
+    assert!(cfops.windows(2).all(|c| c[0].codigo() < c[1].codigo()));
+    assert!(cfops.iter().all(|c| !c.descricao().is_empty()));
+    assert!(cfops.iter().all(|c| c
+        .to_string()
+        .parse::<Cfop>()
+        .map(|p| p == *c)
+        .unwrap_or(false)));
+    assert!(cfops.iter().any(|c| c.devolucao()));
+    assert!(cfops.iter().any(|c| c.remessa()));
+}
+
+#[test]
+fn validar_nota() -> Result<(), String> {
+    let f = File::open("xmls/nfe_layout4.xml").map_err(|e| e.to_string())?;
+    let mut nfe = NfeBase::try_from(f).map_err(|e| e.to_string())?;
+
+    assert_eq!(
+        Ok(true),
+        nfe.itens[0]
+            .produto
+            .cfop()
+            .map(|c| c == "6101")
+            .map_err(|e| e.to_string())
+    );
+
+    // Operação interna em nota interestadual
+    nfe.itens[0].produto.tributacao.cfop = "5101".to_string();
+    match nfe.validar() {
+        Err(ErrorBase::CfopInvalido(e)) => assert!(e.starts_with("Item 1")),
+        r => panic!("{:?}", r),
+    }
+
+    // Entrada em nota de saída
+    nfe.itens[0].produto.tributacao.cfop = "2101".to_string();
+    assert!(nfe.validar().is_err());
+
+    Ok(())
+}
@@ -0,0 +1,210 @@
+//! Testes da chave de acesso
+
+use chrono::prelude::*;
+use std::convert::TryFrom;
+use std::fs::File;
+
+use crate::base::Error as ErrorBase;
+use crate::base::Nfe as NfeBase;
+use crate::*;
+
+#[test]
+fn from_str() -> Result<(), Error> {
+    let chave = "NFe43180906929383000163550010000000261000010301".parse::<ChaveAcesso>()?;
+
+    assert_eq!(43, chave.codigo_uf);
+    assert_eq!(18, chave.ano);
+    assert_eq!(9, chave.mes);
+    assert_eq!("06929383000163", chave.documento);
+    assert_eq!(ModeloDocumentoFiscal::Nfe, chave.modelo);
+    assert_eq!(1, chave.serie);
+    assert_eq!(26, chave.numero);
+    assert_eq!(TipoEmissao::Normal, chave.tipo_emissao);
+    assert_eq!("00001030", chave.codigo);
+    assert_eq!(1, chave.digito_verificador);
+
+    assert_eq!(
+        "43180906929383000163550010000000261000010301",
+        chave.to_string()
+    );
+    assert_eq!(
+        "NFe43180906929383000163550010000000261000010301",
+        chave.id()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn digito_verificador() {
+    assert_eq!(
+        1,
+        calcular_digito_verificador("4318090692938300016355001000000026100001030")
+    );
+    assert_eq!(
+        8,
+        calcular_digito_verificador("2918103365767700015665001000165439900165439")
+    );
+    // Resto 0 ou 1 resulta em dígito 0
+    assert_eq!(
+        0,
+        calcular_digito_verificador("3518050000000000000055001000000001100000000")
+    );
+}
+
+#[test]
+fn invalidas() {
+    let invalidas = [
+        // Dígito verificador incorreto
+        "29181033657677000156650010001654399001654399",
+        // Tamanho
+        "4318090692938300016355001000000026100001030",
+        // Caracteres
+        "4318090692938300016355001000000026100001030A",
+        // Modelo
+        "43180906929383000163570010000000261000010302",
+    ];
+
+    for chave in invalidas.iter() {
+        match chave.parse::<ChaveAcesso>() {
+            Err(ErrorBase::ChaveAcessoInvalida(_)) => {}
+            r => panic!("{}: {:?}", chave, r),
+        }
+    }
+}
+
+#[test]
+fn from_nfe() -> Result<(), String> {
+    let f = File::open("xmls/nfe_layout4.xml").map_err(|e| e.to_string())?;
+    let nfe = Nfe::try_from(f).map_err(|e| e.to_string())?;
+
+    let chave = ChaveAcesso::from_nfe(&nfe.ide, &nfe.emit).map_err(|e| e.to_string())?;
+
+    assert_eq!(nfe.chave_acesso, chave.to_string());
+    assert_eq!(chave, nfe.chave().map_err(|e| e.to_string())?);
+
+    Ok(())
+}
+
+#[test]
+fn validar() -> Result<(), String> {
+    let f = File::open("xmls/nfe_layout4.xml").map_err(|e| e.to_string())?;
+    let nfe = Nfe::try_from(f).map_err(|e| e.to_string())?;
+
+    nfe.validar().map_err(|e| e.to_string())?;
+
+    // A chave do arquivo da NFC-e possui o cDV incorreto
+    let f = File::open("xmls/nfce_layout4.xml").map_err(|e| e.to_string())?;
+    let nfce = NfeBase::try_from(f).map_err(|e| e.to_string())?;
+
+    match nfce.validar() {
+        Err(ErrorBase::ChaveAcessoInvalida(_)) => {}
+        r => panic!("{:?}", r),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn divergente_da_nota() -> Result<(), String> {
+    let f = File::open("xmls/nfe_layout4.xml").map_err(|e| e.to_string())?;
+    let mut nfe = NfeBase::try_from(f).map_err(|e| e.to_string())?;
+
+    nfe.ide.numero = 27;
+
+    match nfe.validar() {
+        Err(ErrorBase::ChaveAcessoDivergente(e)) => {
+            assert_eq!("nNF da chave(26) difere do informado na nota(27)", e)
+        }
+        r => panic!("{:?}", r),
+    }
+
+    nfe.ide.numero = 26;
+    nfe.ide.codigo_uf = 35;
+
+    match nfe.validar() {
+        Err(ErrorBase::ChaveAcessoDivergente(e)) => {
+            assert_eq!("cUF da chave(43) difere do informado na nota(35)", e)
+        }
+        r => panic!("{:?}", r),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn codigo_numerico() {
+    assert!(codigo_numerico_valido("00001030", 26));
+    assert!(codigo_numerico_valido("10293847", 26));
+
+    assert!(!codigo_numerico_valido("00000026", 26));
+    assert!(!codigo_numerico_valido("00000000", 26));
+    assert!(!codigo_numerico_valido("77777777", 26));
+    assert!(!codigo_numerico_valido("12345678", 26));
+    assert!(!codigo_numerico_valido("89012345", 26));
+    assert!(!codigo_numerico_valido("87654321", 26));
+    assert!(!codigo_numerico_valido("1234567", 26));
+    assert!(!codigo_numerico_valido("1234567A", 26));
+
+    for _ in 0..100 {
+        assert!(codigo_numerico_valido(&gerar_codigo_numerico(26), 26));
+    }
+}
+
+#[test]
+fn gerar() -> Result<(), Error> {
+    let chave = ChaveAcesso::gerar(
+        43,
+        Utc.with_ymd_and_hms(2021, 11, 30, 23, 0, 0).unwrap(),
+        "06929383000163",
+        ModeloDocumentoFiscal::Nfce,
+        2,
+        1500,
+        TipoEmissao::Normal,
+    )?;
+
+    let texto = chave.to_string();
+
+    assert_eq!(44, texto.len());
+    assert!(texto.starts_with("4321110692938300016365002000001500"));
+    assert_ne!("00001500", chave.codigo);
+    assert_eq!(chave, texto.parse::<ChaveAcesso>()?);
+
+    // O AAMM segue o horário de Brasília
+    let chave = ChaveAcesso::gerar(
+        43,
+        Utc.with_ymd_and_hms(2021, 12, 1, 2, 0, 0).unwrap(),
+        "06929383000163",
+        ModeloDocumentoFiscal::Nfe,
+        1,
+        1,
+        TipoEmissao::Normal,
+    )?;
+
+    assert_eq!((21, 11), (chave.ano, chave.mes));
+
+    Ok(())
+}
+
+#[test]
+fn gerar_na_nota() -> Result<(), String> {
+    let f = File::open("xmls/nfe_layout4.xml").map_err(|e| e.to_string())?;
+    let mut nfe = Nfe::try_from(f).map_err(|e| e.to_string())?;
+
+    nfe.ide.numero = 27;
+    let chave = nfe.gerar_chave_acesso().map_err(|e| e.to_string())?;
+
+    assert_eq!(chave.to_string(), nfe.chave_acesso);
+    assert_eq!(chave.codigo, nfe.ide.chave.codigo);
+    assert_eq!(chave.digito_verificador, nfe.ide.chave.digito_verificador);
+    assert_eq!(27, chave.numero);
+
+    nfe.validar().map_err(|e| e.to_string())?;
+
+    let xml = nfe.to_string();
+    let nfe = xml.parse::<Nfe>().map_err(|e| e.to_string())?;
+
+    nfe.validar().map_err(|e| e.to_string())?;
+
+    Ok(())
+}
@@ -0,0 +1,374 @@
+//! Testes do cliente dos web services, contra um servidor HTTPS local
+
+use openssl::asn1::Asn1Time;
+use openssl::bn::BigNum;
+use openssl::hash::MessageDigest;
+use openssl::nid::Nid;
+use openssl::pkey::{PKey, Private};
+use openssl::rsa::Rsa;
+use openssl::ssl::{SslAcceptor, SslMethod, SslVerifyMode};
+use openssl::x509::extension::SubjectAlternativeName;
+use openssl::x509::{X509Name, X509};
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+use super::assinatura::certificado_teste;
+use super::ler;
+use crate::base::Nfe as NfeBase;
+use crate::client::{soap, Cliente};
+use crate::*;
+
+/// Requisição recebida pelo servidor local
+struct Requisicao {
+    linha: String,
+    cabecalhos: String,
+    corpo: String,
+    /// Titular do certificado apresentado pelo cliente
+    titular: String,
+}
+
+/// Certificado autoassinado do servidor, emitido para 127.0.0.1
+fn certificado_servidor() -> (X509, PKey<Private>) {
+    let chave = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+
+    let mut nome = X509Name::builder().unwrap();
+    nome.append_entry_by_text("CN", "localhost").unwrap();
+    let nome = nome.build();
+
+    let mut x509 = X509::builder().unwrap();
+    x509.set_version(2).unwrap();
+    x509.set_serial_number(&BigNum::from_u32(2).unwrap().to_asn1_integer().unwrap())
+        .unwrap();
+    x509.set_subject_name(&nome).unwrap();
+    x509.set_issuer_name(&nome).unwrap();
+    x509.set_pubkey(&chave).unwrap();
+    x509.set_not_before(&Asn1Time::days_from_now(0).unwrap())
+        .unwrap();
+    x509.set_not_after(&Asn1Time::days_from_now(1).unwrap())
+        .unwrap();
+    let san = SubjectAlternativeName::new()
+        .dns("localhost")
+        .ip("127.0.0.1")
+        .build(&x509.x509v3_context(None, None))
+        .unwrap();
+    x509.append_extension(san).unwrap();
+    x509.sign(&chave, MessageDigest::sha256()).unwrap();
+
+    (x509.build(), chave)
+}
+
+/// Sobe um servidor que atende uma requisição com a resposta informada
+///
+/// Retorna o cliente, que confia no servidor, a URL e a requisição recebida
+fn servidor(status: &'static str, resposta: String) -> (Cliente, String, JoinHandle<Requisicao>) {
+    let (x509, chave) = certificado_servidor();
+
+    let mut acceptor = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls()).unwrap();
+    acceptor.set_private_key(&chave).unwrap();
+    acceptor.set_certificate(&x509).unwrap();
+    // Exige o certificado do cliente, aceitando o autoassinado de teste
+    acceptor.set_verify_callback(
+        SslVerifyMode::PEER | SslVerifyMode::FAIL_IF_NO_PEER_CERT,
+        |_, _| true,
+    );
+    let acceptor = acceptor.build();
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!(
+        "https://127.0.0.1:{}/ws/NFeAutorizacao4.asmx",
+        listener.local_addr().unwrap().port()
+    );
+
+    let tarefa = thread::spawn(move || {
+        let (tcp, _) = listener.accept().unwrap();
+        let mut tls = acceptor.accept(tcp).unwrap();
+
+        let titular = tls
+            .ssl()
+            .peer_certificate()
+            .and_then(|c| {
+                c.subject_name()
+                    .entries_by_nid(Nid::COMMONNAME)
+                    .next()
+                    .and_then(|e| e.data().to_string().ok())
+            })
+            .unwrap_or_default();
+
+        // Cabeçalhos até a linha em branco, seguidos do corpo pelo Content-Length
+        let mut lido = Vec::new();
+        let mut byte = [0u8; 1];
+        while !lido.ends_with(b"\r\n\r\n") {
+            tls.read_exact(&mut byte).unwrap();
+            lido.push(byte[0]);
+        }
+        let cabecalho = String::from_utf8(lido).unwrap();
+        let tamanho = cabecalho
+            .lines()
+            .find_map(|l| {
+                let (nome, valor) = l.split_once(':')?;
+                if nome.eq_ignore_ascii_case("content-length") {
+                    valor.trim().parse::<usize>().ok()
+                } else {
+                    None
+                }
+            })
+            .unwrap_or(0);
+        let mut corpo = vec![0u8; tamanho];
+        tls.read_exact(&mut corpo).unwrap();
+
+        write!(
+            tls,
+            "HTTP/1.1 {}\r\nContent-Type: application/soap+xml; charset=utf-8\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            resposta.len(),
+            resposta
+        )
+        .unwrap();
+        tls.flush().unwrap();
+        let _ = tls.shutdown();
+
+        let (linha, cabecalhos) = cabecalho.split_once("\r\n").unwrap();
+        Requisicao {
+            linha: linha.to_string(),
+            cabecalhos: cabecalhos.to_string(),
+            corpo: String::from_utf8(corpo).unwrap(),
+            titular,
+        }
+    });
+
+    let raiz = Certificado::from_der(&x509.to_der().unwrap()).unwrap();
+    let cliente = Cliente::com_raizes(&certificado_teste(), &[raiz]).unwrap();
+
+    (cliente, url, tarefa)
+}
+
+fn envelope_resposta(servico: Servico, conteudo: &str) -> String {
+    format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+         <soap:Envelope xmlns:soap=\"http://www.w3.org/2003/05/soap-envelope\">\
+         <soap:Body><nfeResultMsg xmlns=\"{}\">{}</nfeResultMsg></soap:Body></soap:Envelope>",
+        servico.namespace(),
+        conteudo
+    )
+}
+
+#[test]
+fn autorizar_sincrono() -> Result<(), String> {
+    let mut nfe = ler("xmls/nfe_layout4.xml")?
+        .parse::<NfeBase>()
+        .map_err(|e| e.to_string())?;
+    nfe.assinar(&certificado_teste())
+        .map_err(|e| e.to_string())?;
+    let lote = EnvioLote::new(7, true, vec![nfe]).map_err(|e| e.to_string())?;
+
+    let protocolo = ler("xmls/nfe_proc_layout4.xml")?;
+    let protocolo = &protocolo[protocolo.find("<protNFe").ok_or("Sem protocolo")?
+        ..protocolo.find("</nfeProc>").ok_or("Sem protocolo")?];
+    let retorno = format!(
+        "<retEnviNFe xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"4.00\">\
+         <tpAmb>1</tpAmb><verAplic>RS20180920103458</verAplic><cStat>104</cStat>\
+         <xMotivo>Lote processado</xMotivo><cUF>43</cUF>\
+         <dhRecbto>2018-09-25T10:02:57-03:00</dhRecbto>{}</retEnviNFe>",
+        protocolo
+    );
+
+    let (cliente, url, servidor) =
+        servidor("200 OK", envelope_resposta(Servico::Autorizacao, &retorno));
+
+    // Endereço do RS substituído pelo servidor local
+    let mut ws = WebServices::new();
+    ws.substituir(
+        43,
+        ModeloDocumentoFiscal::Nfe,
+        TipoAmbiente::Homologacao,
+        Servico::Autorizacao,
+        &url,
+    );
+    let url = ws
+        .url(
+            43,
+            ModeloDocumentoFiscal::Nfe,
+            TipoAmbiente::Homologacao,
+            Servico::Autorizacao,
+        )
+        .map_err(|e| e.to_string())?;
+    let retorno = cliente.autorizar(&url, &lote).map_err(|e| e.to_string())?;
+    let requisicao = servidor.join().map_err(|_| "Falha no servidor")?;
+
+    // Autenticado com o certificado do emitente
+    assert_eq!("EMPRESA DE TESTE LTDA:06929383000163", requisicao.titular);
+    assert_eq!("POST /ws/NFeAutorizacao4.asmx HTTP/1.1", requisicao.linha);
+    assert!(requisicao.cabecalhos.contains(
+        "application/soap+xml; charset=utf-8; \
+         action=\"http://www.portalfiscal.inf.br/nfe/wsdl/NFeAutorizacao4/nfeAutorizacaoLote\""
+    ));
+    assert!(requisicao.corpo.contains(
+        "<soap12:Body><nfeDadosMsg xmlns=\"http://www.portalfiscal.inf.br/nfe/wsdl/NFeAutorizacao4\">\
+         <enviNFe xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"4.00\">\
+         <idLote>7</idLote><indSinc>1</indSinc><NFe"
+    ));
+    assert!(requisicao
+        .corpo
+        .contains(&format!("{}</nfeDadosMsg>", lote)));
+
+    assert!(retorno.processado());
+    let protocolo = retorno.protocolo.ok_or("Retorno sem protocolo")?;
+    assert!(protocolo.autorizado());
+    assert_eq!(Some("143180000001234".to_string()), protocolo.numero);
+
+    Ok(())
+}
+
+#[test]
+fn consultar_recibo() -> Result<(), String> {
+    let retorno = "<retConsReciNFe xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"4.00\">\
+                   <tpAmb>2</tpAmb><verAplic>RS20180920103458</verAplic><nRec>431000012345678</nRec>\
+                   <cStat>105</cStat><xMotivo>Lote em processamento</xMotivo><cUF>43</cUF>\
+                   <dhRecbto>2018-09-25T10:02:58-03:00</dhRecbto></retConsReciNFe>";
+
+    let (cliente, url, servidor) = servidor(
+        "200 OK",
+        envelope_resposta(Servico::RetornoAutorizacao, retorno),
+    );
+    let consulta = ConsultaRecibo {
+        ambiente: TipoAmbiente::Homologacao,
+        recibo: "431000012345678".to_string(),
+    };
+    let retorno = cliente
+        .consultar_recibo(&url, &consulta)
+        .map_err(|e| e.to_string())?;
+    let requisicao = servidor.join().map_err(|_| "Falha no servidor")?;
+
+    assert!(requisicao
+        .cabecalhos
+        .contains("NFeRetAutorizacao4/nfeRetAutorizacaoLote"));
+    assert!(requisicao.corpo.contains(&consulta.to_string()));
+    assert!(retorno.em_processamento());
+    assert_eq!("431000012345678", retorno.recibo);
+
+    Ok(())
+}
+
+#[test]
+fn consultar_situacao() -> Result<(), String> {
+    let retorno = ler("xmls/ret_cons_sit_nfe.xml")?;
+    let retorno = retorno
+        .trim_start_matches("<?xml version=\"1.0\" encoding=\"UTF-8\"?>")
+        .trim();
+
+    let (cliente, url, servidor) = servidor(
+        "200 OK",
+        envelope_resposta(Servico::ConsultaProtocolo, retorno),
+    );
+    let consulta = ConsultaSituacao {
+        ambiente: TipoAmbiente::Homologacao,
+        chave_acesso: "43180906929383000163550010000000261000010301".to_string(),
+    };
+    let retorno = cliente
+        .consultar_situacao(&url, &consulta)
+        .map_err(|e| e.to_string())?;
+    let requisicao = servidor.join().map_err(|_| "Falha no servidor")?;
+
+    assert!(requisicao
+        .cabecalhos
+        .contains("NFeConsultaProtocolo4/nfeConsultaNF"));
+    assert!(requisicao.corpo.contains(&consulta.to_string()));
+    assert!(retorno.cancelada());
+    assert_eq!(2, retorno.eventos.len());
+
+    // A chave inválida não chega a ser enviada
+    let invalida = ConsultaSituacao {
+        chave_acesso: "4318".to_string(),
+        ..consulta
+    };
+    assert!(cliente.consultar_situacao(&url, &invalida).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn distribuir() -> Result<(), String> {
+    let retorno = ler("xmls/ret_dist_dfe_int.xml")?;
+    let retorno = retorno
+        .trim_start_matches("<?xml version=\"1.0\" encoding=\"UTF-8\"?>")
+        .trim();
+
+    // A distribuição responde dentro do elemento da operação
+    let resposta = format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+         <soap:Envelope xmlns:soap=\"http://www.w3.org/2003/05/soap-envelope\"><soap:Body>\
+         <nfeDistDFeInteresseResponse xmlns=\"{0}\"><nfeDistDFeInteresseResult>{1}\
+         </nfeDistDFeInteresseResult></nfeDistDFeInteresseResponse></soap:Body></soap:Envelope>",
+        Servico::DistribuicaoDFe.namespace(),
+        retorno
+    );
+
+    let (cliente, url, servidor) = servidor("200 OK", resposta);
+    let distribuicao = DistribuicaoDFe {
+        ambiente: TipoAmbiente::Homologacao,
+        codigo_uf_autor: 43,
+        interessado: Interessado::Cnpj(
+            "06929383000163"
+                .parse::<Cnpj>()
+                .map_err(|e| e.to_string())?,
+        ),
+        consulta: Some(ConsultaDistribuicao::UltimoNsu(0)),
+        chave_acesso: None,
+    };
+    let retorno = cliente
+        .distribuir(&url, &distribuicao)
+        .map_err(|e| e.to_string())?;
+    let requisicao = servidor.join().map_err(|_| "Falha no servidor")?;
+
+    assert!(requisicao
+        .cabecalhos
+        .contains("NFeDistribuicaoDFe/nfeDistDFeInteresse"));
+    assert!(requisicao.corpo.contains(&format!(
+        "<nfeDistDFeInteresse xmlns=\"{}\"><nfeDadosMsg>{}</nfeDadosMsg></nfeDistDFeInteresse>",
+        Servico::DistribuicaoDFe.namespace(),
+        distribuicao
+    )));
+    assert!(retorno.documentos_localizados());
+    let documentos = retorno.decodificar();
+    assert_eq!(4, documentos.len());
+    assert!(documentos.iter().all(|(_, doc)| doc.is_ok()));
+
+    Ok(())
+}
+
+#[test]
+fn falha_soap() -> Result<(), String> {
+    let falha = "<soap:Envelope xmlns:soap=\"http://www.w3.org/2003/05/soap-envelope\"><soap:Body>\
+                 <soap:Fault><soap:Code><soap:Value>soap:Receiver</soap:Value></soap:Code>\
+                 <soap:Reason><soap:Text xml:lang=\"pt\">Servico paralisado</soap:Text></soap:Reason>\
+                 </soap:Fault></soap:Body></soap:Envelope>";
+
+    let (cliente, url, servidor) = servidor("500 Internal Server Error", falha.to_string());
+    let consulta = ConsultaRecibo {
+        ambiente: TipoAmbiente::Homologacao,
+        recibo: "431000012345678".to_string(),
+    };
+    let erro = cliente
+        .consultar_recibo(&url, &consulta)
+        .map_err(|e| e.to_string())
+        .unwrap_err();
+    servidor.join().map_err(|_| "Falha no servidor")?;
+
+    assert_eq!(
+        "Falha na comunicação com a SEFAZ. HTTP 500: Servico paralisado",
+        erro
+    );
+
+    // SOAP 1.1 e resposta sem o resultado
+    assert_eq!(
+        Some("Erro".to_string()),
+        soap::falha(
+            "<s:Fault><faultcode>s:Server</faultcode><faultstring>Erro</faultstring></s:Fault>"
+        )
+    );
+    assert_eq!(None, soap::resultado("<soap:Body></soap:Body>"));
+
+    Ok(())
+}
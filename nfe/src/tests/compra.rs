@@ -0,0 +1,42 @@
+//! Testes da tag <compra>
+
+use crate::*;
+
+#[test]
+fn manual() -> Result<(), Error> {
+    let xml = "
+        <compra>
+            <xNEmp>2021NE000123</xNEmp>
+            <xPed>PED-4455</xPed>
+            <xCont>CT-2021/15</xCont>
+        </compra>
+    ";
+
+    let compra = xml.parse::<Compra>()?;
+
+    assert_eq!(Some("2021NE000123".to_string()), compra.nota_empenho);
+    assert_eq!(Some("PED-4455".to_string()), compra.pedido);
+    assert_eq!(Some("CT-2021/15".to_string()), compra.contrato);
+
+    Ok(())
+}
+
+#[test]
+fn to_string() -> Result<(), Error> {
+    let mut xml_original = "
+        <compra>
+            <xPed>PED-4455</xPed>
+        </compra>
+    "
+    .to_string();
+    xml_original.retain(|c| c != '\n' && c != ' ');
+
+    let compra = xml_original.parse::<Compra>()?;
+    let xml_novo = compra.to_string();
+
+    assert_eq!(None, compra.nota_empenho);
+    assert_eq!(None, compra.contrato);
+    assert_eq!(xml_original, xml_novo);
+
+    Ok(())
+}
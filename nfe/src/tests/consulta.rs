@@ -0,0 +1,233 @@
+//! Testes das consultas de status do serviço, situação da nota e cadastro
+
+use chrono::prelude::*;
+
+use super::ler;
+use crate::base::c14n;
+use crate::*;
+
+const CHAVE: &str = "43180906929383000163550010000000261000010301";
+
+#[test]
+fn status_servico() -> Result<(), String> {
+    let consulta = ConsultaStatus {
+        ambiente: TipoAmbiente::Homologacao,
+        codigo_uf: 43,
+    };
+    assert_eq!(
+        "<consStatServ xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"4.00\">\
+         <tpAmb>2</tpAmb><cUF>43</cUF><xServ>STATUS</xServ></consStatServ>",
+        consulta.to_string()
+    );
+
+    let xml = "<retConsStatServ xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"4.00\">\
+               <tpAmb>2</tpAmb><verAplic>RS20180920103458</verAplic><cStat>107</cStat>\
+               <xMotivo>Servico em Operacao</xMotivo><cUF>43</cUF>\
+               <dhRecbto>2018-09-25T10:02:57-03:00</dhRecbto><tMed>1</tMed></retConsStatServ>";
+    let retorno = xml.parse::<RetornoStatus>().map_err(|e| e.to_string())?;
+    assert!(retorno.em_operacao());
+    assert!(!retorno.paralisado());
+    assert_eq!(Some(1), retorno.tempo_medio);
+    assert_eq!(None, retorno.previsao_retorno);
+
+    // Paralisado, com a previsão de retorno
+    let xml = "<retConsStatServ xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"4.00\">\
+               <tpAmb>1</tpAmb><verAplic>SP_NFE_PL009_V4</verAplic><cStat>108</cStat>\
+               <xMotivo>Servico Paralisado Momentaneamente (curto prazo)</xMotivo><cUF>35</cUF>\
+               <dhRecbto>2018-09-25T10:02:57-03:00</dhRecbto>\
+               <dhRetorno>2018-09-25T11:00:00-03:00</dhRetorno>\
+               <xObs>Manutencao programada</xObs></retConsStatServ>";
+    let retorno = xml.parse::<RetornoStatus>().map_err(|e| e.to_string())?;
+    assert!(retorno.paralisado());
+    assert_eq!(
+        Some(
+            FixedOffset::west_opt(3 * 3600)
+                .unwrap()
+                .with_ymd_and_hms(2018, 9, 25, 11, 0, 0)
+                .unwrap()
+        ),
+        retorno.previsao_retorno
+    );
+    assert_eq!(
+        Some("Manutencao programada".to_string()),
+        retorno.observacao
+    );
+
+    Ok(())
+}
+
+#[test]
+fn situacao_da_nota() -> Result<(), String> {
+    let consulta = ConsultaSituacao {
+        ambiente: TipoAmbiente::Homologacao,
+        chave_acesso: CHAVE.to_string(),
+    };
+    assert_eq!(
+        "<consSitNFe xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"4.00\">\
+         <tpAmb>2</tpAmb><xServ>CONSULTAR</xServ>\
+         <chNFe>43180906929383000163550010000000261000010301</chNFe></consSitNFe>",
+        consulta.to_string()
+    );
+    consulta.validar().map_err(|e| e.to_string())?;
+
+    let invalida = ConsultaSituacao {
+        chave_acesso: "43180906929383000163550010000000261000010302".to_string(),
+        ..consulta
+    };
+    assert!(invalida.validar().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn retorno_situacao() -> Result<(), String> {
+    let xml = ler("xmls/ret_cons_sit_nfe.xml")?;
+    let retorno = xml.parse::<RetornoSituacao>().map_err(|e| e.to_string())?;
+
+    assert!(retorno.cancelada());
+    assert!(!retorno.autorizada());
+    assert_eq!(Some(CHAVE.to_string()), retorno.chave_acesso);
+
+    let protocolo = retorno.protocolo.as_ref().ok_or("Retorno sem protocolo")?;
+    assert!(protocolo.autorizado());
+    assert_eq!(Some("143180000001234".to_string()), protocolo.numero);
+
+    assert_eq!(2, retorno.eventos.len());
+    let cancelamentos = retorno.eventos_do_tipo(110111);
+    assert_eq!(1, cancelamentos.len());
+    assert_eq!(
+        Some("143180000005678".to_string()),
+        cancelamentos[0].retorno.protocolo
+    );
+
+    // Os eventos preservam a assinatura, igual à leitura do arquivo do evento
+    let cce = ler("xmls/proc_evento_cce.xml")?
+        .parse::<ProcEvento>()
+        .map_err(|e| e.to_string())?;
+    assert_eq!(vec![&cce], retorno.eventos_do_tipo(110110));
+
+    let evento = &retorno.eventos_do_tipo(110110)[0].evento;
+    let assinatura = evento.assinatura.as_ref().ok_or("Evento sem assinatura")?;
+    assert_eq!(
+        c14n::digest(&evento.to_string(), "infEvento").map_err(|e| e.to_string())?,
+        assinatura.digest()
+    );
+
+    assert!(retorno.eventos_nao_suportados.is_empty());
+
+    // Eventos de tipos não suportados não impedem a leitura da situação
+    let xml = ler("xmls/ret_cons_sit_nfe.xml")?.replacen(
+        "<tpEvento>110110</tpEvento>",
+        "<tpEvento>610600</tpEvento>",
+        1,
+    );
+    let retorno = xml.parse::<RetornoSituacao>().map_err(|e| e.to_string())?;
+    assert!(retorno.cancelada());
+    assert_eq!(1, retorno.eventos.len());
+    assert_eq!(1, retorno.eventos_do_tipo(110111).len());
+    assert_eq!(1, retorno.eventos_nao_suportados.len());
+
+    let nao_suportado = &retorno.eventos_nao_suportados[0];
+    assert_eq!(Some(610600), nao_suportado.tipo);
+    assert!(nao_suportado.xml.starts_with("<procEventoNFe"));
+    assert!(nao_suportado.xml.ends_with("</procEventoNFe>"));
+
+    // Nota inexistente, sem protocolo e sem eventos
+    let xml = "<retConsSitNFe xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"4.00\">\
+               <tpAmb>2</tpAmb><verAplic>RS20180920103458</verAplic><cStat>217</cStat>\
+               <xMotivo>Rejeicao: NF-e nao consta na base de dados da SEFAZ</xMotivo>\
+               <cUF>43</cUF><dhRecbto>2018-09-28T09:15:42-03:00</dhRecbto>\
+               <chNFe>43180906929383000163550010000000261000010301</chNFe></retConsSitNFe>";
+    let retorno = xml.parse::<RetornoSituacao>().map_err(|e| e.to_string())?;
+    assert_eq!(217, retorno.status);
+    assert_eq!(None, retorno.protocolo);
+    assert!(retorno.eventos.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn cadastro() -> Result<(), String> {
+    let consulta = ConsultaCadastro {
+        uf: Uf::Rs,
+        documento: DocumentoCadastro::Ie("001/8000762".to_string()),
+    };
+    consulta.validar().map_err(|e| e.to_string())?;
+    assert_eq!(
+        "<ConsCad xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"2.00\">\
+         <infCons><xServ>CONS-CAD</xServ><UF>RS</UF><IE>0018000762</IE></infCons></ConsCad>",
+        consulta.to_string()
+    );
+
+    let por_cnpj = ConsultaCadastro {
+        uf: Uf::Sp,
+        documento: DocumentoCadastro::Cnpj(
+            "58716523000119"
+                .parse::<Cnpj>()
+                .map_err(|e| e.to_string())?,
+        ),
+    };
+    assert!(por_cnpj
+        .to_string()
+        .contains("<UF>SP</UF><CNPJ>58716523000119</CNPJ></infCons>"));
+
+    // IE inválida para a UF e isento não são consultados
+    let invalida = ConsultaCadastro {
+        uf: Uf::Rs,
+        documento: DocumentoCadastro::Ie("0018000763".to_string()),
+    };
+    assert!(invalida.validar().is_err());
+    let isento = ConsultaCadastro {
+        uf: Uf::Rs,
+        documento: DocumentoCadastro::Ie("ISENTO".to_string()),
+    };
+    assert!(isento.validar().is_err());
+
+    let xml = "<retConsCad xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"2.00\"><infCons>\
+               <verAplic>RS20180920103458</verAplic><cStat>111</cStat>\
+               <xMotivo>Consulta cadastro com uma ocorrencia</xMotivo><UF>RS</UF>\
+               <IE>0018000762</IE><dhCons>2018-09-25T10:02:57-03:00</dhCons><cUF>43</cUF>\
+               <infCad><IE>0018000762</IE><CNPJ>06929383000163</CNPJ><UF>RS</UF><cSit>1</cSit>\
+               <indCredNFe>1</indCredNFe><indCredCTe>4</indCredCTe>\
+               <xNome>UMA RAZAO SOCIAL DE TESTE QUALQUER</xNome>\
+               <xRegApur>NORMAL - REGIME PERIODICO DE APURACAO</xRegApur><CNAE>6202300</CNAE>\
+               <dIniAtiv>2004-10-15</dIniAtiv><dUltSit>2004-10-15</dUltSit>\
+               <ender><xLgr>Rua dos Testes</xLgr><nro>1020</nro><xBairro>Centro</xBairro>\
+               <cMun>4319901</cMun><xMun>SAPIRANGA</xMun><CEP>93800000</CEP></ender>\
+               </infCad></infCons></retConsCad>";
+    let retorno = xml.parse::<RetornoCadastro>().map_err(|e| e.to_string())?;
+    assert!(retorno.encontrado());
+    assert_eq!(Uf::Rs, retorno.uf);
+
+    let contribuinte = retorno
+        .contribuinte("001/8000762")
+        .ok_or("Contribuinte não encontrado")?;
+    assert!(contribuinte.habilitado());
+    assert!(contribuinte.credenciado_nfe());
+    assert_eq!("06929383000163", contribuinte.documento);
+    assert!(contribuinte.documento.cnpj().is_some());
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2004, 10, 15),
+        contribuinte.inicio_atividade
+    );
+    assert_eq!(None, contribuinte.baixa);
+    assert_eq!(
+        Some(4319901),
+        contribuinte
+            .endereco
+            .as_ref()
+            .and_then(|e| e.codigo_municipio)
+    );
+
+    // Documento sem cadastro
+    let xml = "<retConsCad xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"2.00\"><infCons>\
+               <verAplic>RS20180920103458</verAplic><cStat>259</cStat>\
+               <xMotivo>Rejeicao: CNPJ da consulta nao cadastrado como contribuinte na UF</xMotivo>\
+               <UF>RS</UF><CNPJ>58716523000119</CNPJ><dhCons>2018-09-25T10:02:57-03:00</dhCons>\
+               <cUF>43</cUF></infCons></retConsCad>";
+    let retorno = xml.parse::<RetornoCadastro>().map_err(|e| e.to_string())?;
+    assert!(!retorno.encontrado());
+    assert!(retorno.contribuintes.is_empty());
+
+    Ok(())
+}
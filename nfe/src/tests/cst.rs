@@ -0,0 +1,84 @@
+//! Testes dos códigos de situação tributária
+
+use std::convert::TryFrom;
+use std::fs::File;
+
+use crate::base::Error as ErrorBase;
+use crate::base::Nfe as NfeBase;
+use crate::*;
+
+#[test]
+fn codigos() -> Result<(), String> {
+    let cst: CstPisCofins = "01".parse().map_err(|e: ErrorBase| e.to_string())?;
+    assert_eq!(CstPisCofins::Cst01, cst);
+    assert_eq!("Aliq", cst.grupo());
+    assert_eq!("Operação Tributável com Alíquota Básica", cst.descricao());
+    assert_eq!("01", cst.to_string());
+
+    assert_eq!("ICMS40", CstIcms::Cst41.grupo());
+    assert_eq!("ICMSSN102", Csosn::Csosn400.grupo());
+    assert_eq!("IPINT", CstIpi::Cst53.grupo());
+    assert_eq!("IPITrib", CstIpi::Cst50.grupo());
+
+    assert!("1".parse::<CstPisCofins>().is_err());
+    assert!("10".parse::<CstPisCofins>().is_err());
+    assert!("102".parse::<CstIcms>().is_err());
+    assert!("104".parse::<Csosn>().is_err());
+
+    for cst in CstPisCofins::TODOS.iter() {
+        assert_eq!(
+            Ok(*cst),
+            cst.codigo().parse().map_err(|e: ErrorBase| e.to_string())
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn parse_invalido() {
+    let xml = "<imposto><PIS><PISNT><CST>10</CST></PISNT></PIS></imposto>";
+
+    assert!(xml.parse::<Imposto>().is_err());
+}
+
+#[test]
+fn grupo_incorreto() -> Result<(), String> {
+    let xml = "<imposto><PIS><PISNT><CST>01</CST></PISNT></PIS></imposto>";
+    let imposto = xml.parse::<Imposto>().map_err(|e| e.to_string())?;
+
+    match imposto.validar() {
+        Err(ErrorBase::CstInvalido(e)) => {
+            assert_eq!(
+                "Código 01 informado no grupo PISNT, mas pertence ao grupo PISAliq",
+                e
+            )
+        }
+        r => panic!("{:?}", r),
+    }
+
+    let xml = "<imposto><PIS><PISNT><CST>04</CST></PISNT></PIS></imposto>";
+    let imposto = xml.parse::<Imposto>().map_err(|e| e.to_string())?;
+    imposto.validar().map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+#[test]
+fn validar_nota() -> Result<(), String> {
+    let f = File::open("xmls/nfe_layout4.xml").map_err(|e| e.to_string())?;
+    let mut nfe = NfeBase::try_from(f).map_err(|e| e.to_string())?;
+
+    nfe.validar().map_err(|e| e.to_string())?;
+
+    if let Some(GrupoIcms::IcmsSn202(g)) = &mut nfe.itens[0].imposto.icms {
+        g.codigo_situacao = Csosn::Csosn102;
+    }
+
+    match nfe.validar() {
+        Err(ErrorBase::CstInvalido(e)) => assert!(e.starts_with("Item 1")),
+        r => panic!("{:?}", r),
+    }
+
+    Ok(())
+}
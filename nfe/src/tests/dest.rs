@@ -10,7 +10,7 @@ fn from_instance() -> Result<(), String> {
     let f = File::open("xmls/nfe_layout4.xml").map_err(|e| e.to_string())?;
     let dest = Nfe::try_from(f).map_err(|e| e.to_string())?.dest;
 
-    assert_eq!("58716523000119", dest.cnpj);
+    assert_eq!("58716523000119", dest.documento);
     assert_eq!(
         "NF-E EMITIDA EM AMBIENTE DE HOMOLOGACAO - SEM VALOR FISCAL",
         dest.razao_social
@@ -55,7 +55,7 @@ fn manual() -> Result<(), Error> {
 
     let dest = xml.parse::<Destinatario>()?;
 
-    assert_eq!("58716523000119", dest.cnpj);
+    assert_eq!("58716523000119", dest.documento);
     assert_eq!(
         "NF-E EMITIDA EM AMBIENTE DE HOMOLOGACAO - SEM VALOR FISCAL",
         dest.razao_social
@@ -107,3 +107,86 @@ fn to_string() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn pessoa_fisica() -> Result<(), Error> {
+    let mut xml = "
+        <dest>
+            <CPF>52998224725</CPF>
+            <xNome>CONSUMIDOR</xNome>
+            <enderDest>
+                <xLgr>Av.Teste</xLgr>
+                <nro>2040</nro>
+                <xBairro>Centro</xBairro>
+                <cMun>3550308</cMun>
+                <xMun>SaoPaulo</xMun>
+                <UF>SP</UF>
+                <CEP>04207040</CEP>
+                <cPais>1058</cPais>
+                <xPais>BRASIL</xPais>
+            </enderDest>
+            <indIEDest>9</indIEDest>
+        </dest>
+    "
+    .to_string();
+    xml.retain(|c| c != '\n' && c != ' ');
+
+    let destinatario = xml.parse::<Destinatario>()?;
+
+    assert_eq!("52998224725", destinatario.documento);
+    assert_eq!(None, destinatario.documento.cnpj());
+    assert_eq!(
+        IndicadorContribuicaoIe::NaoContribuinte,
+        destinatario.indicador_ie
+    );
+    assert!(destinatario
+        .to_string()
+        .starts_with("<dest><CPF>52998224725</CPF><xNome>CONSUMIDOR</xNome>"));
+
+    Ok(())
+}
+
+#[test]
+fn estrangeiro() -> Result<(), Error> {
+    let mut xml = "
+        <dest>
+            <idEstrangeiro>AB1234567</idEstrangeiro>
+            <xNome>IMPORTADORAARGENTINA</xNome>
+            <enderDest>
+                <xLgr>AvCorrientes</xLgr>
+                <nro>1000</nro>
+                <xBairro>SanNicolas</xBairro>
+                <cMun>9999999</cMun>
+                <xMun>EXTERIOR</xMun>
+                <UF>EX</UF>
+                <CEP>00000000</CEP>
+                <cPais>639</cPais>
+                <xPais>ARGENTINA</xPais>
+            </enderDest>
+            <indIEDest>9</indIEDest>
+        </dest>
+    "
+    .to_string();
+    xml.retain(|c| c != '\n' && c != ' ');
+
+    let destinatario = xml.parse::<Destinatario>()?;
+
+    assert_eq!(Some("AB1234567"), destinatario.documento.estrangeiro());
+    assert_eq!(None, destinatario.documento.cnpj());
+    assert_eq!(None, destinatario.documento.cpf());
+    assert!(destinatario.to_string().starts_with(
+        "<dest><idEstrangeiro>AB1234567</idEstrangeiro><xNome>IMPORTADORAARGENTINA</xNome>"
+    ));
+
+    // Destinatário sem documento no exterior informa a tag vazia
+    let sem_documento = xml.replace("AB1234567", "").parse::<Destinatario>()?;
+    assert_eq!(Some(""), sem_documento.documento.estrangeiro());
+
+    // O idEstrangeiro, quando informado, possui de 5 a 20 caracteres
+    match xml.replace("AB1234567", "AB12").parse::<Destinatario>() {
+        Err(e) => assert!(e.to_string().contains("idEstrangeiro")),
+        Ok(_) => panic!("idEstrangeiro curto aceito"),
+    }
+
+    Ok(())
+}
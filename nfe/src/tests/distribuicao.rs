@@ -0,0 +1,232 @@
+//! Testes da distribuição de documentos fiscais de interesse
+
+use chrono::prelude::*;
+use chrono::Duration;
+
+use super::ler;
+use crate::*;
+
+const CHAVE: &str = "43180906929383000163550010000000261000010301";
+
+fn distribuicao() -> Result<DistribuicaoDFe, String> {
+    Ok(DistribuicaoDFe {
+        ambiente: TipoAmbiente::Homologacao,
+        codigo_uf_autor: 43,
+        interessado: Interessado::Cnpj(
+            "58716523000119"
+                .parse::<Cnpj>()
+                .map_err(|e| e.to_string())?,
+        ),
+        consulta: Some(ConsultaDistribuicao::UltimoNsu(0)),
+        chave_acesso: None,
+    })
+}
+
+#[test]
+fn pedido_de_distribuicao() -> Result<(), String> {
+    let pedido = distribuicao()?;
+    pedido.validar().map_err(|e| e.to_string())?;
+    assert_eq!(
+        "<distDFeInt xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"1.01\">\
+         <tpAmb>2</tpAmb><cUFAutor>43</cUFAutor><CNPJ>58716523000119</CNPJ>\
+         <distNSU><ultNSU>000000000000000</ultNSU></distNSU></distDFeInt>",
+        pedido.to_string()
+    );
+
+    let por_nsu = DistribuicaoDFe {
+        consulta: Some(ConsultaDistribuicao::Nsu(1234)),
+        ..pedido.clone()
+    };
+    assert!(por_nsu
+        .to_string()
+        .contains("<consNSU><NSU>000000000001234</NSU></consNSU></distDFeInt>"));
+
+    let por_chave = DistribuicaoDFe {
+        consulta: None,
+        chave_acesso: Some(CHAVE.to_string()),
+        ..pedido.clone()
+    };
+    por_chave.validar().map_err(|e| e.to_string())?;
+    assert!(por_chave.to_string().contains(
+        "<consChNFe><chNFe>43180906929383000163550010000000261000010301</chNFe></consChNFe>"
+    ));
+
+    // Apenas uma forma de consulta, e com chave válida
+    let ambas = DistribuicaoDFe {
+        chave_acesso: Some(CHAVE.to_string()),
+        ..pedido.clone()
+    };
+    assert!(ambas.validar().is_err());
+    let chave_invalida = DistribuicaoDFe {
+        chave_acesso: Some("43180906929383000163550010000000261000010302".to_string()),
+        ..por_chave
+    };
+    assert!(chave_invalida.validar().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn retorno_com_documentos() -> Result<(), String> {
+    let xml = ler("xmls/ret_dist_dfe_int.xml")?;
+    let retorno = xml
+        .parse::<RetornoDistribuicao>()
+        .map_err(|e| e.to_string())?;
+
+    assert!(retorno.documentos_localizados());
+    assert!(retorno.possui_mais());
+    assert_eq!(5, retorno.ultimo_nsu);
+    assert_eq!(8, retorno.maximo_nsu);
+    assert_eq!(
+        vec![1, 2, 4, 5],
+        retorno.documentos.iter().map(|d| d.nsu).collect::<Vec<_>>()
+    );
+    assert_eq!(vec![3], retorno.lacunas(0));
+    assert!(retorno.lacunas(5).is_empty());
+
+    let documentos = retorno
+        .decodificar()
+        .into_iter()
+        .map(|(nsu, doc)| doc.map(|doc| (nsu, doc)))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    match &documentos[0] {
+        (1, DocumentoDistribuido::ResumoNfe(resumo)) => {
+            assert_eq!(CHAVE, resumo.chave_acesso);
+            assert_eq!("06929383000163", resumo.documento);
+            assert!(resumo.documento.cnpj().is_some());
+            assert_eq!(TipoOperacao::Saida, resumo.tipo_operacao);
+            assert_eq!(500.0, resumo.valor_total);
+            assert_eq!("143180000001234", resumo.protocolo);
+            assert!(resumo.autorizada());
+        }
+        outro => panic!("{:?}", outro),
+    }
+
+    match &documentos[1] {
+        (2, DocumentoDistribuido::ResumoEvento(resumo)) => {
+            assert_eq!("06929383000163", resumo.autor);
+            assert_eq!(110110, resumo.tipo);
+            assert_eq!(2, resumo.sequencia);
+            assert_eq!("Carta de Correcao", resumo.descricao);
+        }
+        outro => panic!("{:?}", outro),
+    }
+
+    // Emitente pessoa física, identificado pelo CPF
+    let resumo =
+        "<resNFe versao=\"1.01\"><chNFe>43180906929383000163550010000000261000010301</chNFe>\
+        <CPF>52998224725</CPF><xNome>PRODUTOR RURAL</xNome><dhEmi>2018-09-25T00:00:00-03:00</dhEmi>\
+        <tpNF>1</tpNF><vNF>500.00</vNF><dhRecbto>2018-09-25T10:02:57-03:00</dhRecbto>\
+        <nProt>143180000001234</nProt><cSitNFe>1</cSitNFe></resNFe>"
+            .parse::<ResumoNfe>()
+            .map_err(|e| e.to_string())?;
+    assert_eq!(
+        Some(&"52998224725".parse::<Cpf>().map_err(|e| e.to_string())?),
+        resumo.documento.cpf()
+    );
+
+    // Os documentos completos são iguais aos lidos dos arquivos
+    let proc = ler("xmls/nfe_proc_layout4.xml")?
+        .parse::<NfeProc>()
+        .map_err(|e| e.to_string())?;
+    assert_eq!(
+        (4, DocumentoDistribuido::Nfe(Box::new(proc))),
+        documentos[2]
+    );
+
+    let cce = ler("xmls/proc_evento_cce.xml")?
+        .parse::<ProcEvento>()
+        .map_err(|e| e.to_string())?;
+    assert_eq!(
+        (5, DocumentoDistribuido::Evento(Box::new(cce))),
+        documentos[3]
+    );
+
+    // Um documento inválido não impede a leitura dos demais
+    let mut com_invalido = retorno.clone();
+    com_invalido.documentos[0].conteudo = "PHJlc05GZT48L3Jlc05GZT4=".to_string();
+    let documentos = com_invalido.decodificar();
+    assert_eq!(4, documentos.len());
+    assert!(matches!(documentos[0], (1, Err(_))));
+    assert!(documentos[1..].iter().all(|(_, doc)| doc.is_ok()));
+    assert!(matches!(
+        documentos[2],
+        (4, Ok(DocumentoDistribuido::Nfe(_)))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn documento_zip() -> Result<(), String> {
+    let xml = ler("xmls/ret_dist_dfe_int.xml")?;
+    let retorno = xml
+        .parse::<RetornoDistribuicao>()
+        .map_err(|e| e.to_string())?;
+
+    let doc = &retorno.documentos[1];
+    assert_eq!("resEvento", doc.tipo());
+    assert!(doc
+        .xml()
+        .map_err(|e| e.to_string())?
+        .starts_with("<resEvento"));
+
+    // Schema desconhecido é mantido como XML
+    let desconhecido = DocumentoZip {
+        schema: "resCTe_v1.00.xsd".to_string(),
+        ..doc.clone()
+    };
+    match desconhecido.decodificar().map_err(|e| e.to_string())? {
+        DocumentoDistribuido::Outro { schema, xml } => {
+            assert_eq!("resCTe_v1.00.xsd", schema);
+            assert!(xml.contains("<tpEvento>110110</tpEvento>"));
+        }
+        outro => panic!("{:?}", outro),
+    }
+
+    // Conteúdo que não é gzip
+    let invalido = DocumentoZip {
+        conteudo: "PHJlc05GZT48L3Jlc05GZT4=".to_string(),
+        ..doc.clone()
+    };
+    assert!(invalido.decodificar().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn controle_nsu() -> Result<(), String> {
+    let agora = Utc.with_ymd_and_hms(2018, 9, 27, 11, 30, 0).unwrap();
+    let mut controle = ControleNsu::new(0);
+    assert!(controle.pode_consultar(agora));
+    assert_eq!(ConsultaDistribuicao::UltimoNsu(0), controle.consulta());
+
+    // Com documentos pendentes, a consulta segue sem espera
+    let xml = ler("xmls/ret_dist_dfe_int.xml")?;
+    let mut retorno = xml
+        .parse::<RetornoDistribuicao>()
+        .map_err(|e| e.to_string())?;
+    controle.registrar(&retorno, agora);
+    assert_eq!(5, controle.ultimo_nsu);
+    assert_eq!(8, controle.maximo_nsu);
+    assert!(controle.pode_consultar(agora));
+
+    // Ao alcançar o maior NSU, aguarda uma hora
+    retorno.ultimo_nsu = 8;
+    controle.registrar(&retorno, agora);
+    assert_eq!(ConsultaDistribuicao::UltimoNsu(8), controle.consulta());
+    assert!(!controle.pode_consultar(agora + Duration::minutes(59)));
+    assert!(controle.pode_consultar(agora + Duration::hours(1)));
+
+    // A rejeição não altera o NSU, mas também impõe a espera
+    let depois = agora + Duration::hours(2);
+    retorno.status = 656;
+    retorno.ultimo_nsu = 0;
+    controle.registrar(&retorno, depois);
+    assert_eq!(8, controle.ultimo_nsu);
+    assert!(!controle.pode_consultar(depois));
+
+    Ok(())
+}
@@ -0,0 +1,179 @@
+//! Testes dos documentos CNPJ e CPF
+
+use crate::base::Error as ErrorBase;
+use crate::*;
+
+#[test]
+fn cnpj() -> Result<(), Error> {
+    let cnpj = "06929383000163".parse::<Cnpj>()?;
+
+    assert_eq!("06929383000163", cnpj);
+    assert_eq!("06.929.383/0001-63", cnpj.formatado());
+    assert_eq!("06929383", cnpj.raiz());
+    assert!(!cnpj.alfanumerico());
+
+    let cnpj = "06.929.383/0001-63".parse::<Cnpj>()?;
+    assert_eq!("06929383000163", cnpj.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn cnpj_alfanumerico() -> Result<(), Error> {
+    let cnpj = "12.ABC.345/01DE-35".parse::<Cnpj>()?;
+
+    assert_eq!("12ABC34501DE35", cnpj);
+    assert_eq!("12.ABC.345/01DE-35", cnpj.formatado());
+    assert_eq!("12ABC345", cnpj.raiz());
+    assert!(cnpj.alfanumerico());
+
+    assert_eq!(cnpj, "12abc34501de35".parse::<Cnpj>()?);
+
+    Ok(())
+}
+
+#[test]
+fn cnpj_invalido() {
+    let invalidos = [
+        "06929383000164",
+        "0692938300016",
+        "069293830001633",
+        "00000000000000",
+        "12ABC34501DE36",
+        "12ABC34501DEA5",
+        "12ABC3450@DE35",
+    ];
+
+    for cnpj in invalidos.iter() {
+        match cnpj.parse::<Cnpj>() {
+            Err(ErrorBase::CnpjInvalido(_)) => {}
+            r => panic!("{}: {:?}", cnpj, r),
+        }
+    }
+}
+
+#[test]
+fn cpf() -> Result<(), Error> {
+    let cpf = "529.982.247-25".parse::<Cpf>()?;
+
+    assert_eq!("52998224725", cpf);
+    assert_eq!("529.982.247-25", cpf.formatado());
+
+    let invalidos = ["52998224724", "5299822472", "11111111111", "5299822472A"];
+
+    for cpf in invalidos.iter() {
+        match cpf.parse::<Cpf>() {
+            Err(ErrorBase::CpfInvalido(_)) => {}
+            r => panic!("{}: {:?}", cpf, r),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn documento() -> Result<(), Error> {
+    let documento = "06.929.383/0001-63".parse::<Documento>()?;
+
+    assert_eq!("06929383000163", documento);
+    assert_eq!(Some(&"06929383000163".parse::<Cnpj>()?), documento.cnpj());
+    assert_eq!(None, documento.cpf());
+
+    let documento = "529.982.247-25".parse::<Documento>()?;
+
+    assert_eq!("52998224725", documento);
+    assert_eq!("529.982.247-25", documento.formatado());
+    assert_eq!(Documento::Cpf("52998224725".parse()?), documento);
+
+    assert!("52998224724".parse::<Documento>().is_err());
+    assert!("0692938300016".parse::<Documento>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn emitente_pessoa_fisica() -> Result<(), Error> {
+    let xml = "
+        <emit>
+            <CPF>52998224725</CPF>
+            <xNome>PRODUTOR RURAL</xNome>
+            <IE>0018000762</IE>
+            <enderEmit>
+                <xLgr>Testes</xLgr>
+                <nro>1020</nro>
+                <xBairro>Centro</xBairro>
+                <cMun>4319901</cMun>
+                <xMun>SAPIRANGA</xMun>
+                <UF>RS</UF>
+                <CEP>93800000</CEP>
+                <cPais>1058</cPais>
+                <xPais>BRASIL</xPais>
+            </enderEmit>
+        </emit>
+    ";
+
+    let emit = xml.parse::<Emitente>()?;
+
+    assert_eq!(Documento::Cpf("52998224725".parse()?), emit.documento);
+    assert!(emit
+        .to_string()
+        .starts_with("<emit><CPF>52998224725</CPF><xNome>"));
+
+    // Apenas um dos documentos deve ser informado
+    assert!(xml
+        .replace("<CPF>52998224725</CPF>", "")
+        .parse::<Emitente>()
+        .is_err());
+    assert!(xml
+        .replace("</CPF>", "</CPF><CNPJ>06929383000163</CNPJ>")
+        .parse::<Emitente>()
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn emitente_com_cnpj_invalido() {
+    let xml = "
+        <emit>
+            <CNPJ>06929383000199</CNPJ>
+            <xNome>QUALQUER</xNome>
+            <IE>0018000762</IE>
+            <enderEmit>
+                <xLgr>Testes</xLgr>
+                <nro>1020</nro>
+                <xBairro>Centro</xBairro>
+                <cMun>4319901</cMun>
+                <xMun>SAPIRANGA</xMun>
+                <UF>RS</UF>
+                <CEP>93800000</CEP>
+                <cPais>1058</cPais>
+                <xPais>BRASIL</xPais>
+            </enderEmit>
+        </emit>
+    ";
+
+    assert!(xml.parse::<Emitente>().is_err());
+    assert!(xml
+        .replace("06929383000199", "12ABC34501DE35")
+        .parse::<Emitente>()
+        .is_ok());
+}
+
+#[test]
+fn chave_com_cnpj_alfanumerico() -> Result<(), Error> {
+    let chave = "43260712ABC34501DE35550010000000261000010304".parse::<ChaveAcesso>()?;
+
+    assert_eq!("12ABC34501DE35", chave.documento);
+    assert_eq!(26, chave.numero);
+    assert_eq!(
+        "43260712ABC34501DE35550010000000261000010304",
+        chave.to_string()
+    );
+
+    assert!("43260712ABC34501DE35550010000000261000010305"
+        .parse::<ChaveAcesso>()
+        .is_err());
+
+    Ok(())
+}
@@ -10,7 +10,7 @@ fn from_instance() -> Result<(), String> {
     let f = File::open("xmls/nfe_layout4.xml").map_err(|e| e.to_string())?;
     let emit = Nfe::try_from(f).map_err(|e| e.to_string())?.emit;
 
-    assert_eq!("06929383000163", emit.cnpj);
+    assert_eq!("06929383000163", emit.documento);
     assert_eq!("UMA RAZAO SOCIAL DE TESTE QUALQUER", emit.razao_social);
     assert_eq!(None, emit.nome_fantasia);
     assert_eq!("0018000762", emit.ie);
@@ -54,7 +54,7 @@ fn manual() -> Result<(), Error> {
 
     let emit = xml.parse::<Emitente>()?;
 
-    assert_eq!("06929383000163", emit.cnpj);
+    assert_eq!("06929383000163", emit.documento);
     assert_eq!("UMA RAZAO SOCIAL DE TESTE QUALQUER", emit.razao_social);
     assert_eq!(None, emit.nome_fantasia);
     assert_eq!("0018000762", emit.ie);
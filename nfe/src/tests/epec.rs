@@ -0,0 +1,148 @@
+//! Testes do evento prévio de emissão em contingência(EPEC)
+
+use chrono::prelude::*;
+
+use super::assinatura::certificado_teste;
+use super::ler;
+use crate::base::Nfe as NfeBase;
+use crate::*;
+
+const VERSAO_APLICATIVO: &str = "ERP 5.2";
+
+/// Nota do exemplo emitida em contingência EPEC
+fn nota_epec() -> Result<NfeBase, String> {
+    let mut nfe = ler("xmls/nfe_layout4.xml")?
+        .parse::<NfeBase>()
+        .map_err(|e| e.to_string())?;
+    nfe.ide.emissao.tipo = TipoEmissao::ContigenciaEpec;
+    nfe.gerar_chave_acesso().map_err(|e| e.to_string())?;
+
+    Ok(nfe)
+}
+
+fn horario() -> DateTime<FixedOffset> {
+    FixedOffset::west_opt(3 * 3600)
+        .unwrap()
+        .with_ymd_and_hms(2018, 9, 25, 0, 5, 0)
+        .unwrap()
+}
+
+#[test]
+fn epec() -> Result<(), String> {
+    let nfe = nota_epec()?;
+    let mut evento = Evento::epec(&nfe, VERSAO_APLICATIVO, horario()).map_err(|e| e.to_string())?;
+
+    assert_eq!(ORGAO_AMBIENTE_NACIONAL, evento.orgao);
+    assert_eq!("06929383000163", evento.autor.as_str());
+    assert_eq!(nfe.chave_acesso, evento.chave_acesso);
+    assert_eq!(TipoEvento::Epec, evento.tipo());
+    assert_eq!(format!("ID110140{}01", nfe.chave_acesso), evento.id());
+
+    let epec = match &evento.detalhe {
+        DetalheEvento::Epec(epec) => epec,
+        outro => return Err(format!("Detalhe inesperado: {:?}", outro)),
+    };
+    assert_eq!(43, epec.orgao_autor);
+    assert_eq!(nfe.ide.emissao.horario, epec.emissao);
+    assert_eq!(TipoOperacao::Saida, epec.tipo_operacao);
+    assert_eq!("0018000762", epec.ie);
+    assert_eq!(
+        DestinatarioEpec {
+            uf: "SP".to_string(),
+            documento: "58716523000119"
+                .parse::<Documento>()
+                .map_err(|e| e.to_string())?,
+            ie: Some("112006603110".to_string()),
+            valor_total: 500.0,
+            valor_icms: 0.0,
+            valor_icms_st: 0.0,
+        },
+        epec.destinatario
+    );
+
+    evento
+        .assinar(&certificado_teste())
+        .map_err(|e| e.to_string())?;
+
+    let xml = evento.to_string();
+    assert!(xml.contains("<cOrgao>91</cOrgao>"));
+    assert!(xml.contains(
+        "<detEvento versao=\"1.00\"><descEvento>EPEC</descEvento>\
+         <cOrgaoAutor>43</cOrgaoAutor><tpAutor>1</tpAutor><verAplic>ERP 5.2</verAplic>\
+         <dhEmi>2018-09-25T03:00:00+00:00</dhEmi><tpNF>1</tpNF><IE>0018000762</IE>\
+         <dest><UF>SP</UF><CNPJ>58716523000119</CNPJ><IE>112006603110</IE>\
+         <vNF>500.00</vNF><vICMS>0.00</vICMS><vST>0.00</vST></dest></detEvento>"
+    ));
+    assert_eq!(evento, xml.parse::<Evento>().map_err(|e| e.to_string())?);
+
+    // O vST do resumo vem da totalização da nota
+    let mut nfe = nota_epec()?;
+    nfe.totais.valor_icms_st = 37.5;
+    let evento = Evento::epec(&nfe, VERSAO_APLICATIVO, horario()).map_err(|e| e.to_string())?;
+    match &evento.detalhe {
+        DetalheEvento::Epec(epec) => assert_eq!(37.5, epec.destinatario.valor_icms_st),
+        outro => return Err(format!("Detalhe inesperado: {:?}", outro)),
+    }
+    assert!(evento.to_string().contains("<vST>37.50</vST>"));
+
+    Ok(())
+}
+
+#[test]
+fn epec_invalido() -> Result<(), String> {
+    let mut nfe = nota_epec()?;
+    nfe.ide.emissao.tipo = TipoEmissao::Normal;
+    nfe.gerar_chave_acesso().map_err(|e| e.to_string())?;
+
+    assert_eq!(
+        "Evento inválido. A nota do EPEC deve ser emitida com o tpEmis 4. Informado: 1",
+        Evento::epec(&nfe, VERSAO_APLICATIVO, horario())
+            .map_err(|e| e.to_string())
+            .unwrap_err()
+    );
+
+    let nfe = nota_epec()?;
+    assert!(Evento::epec(&nfe, " ", horario())
+        .map_err(|e| e.to_string())
+        .unwrap_err()
+        .contains("versão do aplicativo"));
+
+    // Os totais são informados junto ao destinatário, que é obrigatório
+    let mut nfe = nota_epec()?;
+    nfe.dest = None;
+    assert!(Evento::epec(&nfe, VERSAO_APLICATIVO, horario())
+        .map_err(|e| e.to_string())
+        .unwrap_err()
+        .contains("exige o destinatário"));
+
+    Ok(())
+}
+
+#[test]
+fn conferir_nota() -> Result<(), String> {
+    let mut nfe = nota_epec()?;
+    let evento = Evento::epec(&nfe, VERSAO_APLICATIVO, horario()).map_err(|e| e.to_string())?;
+
+    let epec = match evento.detalhe {
+        DetalheEvento::Epec(epec) => epec,
+        outro => return Err(format!("Detalhe inesperado: {:?}", outro)),
+    };
+    assert!(epec.conferir(&nfe).is_ok());
+
+    // ICMS ST diferente do registrado no EPEC
+    nfe.totais.valor_icms_st = 37.5;
+    assert!(epec.conferir(&nfe).is_err());
+    nfe.totais.valor_icms_st = 0.0;
+
+    // Nota alterada após o registro do EPEC
+    nfe.totais.valor_total = 550.0;
+    assert_eq!(
+        format!(
+            "Evento inválido. A nota {} diverge do resumo do EPEC",
+            nfe.chave_acesso
+        ),
+        epec.conferir(&nfe).map_err(|e| e.to_string()).unwrap_err()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,225 @@
+//! Testes dos eventos da NF-e e do cancelamento
+
+use chrono::prelude::*;
+
+use super::assinatura::certificado_teste;
+use super::ler;
+use crate::base::Nfe as NfeBase;
+use crate::*;
+
+const CHAVE: &str = "43180906929383000163550010000000261000010301";
+const ID_CANCELAMENTO: &str = "ID1101114318090692938300016355001000000026100001030101";
+
+fn nota() -> Result<NfeBase, String> {
+    ler("xmls/nfe_layout4.xml")?
+        .parse::<NfeBase>()
+        .map_err(|e| e.to_string())
+}
+
+fn horario() -> DateTime<FixedOffset> {
+    FixedOffset::west_opt(3 * 3600)
+        .unwrap()
+        .with_ymd_and_hms(2018, 9, 25, 14, 30, 0)
+        .unwrap()
+}
+
+#[test]
+fn cancelamento() -> Result<(), String> {
+    let nfe = nota()?;
+    let evento = Evento::cancelamento(
+        &nfe,
+        "143180000001234",
+        "  Pedido cancelado pelo cliente antes da entrega ",
+        horario(),
+    )
+    .map_err(|e| e.to_string())?;
+
+    assert_eq!(43, evento.orgao);
+    assert_eq!(nfe.ide.ambiente, evento.ambiente);
+    assert_eq!("06929383000163", evento.autor.as_str());
+    assert_eq!(CHAVE, evento.chave_acesso);
+    assert_eq!(1, evento.sequencia);
+    assert_eq!(TipoEvento::Cancelamento, evento.tipo());
+    assert_eq!(110111, evento.tipo().codigo());
+    assert_eq!(ID_CANCELAMENTO, evento.id());
+    assert_eq!(
+        DetalheEvento::Cancelamento(Cancelamento {
+            protocolo: "143180000001234".to_string(),
+            justificativa: "Pedido cancelado pelo cliente antes da entrega".to_string(),
+        }),
+        evento.detalhe
+    );
+
+    let xml = evento.to_string();
+    assert!(xml.starts_with(
+        "<evento xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"1.00\">\
+         <infEvento Id=\"ID1101114318090692938300016355001000000026100001030101\">\
+         <cOrgao>43</cOrgao>"
+    ));
+    assert!(xml.contains(
+        "<dhEvento>2018-09-25T14:30:00-03:00</dhEvento><tpEvento>110111</tpEvento>\
+         <nSeqEvento>1</nSeqEvento><verEvento>1.00</verEvento>\
+         <detEvento versao=\"1.00\"><descEvento>Cancelamento</descEvento>\
+         <nProt>143180000001234</nProt>\
+         <xJust>Pedido cancelado pelo cliente antes da entrega</xJust></detEvento>"
+    ));
+    assert_eq!(evento, xml.parse::<Evento>().map_err(|e| e.to_string())?);
+
+    Ok(())
+}
+
+#[test]
+fn cancelamento_invalido() -> Result<(), String> {
+    let nfe = nota()?;
+    let erro = |protocolo: &str, justificativa: &str| {
+        Evento::cancelamento(&nfe, protocolo, justificativa, horario())
+            .map_err(|e| e.to_string())
+            .unwrap_err()
+    };
+
+    assert_eq!(
+        "Evento inválido. A justificativa deve possuir de 15 a 255 caracteres. Informado: 9",
+        erro("143180000001234", "Cancelado")
+    );
+    assert!(erro("143180000001234", &"x".repeat(256)).contains("Informado: 256"));
+    assert!(erro("1431800000", "Pedido cancelado pelo cliente")
+        .contains("O protocolo deve possuir 15 dígitos"));
+
+    // Acentos contam como um caractere
+    assert!(Evento::cancelamento(&nfe, "143180000001234", "ÇÇÇÇÇÇÇÇÇÇÇÇÇÇÇ", horario()).is_ok());
+
+    let mut sem_chave = nfe;
+    sem_chave.chave_acesso = "4318".to_string();
+    assert!(Evento::cancelamento(
+        &sem_chave,
+        "143180000001234",
+        "Pedido cancelado pelo cliente",
+        horario()
+    )
+    .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn assinar_e_enviar() -> Result<(), String> {
+    let mut evento = Evento::cancelamento(
+        &nota()?,
+        "143180000001234",
+        "Pedido cancelado pelo cliente antes da entrega",
+        horario(),
+    )
+    .map_err(|e| e.to_string())?;
+
+    evento
+        .assinar(&certificado_teste())
+        .map_err(|e| e.to_string())?;
+
+    let assinatura = evento.assinatura.as_ref().ok_or("Evento não assinado")?;
+    assert_eq!(format!("#{}", ID_CANCELAMENTO), assinatura.referencia());
+
+    let xml = evento.to_string();
+    assert!(xml.ends_with("</Signature></evento>"));
+    assert_eq!(
+        c14n::digest(&xml, "infEvento").map_err(|e| e.to_string())?,
+        assinatura.digest()
+    );
+    assert_eq!(evento, xml.parse::<Evento>().map_err(|e| e.to_string())?);
+
+    let lote = EnvioEvento::new(201809250001, vec![evento.clone()]).map_err(|e| e.to_string())?;
+    let envio = lote.to_string();
+    assert!(envio.starts_with(
+        "<envEvento xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"1.00\">\
+         <idLote>201809250001</idLote><evento"
+    ));
+    assert!(envio.contains(&xml));
+    assert!(envio.ends_with("</evento></envEvento>"));
+
+    assert!(EnvioEvento::new(1, vec![]).is_err());
+    assert!(EnvioEvento::new(1, vec![evento; 21]).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn proc_evento() -> Result<(), String> {
+    let xml = ler("xmls/proc_evento_cancelamento.xml")?;
+    let proc = xml.parse::<ProcEvento>().map_err(|e| e.to_string())?;
+
+    assert_eq!(CHAVE, proc.evento.chave_acesso);
+    assert_eq!(horario(), proc.evento.horario);
+    assert!(proc.evento.assinatura.is_some());
+    match &proc.evento.detalhe {
+        DetalheEvento::Cancelamento(c) => assert_eq!("143180000001234", c.protocolo),
+        outro => return Err(format!("Detalhe inesperado: {:?}", outro)),
+    }
+
+    let retorno = &proc.retorno;
+    assert!(retorno.registrado());
+    assert_eq!(135, retorno.status);
+    assert_eq!(Some(110111), retorno.tipo);
+    assert_eq!(Some(1), retorno.sequencia);
+    assert_eq!(Some("143180000005678".to_string()), retorno.protocolo);
+    assert_eq!(
+        Some(
+            FixedOffset::west_opt(3 * 3600)
+                .unwrap()
+                .with_ymd_and_hms(2018, 9, 25, 14, 30, 12)
+                .unwrap()
+        ),
+        retorno.registro
+    );
+
+    // Gerado novamente sem perdas, com a assinatura original
+    let gerado = proc.to_string();
+    assert!(gerado.contains(proc.evento.assinatura.as_ref().unwrap().xml()));
+    assert_eq!(
+        proc,
+        gerado.parse::<ProcEvento>().map_err(|e| e.to_string())?
+    );
+
+    let mut rejeitado = proc.retorno.clone();
+    rejeitado.status = 573;
+    rejeitado.protocolo = None;
+    assert_eq!(
+        "Evento inválido. Evento não registrado: 573 - Evento registrado e vinculado a NF-e",
+        ProcEvento::new(proc.evento.clone(), rejeitado)
+            .map_err(|e| e.to_string())
+            .unwrap_err()
+    );
+
+    let mut outra_chave = proc.retorno.clone();
+    outra_chave.chave_acesso = Some("43180906929383000163550010000000271000010307".to_string());
+    assert!(ProcEvento::new(proc.evento, outra_chave).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn retorno_lote() -> Result<(), String> {
+    let proc = ler("xmls/proc_evento_cancelamento.xml")?
+        .parse::<ProcEvento>()
+        .map_err(|e| e.to_string())?;
+
+    let xml = format!(
+        "<retEnvEvento xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"1.00\">\
+         <idLote>201809250001</idLote><tpAmb>2</tpAmb><verAplic>RS20180920103458</verAplic>\
+         <cOrgao>43</cOrgao><cStat>128</cStat><xMotivo>Lote de Evento Processado</xMotivo>\
+         {}</retEnvEvento>",
+        proc.retorno
+    );
+
+    let lote = xml
+        .parse::<RetornoLoteEvento>()
+        .map_err(|e| e.to_string())?;
+    assert!(lote.processado());
+    assert_eq!(201809250001, lote.lote);
+    assert_eq!(1, lote.retornos.len());
+    assert_eq!(Some(&proc.retorno), lote.retorno(&proc.evento));
+
+    let vinculado = ProcEvento::new(proc.evento.clone(), lote.retornos[0].clone())
+        .map_err(|e| e.to_string())?;
+    assert_eq!(proc, vinculado);
+
+    Ok(())
+}
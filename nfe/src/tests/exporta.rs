@@ -0,0 +1,42 @@
+//! Testes da tag <exporta>
+
+use crate::*;
+
+#[test]
+fn manual() -> Result<(), Error> {
+    let xml = "
+        <exporta>
+            <UFSaidaPais>PR</UFSaidaPais>
+            <xLocExporta>PORTO DE PARANAGUA</xLocExporta>
+            <xLocDespacho>PARANAGUA</xLocDespacho>
+        </exporta>
+    ";
+
+    let exporta = xml.parse::<Exportacao>()?;
+
+    assert_eq!("PR", exporta.uf_embarque);
+    assert_eq!("PORTO DE PARANAGUA", exporta.local_embarque);
+    assert_eq!(Some("PARANAGUA".to_string()), exporta.local_despacho);
+
+    Ok(())
+}
+
+#[test]
+fn to_string() -> Result<(), Error> {
+    let mut xml_original = "
+        <exporta>
+            <UFSaidaPais>SP</UFSaidaPais>
+            <xLocExporta>SANTOS</xLocExporta>
+        </exporta>
+    "
+    .to_string();
+    xml_original.retain(|c| c != '\n' && c != ' ');
+
+    let exporta = xml_original.parse::<Exportacao>()?;
+    let xml_novo = exporta.to_string();
+
+    assert_eq!(None, exporta.local_despacho);
+    assert_eq!(xml_original, xml_novo);
+
+    Ok(())
+}
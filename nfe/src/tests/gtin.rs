@@ -0,0 +1,121 @@
+//! Testes da validação do GTIN dos produtos
+
+use std::convert::TryFrom;
+use std::fs::File;
+
+use crate::base::Error as ErrorBase;
+use crate::base::Nfe as NfeBase;
+use crate::*;
+
+#[test]
+fn formatos() -> Result<(), String> {
+    let gtin8: Gtin = "96385074".parse().map_err(|e: ErrorBase| e.to_string())?;
+    assert_eq!(FormatoGtin::Gtin8, gtin8.formato());
+    assert_eq!(None, gtin8.prefixo());
+
+    let gtin12: Gtin = "036000291452"
+        .parse()
+        .map_err(|e: ErrorBase| e.to_string())?;
+    assert_eq!(FormatoGtin::Gtin12, gtin12.formato());
+    assert_eq!(Some("003".to_string()), gtin12.prefixo());
+
+    let gtin13: Gtin = "7893049207584"
+        .parse()
+        .map_err(|e: ErrorBase| e.to_string())?;
+    assert_eq!(FormatoGtin::Gtin13, gtin13.formato());
+    assert!(gtin13.brasileiro());
+
+    // O indicador de embalagem não faz parte do prefixo
+    let gtin14: Gtin = "17893049207581"
+        .parse()
+        .map_err(|e: ErrorBase| e.to_string())?;
+    assert_eq!(FormatoGtin::Gtin14, gtin14.formato());
+    assert!(gtin14.brasileiro());
+
+    let gtin790: Gtin = "7901234567891"
+        .parse()
+        .map_err(|e: ErrorBase| e.to_string())?;
+    assert_eq!(Some("790".to_string()), gtin790.prefixo());
+    assert!(gtin790.brasileiro());
+
+    let alemao: Gtin = "4006381333931"
+        .parse()
+        .map_err(|e: ErrorBase| e.to_string())?;
+    assert_eq!(Some("400".to_string()), alemao.prefixo());
+    assert!(!alemao.brasileiro());
+
+    Ok(())
+}
+
+#[test]
+fn invalidos() {
+    assert!("7893049207585".parse::<Gtin>().is_err());
+    assert!("789304920758".parse::<Gtin>().is_err());
+    assert!("78930492075A4".parse::<Gtin>().is_err());
+    assert!("00000000".parse::<Gtin>().is_err());
+
+    let restrito: Gtin = "2000000001234".parse().unwrap();
+    assert!(restrito.circulacao_restrita());
+    assert!(!"7893049207584"
+        .parse::<Gtin>()
+        .unwrap()
+        .circulacao_restrita());
+}
+
+#[test]
+fn validar_produto() -> Result<(), String> {
+    let f = File::open("xmls/nfce_layout4.xml").map_err(|e| e.to_string())?;
+    let nfce = NfeBase::try_from(f).map_err(|e| e.to_string())?;
+
+    // Com e sem GTIN
+    for item in &nfce.itens {
+        item.produto.validar_gtin().map_err(|e| e.to_string())?;
+    }
+
+    let mut produto = nfce.itens[0].produto.clone();
+    assert_eq!(
+        Some("7893049207584".to_string()),
+        produto.gtin_comercial().unwrap().map(|g| g.to_string())
+    );
+
+    produto.gtin = Some("7893049207585".to_string());
+    match produto.validar_gtin() {
+        Err(ErrorBase::GtinInvalido(e)) => assert!(e.starts_with("cEAN:")),
+        r => panic!("{:?}", r),
+    }
+
+    // Mesma unidade exige o mesmo GTIN
+    produto.gtin = Some("17893049207581".to_string());
+    assert!(produto.validar_gtin().is_err());
+
+    produto.unidade = "CX".to_string();
+    produto.validar_gtin().map_err(|e| e.to_string())?;
+
+    produto.tributacao.gtin = None;
+    match produto.validar_gtin() {
+        Err(ErrorBase::GtinInvalido(e)) => assert!(e.contains("sem o cEANTrib")),
+        r => panic!("{:?}", r),
+    }
+
+    produto.gtin = Some("2000000001234".to_string());
+    produto.tributacao.gtin = Some("2000000001234".to_string());
+    assert!(produto.validar_gtin().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn validar_nota() -> Result<(), String> {
+    let f = File::open("xmls/nfe_layout4.xml").map_err(|e| e.to_string())?;
+    let mut nfe = NfeBase::try_from(f).map_err(|e| e.to_string())?;
+
+    nfe.validar().map_err(|e| e.to_string())?;
+
+    nfe.itens[0].produto.tributacao.gtin = Some("7893049207584".to_string());
+    match nfe.validar() {
+        Err(ErrorBase::GtinInvalido(e)) => assert!(e.starts_with("Item 1")),
+        r => panic!("{:?}", r),
+    }
+
+    Ok(())
+}
@@ -46,7 +46,10 @@ fn emissao_from_instance() -> Result<(), String> {
     let f = File::open("xmls/nfe_layout4.xml").map_err(|e| e.to_string())?;
     let emissao = Nfe::try_from(f).map_err(|e| e.to_string())?.ide.emissao;
 
-    assert_eq!(Utc.ymd(2018, 09, 25).and_hms(3, 0, 0), emissao.horario);
+    assert_eq!(
+        Utc.with_ymd_and_hms(2018, 9, 25, 3, 0, 0).unwrap(),
+        emissao.horario
+    );
     assert_eq!(TipoEmissao::Normal, emissao.tipo);
     assert_eq!(FinalidadeEmissao::Normal, emissao.finalidade);
     assert_eq!(
@@ -65,7 +68,7 @@ fn operacao_from_instance() -> Result<(), String> {
 
     assert_eq!("Venda de producao do estabelecimento", operacao.natureza);
     assert_eq!(
-        Some(Utc.ymd(2018, 09, 25).and_hms(18, 14, 0)),
+        Utc.with_ymd_and_hms(2018, 9, 25, 18, 14, 0).single(),
         operacao.horario
     );
     assert_eq!(TipoOperacao::Saida, operacao.tipo);
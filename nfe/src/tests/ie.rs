@@ -0,0 +1,116 @@
+//! Testes da validação da inscrição estadual
+
+use std::convert::TryFrom;
+use std::fs::File;
+
+use crate::base::ie::validar_ie;
+use crate::base::Error as ErrorBase;
+use crate::base::Nfe as NfeBase;
+
+const VALIDAS: &[(&str, &str)] = &[
+    ("AC", "01.004.823/001-12"),
+    ("AL", "240000048"),
+    ("AP", "030123459"),
+    ("AM", "99.999.999-0"),
+    ("BA", "123456-63"),
+    ("BA", "1000003-06"),
+    ("CE", "06000001-5"),
+    ("DF", "07.300001.001-09"),
+    ("ES", "999999990"),
+    ("GO", "10.987.654-7"),
+    ("MA", "120000385"),
+    ("MT", "0013000001-9"),
+    ("MT", "130000019"),
+    ("MS", "283115947"),
+    ("MG", "062.307.904/0081"),
+    ("PA", "15-999999-5"),
+    ("PB", "06000001-5"),
+    ("PR", "123.45678-50"),
+    ("PE", "0321418-40"),
+    ("PE", "18.1.001.0000004-9"),
+    ("PI", "012345679"),
+    ("RJ", "99.999.99-3"),
+    ("RN", "20.040.040-1"),
+    ("RN", "20.0.040.040-0"),
+    ("RS", "224/3658792"),
+    ("RO", "101.62521-3"),
+    ("RO", "0000000062521-3"),
+    ("RR", "24006628-1"),
+    ("SC", "251.040.852"),
+    ("SP", "110.042.490.114"),
+    ("SP", "P-01100424.3/002"),
+    ("SE", "27123456-3"),
+    ("TO", "29010227836"),
+];
+
+#[test]
+fn validas() {
+    for (uf, ie) in VALIDAS {
+        assert!(validar_ie(uf, ie).is_ok(), "{} {}", uf, ie);
+    }
+}
+
+#[test]
+fn digito_incorreto() {
+    for (uf, ie) in VALIDAS {
+        // O último dígito da IE rural de SP não é verificador
+        if ie.starts_with('P') {
+            continue;
+        }
+
+        let ultimo = ie.chars().last().and_then(|c| c.to_digit(10)).unwrap();
+        let invalida = format!("{}{}", &ie[..ie.len() - 1], (ultimo + 1) % 10);
+
+        match validar_ie(uf, &invalida) {
+            Err(ErrorBase::IeInvalida(_)) => {}
+            r => panic!("{} {}: {:?}", uf, invalida, r),
+        }
+    }
+}
+
+#[test]
+fn isento_e_formatos() {
+    assert!(validar_ie("RS", "ISENTO").is_ok());
+    assert!(validar_ie("SP", "isento").is_ok());
+
+    assert!(validar_ie("RS", "").is_err());
+    assert!(validar_ie("RS", "00180007A2").is_err());
+    assert!(validar_ie("RS", "018000762").is_err());
+    assert!(validar_ie("XX", "0018000762").is_err());
+    // IE válida, mas de outra UF
+    assert!(validar_ie("SC", "0018000762").is_err());
+}
+
+#[test]
+fn validar_nota() -> Result<(), String> {
+    let f = File::open("xmls/nfe_layout4.xml").map_err(|e| e.to_string())?;
+    let mut nfe = NfeBase::try_from(f).map_err(|e| e.to_string())?;
+
+    nfe.validar().map_err(|e| e.to_string())?;
+
+    let mut dest = nfe.dest.clone().unwrap();
+
+    dest.ie = Some("112006603111".to_string());
+    nfe.dest = Some(dest.clone());
+    match nfe.validar() {
+        Err(ErrorBase::IeInvalida(e)) => assert!(e.starts_with("Destinatário")),
+        r => panic!("{:?}", r),
+    }
+
+    dest.ie = None;
+    nfe.dest = Some(dest.clone());
+    assert!(nfe.validar().is_err());
+
+    // Não contribuintes podem omitir a IE
+    dest.indicador_ie = crate::IndicadorContribuicaoIe::NaoContribuinte;
+    nfe.dest = Some(dest);
+    nfe.validar().map_err(|e| e.to_string())?;
+
+    nfe.emit.ie = "0018000763".to_string();
+    match nfe.validar() {
+        Err(ErrorBase::IeInvalida(e)) => assert!(e.starts_with("Emitente")),
+        r => panic!("{:?}", r),
+    }
+
+    Ok(())
+}
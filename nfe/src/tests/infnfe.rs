@@ -2,6 +2,7 @@
 
 use std::convert::TryFrom;
 use std::fs::File;
+use std::io::Read;
 
 use crate::base::Nfe as NfeBase;
 use crate::*;
@@ -37,7 +38,9 @@ fn informacao_complementar_from_instance() -> Result<(), String> {
 #[test]
 fn base_to_string() -> Result<(), String> {
     let f = File::open("xmls/nfce_layout4.xml").map_err(|e| e.to_string())?;
-    let nfe = NfeBase::try_from(f).map_err(|e| e.to_string())?;
+    let mut nfe = NfeBase::try_from(f).map_err(|e| e.to_string())?;
+    // Sem a assinatura, a nota é escrita a partir do modelo
+    nfe.assinatura = None;
 
     let xml_novo = nfe.to_string();
 
@@ -53,7 +56,9 @@ fn base_to_string() -> Result<(), String> {
 #[test]
 fn to_string() -> Result<(), String> {
     let f = File::open("xmls/nfe_layout4.xml").map_err(|e| e.to_string())?;
-    let nfe = Nfe::try_from(f).map_err(|e| e.to_string())?;
+    let mut nfe = Nfe::try_from(f).map_err(|e| e.to_string())?;
+    // Sem a assinatura, a nota é escrita a partir do modelo
+    nfe.assinatura = None;
 
     let xml_novo = nfe.to_string();
 
@@ -92,3 +97,58 @@ fn base_parse_to_string_parse_to_string() -> Result<(), String> {
 
     Ok(())
 }
+
+#[test]
+fn exporta_compra_cana() -> Result<(), String> {
+    let mut f = File::open("xmls/nfe_layout4.xml").map_err(|e| e.to_string())?;
+    let mut xml = String::new();
+    f.read_to_string(&mut xml).map_err(|e| e.to_string())?;
+
+    let xml = xml.replace(
+        "</pag>",
+        "</pag>
+        <exporta>
+            <UFSaidaPais>RS</UFSaidaPais>
+            <xLocExporta>PORTO DE RIO GRANDE</xLocExporta>
+        </exporta>
+        <compra>
+            <xPed>PED-4455</xPed>
+        </compra>
+        <cana>
+            <safra>2018</safra>
+            <ref>09/2018</ref>
+            <forDia dia=\"25\">
+                <qtde>10</qtde>
+            </forDia>
+            <qTotMes>10</qTotMes>
+            <qTotAnt>0</qTotAnt>
+            <qTotGer>10</qTotGer>
+            <vFor>500.00</vFor>
+            <vTotDed>0.00</vTotDed>
+            <vLiqFor>500.00</vLiqFor>
+        </cana>",
+    );
+
+    let mut nfe = xml.parse::<Nfe>().map_err(|e| e.to_string())?;
+    nfe.assinatura = None;
+
+    let exporta = nfe.exportacao.as_ref().ok_or("exporta não encontrado")?;
+    assert_eq!("RS", exporta.uf_embarque);
+    let compra = nfe.compra.as_ref().ok_or("compra não encontrado")?;
+    assert_eq!(Some("PED-4455".to_string()), compra.pedido);
+    let cana = nfe.cana.as_ref().ok_or("cana não encontrado")?;
+    assert_eq!(25, cana.fornecimentos[0].dia);
+
+    let xml_novo = nfe.to_string();
+
+    assert!(xml_novo.contains("</transp><pag><detPag><tPag>01</tPag><vPag>500</vPag></detPag></pag><exporta><UFSaidaPais>RS</UFSaidaPais><xLocExporta>PORTO DE RIO GRANDE</xLocExporta></exporta><compra><xPed>PED-4455</xPed></compra><cana>"));
+    assert_eq!(
+        xml_novo,
+        xml_novo
+            .parse::<Nfe>()
+            .map_err(|e| e.to_string())?
+            .to_string()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,203 @@
+//! Testes da inutilização de numeração
+
+use chrono::prelude::*;
+
+use super::assinatura::certificado_teste;
+use super::ler;
+use crate::*;
+
+const ID: &str = "ID43180692938300016355001000000027000000030";
+
+fn inutilizacao() -> Result<Inutilizacao, String> {
+    Ok(Inutilizacao {
+        ambiente: TipoAmbiente::Homologacao,
+        codigo_uf: 43,
+        ano: 18,
+        documento: "06929383000163"
+            .parse::<Documento>()
+            .map_err(|e| e.to_string())?,
+        modelo: ModeloDocumentoFiscal::Nfe,
+        serie: 1,
+        numero_inicial: 27,
+        numero_final: 30,
+        justificativa: "Falha no sistema de faturamento pulou a numeracao".to_string(),
+        assinatura: None,
+    })
+}
+
+#[test]
+fn pedido() -> Result<(), String> {
+    let mut inut = inutilizacao()?;
+    assert_eq!(ID, inut.id());
+
+    inut.assinar(&certificado_teste())
+        .map_err(|e| e.to_string())?;
+
+    let assinatura = inut.assinatura.as_ref().ok_or("Pedido não assinado")?;
+    assert_eq!(format!("#{}", ID), assinatura.referencia());
+
+    let xml = inut.to_string();
+    assert!(xml.starts_with(
+        "<inutNFe xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"4.00\">\
+         <infInut Id=\"ID43180692938300016355001000000027000000030\">\
+         <tpAmb>2</tpAmb><xServ>INUTILIZAR</xServ><cUF>43</cUF><ano>18</ano>\
+         <CNPJ>06929383000163</CNPJ><mod>55</mod><serie>1</serie>\
+         <nNFIni>27</nNFIni><nNFFin>30</nNFFin>\
+         <xJust>Falha no sistema de faturamento pulou a numeracao</xJust></infInut><Signature"
+    ));
+    assert!(xml.ends_with("</Signature></inutNFe>"));
+    assert_eq!(
+        c14n::digest(&xml, "infInut").map_err(|e| e.to_string())?,
+        assinatura.digest()
+    );
+    assert_eq!(
+        inut,
+        xml.parse::<Inutilizacao>().map_err(|e| e.to_string())?
+    );
+
+    // NFC-e, com o ano de um dígito completado com zero
+    let mut nfce = inutilizacao()?;
+    nfce.modelo = ModeloDocumentoFiscal::Nfce;
+    nfce.ano = 9;
+    nfce.numero_inicial = 5;
+    nfce.numero_final = 5;
+    assert_eq!("ID43090692938300016365001000000005000000005", nfce.id());
+    assert!(nfce.to_string().contains("<ano>09</ano>"));
+    assert_eq!(
+        nfce,
+        nfce.to_string()
+            .parse::<Inutilizacao>()
+            .map_err(|e| e.to_string())?
+    );
+
+    // Produtor rural, com o CPF completado com zeros no Id
+    let mut produtor = inutilizacao()?;
+    produtor.documento = "52998224725"
+        .parse::<Documento>()
+        .map_err(|e| e.to_string())?;
+    assert_eq!("ID43180005299822472555001000000027000000030", produtor.id());
+    assert!(produtor
+        .to_string()
+        .contains("<ano>18</ano><CPF>52998224725</CPF><mod>55</mod>"));
+    assert_eq!(
+        produtor,
+        produtor
+            .to_string()
+            .parse::<Inutilizacao>()
+            .map_err(|e| e.to_string())?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn pedido_invalido() -> Result<(), String> {
+    let erro = |alterar: fn(&mut Inutilizacao)| -> Result<String, String> {
+        let mut inut = inutilizacao()?;
+        alterar(&mut inut);
+
+        Ok(inut.validar().map_err(|e| e.to_string()).unwrap_err())
+    };
+
+    assert_eq!(
+        "Inutilização inválida. Faixa de numeração inválida: 31 a 30",
+        erro(|i| i.numero_inicial = 31)?
+    );
+    assert!(erro(|i| i.numero_inicial = 0)?.contains("Faixa de numeração inválida"));
+    assert!(erro(|i| i.numero_final = 1_000_000_000)?.contains("Faixa de numeração inválida"));
+    assert!(erro(|i| i.serie = 1000)?.contains("Série fora do limite"));
+    assert!(erro(|i| i.ano = 118)?.contains("dois dígitos"));
+    assert_eq!(
+        "Inutilização inválida. A justificativa deve possuir de 15 a 255 caracteres. Informado: 10",
+        erro(|i| i.justificativa = "Pulou nota".to_string())?
+    );
+
+    // Não assina um pedido inválido
+    let mut inut = inutilizacao()?;
+    inut.numero_final = 1;
+    assert!(inut.assinar(&certificado_teste()).is_err());
+    assert!(inut.assinatura.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn proc_inutilizacao() -> Result<(), String> {
+    let xml = ler("xmls/proc_inut_nfe.xml")?;
+    let proc = xml.parse::<ProcInutilizacao>().map_err(|e| e.to_string())?;
+
+    let inut = &proc.inutilizacao;
+    assert_eq!(ID, inut.id());
+    assert_eq!(27, inut.numero_inicial);
+    assert_eq!(30, inut.numero_final);
+
+    let retorno = &proc.retorno;
+    assert!(retorno.homologada());
+    assert_eq!(Some("143180000007321".to_string()), retorno.protocolo);
+    assert_eq!(Some(ModeloDocumentoFiscal::Nfe), retorno.modelo);
+    assert_eq!(
+        FixedOffset::west_opt(3 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2018, 9, 28, 16, 20, 45)
+            .unwrap(),
+        retorno.recebimento
+    );
+
+    // A assinatura do pedido confere com o conteúdo lido
+    let assinatura = inut.assinatura.as_ref().ok_or("Pedido sem assinatura")?;
+    assert_eq!(
+        c14n::digest(&inut.to_string(), "infInut").map_err(|e| e.to_string())?,
+        assinatura.digest()
+    );
+
+    let gerado = proc.to_string();
+    assert!(gerado.starts_with(
+        "<procInutNFe xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"4.00\"><inutNFe"
+    ));
+    assert_eq!(
+        proc,
+        gerado
+            .parse::<ProcInutilizacao>()
+            .map_err(|e| e.to_string())?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn retorno_rejeitado() -> Result<(), String> {
+    let xml = "<retInutNFe xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"4.00\">\
+               <infInut><tpAmb>2</tpAmb><verAplic>RS20180920103458</verAplic>\
+               <cStat>563</cStat><xMotivo>Rejeicao: Ja existe pedido de Inutilizacao com a mesma faixa</xMotivo>\
+               <cUF>43</cUF><dhRecbto>2018-09-28T16:25:02-03:00</dhRecbto></infInut></retInutNFe>";
+
+    let retorno = xml
+        .parse::<RetornoInutilizacao>()
+        .map_err(|e| e.to_string())?;
+    assert!(!retorno.homologada());
+    assert_eq!(563, retorno.status);
+    assert_eq!(None, retorno.protocolo);
+    assert_eq!(None, retorno.numero_inicial);
+
+    assert_eq!(
+        "Inutilização inválida. Inutilização não homologada: 563 - \
+         Rejeicao: Ja existe pedido de Inutilizacao com a mesma faixa",
+        ProcInutilizacao::new(inutilizacao()?, retorno)
+            .map_err(|e| e.to_string())
+            .unwrap_err()
+    );
+
+    // Retorno homologado de outra faixa
+    let proc = ler("xmls/proc_inut_nfe.xml")?
+        .parse::<ProcInutilizacao>()
+        .map_err(|e| e.to_string())?;
+    let mut outra = inutilizacao()?;
+    outra.numero_final = 31;
+    assert!(ProcInutilizacao::new(outra, proc.retorno.clone())
+        .map_err(|e| e.to_string())
+        .unwrap_err()
+        .contains("outra faixa"));
+    assert!(ProcInutilizacao::new(inutilizacao()?, proc.retorno).is_ok());
+
+    Ok(())
+}
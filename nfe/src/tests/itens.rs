@@ -85,7 +85,7 @@ fn produto_from_instance() -> Result<(), String> {
     assert_eq!(None, produto.valor_seguro);
     assert_eq!(None, produto.valor_desconto);
     assert_eq!(None, produto.valor_outros);
-    assert_eq!(true, produto.valor_compoe_total_nota);
+    assert!(produto.valor_compoe_total_nota);
 
     Ok(())
 }
@@ -136,7 +136,7 @@ fn produto_manual() -> Result<(), Error> {
     assert_eq!(None, produto.valor_seguro);
     assert_eq!(None, produto.valor_desconto);
     assert_eq!(None, produto.valor_outros);
-    assert_eq!(true, produto.valor_compoe_total_nota);
+    assert!(produto.valor_compoe_total_nota);
 
     Ok(())
 }
@@ -172,7 +172,7 @@ fn produtos() -> Result<(), String> {
     assert_eq!(None, produto.valor_seguro);
     assert_eq!(None, produto.valor_desconto);
     assert_eq!(None, produto.valor_outros);
-    assert_eq!(true, produto.valor_compoe_total_nota);
+    assert!(produto.valor_compoe_total_nota);
 
     let produto = &itens[1].produto;
 
@@ -201,7 +201,7 @@ fn produtos() -> Result<(), String> {
     assert_eq!(None, produto.valor_seguro);
     assert_eq!(None, produto.valor_desconto);
     assert_eq!(None, produto.valor_outros);
-    assert_eq!(true, produto.valor_compoe_total_nota);
+    assert!(produto.valor_compoe_total_nota);
 
     Ok(())
 }
@@ -223,7 +223,7 @@ fn imposto_from_instance() -> Result<(), String> {
             valor: 0.0,
             valor_base_calculo: 0.0,
             base_calculo: ModalidadeBaseCalculoIcmsSt::MargemValorAgregado,
-            codigo_situacao: "202".to_string()
+            codigo_situacao: Csosn::Csosn202
         })),
         imposto.icms
     );
@@ -231,7 +231,7 @@ fn imposto_from_instance() -> Result<(), String> {
         Some(GrupoPis::PisOutr(GrupoPisOutr {
             aliquota: 0.0,
             valor_base_calculo: 0.0,
-            codigo_situacao: "49".to_string()
+            codigo_situacao: CstPisCofins::Cst49
         })),
         imposto.pis
     );
@@ -239,7 +239,7 @@ fn imposto_from_instance() -> Result<(), String> {
         Some(GrupoCofins::CofinsOutr(GrupoCofinsOutr {
             aliquota: 0.0,
             valor_base_calculo: 0.0,
-            codigo_situacao: "49".to_string()
+            codigo_situacao: CstPisCofins::Cst49
         })),
         imposto.cofins
     );
@@ -291,7 +291,7 @@ fn imposto_manual() -> Result<(), Error> {
             valor: 0.0,
             valor_base_calculo: 0.0,
             base_calculo: ModalidadeBaseCalculoIcmsSt::MargemValorAgregado,
-            codigo_situacao: "202".to_string()
+            codigo_situacao: Csosn::Csosn202
         })),
         imposto.icms
     );
@@ -299,7 +299,7 @@ fn imposto_manual() -> Result<(), Error> {
         Some(GrupoPis::PisOutr(GrupoPisOutr {
             aliquota: 0.0,
             valor_base_calculo: 0.0,
-            codigo_situacao: "49".to_string()
+            codigo_situacao: CstPisCofins::Cst49
         })),
         imposto.pis
     );
@@ -307,7 +307,7 @@ fn imposto_manual() -> Result<(), Error> {
         Some(GrupoCofins::CofinsOutr(GrupoCofinsOutr {
             aliquota: 0.0,
             valor_base_calculo: 0.0,
-            codigo_situacao: "49".to_string()
+            codigo_situacao: CstPisCofins::Cst49
         })),
         imposto.cofins
     );
@@ -328,6 +328,7 @@ fn impostos() -> Result<(), String> {
     assert_eq!(
         Some(GrupoIcms::Icms60(GrupoIcms60 {
             origem: OrigemMercadoria::Nacional,
+            codigo_situacao: CstIcms::Cst60,
             aliquota: 0.0,
             valor: 0.0,
             valor_base_calculo: 0.0,
@@ -336,13 +337,13 @@ fn impostos() -> Result<(), String> {
     );
     assert_eq!(
         Some(GrupoPis::PisNt(GrupoPisNt {
-            codigo_situacao: "04".to_string()
+            codigo_situacao: CstPisCofins::Cst04
         })),
         imposto.pis
     );
     assert_eq!(
         Some(GrupoCofins::CofinsNt(GrupoCofinsNt {
-            codigo_situacao: "04".to_string()
+            codigo_situacao: CstPisCofins::Cst04
         })),
         imposto.cofins
     );
@@ -353,6 +354,7 @@ fn impostos() -> Result<(), String> {
     assert_eq!(
         Some(GrupoIcms::Icms60(GrupoIcms60 {
             origem: OrigemMercadoria::Nacional,
+            codigo_situacao: CstIcms::Cst60,
             aliquota: 0.0,
             valor: 0.0,
             valor_base_calculo: 0.0,
@@ -364,7 +366,7 @@ fn impostos() -> Result<(), String> {
             valor: 0.89,
             aliquota: 1.65,
             valor_base_calculo: 53.78,
-            codigo_situacao: "01".to_string()
+            codigo_situacao: CstPisCofins::Cst01
         })),
         imposto.pis
     );
@@ -373,7 +375,7 @@ fn impostos() -> Result<(), String> {
             valor: 4.09,
             aliquota: 7.6,
             valor_base_calculo: 53.78,
-            codigo_situacao: "01".to_string()
+            codigo_situacao: CstPisCofins::Cst01
         })),
         imposto.cofins
     );
@@ -0,0 +1,134 @@
+//! Testes da manifestação do destinatário
+
+use chrono::prelude::*;
+
+use super::assinatura::certificado_teste;
+use super::ler;
+use crate::*;
+
+const CHAVE: &str = "43180906929383000163550010000000261000010301";
+
+fn proc() -> Result<NfeProc, String> {
+    let xml = ler("xmls/nfe_proc_layout4.xml")?;
+
+    xml.parse::<NfeProc>().map_err(|e| e.to_string())
+}
+
+fn horario() -> DateTime<FixedOffset> {
+    FixedOffset::west_opt(3 * 3600)
+        .unwrap()
+        .with_ymd_and_hms(2018, 9, 27, 8, 0, 0)
+        .unwrap()
+}
+
+#[test]
+fn ciencia_operacao() -> Result<(), String> {
+    let proc = proc()?;
+    let evento =
+        Evento::manifestacao(&proc, Manifestacao::Ciencia, horario()).map_err(|e| e.to_string())?;
+
+    assert_eq!(ORGAO_AMBIENTE_NACIONAL, evento.orgao);
+    assert_eq!(91, evento.orgao);
+    // O autor é o destinatário da nota
+    assert_eq!("58716523000119", evento.autor.as_str());
+    assert_eq!(CHAVE, evento.chave_acesso);
+    assert_eq!(1, evento.sequencia);
+    assert_eq!(TipoEvento::CienciaOperacao, evento.tipo());
+    assert_eq!(format!("ID210210{}01", CHAVE), evento.id());
+
+    let xml = evento.to_string();
+    assert!(xml.contains("<cOrgao>91</cOrgao><tpAmb>2</tpAmb><CNPJ>58716523000119</CNPJ>"));
+    assert!(xml.contains(
+        "<tpEvento>210210</tpEvento><nSeqEvento>1</nSeqEvento><verEvento>1.00</verEvento>\
+         <detEvento versao=\"1.00\"><descEvento>Ciencia da Operacao</descEvento></detEvento>"
+    ));
+    assert_eq!(evento, xml.parse::<Evento>().map_err(|e| e.to_string())?);
+
+    Ok(())
+}
+
+#[test]
+fn tipos_manifestacao() -> Result<(), String> {
+    let proc = proc()?;
+    let manifestacoes = vec![
+        (Manifestacao::Confirmacao, 210200, "Confirmacao da Operacao"),
+        (Manifestacao::Ciencia, 210210, "Ciencia da Operacao"),
+        (
+            Manifestacao::Desconhecimento,
+            210220,
+            "Desconhecimento da Operacao",
+        ),
+        (
+            Manifestacao::NaoRealizada("Mercadoria devolvida na portaria".to_string()),
+            210240,
+            "Operacao nao Realizada",
+        ),
+    ];
+
+    for (manifestacao, codigo, descricao) in manifestacoes {
+        let mut evento = Evento::manifestacao(&proc, manifestacao.clone(), horario())
+            .map_err(|e| e.to_string())?;
+        evento
+            .assinar(&certificado_teste())
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(codigo, evento.tipo().codigo());
+        assert_eq!(descricao, evento.tipo().descricao());
+        assert_eq!(DetalheEvento::Manifestacao(manifestacao), evento.detalhe);
+
+        let xml = evento.to_string();
+        assert!(xml.contains(&format!("<descEvento>{}</descEvento>", descricao)));
+        assert_eq!(evento, xml.parse::<Evento>().map_err(|e| e.to_string())?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn operacao_nao_realizada() -> Result<(), String> {
+    let proc = proc()?;
+    let evento = Evento::manifestacao(
+        &proc,
+        Manifestacao::NaoRealizada(" Mercadoria devolvida na portaria  ".to_string()),
+        horario(),
+    )
+    .map_err(|e| e.to_string())?;
+
+    let xml = evento.to_string();
+    assert!(xml.contains(
+        "<descEvento>Operacao nao Realizada</descEvento>\
+         <xJust>Mercadoria devolvida na portaria</xJust></detEvento>"
+    ));
+
+    // A justificativa é obrigatória
+    let sem_justificativa = xml.replace("<xJust>Mercadoria devolvida na portaria</xJust>", "");
+    assert!(sem_justificativa.parse::<Evento>().is_err());
+
+    assert_eq!(
+        "Evento inválido. A justificativa deve possuir de 15 a 255 caracteres. Informado: 9",
+        Evento::manifestacao(
+            &proc,
+            Manifestacao::NaoRealizada("Devolvida".to_string()),
+            horario()
+        )
+        .map_err(|e| e.to_string())
+        .unwrap_err()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn nota_sem_destinatario() -> Result<(), String> {
+    let mut proc = proc()?;
+    proc.nfe.dest = None;
+
+    assert_eq!(
+        "Evento inválido. Nota sem destinatário para a manifestação",
+        Evento::manifestacao(&proc, Manifestacao::Confirmacao, horario())
+            .map_err(|e| e.to_string())
+            .unwrap_err()
+    );
+
+    Ok(())
+}
@@ -1,9 +1,59 @@
+//! Testes da biblioteca, com os XMLs de exemplo da pasta xmls
+
+use std::fs::File;
+use std::io::Read;
+
+pub mod assinatura;
+pub mod autorizacao;
+pub mod c14n;
+pub mod cana;
+pub mod carta_correcao;
+pub mod cfop;
+pub mod chave;
+#[cfg(feature = "client")]
+pub mod client;
+pub mod compra;
+pub mod consulta;
+pub mod cst;
 pub mod dest;
+pub mod distribuicao;
+pub mod documento;
 pub mod emit;
 pub mod endereco;
+pub mod epec;
+pub mod evento;
+pub mod exporta;
+pub mod gtin;
 pub mod ide;
+pub mod ie;
 pub mod infnfe;
+pub mod inutilizacao;
 pub mod itens;
+pub mod manifestacao;
+pub mod municipio;
+pub mod ncm;
+pub mod pagamento;
+pub mod pais;
 pub mod parse;
+pub mod protocolo;
+pub mod substituicao;
 pub mod totais;
 pub mod transporte;
+pub mod verificacao;
+pub mod webservice;
+
+/// Conteúdo do arquivo de exemplo
+pub fn ler(caminho: &str) -> Result<String, String> {
+    let mut xml = String::new();
+    File::open(caminho)
+        .map_err(|e| e.to_string())?
+        .read_to_string(&mut xml)
+        .map_err(|e| e.to_string())?;
+
+    Ok(xml)
+}
+
+/// Remove a indentação dos exemplos, que foram formatados após a assinatura
+pub fn compactar(xml: &str) -> String {
+    xml.lines().map(str::trim).collect()
+}
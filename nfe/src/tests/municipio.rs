@@ -0,0 +1,90 @@
+//! Testes das UFs e dos códigos de município do IBGE
+
+use crate::base::Error as ErrorBase;
+use crate::base::Nfe as NfeBase;
+use crate::*;
+use std::convert::TryFrom;
+use std::fs::File;
+
+#[test]
+fn uf() -> Result<(), String> {
+    assert_eq!(Uf::Rs, Uf::try_from(43).map_err(|e| e.to_string())?);
+    assert_eq!(Uf::Sp, "sp".parse::<Uf>().map_err(|e| e.to_string())?);
+    assert_eq!("DF", Uf::Df.to_string());
+    assert_eq!(53, Uf::Df.codigo());
+    assert_eq!("Espírito Santo", Uf::Es.nome());
+
+    assert!(Uf::try_from(34).is_err());
+    assert!("EX".parse::<Uf>().is_err());
+
+    for uf in Uf::TODAS.iter() {
+        assert_eq!(
+            Ok(*uf),
+            Uf::try_from(uf.codigo()).map_err(|e| e.to_string())
+        );
+        assert_eq!(Ok(*uf), uf.sigla().parse::<Uf>().map_err(|e| e.to_string()));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn codigo_municipio() {
+    assert_eq!(1, calcular_digito_municipio(4319901));
+    assert!(codigo_municipio_valido(4319901));
+    assert!(codigo_municipio_valido(5300108));
+    assert!(!codigo_municipio_valido(4319902));
+    assert!(!codigo_municipio_valido(431990));
+
+    // Código oficial cujo dígito não confere com o cálculo
+    assert!(codigo_municipio_valido(4305871));
+}
+
+#[test]
+fn validar_codigo_e_uf() {
+    assert!(validar_municipio(3550308, Uf::Sp).is_ok());
+    assert!(validar_municipio(4307609, Uf::Rs).is_ok());
+    assert!(validar_municipio(3550308, Uf::Rj).is_err());
+    assert!(validar_municipio(3550309, Uf::Sp).is_err());
+}
+
+#[test]
+fn validar_nota() -> Result<(), String> {
+    let f = File::open("xmls/nfe_layout4.xml").map_err(|e| e.to_string())?;
+    let mut nfe = NfeBase::try_from(f).map_err(|e| e.to_string())?;
+
+    nfe.validar().map_err(|e| e.to_string())?;
+
+    let mut dest = nfe.dest.clone().unwrap();
+    let mut endereco = dest.endereco.clone().unwrap();
+
+    // Código de Porto Alegre no endereço de SP
+    let codigo_sp = endereco.codigo_municipio;
+    endereco.codigo_municipio = 4314902;
+    dest.endereco = Some(endereco.clone());
+    nfe.dest = Some(dest.clone());
+    match nfe.validar() {
+        Err(ErrorBase::MunicipioInvalido(e)) => assert!(e.contains("UF SP")),
+        r => panic!("{:?}", r),
+    }
+    endereco.codigo_municipio = codigo_sp;
+
+    // Exterior exige o código 9999999
+    endereco.sigla_uf = "EX".to_string();
+    dest.endereco = Some(endereco.clone());
+    nfe.dest = Some(dest.clone());
+    match nfe.validar() {
+        Err(ErrorBase::MunicipioInvalido(e)) => assert!(e.contains("9999999")),
+        r => panic!("{:?}", r),
+    }
+
+    // cMunFG de outra UF
+    nfe.dest = None;
+    nfe.ide.codigo_municipio = 3550308;
+    match nfe.validar() {
+        Err(ErrorBase::MunicipioInvalido(e)) => assert!(e.contains("UF RS")),
+        r => panic!("{:?}", r),
+    }
+
+    Ok(())
+}
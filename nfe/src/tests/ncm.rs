@@ -0,0 +1,64 @@
+//! Testes dos códigos NCM e CEST
+
+use std::convert::TryFrom;
+use std::fs::File;
+
+use crate::base::Error as ErrorBase;
+use crate::base::Nfe as NfeBase;
+use crate::*;
+
+#[test]
+fn formato_ncm() {
+    assert!(validar_ncm("8708.30.90").is_ok());
+    assert!(validar_ncm("64011000").is_ok());
+    assert!(validar_ncm(NCM_SERVICO).is_ok());
+
+    assert!(validar_ncm("8708309").is_err());
+    assert!(validar_ncm("8708309A").is_err());
+
+    // Capítulos inexistentes
+    assert!(!capitulo_ncm_valido(0));
+    assert!(!capitulo_ncm_valido(77));
+    assert!(!capitulo_ncm_valido(98));
+    assert!(validar_ncm("77011000").is_err());
+    match validar_ncm("99999999") {
+        Err(ErrorBase::NcmInvalido(e)) => assert!(e.contains("Capítulo 99")),
+        r => panic!("{:?}", r),
+    }
+}
+
+#[test]
+fn formato_cest() {
+    assert!(validar_cest("16.001.00").is_ok());
+    assert!(validar_cest("2800100").is_ok());
+
+    assert!(validar_cest("160010").is_err());
+    assert!(validar_cest("0000100").is_err());
+    match validar_cest("2900100") {
+        Err(ErrorBase::CestInvalido(e)) => assert!(e.contains("Segmento 29")),
+        r => panic!("{:?}", r),
+    }
+}
+
+#[test]
+fn validar_nota() -> Result<(), String> {
+    let f = File::open("xmls/nfe_layout4.xml").map_err(|e| e.to_string())?;
+    let mut nfe = NfeBase::try_from(f).map_err(|e| e.to_string())?;
+
+    nfe.validar().map_err(|e| e.to_string())?;
+
+    nfe.itens[0].produto.tributacao.cest = Some("2900100".to_string());
+    match nfe.validar() {
+        Err(ErrorBase::CestInvalido(e)) => assert!(e.starts_with("Item 1")),
+        r => panic!("{:?}", r),
+    }
+
+    nfe.itens[0].produto.tributacao.cest = None;
+    nfe.itens[0].produto.ncm = "77011000".to_string();
+    match nfe.validar() {
+        Err(ErrorBase::NcmInvalido(e)) => assert!(e.starts_with("Item 1")),
+        r => panic!("{:?}", r),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+//! Testes do grupo de pagamento
+
+use super::ler;
+use crate::*;
+
+#[test]
+fn pagamento_da_nota() -> Result<(), String> {
+    let xml = ler("xmls/nfe_layout4.xml")?;
+
+    let nfe = xml.parse::<Nfe>().map_err(|e| e.to_string())?;
+    let pagamento = nfe.pagamento.as_ref().ok_or("pag não encontrado")?;
+
+    assert_eq!(1, pagamento.detalhes.len());
+    assert_eq!(MeioPagamento::Dinheiro, pagamento.detalhes[0].meio);
+    assert_eq!(500.0, pagamento.valor_pago());
+    assert_eq!(None, pagamento.troco);
+    pagamento.validar().map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+#[test]
+fn pagamento_to_string() -> Result<(), String> {
+    let pagamento = Pagamento {
+        detalhes: vec![
+            DetalhePagamento {
+                indicador: Some(IndicadorPagamento::AVista),
+                meio: MeioPagamento::Pix,
+                descricao: None,
+                valor: 80.0,
+            },
+            DetalhePagamento {
+                indicador: None,
+                meio: MeioPagamento::Dinheiro,
+                descricao: None,
+                valor: 30.0,
+            },
+        ],
+        troco: Some(10.0),
+    };
+
+    let xml = pagamento.to_string();
+    assert_eq!(
+        "<pag><detPag><indPag>0</indPag><tPag>17</tPag><vPag>80</vPag></detPag>\
+         <detPag><tPag>01</tPag><vPag>30</vPag></detPag><vTroco>10</vTroco></pag>",
+        xml
+    );
+    assert_eq!(
+        pagamento,
+        xml.parse::<Pagamento>().map_err(|e| e.to_string())?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn pagamento_invalido() {
+    let outros = Pagamento {
+        detalhes: vec![DetalhePagamento {
+            indicador: None,
+            meio: MeioPagamento::Outros,
+            descricao: None,
+            valor: 10.0,
+        }],
+        troco: None,
+    };
+    assert!(outros.validar().is_err());
+
+    let sem_detalhes = Pagamento {
+        detalhes: vec![],
+        troco: None,
+    };
+    assert!(sem_detalhes.validar().is_err());
+
+    assert!("07".parse::<MeioPagamento>().is_err());
+    assert_eq!(
+        Ok(MeioPagamento::SemPagamento),
+        "90".parse().map_err(|_| ())
+    );
+}
@@ -0,0 +1,110 @@
+//! Testes da tabela de países do BACEN
+
+use crate::base::Error as ErrorBase;
+use crate::base::Nfe as NfeBase;
+use crate::*;
+use std::convert::TryFrom;
+use std::fs::File;
+
+#[test]
+fn tabela_paises() {
+    let pais = buscar_pais(CODIGO_BRASIL).expect("Brasil na tabela");
+    assert_eq!(NOME_BRASIL, pais.nome);
+
+    assert_eq!(
+        Some(639),
+        buscar_pais_por_nome("Argentina").map(|p| p.codigo)
+    );
+    assert_eq!(Some(2755), buscar_pais_por_nome("França").map(|p| p.codigo));
+    assert_eq!(None, buscar_pais_por_nome("Atlântida"));
+
+    assert!(validar_pais(1058, "Brasil").is_ok());
+    assert!(validar_pais(1058, "ARGENTINA").is_err());
+    assert!(validar_pais(0, "").is_err());
+    assert!(validar_pais(10580, "BRASIL").is_err());
+
+    assert_eq!(Some("SUICA"), buscar_pais(7676).map(|p| p.nome));
+    assert_eq!(
+        Some(3514),
+        buscar_pais_por_nome("Hong Kong").map(|p| p.codigo)
+    );
+
+    // Fora da tabela, o nome não é conferido
+    assert!(validar_pais(9999, "QUALQUER").is_ok());
+
+    // Códigos e nomes únicos, em ordem de código
+    let paises: Vec<Pais> = paises().collect();
+    assert!(paises.len() > 200);
+    assert!(paises.windows(2).all(|p| p[0].codigo < p[1].codigo));
+    for pais in &paises {
+        assert_eq!(
+            Some(pais.codigo),
+            buscar_pais_por_nome(pais.nome).map(|p| p.codigo)
+        );
+    }
+}
+
+#[test]
+fn endereco_exterior() -> Result<(), String> {
+    let f = File::open("xmls/nfe_layout4.xml").map_err(|e| e.to_string())?;
+    let nfe = NfeBase::try_from(f).map_err(|e| e.to_string())?;
+
+    let mut endereco = nfe.dest.unwrap().endereco.unwrap();
+    endereco.validar_pais().map_err(|e| e.to_string())?;
+
+    endereco.definir_exterior(buscar_pais(8451).unwrap());
+    assert_eq!("EX", endereco.sigla_uf);
+    assert_eq!(CODIGO_EXTERIOR, endereco.codigo_municipio);
+    assert_eq!("EXTERIOR", endereco.nome_municipio);
+    assert_eq!("URUGUAI", endereco.nome_pais);
+    endereco.validar_pais().map_err(|e| e.to_string())?;
+    endereco.validar_municipio().map_err(|e| e.to_string())?;
+
+    endereco.codigo_pais = CODIGO_BRASIL;
+    endereco.nome_pais = NOME_BRASIL.to_string();
+    assert!(endereco.validar_pais().is_err());
+
+    endereco.sigla_uf = "SP".to_string();
+    endereco.codigo_pais = 8451;
+    endereco.nome_pais = "URUGUAI".to_string();
+    match endereco.validar_pais() {
+        Err(ErrorBase::PaisInvalido(e)) => assert!(e.contains("nacional")),
+        r => panic!("{:?}", r),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn validar_nota_exportacao() -> Result<(), String> {
+    let f = File::open("xmls/nfe_layout4.xml").map_err(|e| e.to_string())?;
+    let mut nfe = NfeBase::try_from(f).map_err(|e| e.to_string())?;
+
+    let mut dest = nfe.dest.clone().unwrap();
+    let mut endereco = dest.endereco.clone().unwrap();
+    endereco.definir_exterior(buscar_pais(2496).unwrap());
+    dest.endereco = Some(endereco);
+    dest.ie = None;
+    dest.indicador_ie = crate::IndicadorContribuicaoIe::NaoContribuinte;
+    nfe.dest = Some(dest.clone());
+
+    // Destinatário do exterior exige idDest=3
+    match nfe.validar() {
+        Err(ErrorBase::PaisInvalido(e)) => assert!(e.contains("EX")),
+        r => panic!("{:?}", r),
+    }
+
+    // e é identificado pelo idEstrangeiro, não pelo CNPJ
+    nfe.ide.operacao.destino = DestinoOperacao::ComExterior;
+    match nfe.validar() {
+        Err(ErrorBase::DocumentoInvalido(e)) => assert!(e.contains("EX")),
+        r => panic!("{:?}", r),
+    }
+
+    dest.documento = Documento::Estrangeiro("AB1234567".to_string());
+    nfe.dest = Some(dest);
+    nfe.itens[0].produto.tributacao.cfop = "7101".to_string();
+    nfe.validar().map_err(|e| e.to_string())?;
+
+    Ok(())
+}
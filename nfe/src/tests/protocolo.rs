@@ -0,0 +1,123 @@
+//! Testes do protocolo de autorização e do nfeProc
+
+use chrono::prelude::*;
+use std::convert::TryFrom;
+use std::fs::File;
+
+use super::{compactar, ler};
+use crate::base::Error as ErrorBase;
+use crate::base::Nfe as NfeBase;
+use crate::*;
+
+#[test]
+fn from_str() -> Result<(), String> {
+    let xml = ler("xmls/nfe_proc_layout4.xml")?;
+    let proc = xml.parse::<NfeProc>().map_err(|e| e.to_string())?;
+
+    assert_eq!(VersaoLayout::V4_00, proc.versao);
+    assert_eq!(
+        "43180906929383000163550010000000261000010301",
+        proc.nfe.chave_acesso
+    );
+
+    let prot = &proc.protocolo;
+    assert_eq!(Some("ID143180000001234".to_string()), prot.id);
+    assert_eq!(TipoAmbiente::Producao, prot.ambiente);
+    assert_eq!("RS20180920103458", prot.versao_aplicativo);
+    assert_eq!(proc.nfe.chave_acesso, prot.chave_acesso);
+    assert_eq!(
+        FixedOffset::west_opt(3 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2018, 9, 25, 10, 2, 57)
+            .unwrap(),
+        prot.recebimento
+    );
+    assert_eq!(Some("143180000001234".to_string()), prot.numero);
+    assert_eq!(
+        Some("Hwr3X8T6PR7EUzdSHZYeHpn14e4=".to_string()),
+        prot.digest
+    );
+    assert_eq!(100, prot.status);
+    assert_eq!("Autorizado o uso da NF-e", prot.motivo);
+    assert!(prot.autorizado());
+
+    Ok(())
+}
+
+#[test]
+fn nfe_com_protocolo() -> Result<(), String> {
+    // A nota também pode ser lida diretamente do nfeProc
+    let xml = ler("xmls/nfe_proc_layout4.xml")?;
+    let nfe = xml.parse::<NfeBase>().map_err(|e| e.to_string())?;
+
+    let f = File::open("xmls/nfe_layout4.xml").map_err(|e| e.to_string())?;
+    assert_eq!(NfeBase::try_from(f).map_err(|e| e.to_string())?, nfe);
+
+    let modelo = xml.parse::<Nfe>().map_err(|e| e.to_string())?;
+    assert_eq!(nfe.chave_acesso, modelo.chave_acesso);
+
+    // O tipo é identificado pelo elemento raiz e não por trechos do conteúdo
+    let avulsa =
+        ler("xmls/nfe_layout4.xml")?.replacen("<NFe ", "<!-- Extraída do <nfeProc> --><NFe ", 1);
+    assert_eq!(nfe, avulsa.parse::<NfeBase>().map_err(|e| e.to_string())?);
+
+    Ok(())
+}
+
+#[test]
+fn anexar_protocolo() -> Result<(), String> {
+    let proc = ler("xmls/nfe_proc_layout4.xml")?
+        .parse::<NfeProc>()
+        .map_err(|e| e.to_string())?;
+    let protocolo = proc.protocolo.clone();
+
+    // O digVal confere apenas com o XML original, sem a formatação
+    let nfe = compactar(&ler("xmls/nfe_layout4.xml")?)
+        .parse::<NfeBase>()
+        .map_err(|e| e.to_string())?;
+
+    let anexado = nfe
+        .anexar_protocolo(protocolo.clone())
+        .map_err(|e| e.to_string())?;
+
+    // O XML gerado deve ser lido novamente sem perdas
+    let xml = anexado.to_string();
+    assert!(xml.starts_with(
+        "<nfeProc xmlns=\"http://www.portalfiscal.inf.br/nfe\" versao=\"4.00\">\
+         <NFe xmlns=\"http://www.portalfiscal.inf.br/nfe\">"
+    ));
+    assert_eq!(anexado, xml.parse::<NfeProc>().map_err(|e| e.to_string())?);
+
+    let mut rejeitado = protocolo.clone();
+    rejeitado.status = 539;
+    rejeitado.numero = None;
+    match NfeProc::new(anexado.nfe, rejeitado) {
+        Err(ErrorBase::ProtocoloInvalido(e)) => assert!(e.contains("539")),
+        r => panic!("{:?}", r),
+    }
+
+    // Mesma chave, mas conteúdo diferente do autorizado
+    let f = File::open("xmls/nfe_layout4.xml").map_err(|e| e.to_string())?;
+    let formatada = NfeBase::try_from(f).map_err(|e| e.to_string())?;
+    match formatada.anexar_protocolo(protocolo) {
+        Err(ErrorBase::ProtocoloInvalido(e)) => assert!(e.contains("digVal")),
+        r => panic!("{:?}", r),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn chave_divergente() -> Result<(), String> {
+    let f = File::open("xmls/nfce_layout4.xml").map_err(|e| e.to_string())?;
+    let nfce = NfeBase::try_from(f).map_err(|e| e.to_string())?;
+
+    let protocolo = ler("xmls/nfe_proc_layout4.xml")?
+        .parse::<NfeProc>()
+        .map_err(|e| e.to_string())?
+        .protocolo;
+
+    assert!(nfce.anexar_protocolo(protocolo).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+//! Testes do cancelamento por substituição da NFC-e
+
+use chrono::prelude::*;
+
+use super::assinatura::certificado_teste;
+use super::ler;
+use crate::base::Nfe as NfeBase;
+use crate::*;
+
+const CHAVE: &str = "29181033657677000156650010001654399001654398";
+const VERSAO_APLICATIVO: &str = "PDV 2.4.1";
+
+fn nfce() -> Result<NfeBase, String> {
+    let xml = ler("xmls/nfce_layout4.xml")?;
+
+    let mut nfce = xml.parse::<NfeBase>().map_err(|e| e.to_string())?;
+    // O XML de exemplo possui o dígito verificador incorreto
+    nfce.chave_acesso = CHAVE.to_string();
+
+    Ok(nfce)
+}
+
+/// Chave com o dígito verificador calculado a partir das 43 posições
+fn chave(sem_digito: &str) -> String {
+    format!("{}{}", sem_digito, calcular_digito_verificador(sem_digito))
+}
+
+/// NFC-e emitida em contingência e autorizada, que substitui a do XML
+fn substituta() -> String {
+    chave("2918103365767700015665001000165440148213657")
+}
+
+fn horario() -> DateTime<FixedOffset> {
+    FixedOffset::west_opt(3 * 3600)
+        .unwrap()
+        .with_ymd_and_hms(2018, 10, 2, 11, 40, 0)
+        .unwrap()
+}
+
+#[test]
+fn cancelamento_substituicao() -> Result<(), String> {
+    let nfe = nfce()?;
+    let mut evento = Evento::cancelamento_substituicao(
+        &nfe,
+        "129180000123456",
+        "NFC-e emitida em duplicidade na contingencia",
+        &substituta(),
+        VERSAO_APLICATIVO,
+        horario(),
+    )
+    .map_err(|e| e.to_string())?;
+
+    assert_eq!(29, evento.orgao);
+    assert_eq!("33657677000156", evento.autor.as_str());
+    assert_eq!(CHAVE, evento.chave_acesso);
+    assert_eq!(TipoEvento::CancelamentoSubstituicao, evento.tipo());
+    assert_eq!(format!("ID110112{}01", CHAVE), evento.id());
+    assert_eq!(
+        DetalheEvento::CancelamentoSubstituicao(CancelamentoSubstituicao {
+            orgao_autor: 29,
+            tipo_autor: TIPO_AUTOR_EMITENTE,
+            versao_aplicativo: VERSAO_APLICATIVO.to_string(),
+            protocolo: "129180000123456".to_string(),
+            justificativa: "NFC-e emitida em duplicidade na contingencia".to_string(),
+            chave_referenciada: substituta(),
+        }),
+        evento.detalhe
+    );
+
+    evento
+        .assinar(&certificado_teste())
+        .map_err(|e| e.to_string())?;
+
+    let xml = evento.to_string();
+    assert!(xml.contains(&format!(
+        "<tpEvento>110112</tpEvento><nSeqEvento>1</nSeqEvento><verEvento>1.00</verEvento>\
+         <detEvento versao=\"1.00\"><descEvento>Cancelamento por substituicao</descEvento>\
+         <cOrgaoAutor>29</cOrgaoAutor><tpAutor>1</tpAutor><verAplic>PDV 2.4.1</verAplic>\
+         <nProt>129180000123456</nProt>\
+         <xJust>NFC-e emitida em duplicidade na contingencia</xJust>\
+         <chNFeRef>{}</chNFeRef></detEvento>",
+        substituta()
+    )));
+    assert_eq!(evento, xml.parse::<Evento>().map_err(|e| e.to_string())?);
+
+    // Todos os campos do detalhe são obrigatórios na leitura
+    let sem_referencia = xml.replace(&format!("<chNFeRef>{}</chNFeRef>", substituta()), "");
+    assert!(sem_referencia.parse::<Evento>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn cancelamento_substituicao_invalido() -> Result<(), String> {
+    let nfce = nfce()?;
+    let erro = |nfe: &NfeBase, referenciada: &str| {
+        Evento::cancelamento_substituicao(
+            nfe,
+            "129180000123456",
+            "NFC-e emitida em duplicidade na contingencia",
+            referenciada,
+            VERSAO_APLICATIVO,
+            horario(),
+        )
+        .map_err(|e| e.to_string())
+        .unwrap_err()
+    };
+
+    // Substituta de outro emitente
+    let outro_emitente = chave("2918100692938300016365001000165440148213657");
+    assert_eq!(
+        "Evento inválido. A NFC-e substituta é de outro emitente: 06929383000163",
+        erro(&nfce, &outro_emitente)
+    );
+
+    // Substituta modelo 55
+    let nfe = chave("2918103365767700015655001000165440148213657");
+    assert!(erro(&nfce, &nfe).contains("exclusivo da NFC-e"));
+
+    // A própria nota como substituta
+    assert_eq!(
+        "Evento inválido. A NFC-e substituta deve ser diferente da cancelada",
+        erro(&nfce, CHAVE)
+    );
+
+    assert!(erro(&nfce, "2918103365767700015665001000165440").contains("44 posições"));
+
+    // Nota cancelada modelo 55
+    let mut modelo55 = nfce;
+    modelo55.chave_acesso = chave("2918103365767700015655001000165439190016543");
+    assert!(erro(&modelo55, &substituta()).contains("exclusivo da NFC-e"));
+
+    Ok(())
+}
@@ -13,6 +13,7 @@ fn apenas_valores_dos_produtos() -> Result<(), String> {
 
     assert_eq!(0.0, totais.valor_base_calculo);
     assert_eq!(0.0, totais.valor_icms);
+    assert_eq!(0.0, totais.valor_icms_st);
     assert_eq!(500.0, totais.valor_produtos);
     assert_eq!(0.0, totais.valor_frete);
     assert_eq!(0.0, totais.valor_seguro);
@@ -33,6 +34,7 @@ fn produtos_com_pis_cofins() -> Result<(), String> {
 
     assert_eq!(0.0, totais.valor_base_calculo);
     assert_eq!(0.0, totais.valor_icms);
+    assert_eq!(0.0, totais.valor_icms_st);
     assert_eq!(150.0, totais.valor_produtos);
     assert_eq!(0.0, totais.valor_frete);
     assert_eq!(0.0, totais.valor_seguro);
@@ -77,6 +79,7 @@ fn manual_produtos_com_pis_cofins() -> Result<(), Error> {
 
     assert_eq!(0.0, totais.valor_base_calculo);
     assert_eq!(0.0, totais.valor_icms);
+    assert_eq!(0.0, totais.valor_icms_st);
     assert_eq!(150.0, totais.valor_produtos);
     assert_eq!(0.0, totais.valor_frete);
     assert_eq!(0.0, totais.valor_seguro);
@@ -96,6 +99,7 @@ fn to_string() -> Result<(), Error> {
             <ICMSTot>
                 <vBC>0</vBC>
                 <vICMS>0</vICMS>
+                <vST>12.5</vST>
                 <vProd>150</vProd>
                 <vFrete>0</vFrete>
                 <vSeg>0</vSeg>
@@ -0,0 +1,202 @@
+//! Testes da verificação da assinatura de notas recebidas
+
+use openssl::x509::store::X509StoreBuilder;
+
+use super::assinatura::{certificado_teste, gerar_pkcs12};
+use super::{compactar, ler};
+use crate::base::Nfe as NfeBase;
+use crate::*;
+
+#[test]
+fn notas_originais() -> Result<(), String> {
+    let xml = compactar(&ler("xmls/nfe_layout4.xml")?);
+    let verificacao = NfeBase::verificar_assinatura(&xml).map_err(|e| e.to_string())?;
+
+    assert_eq!(Vec::<String>::new(), verificacao.problemas());
+    assert!(verificacao.valida());
+    assert_eq!("Hwr3X8T6PR7EUzdSHZYeHpn14e4=", verificacao.digest_calculado);
+    assert_eq!(
+        Some("06929383000163"),
+        verificacao.cnpj_certificado.as_ref().map(|c| c.as_str())
+    );
+
+    let xml = compactar(&ler("xmls/nfce_layout4.xml")?);
+    let verificacao = NfeBase::verificar_assinatura(&xml).map_err(|e| e.to_string())?;
+
+    // Exemplo assinado com o certificado de outra empresa
+    assert!(verificacao.digest_valido);
+    assert!(verificacao.assinatura_valida);
+    assert!(verificacao.certificado_vigente);
+    assert!(!verificacao.cnpj_valido);
+    assert_eq!(
+        vec!["CNPJ do certificado(58716523000119) de outra empresa".to_string()],
+        verificacao.problemas()
+    );
+    assert_eq!("m9ZrQTKMxv7A1Blnf/nmNGVX+N8=", verificacao.digest_calculado);
+
+    // Também a partir do nfeProc
+    let xml = compactar(&ler("xmls/nfe_proc_layout4.xml")?);
+    let verificacao = Nfe::verificar_assinatura(&xml).map_err(|e| e.to_string())?;
+    assert!(verificacao.valida());
+
+    Ok(())
+}
+
+#[test]
+fn nota_formatada() -> Result<(), String> {
+    // A indentação altera o infNFe e o SignedInfo assinados
+    let xml = ler("xmls/nfe_layout4.xml")?;
+    let verificacao = NfeBase::verificar_assinatura(&xml).map_err(|e| e.to_string())?;
+
+    assert!(!verificacao.valida());
+    assert!(verificacao.referencia_valida);
+    assert!(!verificacao.digest_valido);
+    assert!(!verificacao.assinatura_valida);
+    assert!(verificacao.cnpj_valido);
+    assert!(verificacao.certificado_vigente);
+    assert_eq!(2, verificacao.problemas().len());
+    assert!(verificacao.problemas()[0].starts_with("DigestValue não confere"));
+
+    Ok(())
+}
+
+#[test]
+fn nota_alterada() -> Result<(), String> {
+    let mut nfe = ler("xmls/nfe_layout4.xml")?
+        .parse::<NfeBase>()
+        .map_err(|e| e.to_string())?;
+    nfe.assinar(&certificado_teste())
+        .map_err(|e| e.to_string())?;
+
+    let xml = nfe.to_string();
+    let verificacao = NfeBase::verificar_assinatura(&xml).map_err(|e| e.to_string())?;
+    assert!(verificacao.valida());
+
+    // Alteração do conteúdo após a assinatura: o SignedInfo continua íntegro
+    let alterado = xml.replace("<nNF>26</nNF>", "<nNF>27</nNF>");
+    assert_ne!(xml, alterado);
+
+    let verificacao = NfeBase::verificar_assinatura(&alterado).map_err(|e| e.to_string())?;
+    assert!(!verificacao.digest_valido);
+    assert!(verificacao.assinatura_valida);
+    assert_eq!(1, verificacao.problemas().len());
+
+    Ok(())
+}
+
+#[test]
+fn nota_reescrita() -> Result<(), String> {
+    // A nota lida e escrita novamente mantém o infNFe assinado, inclusive
+    // os grupos que o modelo não cobre
+    let xml = compactar(&ler("xmls/nfe_layout4.xml")?);
+    let nfe = xml.parse::<NfeBase>().map_err(|e| e.to_string())?;
+    let gerado = nfe.to_string();
+    assert!(gerado.contains("<cobr><fat><nFat>173</nFat>"));
+
+    let verificacao = NfeBase::verificar_assinatura(&gerado).map_err(|e| e.to_string())?;
+    assert_eq!(Vec::<String>::new(), verificacao.problemas());
+
+    let proc = compactar(&ler("xmls/nfe_proc_layout4.xml")?)
+        .parse::<NfeProc>()
+        .map_err(|e| e.to_string())?;
+    let verificacao = Nfe::verificar_assinatura(&proc.to_string()).map_err(|e| e.to_string())?;
+    assert!(verificacao.valida());
+
+    // Após uma alteração, a assinatura que não confere mais deixa de ser escrita
+    let mut alterada = xml.parse::<NfeBase>().map_err(|e| e.to_string())?;
+    alterada.informacao_complementar = Some("Alterada apos a assinatura".to_string());
+    assert!(alterada.assinatura.is_some());
+    assert!(!alterada.to_string().contains("<Signature"));
+
+    // A nota assinada pelo modelo mantém a assinatura, já que o digest confere
+    let mut assinada = alterada;
+    assinada
+        .assinar(&certificado_teste())
+        .map_err(|e| e.to_string())?;
+    let verificacao =
+        NfeBase::verificar_assinatura(&assinada.to_string()).map_err(|e| e.to_string())?;
+    assert!(verificacao.valida());
+
+    Ok(())
+}
+
+#[test]
+fn certificado_de_outra_empresa() -> Result<(), String> {
+    let der = gerar_pkcs12("OUTRA EMPRESA LTDA:11222333000181", "1234");
+    let certificado = CertificadoA1::from_pkcs12(&der, "1234").map_err(|e| e.to_string())?;
+
+    let mut nfe = ler("xmls/nfe_layout4.xml")?
+        .parse::<NfeBase>()
+        .map_err(|e| e.to_string())?;
+    nfe.assinar(&certificado).map_err(|e| e.to_string())?;
+
+    let verificacao = NfeBase::verificar_assinatura(&nfe.to_string()).map_err(|e| e.to_string())?;
+    assert!(verificacao.digest_valido);
+    assert!(verificacao.assinatura_valida);
+    assert!(!verificacao.cnpj_valido);
+    assert_eq!(
+        vec!["CNPJ do certificado(11222333000181) de outra empresa".to_string()],
+        verificacao.problemas()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cadeia_do_certificado() -> Result<(), String> {
+    let certificado = certificado_teste();
+    let mut nfe = ler("xmls/nfe_layout4.xml")?
+        .parse::<NfeBase>()
+        .map_err(|e| e.to_string())?;
+    nfe.assinar(&certificado).map_err(|e| e.to_string())?;
+    let xml = nfe.to_string();
+
+    // Sem as autoridades, a assinatura confere mas o assinante não é confiável
+    let verificacao = NfeBase::verificar_assinatura(&xml).map_err(|e| e.to_string())?;
+    assert!(verificacao.valida());
+    assert!(!verificacao.cadeia_verificada);
+    assert!(!verificacao.confiavel());
+
+    // Certificado com o mesmo titular, mas de outra autoridade
+    let der = gerar_pkcs12("EMPRESA DE TESTE LTDA:06929383000163", "1234");
+    let outro = CertificadoA1::from_pkcs12(&der, "1234").map_err(|e| e.to_string())?;
+    let mut confiaveis = X509StoreBuilder::new().map_err(|e| e.to_string())?;
+    confiaveis
+        .add_cert(outro.certificado().x509().clone())
+        .map_err(|e| e.to_string())?;
+    let confiaveis = confiaveis.build();
+
+    let verificacao =
+        NfeBase::verificar_assinatura_cadeia(&xml, &confiaveis).map_err(|e| e.to_string())?;
+    assert!(verificacao.valida());
+    assert!(!verificacao.confiavel());
+
+    // O certificado autoassinado do teste é a própria raiz
+    let mut confiaveis = X509StoreBuilder::new().map_err(|e| e.to_string())?;
+    confiaveis
+        .add_cert(certificado.certificado().x509().clone())
+        .map_err(|e| e.to_string())?;
+    let confiaveis = confiaveis.build();
+
+    let verificacao =
+        Nfe::verificar_assinatura_cadeia(&xml, &confiaveis).map_err(|e| e.to_string())?;
+    assert!(verificacao.cadeia_verificada);
+    assert!(verificacao.confiavel());
+
+    Ok(())
+}
+
+#[test]
+fn nota_sem_assinatura() -> Result<(), String> {
+    let mut nfe = ler("xmls/nfe_layout4.xml")?
+        .parse::<NfeBase>()
+        .map_err(|e| e.to_string())?;
+    nfe.assinatura = None;
+
+    let erro = NfeBase::verificar_assinatura(&nfe.to_string())
+        .map_err(|e| e.to_string())
+        .unwrap_err();
+    assert_eq!("Assinatura inválida. Nota sem assinatura", erro);
+
+    Ok(())
+}
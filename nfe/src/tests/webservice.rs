@@ -0,0 +1,300 @@
+//! Testes dos endereços dos web services
+
+use crate::*;
+
+#[test]
+fn autorizadores() -> Result<(), String> {
+    let nfe = |uf| Autorizador::da_uf(uf, ModeloDocumentoFiscal::Nfe).map_err(|e| e.to_string());
+    let nfce = |uf| Autorizador::da_uf(uf, ModeloDocumentoFiscal::Nfce).map_err(|e| e.to_string());
+
+    assert_eq!(Autorizador::Rs, nfe(43)?);
+    assert_eq!(Autorizador::Sp, nfe(35)?);
+    assert_eq!(Autorizador::Svan, nfe(21)?);
+    assert_eq!(Autorizador::Svrs, nfe(42)?);
+
+    // A BA autoriza a NF-e, mas a NFC-e fica no SVRS
+    assert_eq!(Autorizador::Ba, nfe(29)?);
+    assert_eq!(Autorizador::Svrs, nfce(29)?);
+    assert_eq!(Autorizador::Svrs, nfce(21)?);
+
+    // Toda UF possui autorizador para os dois modelos
+    for uf in Uf::TODAS.iter() {
+        nfe(uf.codigo())?;
+        nfce(uf.codigo())?;
+    }
+
+    assert_eq!(
+        Autorizador::SvcAn,
+        Autorizador::contingencia(43, ModeloDocumentoFiscal::Nfe).map_err(|e| e.to_string())?
+    );
+    assert_eq!(
+        Autorizador::SvcRs,
+        Autorizador::contingencia(41, ModeloDocumentoFiscal::Nfe).map_err(|e| e.to_string())?
+    );
+    assert!(Autorizador::contingencia(43, ModeloDocumentoFiscal::Nfce).is_err());
+
+    // UFs atendidas pelo SVC-RS. As demais usam o SVC-AN
+    let svc_rs = [
+        Uf::Am,
+        Uf::Ba,
+        Uf::Ce,
+        Uf::Go,
+        Uf::Ma,
+        Uf::Ms,
+        Uf::Mt,
+        Uf::Pa,
+        Uf::Pe,
+        Uf::Pi,
+        Uf::Pr,
+    ];
+    for uf in Uf::TODAS.iter() {
+        let esperado = if svc_rs.contains(uf) {
+            Autorizador::SvcRs
+        } else {
+            Autorizador::SvcAn
+        };
+        assert_eq!(
+            Ok(esperado),
+            Autorizador::contingencia(uf.codigo(), ModeloDocumentoFiscal::Nfe)
+                .map_err(|e| e.to_string()),
+            "{}",
+            uf
+        );
+    }
+
+    assert_eq!(
+        "Web service não encontrado. UF sem autorizador: 99",
+        nfe(99).unwrap_err()
+    );
+    assert_eq!(
+        Ok(Autorizador::SvcRs),
+        "svc-rs".parse::<Autorizador>().map_err(|e| e.to_string())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn enderecos() -> Result<(), String> {
+    let ws = WebServices::new();
+    let url = |uf, modelo, ambiente, servico| {
+        ws.url(uf, modelo, ambiente, servico)
+            .map_err(|e| e.to_string())
+    };
+
+    assert_eq!(
+        "https://nfe.sefazrs.rs.gov.br/ws/NfeAutorizacao/NFeAutorizacao4.asmx",
+        url(
+            43,
+            ModeloDocumentoFiscal::Nfe,
+            TipoAmbiente::Producao,
+            Servico::Autorizacao
+        )?
+    );
+    assert_eq!(
+        "https://nfce-homologacao.sefazrs.rs.gov.br/ws/NfeAutorizacao/NFeAutorizacao4.asmx",
+        url(
+            43,
+            ModeloDocumentoFiscal::Nfce,
+            TipoAmbiente::Homologacao,
+            Servico::Autorizacao
+        )?
+    );
+    assert_eq!(
+        "https://homologacao.nfe.fazenda.sp.gov.br/ws/nfestatusservico4.asmx",
+        url(
+            35,
+            ModeloDocumentoFiscal::Nfe,
+            TipoAmbiente::Homologacao,
+            Servico::StatusServico
+        )?
+    );
+    assert_eq!(
+        "https://nfce.svrs.rs.gov.br/ws/NfeConsulta/NfeConsulta4.asmx",
+        url(
+            42,
+            ModeloDocumentoFiscal::Nfce,
+            TipoAmbiente::Producao,
+            Servico::ConsultaProtocolo
+        )?
+    );
+
+    // Distribuição sempre no Ambiente Nacional
+    assert_eq!(
+        "https://www1.nfe.fazenda.gov.br/NFeDistribuicaoDFe/NFeDistribuicaoDFe.asmx",
+        url(
+            43,
+            ModeloDocumentoFiscal::Nfe,
+            TipoAmbiente::Producao,
+            Servico::DistribuicaoDFe
+        )?
+    );
+    assert_eq!(
+        "https://hom1.nfe.fazenda.gov.br/NFeRecepcaoEvento4/NFeRecepcaoEvento4.asmx",
+        ws.url_autorizador(
+            Autorizador::An,
+            ModeloDocumentoFiscal::Nfe,
+            TipoAmbiente::Homologacao,
+            Servico::RecepcaoEvento
+        )
+        .map_err(|e| e.to_string())?
+    );
+
+    // Eventos do Ambiente Nacional, como a manifestação e o EPEC
+    assert_eq!(
+        "https://www.nfe.fazenda.gov.br/NFeRecepcaoEvento4/NFeRecepcaoEvento4.asmx",
+        url(
+            ORGAO_AMBIENTE_NACIONAL,
+            ModeloDocumentoFiscal::Nfe,
+            TipoAmbiente::Producao,
+            Servico::RecepcaoEvento
+        )?
+    );
+    assert_eq!(
+        "https://nfe.sefazrs.rs.gov.br/ws/recepcaoevento/recepcaoevento4.asmx",
+        url(
+            43,
+            ModeloDocumentoFiscal::Nfe,
+            TipoAmbiente::Producao,
+            Servico::RecepcaoEvento
+        )?
+    );
+    assert!(url(
+        ORGAO_AMBIENTE_NACIONAL,
+        ModeloDocumentoFiscal::Nfe,
+        TipoAmbiente::Producao,
+        Servico::Autorizacao
+    )
+    .is_err());
+
+    // Contingência, que não possui inutilização
+    assert_eq!(
+        "https://www.svc.fazenda.gov.br/NFeAutorizacao4/NFeAutorizacao4.asmx",
+        ws.url_contingencia(
+            43,
+            ModeloDocumentoFiscal::Nfe,
+            TipoAmbiente::Producao,
+            Servico::Autorizacao
+        )
+        .map_err(|e| e.to_string())?
+    );
+    assert_eq!(
+        "Web service não encontrado. SVC-AN sem o serviço NFeInutilizacao4 para o modelo 55 em Producao",
+        ws.url_contingencia(
+            43,
+            ModeloDocumentoFiscal::Nfe,
+            TipoAmbiente::Producao,
+            Servico::Inutilizacao
+        )
+        .map_err(|e| e.to_string())
+        .unwrap_err()
+    );
+
+    // Os serviços de autorização existem em todos os autorizadores das UFs
+    for uf in Uf::TODAS.iter() {
+        for modelo in [ModeloDocumentoFiscal::Nfe, ModeloDocumentoFiscal::Nfce].iter() {
+            for ambiente in [TipoAmbiente::Producao, TipoAmbiente::Homologacao].iter() {
+                url(uf.codigo(), *modelo, *ambiente, Servico::Autorizacao)?;
+                url(uf.codigo(), *modelo, *ambiente, Servico::RetornoAutorizacao)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn substituicoes() -> Result<(), String> {
+    let mut ws = WebServices::new();
+    ws.substituir(
+        43,
+        ModeloDocumentoFiscal::Nfe,
+        TipoAmbiente::Homologacao,
+        Servico::Autorizacao,
+        "https://127.0.0.1:8443/autorizacao",
+    );
+    ws.substituir_servico(Servico::StatusServico, "https://127.0.0.1:8443/status");
+
+    let url = |ws: &WebServices, uf, ambiente, servico| {
+        ws.url(uf, ModeloDocumentoFiscal::Nfe, ambiente, servico)
+            .map_err(|e| e.to_string())
+    };
+
+    assert_eq!(
+        "https://127.0.0.1:8443/autorizacao",
+        url(&ws, 43, TipoAmbiente::Homologacao, Servico::Autorizacao)?
+    );
+    // Apenas a UF e o ambiente substituídos
+    assert_eq!(
+        "https://nfe.sefazrs.rs.gov.br/ws/NfeAutorizacao/NFeAutorizacao4.asmx",
+        url(&ws, 43, TipoAmbiente::Producao, Servico::Autorizacao)?
+    );
+    assert_eq!(
+        "https://127.0.0.1:8443/status",
+        url(&ws, 35, TipoAmbiente::Producao, Servico::StatusServico)?
+    );
+
+    // A substituição da UF também vale na contingência
+    assert_eq!(
+        Ok("https://127.0.0.1:8443/autorizacao".to_string()),
+        ws.url_contingencia(
+            43,
+            ModeloDocumentoFiscal::Nfe,
+            TipoAmbiente::Homologacao,
+            Servico::Autorizacao
+        )
+        .map_err(|e| e.to_string())
+    );
+    assert_eq!(
+        Ok("https://hom.svc.fazenda.gov.br/NFeAutorizacao4/NFeAutorizacao4.asmx".to_string()),
+        ws.url_contingencia(
+            35,
+            ModeloDocumentoFiscal::Nfe,
+            TipoAmbiente::Homologacao,
+            Servico::Autorizacao
+        )
+        .map_err(|e| e.to_string())
+    );
+
+    // Tabela atualizada no lugar da embarcada
+    let ws = WebServices::com_tabela(
+        "autorizador;modelo;ambiente;servico;url\n\
+         RS;55;2;NFeAutorizacao4;https://novo.sefazrs.rs.gov.br/NFeAutorizacao4\n"
+            .to_string(),
+    );
+    assert_eq!(
+        "https://novo.sefazrs.rs.gov.br/NFeAutorizacao4",
+        url(&ws, 43, TipoAmbiente::Homologacao, Servico::Autorizacao)?
+    );
+    assert!(url(&ws, 43, TipoAmbiente::Producao, Servico::Autorizacao).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn servicos() -> Result<(), String> {
+    assert_eq!(
+        "http://www.portalfiscal.inf.br/nfe/wsdl/NFeAutorizacao4",
+        Servico::Autorizacao.namespace()
+    );
+    assert_eq!(
+        "http://www.portalfiscal.inf.br/nfe/wsdl/NFeRetAutorizacao4/nfeRetAutorizacaoLote",
+        Servico::RetornoAutorizacao.acao()
+    );
+    assert_eq!(
+        "http://www.portalfiscal.inf.br/nfe/wsdl/CadConsultaCadastro4/consultaCadastro",
+        Servico::ConsultaCadastro.acao()
+    );
+
+    for servico in Servico::TODOS.iter() {
+        assert_eq!(
+            *servico,
+            servico
+                .to_string()
+                .parse::<Servico>()
+                .map_err(|e| e.to_string())?
+        );
+    }
+
+    Ok(())
+}